@@ -13,6 +13,7 @@ pub struct Fixture {
     repo: Option<Repository>,
     cwd: Option<PathBuf>,
     tempdir: Option<TempDir>,
+    bare_path: Option<PathBuf>,
 }
 
 impl Fixture {
@@ -21,9 +22,16 @@ impl Fixture {
             repo: Some(repo),
             tempdir: Some(tempdir),
             cwd: Some(cwd),
+            bare_path: None,
         }
     }
 
+    /// Record where the bare repository lives, for bare fixtures
+    pub(crate) fn with_bare_path(mut self, bare_path: PathBuf) -> Self {
+        self.bare_path = Some(bare_path);
+        self
+    }
+
     pub fn destroy(&mut self) -> Result<()> {
         if let Some(tempdir) = self.tempdir.take() {
             tempdir.close()?
@@ -31,6 +39,7 @@ impl Fixture {
         self.cwd = None;
         self.tempdir = None;
         self.repo = None;
+        self.bare_path = None;
         Ok(())
     }
 
@@ -88,6 +97,13 @@ impl Fixture {
         Ok(ChildPath::new(tempdir.path()))
     }
 
+    /// The bare repository's directory (`<root>/.bare`, unless the builder's `bare_dir`
+    /// names another)
+    pub fn bare_path(&self) -> Result<ChildPath> {
+        let path = self.bare_path.as_ref().ok_or("Not a bare fixture")?;
+        Ok(ChildPath::new(path))
+    }
+
     pub fn repo(&self) -> Result<&Repository> {
         self.repo.as_ref().ok_or_else(|| "No repository".into())
     }
@@ -142,11 +158,20 @@ impl FixtureAssert for Fixture {
     }
 }
 
+/// A change staged by [`CommitBuilder`], applied in the order it was added.
+enum Change {
+    Write(String, String), // (path, content)
+    Delete(String),
+    Rename(String, String), // (from, to)
+}
+
 /// Builder for creating commits with multiple files
 pub struct CommitBuilder<'a> {
     fixture: &'a Fixture,
     worktree_name: &'a str,
-    files: Vec<(String, String)>, // (path, content)
+    changes: Vec<Change>,
+    author: Option<(String, String)>, // (name, email)
+    timestamp: Option<i64>,
 }
 
 impl<'a> CommitBuilder<'a> {
@@ -154,13 +179,41 @@ impl<'a> CommitBuilder<'a> {
         Self {
             fixture,
             worktree_name,
-            files: Vec::new(),
+            changes: Vec::new(),
+            author: None,
+            timestamp: None,
         }
     }
 
     /// Add a file to be committed
     pub fn file(mut self, path: &str, content: &str) -> Self {
-        self.files.push((path.to_string(), content.to_string()));
+        self.changes
+            .push(Change::Write(path.to_string(), content.to_string()));
+        self
+    }
+
+    /// Delete a tracked file in the commit
+    pub fn delete(mut self, path: &str) -> Self {
+        self.changes.push(Change::Delete(path.to_string()));
+        self
+    }
+
+    /// Rename (move) a tracked file in the commit
+    pub fn rename(mut self, from: &str, to: &str) -> Self {
+        self.changes
+            .push(Change::Rename(from.to_string(), to.to_string()));
+        self
+    }
+
+    /// Set the commit's author (the committer stays "Test User")
+    pub fn author(mut self, name: &str, email: &str) -> Self {
+        self.author = Some((name.to_string(), email.to_string()));
+        self
+    }
+
+    /// Set the author and commit time, in seconds since the epoch (UTC), instead of now
+    pub fn timestamp(mut self, epoch: i64) -> Self {
+        self.timestamp = Some(epoch);
         self
     }
 
@@ -183,40 +236,67 @@ impl<'a> CommitBuilder<'a> {
         }
 
         let worktree_repo = Repository::open(&worktree_path)?;
+        let mut index = worktree_repo.index()?;
 
-        // Write all files
-        for (path, content) in &self.files {
-            let file_path = worktree_path.join(path);
-
-            // Create parent directories if needed
-            if let Some(parent) = file_path.parent() {
-                std::fs::create_dir_all(parent)?;
+        // Apply and stage every change
+        for change in &self.changes {
+            match change {
+                Change::Write(path, content) => {
+                    write_file(&worktree_path.join(path), content)?;
+                    index.add_path(Path::new(path))?;
+                }
+                Change::Delete(path) => {
+                    std::fs::remove_file(worktree_path.join(path))?;
+                    index.remove_path(Path::new(path))?;
+                }
+                Change::Rename(from, to) => {
+                    let content = std::fs::read(worktree_path.join(from))?;
+                    std::fs::remove_file(worktree_path.join(from))?;
+                    write_file(&worktree_path.join(to), content)?;
+                    index.remove_path(Path::new(from))?;
+                    index.add_path(Path::new(to))?;
+                }
             }
-
-            std::fs::write(&file_path, content)?;
-        }
-
-        // Stage all files
-        let mut index = worktree_repo.index()?;
-        for (path, _) in &self.files {
-            index.add_path(Path::new(path))?;
         }
         index.write()?;
 
         // Create commit
         let tree_id = index.write_tree()?;
         let tree = worktree_repo.find_tree(tree_id)?;
-        let sig = git2::Signature::now("Test User", "test@example.com")?;
+        let signature = |name: &str, email: &str| match self.timestamp {
+            Some(epoch) => git2::Signature::new(name, email, &git2::Time::new(epoch, 0)),
+            None => git2::Signature::now(name, email),
+        };
+        let committer = signature("Test User", "test@example.com")?;
+        let author = match &self.author {
+            Some((name, email)) => signature(name, email)?,
+            None => committer.clone(),
+        };
 
         let parent_commit = worktree_repo.head()?.peel_to_commit()?;
 
-        let commit_oid =
-            worktree_repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &[&parent_commit])?;
+        let commit_oid = worktree_repo.commit(
+            Some("HEAD"),
+            &author,
+            &committer,
+            message,
+            &tree,
+            &[&parent_commit],
+        )?;
 
         Ok(commit_oid)
     }
 }
 
+/// Write a file, creating parent directories if needed
+fn write_file(path: &Path, content: impl AsRef<[u8]>) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content)?;
+    Ok(())
+}
+
 impl Drop for Fixture {
     fn drop(&mut self) {
         self.destroy().unwrap();
@@ -17,7 +17,7 @@ pub enum RemoteSource {
 impl From<&Fixture> for RemoteSource {
     fn from(fixture: &Fixture) -> Self {
         match fixture.repo().unwrap().is_bare() {
-            true => RemoteSource::Path(fixture.root().unwrap().path().join(".bare")),
+            true => RemoteSource::Path(fixture.bare_path().unwrap().to_path_buf()),
             false => RemoteSource::Path(fixture.root().unwrap().to_path_buf()),
         }
     }
@@ -46,8 +46,10 @@ impl RemoteSource {
 
 pub struct FixtureBuilder<'fixture> {
     bare: bool,
+    bare_dir: &'fixture str,
     default_branch: &'fixture str,
     worktrees: Vec<&'fixture str>,
+    orphan_branches: Vec<&'fixture str>,
     remotes: Vec<(String, RemoteSource)>,
     upstreams: Vec<(String, String)>, // (local_branch, remote_branch)
     configs: Vec<(String, String)>,   // (key, value) for git config
@@ -57,8 +59,10 @@ impl<'fixture> FixtureBuilder<'fixture> {
     pub fn new() -> Self {
         Self {
             bare: false,
+            bare_dir: ".bare",
             default_branch: "main",
             worktrees: Vec::new(),
+            orphan_branches: Vec::new(),
             remotes: Vec::new(),
             upstreams: Vec::new(),
             configs: Vec::new(),
@@ -70,6 +74,13 @@ impl<'fixture> FixtureBuilder<'fixture> {
         self
     }
 
+    /// Name the bare repository's directory, `.bare` by default
+    /// Only used with `bare(true)`
+    pub fn bare_dir(mut self, bare_dir: &'fixture str) -> Self {
+        self.bare_dir = bare_dir;
+        self
+    }
+
     pub fn default_branch(mut self, default_branch: &'fixture str) -> Self {
         self.default_branch = default_branch;
         self
@@ -83,6 +94,20 @@ impl<'fixture> FixtureBuilder<'fixture> {
         self
     }
 
+    /// Add a worktree on a new orphan branch, whose history starts at an empty root commit
+    /// Counts as a worktree for ordering: the Fixture is opened in the last one specified
+    pub fn orphan_worktree(mut self, worktree: &'fixture str) -> Self {
+        self.orphan_branches.push(worktree);
+        self.worktrees.push(worktree);
+        self
+    }
+
+    /// Add an orphan branch, whose history starts at an empty root commit, without a worktree
+    pub fn orphan_branch(mut self, branch: &'fixture str) -> Self {
+        self.orphan_branches.push(branch);
+        self
+    }
+
     /// Add a remote to the repository
     pub fn remote(mut self, name: &str, source: impl Into<RemoteSource>) -> Self {
         self.remotes.push((name.to_string(), source.into()));
@@ -107,7 +132,7 @@ impl<'fixture> FixtureBuilder<'fixture> {
     pub fn build(self) -> Result<Fixture> {
         let tmpdir = TempDir::new()?;
         let path = tmpdir.path().join(if self.bare {
-            ".bare"
+            self.bare_dir
         } else {
             self.default_branch
         });
@@ -130,7 +155,8 @@ impl<'fixture> FixtureBuilder<'fixture> {
             config.set_multivar(key, "^$", value)?;
         }
 
-        empty_commit(&repo)?;
+        // Unsigned, like every other commit the fixture makes
+        empty_commit(&repo, false)?;
 
         if repo
             .find_branch(self.default_branch, BranchType::Local)
@@ -148,6 +174,21 @@ impl<'fixture> FixtureBuilder<'fixture> {
             }
         }
 
+        // Create orphan branches: an empty root commit each, named after the branch so it
+        // can't be the same commit as the default branch's
+        for branch in &self.orphan_branches {
+            let sig = repo.signature()?;
+            let tree = repo.find_tree(repo.treebuilder(None)?.write()?)?;
+            repo.commit(
+                Some(&format!("refs/heads/{}", branch)),
+                &sig,
+                &sig,
+                &format!("Initial commit on {}", branch),
+                &tree,
+                &[],
+            )?;
+        }
+
         // Create worktrees
         for worktree in &self.worktrees {
             if *worktree == self.default_branch && !self.bare {
@@ -161,6 +202,17 @@ impl<'fixture> FixtureBuilder<'fixture> {
             let mut worktree_opts = WorktreeAddOptions::new();
             worktree_opts.checkout_existing(self.bare);
 
+            // Orphan worktrees check out their own branch rather than one made from HEAD
+            let orphan_ref = if self.orphan_branches.contains(worktree) {
+                Some(
+                    repo.find_branch(worktree, BranchType::Local)?
+                        .into_reference(),
+                )
+            } else {
+                None
+            };
+            worktree_opts.reference(orphan_ref.as_ref());
+
             repo.worktree(worktree, &worktree_path, Some(&worktree_opts))?;
         }
 
@@ -192,15 +244,20 @@ impl<'fixture> FixtureBuilder<'fixture> {
             local_branch.set_upstream(Some(remote_branch))?;
         }
 
-        if self.worktrees.is_empty() {
+        let bare_path = self.bare.then(|| path.clone());
+        let fixture = if self.worktrees.is_empty() {
             // No worktrees specified - return the main repo
-            Ok(Fixture::new(repo, path, tmpdir))
+            Fixture::new(repo, path, tmpdir)
         } else {
             // Open the repository from the worktree path instead of using the bare/main repo
             let worktree_path = tmpdir.path().join(self.worktrees.last().unwrap());
             let worktree_repo = Repository::open(&worktree_path)?;
-            Ok(Fixture::new(worktree_repo, worktree_path, tmpdir))
-        }
+            Fixture::new(worktree_repo, worktree_path, tmpdir)
+        };
+        Ok(match bare_path {
+            Some(bare_path) => fixture.with_bare_path(bare_path),
+            None => fixture,
+        })
     }
 }
 
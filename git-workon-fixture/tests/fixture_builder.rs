@@ -677,6 +677,59 @@ mod fixture_builder {
         Ok(())
     }
 
+    #[test]
+    fn fixture_commit_builder_author_and_timestamp() -> Result<(), Box<dyn std::error::Error>> {
+        let fixture = FixtureBuilder::new().bare(true).worktree("main").build()?;
+
+        let commit_oid = fixture
+            .commit("main")
+            .file("file.txt", "content")
+            .author("Ada Lovelace", "ada@example.com")
+            .timestamp(1_700_000_000)
+            .create("Dated commit")?;
+
+        let commit = fixture.repo()?.find_commit(commit_oid)?;
+        assert_eq!(commit.author().name(), Some("Ada Lovelace"));
+        assert_eq!(commit.author().email(), Some("ada@example.com"));
+        assert_eq!(commit.author().when().seconds(), 1_700_000_000);
+        assert_eq!(commit.committer().name(), Some("Test User"));
+        assert_eq!(commit.time().seconds(), 1_700_000_000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fixture_commit_builder_delete_and_rename() -> Result<(), Box<dyn std::error::Error>> {
+        let fixture = FixtureBuilder::new().bare(true).worktree("main").build()?;
+
+        fixture
+            .commit("main")
+            .file("keep.txt", "keep")
+            .file("old.txt", "moved")
+            .file("gone.txt", "gone")
+            .create("Add files")?;
+        let commit_oid = fixture
+            .commit("main")
+            .delete("gone.txt")
+            .rename("old.txt", "dir/new.txt")
+            .create("Delete and rename")?;
+
+        let repo = fixture.repo()?;
+        let tree = repo.find_commit(commit_oid)?.tree()?;
+        assert!(tree.get_name("keep.txt").is_some());
+        assert!(tree.get_name("gone.txt").is_none());
+        assert!(tree.get_name("old.txt").is_none());
+        let renamed = tree.get_path(std::path::Path::new("dir/new.txt"))?;
+        let blob = repo.find_blob(renamed.id())?;
+        assert_eq!(blob.content(), b"moved");
+
+        let worktree = fixture.root()?.join("main");
+        assert!(!worktree.join("gone.txt").exists());
+        assert!(worktree.join("dir/new.txt").exists());
+
+        Ok(())
+    }
+
     #[test]
     fn fixture_update_branch() -> Result<(), Box<dyn std::error::Error>> {
         let fixture = FixtureBuilder::new().bare(true).worktree("main").build()?;
@@ -710,7 +763,7 @@ mod fixture_builder {
             .worktree("docs")
             .build()?;
 
-        let bare_path = fixture.root()?.join(".bare");
+        let bare_path = fixture.bare_path()?;
         let bare_repo = Repository::open(&bare_path)?;
 
         // Verify all worktrees were created
@@ -778,4 +831,97 @@ mod fixture_builder {
 
         Ok(())
     }
+
+    #[test]
+    fn orphan_worktree() -> Result<(), Box<dyn std::error::Error>> {
+        let fixture = FixtureBuilder::new()
+            .bare(true)
+            .worktree("main")
+            .orphan_worktree("docs")
+            .build()?;
+
+        // Fixture is opened in the orphan worktree, on its own branch
+        assert_eq!(fixture.cwd()?.file_name(), Some(OsStr::new("docs")));
+        let head = fixture.head()?;
+        assert_eq!(head.shorthand(), Some("docs"));
+
+        // Its history is a single, empty root commit unrelated to main
+        let docs = head.peel_to_commit()?;
+        assert_eq!(docs.parent_count(), 0);
+        assert!(docs.tree()?.is_empty());
+        let repo = fixture.repo()?;
+        let main = repo
+            .find_branch("main", BranchType::Local)?
+            .get()
+            .peel_to_commit()?;
+        assert_ne!(docs.id(), main.id());
+        assert!(repo.merge_base(docs.id(), main.id()).is_err());
+
+        // Commits land on the orphan branch
+        let oid = fixture
+            .commit("docs")
+            .file("index.md", "# Docs")
+            .create("Add docs")?;
+        assert_eq!(repo.find_commit(oid)?.parent_id(0)?, docs.id());
+
+        Ok(())
+    }
+
+    #[test]
+    fn orphan_branch() -> Result<(), Box<dyn std::error::Error>> {
+        let fixture = FixtureBuilder::new()
+            .bare(true)
+            .worktree("main")
+            .orphan_branch("gh-pages")
+            .build()?;
+
+        let repo = fixture.repo()?;
+        let pages = repo
+            .find_branch("gh-pages", BranchType::Local)?
+            .get()
+            .peel_to_commit()?;
+        assert_eq!(pages.parent_count(), 0);
+        assert!(pages.tree()?.is_empty());
+
+        // No worktree is created for it
+        let bare = Repository::open_bare(fixture.bare_path()?)?;
+        let worktrees = bare.worktrees()?;
+        assert_eq!(worktrees.iter().flatten().collect::<Vec<_>>(), vec!["main"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn bare_dir() -> Result<(), Box<dyn std::error::Error>> {
+        let fixture = FixtureBuilder::new()
+            .bare(true)
+            .bare_dir("custom")
+            .worktree("main")
+            .build()?;
+
+        let bare_path = fixture.bare_path()?;
+        assert_eq!(bare_path.path(), fixture.root()?.join("custom"));
+        assert!(Repository::open_bare(&bare_path)?.is_bare());
+        assert!(!fixture.root()?.join(".bare").exists());
+
+        // Worktrees still live next to it
+        assert_eq!(fixture.cwd()?.path(), fixture.root()?.join("main"));
+        assert_eq!(
+            fixture.repo()?.commondir().canonicalize()?,
+            bare_path.canonicalize()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn bare_path_requires_bare() -> Result<(), Box<dyn std::error::Error>> {
+        let fixture = FixtureBuilder::new().build()?;
+        assert!(fixture.bare_path().is_err());
+
+        let fixture = FixtureBuilder::new().bare(true).build()?;
+        assert_eq!(fixture.bare_path()?.path(), fixture.root()?.join(".bare"));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,115 @@
+//! Branch and worktree name validation.
+//!
+//! `git workon new` checks names against git's ref name rules (`git check-ref-format`)
+//! before creating anything, so a name like `my feature` fails with a clear error and a
+//! sanitized suggestion instead of a cryptic libgit2 error halfway through.
+//!
+//! `workon.nameStyle` picks what happens to names that aren't already in the preferred
+//! style:
+//!
+//! - `preserve` (default): names are used as given, and rejected if git can't use them
+//! - `kebab`: each `/`-separated part is lowercased and kebab-cased
+//!   (`Lettertwo/Fix Login` → `lettertwo/fix-login`)
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::{ConfigError, Result, WorktreeError};
+
+/// How `new` normalizes branch names (`workon.nameStyle`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameStyle {
+    /// Use names as given
+    #[default]
+    Preserve,
+    /// Lowercase kebab-case each path segment
+    Kebab,
+}
+
+impl FromStr for NameStyle {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "preserve" => Ok(NameStyle::Preserve),
+            "kebab" => Ok(NameStyle::Kebab),
+            _ => Err(ConfigError::InvalidNameStyle(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for NameStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameStyle::Preserve => write!(f, "preserve"),
+            NameStyle::Kebab => write!(f, "kebab"),
+        }
+    }
+}
+
+/// Apply `style` to a branch name and check that git accepts the result.
+///
+/// Errors with a sanitized suggestion when the name isn't a valid branch name.
+pub fn normalize_branch_name(name: &str, style: NameStyle) -> Result<String> {
+    let normalized = match style {
+        NameStyle::Preserve => name.to_string(),
+        NameStyle::Kebab => sanitize_branch_path(name),
+    };
+    if is_valid_branch_name(&normalized) {
+        return Ok(normalized);
+    }
+    let suggestion =
+        Some(sanitize_branch_path(name)).filter(|s| s != name && is_valid_branch_name(s));
+    Err(WorktreeError::InvalidBranchName {
+        name: name.to_string(),
+        suggestion,
+    }
+    .into())
+}
+
+/// Whether git accepts `name` as a branch name.
+pub fn is_valid_branch_name(name: &str) -> bool {
+    git2::Branch::name_is_valid(name).unwrap_or(false)
+}
+
+/// Sanitize each `/`-separated segment, keeping the slashes (dropping empty segments).
+fn sanitize_branch_path(name: &str) -> String {
+    name.split('/')
+        .map(sanitize_for_branch_name)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Sanitize a string for use in branch/worktree names.
+///
+/// Lowercases it and replaces anything but letters, digits, `-` and `_` with dashes
+/// (collapsed, and trimmed from the ends), e.g. `Fix Bug #123` → `fix-bug-123`.
+pub fn sanitize_for_branch_name(s: &str) -> String {
+    let sanitized = s
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
+            ' ' | '/' => '-',
+            _ => '-',
+        })
+        .collect::<String>()
+        .to_lowercase();
+
+    // Collapse multiple dashes into single dash
+    let mut result = String::new();
+    let mut last_was_dash = false;
+    for c in sanitized.chars() {
+        if c == '-' {
+            if !last_was_dash {
+                result.push(c);
+            }
+            last_was_dash = true;
+        } else {
+            result.push(c);
+            last_was_dash = false;
+        }
+    }
+
+    result.trim_matches(|c| c == '-' || c == '_').to_string()
+}
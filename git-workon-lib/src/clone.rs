@@ -1,12 +1,47 @@
-use std::path::PathBuf;
+//! Cloning into a workon layout.
+//!
+//! `clone` sets up `<path>/.bare` and fetches the remote's default branch into it. Network
+//! blips on large repositories are retried with exponential backoff (see
+//! [`CloneOptions`]); a remote that can't be reached at all (an unknown host, a refused
+//! connection) fails straight away. If every attempt fails, the partial repository is kept, marked
+//! with `.bare/workon/clone-in-progress`; cloning the same URL into the same path again
+//! resumes it, so objects already fetched are reused instead of downloaded again.
 
-use git2::{build::RepoBuilder, FetchOptions, Repository};
-use log::debug;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
 
-use crate::error::Result;
-use crate::{convert_to_bare, get_default_branch_name, get_remote_callbacks};
+use git2::{ErrorClass, ErrorCode, Repository};
+use tracing::{debug, instrument, warn};
+
+use crate::error::{RepoError, Result, WorkonError};
+use crate::{convert_to_bare, fetch_refspecs, get_default_branch_name};
+
+/// How hard `clone` tries before giving up.
+#[derive(Debug, Clone)]
+pub struct CloneOptions {
+    /// Attempts at fetching, including the first
+    pub attempts: u32,
+    /// Wait before the first retry, doubled for each one after it
+    pub retry_delay: Duration,
+}
+
+impl Default for CloneOptions {
+    fn default() -> Self {
+        Self {
+            attempts: 4,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+}
 
 pub fn clone(path: PathBuf, url: &str) -> Result<Repository> {
+    clone_with_options(path, url, &CloneOptions::default())
+}
+
+#[instrument(level = "debug", skip(path, options))]
+pub fn clone_with_options(path: PathBuf, url: &str, options: &CloneOptions) -> Result<Repository> {
     debug!("path {}", path.display());
     let path = if path.ends_with(".bare") {
         debug!("ended with .bare!");
@@ -18,41 +53,133 @@ pub fn clone(path: PathBuf, url: &str) -> Result<Repository> {
 
     debug!("final path {}", path.display());
 
-    let mut fetch_options = FetchOptions::new();
-    fetch_options.remote_callbacks(get_remote_callbacks()?);
-
-    let mut builder = RepoBuilder::new();
-    builder.bare(true);
-    builder.fetch_options(fetch_options);
-    builder.remote_create(|repo, name, url| {
-        debug!("Creating remote {} at {}", name, url);
-        let remote = repo.remote(name, url)?;
-
-        match get_default_branch_name(repo, Some(remote)) {
-            Ok(default_branch) => {
-                debug!("Default branch: {}", default_branch);
-                repo.remote_add_fetch(
-                    name,
-                    format!(
-                        "+refs/heads/{default_branch}:refs/remotes/origin/{default_branch}",
-                        default_branch = default_branch
-                    )
-                    .as_str(),
-                )?;
-                repo.find_remote(name)
+    // 1. git clone --single-branch <url>.git <path>/.bare
+    let (repo, fresh) = open_or_init(&path, url)?;
+    let mut delay = options.retry_delay;
+    let mut attempt = 1;
+    // Only a transfer that got going is worth retrying; failing to reach the remote at all
+    // won't fix itself between attempts
+    let mut connected = false;
+    let default_branch = loop {
+        match fetch_default_branch(&repo, &mut connected) {
+            Ok(default_branch) => break default_branch,
+            Err(e) if is_transient(&e) && connected && attempt < options.attempts => {
+                warn!(
+                    "Clone of {} interrupted ({}), retrying in {}s ({}/{})",
+                    url,
+                    e,
+                    delay.as_secs_f32(),
+                    attempt,
+                    options.attempts - 1
+                );
+                thread::sleep(delay);
+                delay *= 2;
+                attempt += 1;
+            }
+            Err(e) if is_transient(&e) && (connected || !fresh) => {
+                return Err(RepoError::CloneInterrupted {
+                    url: url.to_string(),
+                    message: e.to_string(),
+                }
+                .into());
             }
-            Err(_) => {
-                debug!("No default branch found");
-                repo.remote(name, url)
+            Err(e) => {
+                // Nothing worth resuming, e.g. a wrong URL, an unreachable host or
+                // rejected credentials
+                if fresh {
+                    let _ = fs::remove_dir_all(&path);
+                }
+                return Err(e);
             }
         }
-    });
-
-    debug!("Cloning {} into {}", url, path.display());
+    };
+    if let Some(default_branch) = default_branch {
+        checkout_default_branch(&repo, &default_branch)?;
+    }
+    fs::remove_file(marker(&path))?;
 
-    // 1. git clone --single-branch <url>.git <path>/.bare
-    let repo = builder.clone(url, &path)?;
     // 2. $ echo "gitdir: ./.bare" > .git
     // 3. $ git config remote.origin.fetch "+refs/heads/*:refs/remotes/origin/*"
     convert_to_bare(repo)
 }
+
+/// Where an interrupted clone records the URL it was cloning.
+fn marker(path: &Path) -> PathBuf {
+    path.join("workon").join("clone-in-progress")
+}
+
+/// Resume an interrupted clone of `url` at `path`, or start a new one. Returns the
+/// repository and whether it's new.
+fn open_or_init(path: &Path, url: &str) -> Result<(Repository, bool)> {
+    let in_use = path
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some());
+    if in_use {
+        let resumable = fs::read_to_string(marker(path)).is_ok_and(|marked| marked.trim() == url);
+        if !resumable {
+            return Err(RepoError::CloneTargetExists(path.to_path_buf()).into());
+        }
+        debug!("Resuming interrupted clone at {}", path.display());
+        return Ok((Repository::open_bare(path)?, false));
+    }
+
+    debug!("Cloning {} into {}", url, path.display());
+    let repo = Repository::init_bare(path)?;
+    repo.remote("origin", url)?;
+    fs::create_dir_all(path.join("workon"))?;
+    fs::write(marker(path), url)?;
+    Ok((repo, true))
+}
+
+/// Fetch the remote's default branch, or every branch if it has none. Returns the default
+/// branch's name, and sets `connected` once the remote has been reached.
+fn fetch_default_branch(repo: &Repository, connected: &mut bool) -> Result<Option<String>> {
+    let default_branch = match get_default_branch_name(repo, Some(repo.find_remote("origin")?)) {
+        Ok(default_branch) => Some(default_branch),
+        Err(e) if is_transient(&e) => return Err(e),
+        Err(e) => {
+            debug!("No default branch found: {}", e);
+            None
+        }
+    };
+    *connected = true;
+    let refspec = match &default_branch {
+        Some(branch) => format!("+refs/heads/{branch}:refs/remotes/origin/{branch}"),
+        None => "+refs/heads/*:refs/remotes/origin/*".to_string(),
+    };
+    fetch_refspecs(repo, "origin", &[refspec.as_str()], Some("clone"))?;
+    Ok(default_branch)
+}
+
+/// Create the default branch from its remote-tracking branch and point HEAD at it, as
+/// `git clone` does.
+fn checkout_default_branch(repo: &Repository, default_branch: &str) -> Result<()> {
+    debug!("Default branch: {}", default_branch);
+    let remote_ref = format!("refs/remotes/origin/{}", default_branch);
+    let commit = repo.find_reference(&remote_ref)?.peel_to_commit()?;
+    let mut branch = repo.branch(default_branch, &commit, true)?;
+    branch.set_upstream(Some(&format!("origin/{}", default_branch)))?;
+    repo.set_head(&format!("refs/heads/{}", default_branch))?;
+    repo.reference_symbolic("refs/remotes/origin/HEAD", &remote_ref, true, "clone")?;
+    repo.remote_add_fetch(
+        "origin",
+        &format!("+refs/heads/{default_branch}:refs/remotes/origin/{default_branch}"),
+    )?;
+    Ok(())
+}
+
+/// Whether an error is worth retrying: a dropped connection or failed transfer, not a
+/// missing repository or rejected credentials.
+fn is_transient(e: &WorkonError) -> bool {
+    match e.git_error() {
+        Some(e) => {
+            e.code() != ErrorCode::Auth
+                && (e.code() == ErrorCode::Eof
+                    || matches!(
+                        e.class(),
+                        ErrorClass::Net | ErrorClass::Os | ErrorClass::Http | ErrorClass::Zlib
+                    ))
+        }
+        None => false,
+    }
+}
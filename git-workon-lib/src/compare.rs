@@ -0,0 +1,79 @@
+//! Comparing two worktrees' histories.
+//!
+//! [`compare_worktrees`] finds where two worktrees' HEADs diverged: their merge base and
+//! the commits each has that the other doesn't, as `git log b..a` and `git log a..b`
+//! would list them (newest first).
+
+use git2::{Oid, Sort};
+
+use crate::error::{Result, WorktreeError};
+use crate::WorktreeDescriptor;
+
+/// A commit's id and the first line of its message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitSummary {
+    pub id: Oid,
+    pub summary: String,
+}
+
+/// How two worktrees' histories relate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// The best common ancestor, or None for unrelated histories
+    pub merge_base: Option<Oid>,
+    /// Commits only the first worktree has
+    pub only_a: Vec<CommitSummary>,
+    /// Commits only the second worktree has
+    pub only_b: Vec<CommitSummary>,
+}
+
+impl Divergence {
+    /// How many commits the first worktree is ahead of the second.
+    pub fn ahead(&self) -> usize {
+        self.only_a.len()
+    }
+
+    /// How many commits the first worktree is behind the second.
+    pub fn behind(&self) -> usize {
+        self.only_b.len()
+    }
+}
+
+/// Compare the HEADs of worktrees `a` and `b`.
+pub fn compare_worktrees(a: &WorktreeDescriptor, b: &WorktreeDescriptor) -> Result<Divergence> {
+    let head = |wt: &WorktreeDescriptor| -> Result<Oid> {
+        wt.head_commit_info()?
+            .map(|(oid, _)| oid)
+            .ok_or_else(|| WorktreeError::NoHeadCommit(wt.name().unwrap_or_default().into()).into())
+    };
+    let (a_head, b_head) = (head(a)?, head(b)?);
+
+    // Worktrees share their object database, so either repository can see both heads
+    let repo = a.repo()?;
+    let merge_base = match repo.merge_base(a_head, b_head) {
+        Ok(oid) => Some(oid),
+        Err(e) if e.code() == git2::ErrorCode::NotFound => None,
+        Err(e) => return Err(e.into()),
+    };
+
+    let only = |include: Oid, exclude: Oid| -> Result<Vec<CommitSummary>> {
+        let mut walk = repo.revwalk()?;
+        walk.set_sorting(Sort::TOPOLOGICAL | Sort::TIME)?;
+        walk.push(include)?;
+        walk.hide(exclude)?;
+        walk.map(|oid| {
+            let commit = repo.find_commit(oid?)?;
+            Ok(CommitSummary {
+                id: commit.id(),
+                summary: commit.summary().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+    };
+
+    Ok(Divergence {
+        merge_base,
+        only_a: only(a_head, b_head)?,
+        only_b: only(b_head, a_head)?,
+    })
+}
@@ -18,14 +18,38 @@
 //!
 //! This module supports the following configuration keys:
 //!
-//! - **workon.defaultBranch** - Default base branch for new worktrees (string, default: None)
+//! - **workon.defaultBranch** - Default branch: base for new worktrees, `prune --merged` target and copy source (string, default: None)
 //! - **workon.postCreateHook** - Commands to run after worktree creation (multi-value, default: [])
 //! - **workon.copyPattern** - Glob patterns for automatic file copying (multi-value, default: [])
 //! - **workon.copyExclude** - Patterns to exclude from copying (multi-value, default: [])
 //! - **workon.autoCopyUntracked** - Enable automatic file copying in new command (bool, default: false)
-//! - **workon.pruneProtectedBranches** - Branches protected from pruning (multi-value, default: [])
+//! - **workon.copySource** - Worktree to auto-copy files from instead of the base branch's (string, default: None)
+//! - **workon.copyMaxFileSize** - Files larger than this are skipped by auto-copy, e.g. `100m` (size, default: no limit)
+//! - **workon.copyMaxTotal** - Auto-copy asks before (or, non-interactively, refuses) copying more than this in total, e.g. `2g` (size, default: no limit)
+//! - **workon.pruneProtectedBranches** - Branches protected from pruning and renaming, along with `workon.rule.<glob>.protect` rules (multi-value, default: [])
+//! - **workon.pruneGracePeriod** - Age below which `prune --gone`/`--merged` leave a worktree alone, e.g. `2d` (duration, default: None)
+//! - **workon.nameStyle** - How `new` normalizes branch names: `preserve` or `kebab` (string, default: preserve)
 //! - **workon.prFormat** - Format string for PR-based worktree names (string, default: "pr-{number}")
 //! - **workon.hookTimeout** - Timeout in seconds for hook execution (integer, default: 300, 0 = no timeout)
+//! - **workon.hookShell** - Shell hooks run with, e.g. `bash` or `pwsh -NoProfile -Command` (string, default: `sh -c`, `cmd /C` on Windows)
+//! - **workon.remoteDefaultBranch** - The remote's default branch (from `origin/HEAD`), detected by `new` while workon.defaultBranch isn't set (string, managed by workon)
+//! - **workon.autoCreate** - Create the worktree when `git workon <name>` matches none, like `find --create`, instead of asking (bool, default: false)
+//! - **workon.statusCache** - Reuse worktree upstream statuses in `list`, `find` and `prompt` while HEAD and upstream are unchanged (bool, default: false)
+//! - **workon.networkFilesystem** - Treat the layout as on NFS/SMB, overriding detection: `move` copies and verifies instead of renaming, CoW cloning is skipped (bool, default: detected)
+//! - **workon.fetchBackend** - How branches are fetched: `auto` (libgit2, falling back to `git fetch` when it can't authenticate), `libgit2` or `git` (string, default: auto)
+//! - **workon.externalWorktree** - Worktrees intentionally kept outside the workon root (multi-value, default: [])
+//! - **workon.hiddenWorktree** - Full names of worktrees left out of `list` unless `--all` is given, and of `find` unless `--include-hidden` is (multi-value, default: [])
+//! - **workon.forkRemote** - `pr-N-fork` remotes added by the PR flow, removed again once no worktree uses them (multi-value, managed by workon)
+//! - **`workon-worktree.<worktree>.label`** - Labels attached to a worktree with `git workon label` (multi-value, default: [])
+//! - **`workon-worktree.<worktree>.note`** - Free-text note attached to a worktree with `git workon note` (string, default: None)
+//! - **`workon-worktree.<worktree>.pr`** - Number of the pull request a worktree was created for (integer, managed by workon)
+//! - **workon.prReadOnly** - Treat every PR worktree as review-only, like `new --review` (bool, default: false)
+//! - **`workon-worktree.<worktree>.review`** - Marks a PR worktree review-only: workon won't push from it (bool, set by `new --review`)
+//! - **`workon-worktree.<worktree>.prTitle`**, **`workon-worktree.<worktree>.prAuthor`** - That pull request's title and author, shown by `list` and `find` (string, managed by workon)
+//! - **`workon.task.<name>`** - Command `git workon run <name>` runs in worktrees, like a hook (string, default: None)
+//! - **`workon.template.<name>.<key>`** - Worktree templates for `new --template`: `base`, `copyPattern`, `postCreateHook`, `sparse`, `label` (see template.rs)
+//! - **`workon.rule.<glob>.<key>`** - Settings for new worktrees whose branch matches a glob: `base`, `postCreateHook`, `protect` (see rule.rs)
+//! - **`workon.alias.<name>`** - Command alias expanded by the CLI before parsing, e.g. `prune --merged --yes` (string, default: None)
 //!
 //! ## Example Configuration
 //!
@@ -42,17 +66,127 @@
 //!   copyPattern = .vscode/
 //!   copyExclude = .env.production
 //!   autoCopyUntracked = true
+//!   copySource = main
 //!   pruneProtectedBranches = main
 //!   pruneProtectedBranches = develop
 //!   pruneProtectedBranches = release/*
+//!   pruneGracePeriod = 2d
 //!   prFormat = pr-{number}
+//!   nameStyle = kebab
+//!   hookShell = bash
+//!
+//! # Per-worktree labels and notes (.git/config) - managed by `git workon label`/`note`
+//! [workon-worktree "user/feature-auth"]
+//!   label = backend
+//!   label = urgent
+//!   note = waiting on API review
 //! ```
+//!
+//! Per-worktree metadata lives in its own `workon-worktree` section, keyed by the
+//! worktree's path relative to the workon root
+//! ([`WorktreeDescriptor::full_name`](crate::WorktreeDescriptor::full_name)), so
+//! `alice/fix` and `bob/fix` keep separate labels, and a worktree named `task` can't
+//! clash with `workon.task.*`.
+//!
+//! ## Hook Working Directory
+//!
+//! Hooks run in the new worktree. A `cwd=` prefix on a hook runs it elsewhere (see
+//! [`PostCreateHook`]):
+//!
+//! ```bash
+//! git config --add workon.postCreateHook "cwd=root ./scripts/register.sh"
+//! git config --add workon.postCreateHook "cwd=base make dist"
+//! ```
+//!
+//! ## Writing Configuration
+//!
+//! Setters validate their input and write to the repository's local config or the user's
+//! global config ([`ConfigScope`]). Per-worktree metadata, the hidden/external worktree
+//! lists and the fork remote list only make sense for one repository, so they are always
+//! written locally.
+//! Multi-value setters return whether anything changed, so callers can report no-ops.
 
+use std::collections::BTreeMap;
+use std::env;
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::Duration;
 
-use git2::Repository;
+use git2::{Config, ConfigLevel, ErrorCode, Repository};
+use glob::Pattern;
 
-use crate::error::{ConfigError, Result};
+use crate::error::{ConfigError, CopyError, Result};
+use crate::rule::read_rules;
+use crate::template::{find_template, read_templates};
+use crate::{is_valid_branch_name, FetchBackend, NameStyle, WorktreeRule, WorktreeTemplate};
+
+/// Which config file a setter writes to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ConfigScope {
+    /// The repository's config (.git/config)
+    #[default]
+    Local,
+    /// The user's config (~/.gitconfig)
+    Global,
+}
+
+/// Where a post-create hook runs, from its `cwd=` prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HookDir {
+    /// The new worktree (`cwd=worktree`, the default)
+    #[default]
+    Worktree,
+    /// The workon root (`cwd=root`)
+    Root,
+    /// The worktree of the base branch, or the default branch's without one (`cwd=base`)
+    Base,
+}
+
+/// A workon.postCreateHook entry: a command and the directory it runs in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostCreateHook {
+    pub command: String,
+    pub dir: HookDir,
+}
+
+impl FromStr for PostCreateHook {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = |reason: String| ConfigError::InvalidValue {
+            key: "workon.postCreateHook".to_string(),
+            value: s.to_string(),
+            reason,
+        };
+
+        let Some(rest) = s.trim_start().strip_prefix("cwd=") else {
+            return Ok(Self {
+                command: s.to_string(),
+                dir: HookDir::Worktree,
+            });
+        };
+        let (dir, command) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let dir = match dir {
+            "worktree" => HookDir::Worktree,
+            "root" => HookDir::Root,
+            "base" => HookDir::Base,
+            _ => {
+                return Err(invalid(format!(
+                    "unknown cwd '{}', expected worktree, root or base",
+                    dir
+                )))
+            }
+        };
+        let command = command.trim_start();
+        if command.is_empty() {
+            return Err(invalid("no command after the cwd= prefix".to_string()));
+        }
+        Ok(Self {
+            command: command.to_string(),
+            dir,
+        })
+    }
+}
 
 /// Configuration reader for workon settings stored in git config.
 ///
@@ -90,6 +224,25 @@ impl<'repo> WorkonConfig<'repo> {
         }
     }
 
+    /// Get the remote's default branch, as detected and cached by
+    /// [`detect_remote_default_branch`](crate::detect_remote_default_branch).
+    ///
+    /// Returns None if it hasn't been detected.
+    pub fn remote_default_branch(&self) -> Result<Option<String>> {
+        let config = self.repo.config()?;
+        match config.get_string("workon.remoteDefaultBranch") {
+            Ok(val) => Ok(Some(val)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Cache the remote's default branch in workon.remoteDefaultBranch (repository config).
+    pub fn set_remote_default_branch(&self, branch: &str) -> Result<()> {
+        self.open(ConfigScope::Local)?
+            .set_str("workon.remoteDefaultBranch", branch)?;
+        Ok(())
+    }
+
     /// Get the format string for PR-based worktree names.
     ///
     /// Precedence: CLI override > workon.prFormat config > "pr-{number}"
@@ -106,35 +259,7 @@ impl<'repo> WorkonConfig<'repo> {
                 .unwrap_or_else(|_| "pr-{number}".to_string())
         };
 
-        // Validate format contains {number} placeholder
-        if !format.contains("{number}") {
-            return Err(ConfigError::InvalidPrFormat {
-                format: format.clone(),
-                reason: "Format must contain {number} placeholder".to_string(),
-            }
-            .into());
-        }
-
-        // Valid placeholders: {number}, {title}, {author}, {branch}
-        let valid_placeholders = ["{number}", "{title}", "{author}", "{branch}"];
-        let mut remaining = format.clone();
-        for placeholder in &valid_placeholders {
-            remaining = remaining.replace(placeholder, "");
-        }
-
-        // Check for invalid placeholders (anything still matching {.*})
-        if remaining.contains('{') {
-            return Err(ConfigError::InvalidPrFormat {
-                format: format.clone(),
-                reason: format!(
-                    "Invalid placeholder found. Valid placeholders: {}",
-                    valid_placeholders.join(", ")
-                ),
-            }
-            .into());
-        }
-
-        Ok(format)
+        validate_pr_format(format)
     }
 
     /// Get the list of post-create hook commands to run after worktree creation.
@@ -181,13 +306,94 @@ impl<'repo> WorkonConfig<'repo> {
         }
     }
 
+    /// Get the worktree that automatic copying reads from.
+    ///
+    /// Precedence: CLI override > workon.copySource config > None
+    ///
+    /// Returns None if not configured. Callers fall back to the base branch's worktree.
+    pub fn copy_source(&self, cli_override: Option<&str>) -> Result<Option<String>> {
+        // CLI takes precedence
+        if let Some(override_val) = cli_override {
+            return Ok(Some(override_val.to_string()));
+        }
+
+        // Read from git config
+        let config = self.repo.config()?;
+        match config.get_string("workon.copySource") {
+            Ok(val) => Ok(Some(val)),
+            Err(_) => Ok(None), // Not configured
+        }
+    }
+
+    /// Get the size above which auto-copy skips a file.
+    ///
+    /// Reads from workon.copyMaxFileSize config, in bytes with an optional `k`, `m` or
+    /// `g` suffix like other git sizes. Returns None if not configured or zero.
+    pub fn copy_max_file_size(&self) -> Result<Option<u64>> {
+        self.read_size("workon.copyMaxFileSize")
+    }
+
+    /// Get the total size above which auto-copy needs confirmation.
+    ///
+    /// Reads from workon.copyMaxTotal config, in bytes with an optional `k`, `m` or `g`
+    /// suffix. Returns None if not configured or zero.
+    pub fn copy_max_total(&self) -> Result<Option<u64>> {
+        self.read_size("workon.copyMaxTotal")
+    }
+
+    fn read_size(&self, key: &str) -> Result<Option<u64>> {
+        let config = self.repo.config()?;
+        match config.get_string(key) {
+            Ok(val) => Ok(parse_size(key, &val)?),
+            Err(_) => Ok(None), // Not configured
+        }
+    }
+
     /// Get the list of branch patterns to protect from pruning.
     ///
-    /// Reads from multi-value workon.pruneProtectedBranches config.
+    /// Reads from multi-value workon.pruneProtectedBranches config, followed by the globs
+    /// of `workon.rule.<glob>.protect` rules.
     /// Patterns support simple glob matching (* and ?).
     /// Returns empty Vec if not configured.
     pub fn prune_protected_branches(&self) -> Result<Vec<String>> {
-        self.read_multivar("workon.pruneProtectedBranches")
+        let mut patterns = self.read_multivar("workon.pruneProtectedBranches")?;
+        patterns.extend(
+            self.rules()?
+                .into_iter()
+                .filter(|rule| rule.protect)
+                .map(|rule| rule.glob),
+        );
+        Ok(patterns)
+    }
+
+    /// Get how `new` normalizes branch names.
+    ///
+    /// Reads from workon.nameStyle config (`preserve` or `kebab`).
+    /// Default: `preserve`. Errors on an unknown style.
+    pub fn name_style(&self) -> Result<NameStyle> {
+        let config = self.repo.config()?;
+        match config.get_string("workon.nameStyle") {
+            Ok(val) => Ok(val.parse()?),
+            Err(_) => Ok(NameStyle::default()),
+        }
+    }
+
+    /// Get the grace period protecting newly created worktrees from filter-based pruning.
+    ///
+    /// Reads from workon.pruneGracePeriod config, a number followed by a unit: `s`, `m`,
+    /// `h`, `d` or `w` (e.g. `90m`, `2d`). A bare number is taken as seconds.
+    /// Returns None if not configured or zero.
+    pub fn prune_grace_period(&self) -> Result<Option<Duration>> {
+        let config = self.repo.config()?;
+        let value = match config.get_string("workon.pruneGracePeriod") {
+            Ok(val) => val,
+            Err(_) => return Ok(None), // Not configured
+        };
+        let period = parse_duration(&value).ok_or_else(|| ConfigError::InvalidDuration {
+            key: "workon.pruneGracePeriod".to_string(),
+            value: value.clone(),
+        })?;
+        Ok(Some(period).filter(|p| !p.is_zero()))
     }
 
     /// Check if a given branch name is protected from pruning.
@@ -211,10 +417,117 @@ impl<'repo> WorkonConfig<'repo> {
                     return true;
                 }
             }
+            if Pattern::new(&pattern).is_ok_and(|p| p.matches(branch_name)) {
+                return true;
+            }
         }
         false
     }
 
+    /// Get the names of worktrees intentionally kept outside the workon root.
+    ///
+    /// Reads from multi-value workon.externalWorktree config.
+    /// Returns empty Vec if not configured.
+    pub fn external_worktrees(&self) -> Result<Vec<String>> {
+        self.read_multivar("workon.externalWorktree")
+    }
+
+    /// Check if a given worktree is registered as external.
+    pub fn is_external(&self, worktree_name: &str) -> bool {
+        self.external_worktrees()
+            .map(|names| names.iter().any(|n| n == worktree_name))
+            .unwrap_or(false)
+    }
+
+    /// Get the names of worktrees hidden from listings.
+    ///
+    /// Reads from multi-value workon.hiddenWorktree config (managed by `git workon hide`).
+    /// Returns empty Vec if not configured.
+    pub fn hidden_worktrees(&self) -> Result<Vec<String>> {
+        self.read_multivar("workon.hiddenWorktree")
+    }
+
+    /// Check if a given worktree is hidden from listings.
+    pub fn is_hidden(&self, worktree_name: &str) -> bool {
+        self.hidden_worktrees()
+            .map(|names| names.iter().any(|n| n == worktree_name))
+            .unwrap_or(false)
+    }
+
+    /// Get the names of fork remotes the PR flow added.
+    ///
+    /// Reads from multi-value workon.forkRemote config. Remotes added by hand aren't listed,
+    /// so workon never removes them.
+    pub fn fork_remotes(&self) -> Result<Vec<String>> {
+        self.read_multivar("workon.forkRemote")
+    }
+
+    /// Get the labels attached to a worktree.
+    ///
+    /// Reads from multi-value `workon-worktree.<worktree>.label` config (managed by `git
+    /// workon label`). Returns empty Vec if the worktree has no labels.
+    pub fn labels(&self, worktree_name: &str) -> Result<Vec<String>> {
+        self.read_multivar(&worktree_key(worktree_name, "label")?)
+    }
+
+    /// Check if a worktree carries every one of the given labels.
+    pub fn has_labels(&self, worktree_name: &str, labels: &[String]) -> bool {
+        if labels.is_empty() {
+            return true;
+        }
+        self.labels(worktree_name)
+            .map(|own| labels.iter().all(|label| own.contains(label)))
+            .unwrap_or(false)
+    }
+
+    /// Get the note attached to a worktree.
+    ///
+    /// Reads from `workon-worktree.<worktree>.note` config (managed by `git workon note`).
+    /// Returns None if the worktree has no note.
+    pub fn note(&self, worktree_name: &str) -> Result<Option<String>> {
+        let config = self.repo.config()?;
+        match config.get_string(&worktree_key(worktree_name, "note")?) {
+            Ok(val) => Ok(Some(val)),
+            Err(_) => Ok(None), // Not configured
+        }
+    }
+
+    /// Get the number of the pull request a worktree was created for.
+    ///
+    /// Reads from `workon-worktree.<worktree>.pr` config, recorded by `git workon new #123`.
+    /// Returns None for worktrees not created from a PR.
+    pub fn pr_number(&self, worktree_name: &str) -> Result<Option<u32>> {
+        let config = self.repo.config()?;
+        match config.get_string(&worktree_key(worktree_name, "pr")?) {
+            Ok(val) => Ok(val.trim().parse().ok()),
+            Err(_) => Ok(None), // Not configured
+        }
+    }
+
+    /// Whether every PR worktree is review-only.
+    ///
+    /// Reads from workon.prReadOnly config (bool). Returns false if not configured.
+    pub fn pr_read_only(&self) -> Result<bool> {
+        let config = self.repo.config()?;
+        match config.get_bool("workon.prReadOnly") {
+            Ok(val) => Ok(val),
+            Err(_) => Ok(false), // Default to false
+        }
+    }
+
+    /// Check if a worktree is review-only: marked with `workon-worktree.<worktree>.review`,
+    /// or a PR worktree while workon.prReadOnly is set.
+    pub fn is_review_only(&self, worktree_name: &str) -> bool {
+        let (Ok(config), Ok(key)) = (self.repo.config(), worktree_key(worktree_name, "review"))
+        else {
+            return false;
+        };
+        if let Ok(review) = config.get_bool(&key) {
+            return review;
+        }
+        self.pr_read_only().unwrap_or(false) && matches!(self.pr_number(worktree_name), Ok(Some(_)))
+    }
+
     /// Get the timeout duration for hook execution.
     ///
     /// Reads from workon.hookTimeout config (integer seconds).
@@ -228,6 +541,284 @@ impl<'repo> WorkonConfig<'repo> {
         Ok(Duration::from_secs(seconds))
     }
 
+    /// Get the shell to run hooks with.
+    ///
+    /// Reads from workon.hookShell config. A lone program (`bash`, `nu`) runs hooks as
+    /// `<program> -c <hook>`; a value with arguments (`pwsh -NoProfile -Command`) is the
+    /// whole prefix the hook is appended to. Returns None if not configured.
+    pub fn hook_shell(&self) -> Result<Option<String>> {
+        let config = self.repo.config()?;
+        match config.get_string("workon.hookShell") {
+            Ok(val) if !val.trim().is_empty() => Ok(Some(val)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get the tasks `git workon run` can run, as (name, command) pairs sorted by name.
+    ///
+    /// Reads every workon.task.<name> config entry. Git lowercases the name; a task defined
+    /// in several config files takes its most specific (e.g. local over global) command.
+    /// Deeper keys (`workon.task.<x>.<key>`) aren't tasks and are skipped.
+    pub fn tasks(&self) -> Result<Vec<(String, String)>> {
+        let config = self.repo.config()?;
+        let mut tasks = BTreeMap::new();
+        let mut entries = config.entries(Some(r"^workon\.task\."))?;
+        while let Some(entry) = entries.next() {
+            let entry = entry?;
+            let (Some(name), Some(command)) = (entry.name(), entry.value()) else {
+                continue;
+            };
+            if let Some(name) = name
+                .strip_prefix("workon.task.")
+                .filter(|name| !name.contains('.'))
+            {
+                tasks.insert(name.to_string(), command.to_string());
+            }
+        }
+        Ok(tasks.into_iter().collect())
+    }
+
+    /// Get the worktree templates, sorted by name.
+    ///
+    /// Reads every workon.template.<name>.<key> config entry (see [`WorktreeTemplate`]).
+    pub fn templates(&self) -> Result<Vec<WorktreeTemplate>> {
+        read_templates(&self.repo.config()?)
+    }
+
+    /// Get the template called `name` (falling back to a case-insensitive match).
+    ///
+    /// Errors with a suggestion if there is no such template.
+    pub fn template(&self, name: &str) -> Result<WorktreeTemplate> {
+        find_template(self.templates()?, name)
+    }
+
+    /// Get the branch-name rules, in the order they're defined.
+    ///
+    /// Reads every workon.rule.<glob>.<key> config entry (see [`WorktreeRule`]).
+    pub fn rules(&self) -> Result<Vec<WorktreeRule>> {
+        read_rules(&self.repo.config()?)
+    }
+
+    /// Get the rules whose glob matches `branch_name`, in the order they're defined.
+    pub fn matching_rules(&self, branch_name: &str) -> Result<Vec<WorktreeRule>> {
+        let mut matching = Vec::new();
+        for rule in self.rules()? {
+            if rule.matches(branch_name)? {
+                matching.push(rule);
+            }
+        }
+        Ok(matching)
+    }
+
+    /// Get whether `find` creates a worktree for a name that matches none.
+    ///
+    /// Precedence: CLI override (`--create`) > workon.autoCreate config > false
+    pub fn auto_create(&self, cli_override: Option<bool>) -> Result<bool> {
+        if let Some(override_val) = cli_override {
+            return Ok(override_val);
+        }
+
+        let config = self.repo.config()?;
+        match config.get_bool("workon.autoCreate") {
+            Ok(val) => Ok(val),
+            Err(_) => Ok(false), // Default to false
+        }
+    }
+
+    /// Get whether `list`, `find` and `prompt` reuse cached worktree upstream statuses.
+    ///
+    /// Precedence: CLI override (`--no-cache`) > workon.statusCache config > false
+    ///
+    /// See [`StatusCache`](crate::StatusCache) for what the cache keys on.
+    pub fn status_cache(&self, cli_override: Option<bool>) -> Result<bool> {
+        if let Some(override_val) = cli_override {
+            return Ok(override_val);
+        }
+
+        let config = self.repo.config()?;
+        match config.get_bool("workon.statusCache") {
+            Ok(val) => Ok(val),
+            Err(_) => Ok(false), // Default to false
+        }
+    }
+
+    /// Get whether worktrees are on a network filesystem, if workon.networkFilesystem says.
+    ///
+    /// None means it isn't set, and the filesystem is detected (see
+    /// [`on_network_filesystem`](crate::on_network_filesystem)).
+    pub fn network_filesystem(&self) -> Result<Option<bool>> {
+        let config = self.repo.config()?;
+        match config.get_bool("workon.networkFilesystem") {
+            Ok(val) => Ok(Some(val)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Get how branches are fetched.
+    ///
+    /// Reads from workon.fetchBackend config (`auto`, `libgit2` or `git`).
+    /// Default: `auto`. Errors on an unknown backend.
+    pub fn fetch_backend(&self) -> Result<FetchBackend> {
+        let config = self.repo.config()?;
+        match config.get_string("workon.fetchBackend") {
+            Ok(val) => Ok(val.parse()?),
+            Err(_) => Ok(FetchBackend::default()),
+        }
+    }
+
+    /// Set workon.defaultBranch.
+    ///
+    /// Errors if `branch` is not a valid branch name. The branch doesn't have to exist yet.
+    pub fn set_default_branch(&self, branch: &str, scope: ConfigScope) -> Result<()> {
+        if !is_valid_branch_name(branch) {
+            return Err(ConfigError::InvalidValue {
+                key: "workon.defaultBranch".to_string(),
+                value: branch.to_string(),
+                reason: "not a valid branch name".to_string(),
+            }
+            .into());
+        }
+        self.open(scope)?.set_str("workon.defaultBranch", branch)?;
+        Ok(())
+    }
+
+    /// Add a workon.copyPattern entry.
+    ///
+    /// Errors if `pattern` is not a valid glob. Returns false if the pattern was already
+    /// configured in `scope`.
+    pub fn add_copy_pattern(&self, pattern: &str, scope: ConfigScope) -> Result<bool> {
+        let pattern = pattern.trim();
+        Pattern::new(pattern.trim_end_matches('/')).map_err(|source| {
+            CopyError::InvalidGlobPattern {
+                pattern: pattern.to_string(),
+                source,
+            }
+        })?;
+        add_value(&mut self.open(scope)?, "workon.copyPattern", pattern)
+    }
+
+    /// Set or clear (`None`) a per-worktree value, `workon-worktree.<worktree>.<key>`.
+    ///
+    /// Returns false if clearing a value that wasn't set.
+    pub fn set_worktree_meta(
+        &self,
+        worktree_name: &str,
+        key: &str,
+        value: Option<&str>,
+    ) -> Result<bool> {
+        let key = worktree_key(worktree_name, key)?;
+        let mut local = self.open(ConfigScope::Local)?;
+        match value {
+            Some(value) => {
+                local.set_str(&key, value)?;
+                Ok(true)
+            }
+            None => match local.remove(&key) {
+                Ok(()) => Ok(true),
+                Err(e) if e.code() == ErrorCode::NotFound => Ok(false),
+                Err(e) => Err(e.into()),
+            },
+        }
+    }
+
+    /// Remove every per-worktree value (`workon-worktree.<worktree>.*`) for a worktree.
+    pub fn clear_worktree_meta(&self, worktree_name: &str) -> Result<()> {
+        let mut local = self.open(ConfigScope::Local)?;
+        let pattern = format!(
+            r"^{}\.{}\.[^.]+$",
+            WORKTREE_SECTION,
+            escape_regex(worktree_name)
+        );
+        let mut keys: Vec<String> = Vec::new();
+        let mut entries = local.entries(Some(&pattern))?;
+        while let Some(entry) = entries.next() {
+            if let Some(key) = entry?.name().filter(|key| !keys.iter().any(|k| k == key)) {
+                keys.push(key.to_string());
+            }
+        }
+        drop(entries);
+        for key in keys {
+            local.remove_multivar(&key, ".*")?;
+        }
+        Ok(())
+    }
+
+    /// Attach a label to a worktree. Returns false if it already had the label.
+    pub fn add_label(&self, worktree_name: &str, label: &str) -> Result<bool> {
+        let label = label.trim();
+        if label.is_empty() {
+            return Err(ConfigError::InvalidValue {
+                key: format!("{}.{}.label", WORKTREE_SECTION, worktree_name),
+                value: label.to_string(),
+                reason: "labels can't be empty".to_string(),
+            }
+            .into());
+        }
+        let key = worktree_key(worktree_name, "label")?;
+        add_value(&mut self.open(ConfigScope::Local)?, &key, label)
+    }
+
+    /// Detach a label from a worktree. Returns false if the worktree didn't have it.
+    pub fn remove_label(&self, worktree_name: &str, label: &str) -> Result<bool> {
+        let key = worktree_key(worktree_name, "label")?;
+        remove_value(&mut self.open(ConfigScope::Local)?, &key, label.trim())
+    }
+
+    /// Add a worktree to, or remove it from, workon.hiddenWorktree.
+    ///
+    /// Returns false if nothing changed. A worktree hidden by global config can't be
+    /// unhidden here, which also returns false.
+    pub fn set_hidden(&self, worktree_name: &str, hidden: bool) -> Result<bool> {
+        let mut local = self.open(ConfigScope::Local)?;
+        if hidden {
+            add_value(&mut local, "workon.hiddenWorktree", worktree_name)
+        } else {
+            remove_value(&mut local, "workon.hiddenWorktree", worktree_name)
+        }
+    }
+
+    /// Add a worktree to, or remove it from, workon.externalWorktree.
+    ///
+    /// Returns false if nothing changed.
+    pub fn set_external(&self, worktree_name: &str, external: bool) -> Result<bool> {
+        let mut local = self.open(ConfigScope::Local)?;
+        if external {
+            add_value(&mut local, "workon.externalWorktree", worktree_name)
+        } else {
+            remove_value(&mut local, "workon.externalWorktree", worktree_name)
+        }
+    }
+
+    /// Add a remote to, or remove it from, workon.forkRemote.
+    ///
+    /// Returns false if nothing changed.
+    pub fn set_fork_remote(&self, remote_name: &str, owned: bool) -> Result<bool> {
+        let mut local = self.open(ConfigScope::Local)?;
+        if owned {
+            add_value(&mut local, "workon.forkRemote", remote_name)
+        } else {
+            remove_value(&mut local, "workon.forkRemote", remote_name)
+        }
+    }
+
+    /// Open the config file for `scope` for writing.
+    fn open(&self, scope: ConfigScope) -> Result<Config> {
+        let config = self.repo.config()?;
+        match scope {
+            ConfigScope::Local => Ok(config.open_level(ConfigLevel::Local)?),
+            ConfigScope::Global => match config.open_level(ConfigLevel::Global) {
+                Ok(global) => Ok(global),
+                // No global config file yet; writing creates it
+                Err(_) => {
+                    let home = env::var_os("HOME")
+                        .filter(|v| !v.is_empty())
+                        .ok_or(ConfigError::NoGlobalConfig)?;
+                    Ok(Config::open(&PathBuf::from(home).join(".gitconfig"))?)
+                }
+            },
+        }
+    }
+
     /// Helper to read multi-value config entries.
     ///
     /// Returns an empty Vec if the key doesn't exist.
@@ -248,3 +839,132 @@ impl<'repo> WorkonConfig<'repo> {
         Ok(values)
     }
 }
+
+/// Check that a PR name format has the `{number}` placeholder and no unknown ones.
+pub(crate) fn validate_pr_format(format: String) -> Result<String> {
+    // Validate format contains {number} placeholder
+    if !format.contains("{number}") {
+        return Err(ConfigError::InvalidPrFormat {
+            format: format.clone(),
+            reason: "Format must contain {number} placeholder".to_string(),
+        }
+        .into());
+    }
+
+    // Valid placeholders: {number}, {title}, {author}, {branch}
+    let valid_placeholders = ["{number}", "{title}", "{author}", "{branch}"];
+    let mut remaining = format.clone();
+    for placeholder in &valid_placeholders {
+        remaining = remaining.replace(placeholder, "");
+    }
+
+    // Check for invalid placeholders (anything still matching {.*})
+    if remaining.contains('{') {
+        return Err(ConfigError::InvalidPrFormat {
+            format: format.clone(),
+            reason: format!(
+                "Invalid placeholder found. Valid placeholders: {}",
+                valid_placeholders.join(", ")
+            ),
+        }
+        .into());
+    }
+
+    Ok(format)
+}
+
+/// Config section holding per-worktree metadata, one subsection per worktree.
+///
+/// Kept out of the `workon` section so worktree names can't collide with its own
+/// subsections (see [`WORKON_SUBSECTIONS`]).
+pub const WORKTREE_SECTION: &str = "workon-worktree";
+
+/// The subsection families of the `workon` section: command aliases
+/// (`workon.alias.<name>`), tasks (`workon.task.<name>`), templates
+/// (`workon.template.<name>.<key>`) and rules (`workon.rule.<glob>.<key>`).
+pub const WORKON_SUBSECTIONS: &[&str] = &["alias", "task", "template", "rule"];
+
+/// Key for a per-worktree value, validating the variable name like git does.
+fn worktree_key(worktree_name: &str, key: &str) -> Result<String> {
+    let full_key = format!("{}.{}.{}", WORKTREE_SECTION, worktree_name, key);
+    let valid = key.starts_with(|c: char| c.is_ascii_alphabetic())
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if !valid || worktree_name.is_empty() || worktree_name.contains(['\n', '\0']) {
+        return Err(ConfigError::InvalidKey(full_key).into());
+    }
+    Ok(full_key)
+}
+
+/// Whether a multi-value key in `config` has `value`.
+fn has_value(config: &Config, key: &str, value: &str) -> Result<bool> {
+    let mut found = false;
+    if let Ok(mut entries) = config.multivar(key, None) {
+        while let Some(entry) = entries.next() {
+            found |= entry?.value() == Some(value);
+        }
+    }
+    Ok(found)
+}
+
+/// Append `value` to a multi-value key unless it's already there.
+fn add_value(config: &mut Config, key: &str, value: &str) -> Result<bool> {
+    if has_value(config, key, value)? {
+        return Ok(false);
+    }
+    // A pattern that never matches appends a new value (like `git config --add`)
+    config.set_multivar(key, "$^", value)?;
+    Ok(true)
+}
+
+/// Remove every occurrence of `value` from a multi-value key.
+fn remove_value(config: &mut Config, key: &str, value: &str) -> Result<bool> {
+    if !has_value(config, key, value)? {
+        return Ok(false);
+    }
+    config.remove_multivar(key, &format!("^{}$", escape_regex(value)))?;
+    Ok(true)
+}
+
+/// Escape regex metacharacters so a config value matches only itself.
+fn escape_regex(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\\.+*?()|[]{}^$".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Parse a size like `512`, `100k`, `20m` or `2g` (1024-based). Zero means no limit.
+pub(crate) fn parse_size(key: &str, value: &str) -> Result<Option<u64>> {
+    let size = Config::parse_i64(value.trim())
+        .ok()
+        .filter(|size| *size >= 0)
+        .ok_or_else(|| ConfigError::InvalidValue {
+            key: key.to_string(),
+            value: value.to_string(),
+            reason: "expected a size in bytes, optionally with a k, m or g suffix".to_string(),
+        })?;
+    Ok(Some(size as u64).filter(|size| *size > 0))
+}
+
+/// Parse a duration like `30s`, `90m`, `12h`, `2d` or `1w`. A bare number is seconds.
+pub(crate) fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number.parse().ok()?;
+    let multiplier = match unit.trim() {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return None,
+    };
+    number.checked_mul(multiplier).map(Duration::from_secs)
+}
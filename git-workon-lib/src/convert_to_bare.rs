@@ -1,7 +1,7 @@
 use std::fs::{rename, write};
 
 use git2::Repository;
-use log::debug;
+use tracing::debug;
 
 use crate::error::Result;
 use crate::workon_root;
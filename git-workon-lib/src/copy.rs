@@ -3,6 +3,16 @@
 //! This module provides pattern-based file copying between worktrees with platform-specific
 //! optimizations for efficient copying of large files and directories.
 //!
+//! ## Candidate Files
+//!
+//! Candidates come from `git status` in the source worktree, not from walking the
+//! filesystem, so tracked files are never copied and `.gitignore` semantics match git's:
+//! - **untracked**: files not in the index and not ignored (`??` in `git status`)
+//! - **ignored**: files matched by `.gitignore` and friends (`!!` in `git status --ignored`)
+//!
+//! Both categories are included by default; [`UntrackedFilter`] selects between them.
+//! Include and exclude patterns are then applied to that set.
+//!
 //! ## Design: Two Modes
 //!
 //! ### 1. Standalone Command (`copy-untracked`)
@@ -18,35 +28,52 @@
 //! - Always respects `workon.copyExclude` patterns
 //! - Flags: `--(no-)copy-untracked` to override config
 //! - Copies from base branch's worktree (or HEAD's worktree if no base specified)
+//! - `workon.copySource` / `--copy-from` names a fixed source worktree instead
 //! - Gracefully skips if source worktree doesn't exist
 //! - Runs after worktree creation, before post-create hooks
 //!
 //! ## Pattern Matching
 //!
-//! Uses standard glob patterns via the `glob` crate:
-//! - `*.env` - All .env files in current directory
+//! Uses standard glob patterns via the `glob` crate, matched against paths relative to
+//! the worktree root:
+//! - `*.env` - All .env files in the worktree root
 //! - `.env*` - All files starting with .env
 //! - `**/*.json` - All JSON files recursively
 //! - `.vscode/` - Entire directory and contents
 //!
+//! A pattern that matches a directory includes everything under it.
+//!
 //! Exclude patterns work the same way, checked after include patterns match.
 //!
 //! ## Platform Optimizations
 //!
 //! Platform-specific copy-on-write optimizations for large files:
 //! - **macOS**: `cp -c` (clonefile) - instant CoW copies on APFS
-//! - **Linux**: `cp --reflink=auto` - CoW copies on btrfs/XFS when supported
+//! - **Linux**: `cp --reflink=always` - CoW copies on btrfs/XFS when supported
 //! - **Other**: Standard `fs::copy` fallback
 //!
+//! Cloning is never attempted on network filesystems (see
+//! [`on_network_filesystem`](crate::on_network_filesystem)), where it can't succeed.
+//!
 //! These optimizations make copying large node_modules or build directories nearly instant
-//! on supported filesystems.
+//! on supported filesystems. Where cloning fails, the file is copied byte for byte, and
+//! [`CopyEntry::cloned`] records which happened, so callers can tell users why a copy was
+//! slow.
 //!
 //! ## Behavior
 //!
 //! - Only copies files (directories are skipped, but created as needed for nested files)
 //! - Automatic parent directory creation for nested files
 //! - Skips files that already exist at destination (unless --force)
-//! - Returns list of successfully copied files
+//! - Returns the copied files with their sizes
+//!
+//! ## Size Limits
+//!
+//! [`plan_copy`] selects the files without copying them, so callers can check sizes
+//! first and hand the (possibly trimmed) list to [`copy_entries`]. Auto-copy during `new`
+//! uses this for `workon.copyMaxFileSize` (larger files are skipped) and
+//! `workon.copyMaxTotal` (a larger total is confirmed interactively, refused otherwise),
+//! so a stray multi-gigabyte artifact isn't duplicated by accident.
 //!
 //! ## Example Usage
 //!
@@ -67,73 +94,198 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use git2::{Repository, Status, StatusOptions};
+use glob::{MatchOptions, Pattern};
+use tracing::{debug, instrument};
+
 use crate::error::{CopyError, Result};
+use crate::{network_filesystem, on_network_filesystem};
+
+/// Which kinds of files outside version control are eligible for copying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UntrackedFilter {
+    /// Files not in the index and not ignored
+    pub untracked: bool,
+    /// Files matched by gitignore rules
+    pub ignored: bool,
+}
 
-/// Copy files from source to destination using glob patterns
+impl Default for UntrackedFilter {
+    fn default() -> Self {
+        Self {
+            untracked: true,
+            ignored: true,
+        }
+    }
+}
+
+/// A file selected for copying.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyEntry {
+    /// Path relative to the worktree root
+    pub path: PathBuf,
+    /// Size in bytes
+    pub size: u64,
+    /// Whether the copy was a copy-on-write clone rather than a byte copy. Always false
+    /// until [`copy_entries`] has copied the file.
+    pub cloned: bool,
+}
+
+/// Copy untracked and/or ignored files from source to destination using glob patterns
 ///
-/// Returns a list of successfully copied file paths
+/// Returns the copied files with their sizes
+#[instrument(level = "debug", skip(to_path, force))]
 pub fn copy_files(
     from_path: &Path,
     to_path: &Path,
     patterns: &[String],
     excludes: &[String],
+    filter: UntrackedFilter,
     force: bool,
-) -> Result<Vec<PathBuf>> {
-    let mut copied_files = Vec::new();
-
-    for pattern in patterns {
-        // Build full pattern path relative to source
-        let pattern_path = from_path.join(pattern);
-        let pattern_str = pattern_path
-            .to_str()
-            .ok_or_else(|| CopyError::InvalidPatternPath {
-                path: pattern_path.clone(),
-            })?;
-
-        // Find all files matching the pattern
-        for entry in glob::glob(pattern_str).map_err(|e| CopyError::InvalidGlobPattern {
-            pattern: pattern.clone(),
-            source: e,
-        })? {
-            let src_file = entry.map_err(CopyError::from)?;
-
-            // Skip directories - only copy files
-            if src_file.is_dir() {
-                continue;
-            }
-
-            // Skip if file should be excluded
-            if should_exclude(&src_file, from_path, excludes)? {
-                continue;
-            }
-
-            // Calculate relative path from source
-            let rel_path = src_file
-                .strip_prefix(from_path)
-                .expect("src_file is under from_path")
-                .to_path_buf();
-
-            // Build destination path
-            let dest_file = to_path.join(&rel_path);
-
-            // Skip if destination exists and force is false
-            if dest_file.exists() && !force {
-                eprintln!("Skipping (already exists): {}", rel_path.display());
-                continue;
-            }
-
-            // Create parent directories if needed
-            if let Some(parent) = dest_file.parent() {
-                fs::create_dir_all(parent)?;
-            }
-
-            // Copy the file using platform-specific optimization
-            copy_file_platform(&src_file, &dest_file)?;
-            copied_files.push(rel_path);
+) -> Result<Vec<CopyEntry>> {
+    let mut entries = plan_copy(from_path, to_path, patterns, excludes, filter, force)?;
+    copy_entries(from_path, to_path, &mut entries)?;
+    Ok(entries)
+}
+
+/// Select the files [`copy_files`] would copy, without copying anything
+pub fn plan_copy(
+    from_path: &Path,
+    to_path: &Path,
+    patterns: &[String],
+    excludes: &[String],
+    filter: UntrackedFilter,
+    force: bool,
+) -> Result<Vec<CopyEntry>> {
+    let includes = compile_patterns(patterns)?;
+    let mut entries = Vec::new();
+
+    for rel_path in untracked_files(from_path, filter)? {
+        // Skip files that no include pattern matches
+        if !matches_any(&rel_path, &includes)? {
+            continue;
+        }
+
+        let src_file = from_path.join(&rel_path);
+
+        // Skip directories (e.g. nested repositories) - only copy files
+        let metadata = fs::metadata(&src_file).ok();
+        if metadata.as_ref().is_some_and(|m| m.is_dir()) {
+            continue;
+        }
+
+        // Skip if file should be excluded
+        if should_exclude(&src_file, from_path, excludes)? {
+            continue;
+        }
+
+        // Skip if destination exists and force is false
+        if to_path.join(&rel_path).exists() && !force {
+            eprintln!("Skipping (already exists): {}", rel_path.display());
+            continue;
+        }
+
+        entries.push(CopyEntry {
+            path: rel_path,
+            size: metadata.map_or(0, |m| m.len()),
+            cloned: false,
+        });
+    }
+
+    Ok(entries)
+}
+
+/// Copy files selected by [`plan_copy`] from source to destination, recording in each
+/// entry whether it was cloned
+pub fn copy_entries(from_path: &Path, to_path: &Path, entries: &mut [CopyEntry]) -> Result<()> {
+    let network = match Repository::open(from_path) {
+        Ok(repo) => {
+            on_network_filesystem(&repo, from_path)? || on_network_filesystem(&repo, to_path)?
         }
+        Err(_) => network_filesystem(from_path).is_some() || network_filesystem(to_path).is_some(),
+    };
+    for entry in entries {
+        let dest_file = to_path.join(&entry.path);
+
+        // Create parent directories if needed
+        if let Some(parent) = dest_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Copy the file using platform-specific optimization
+        let src_file = from_path.join(&entry.path);
+        entry.cloned = if network {
+            byte_copy(&src_file, &dest_file)?;
+            false
+        } else {
+            copy_file_platform(&src_file, &dest_file)?
+        };
+    }
+    Ok(())
+}
+
+/// List the worktree's untracked and/or ignored files, relative to its root
+fn untracked_files(worktree_path: &Path, filter: UntrackedFilter) -> Result<Vec<PathBuf>> {
+    let repo = Repository::open(worktree_path)?;
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(filter.untracked)
+        .recurse_untracked_dirs(filter.untracked)
+        .include_ignored(filter.ignored)
+        .recurse_ignored_dirs(filter.ignored)
+        .exclude_submodules(true);
+
+    let mut wanted = Status::empty();
+    if filter.untracked {
+        wanted |= Status::WT_NEW;
+    }
+    if filter.ignored {
+        wanted |= Status::IGNORED;
     }
 
-    Ok(copied_files)
+    let statuses = repo.statuses(Some(&mut opts))?;
+    Ok(statuses
+        .iter()
+        .filter(|entry| entry.status().intersects(wanted))
+        .filter_map(|entry| entry.path().map(PathBuf::from))
+        .collect())
+}
+
+/// Parse include patterns, dropping trailing slashes (`.vscode/` means the directory)
+fn compile_patterns(patterns: &[String]) -> Result<Vec<Pattern>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            let trimmed = pattern.trim_end_matches('/');
+            Pattern::new(trimmed).map_err(|e| {
+                CopyError::InvalidGlobPattern {
+                    pattern: pattern.clone(),
+                    source: e,
+                }
+                .into()
+            })
+        })
+        .collect()
+}
+
+/// Check if a relative path, or any directory containing it, matches an include pattern
+fn matches_any(rel_path: &Path, patterns: &[Pattern]) -> Result<bool> {
+    // Like a filesystem glob, `*` doesn't cross directory boundaries
+    let options = MatchOptions {
+        require_literal_separator: true,
+        ..MatchOptions::new()
+    };
+    let rel_path_str = rel_path.to_str().ok_or_else(|| CopyError::InvalidPath {
+        path: rel_path.to_path_buf(),
+    })?;
+
+    let mut candidate = Some(rel_path_str);
+    while let Some(path) = candidate {
+        if patterns.iter().any(|p| p.matches_with(path, options)) {
+            return Ok(true);
+        }
+        candidate = path.rsplit_once('/').map(|(parent, _)| parent);
+    }
+    Ok(false)
 }
 
 /// Check if a file should be excluded based on exclusion patterns
@@ -167,41 +319,35 @@ fn should_exclude(path: &Path, base: &Path, excludes: &[String]) -> Result<bool>
 
 /// Copy a file using platform-specific optimizations
 ///
-/// Attempts to use copy-on-write when available, falls back to standard copy
+/// Attempts to use copy-on-write when available, falls back to standard copy.
+/// Returns whether the file was cloned.
 #[cfg(target_os = "macos")]
-fn copy_file_platform(src: &Path, dest: &Path) -> Result<()> {
+fn copy_file_platform(src: &Path, dest: &Path) -> Result<bool> {
     // Try using cp -c (clonefile) for copy-on-write on macOS
-    let result = Command::new("cp")
-        .arg("-c")
-        .arg(src)
-        .arg(dest)
-        .status()
-        .map_err(|e| CopyError::CopyFailed {
-            src: src.to_path_buf(),
-            dest: dest.to_path_buf(),
-            source: e,
-        })?;
-
-    if result.success() {
-        Ok(())
-    } else {
-        // Fallback to standard copy
-        fs::copy(src, dest).map_err(|e| CopyError::CopyFailed {
-            src: src.to_path_buf(),
-            dest: dest.to_path_buf(),
-            source: e,
-        })?;
-        Ok(())
-    }
+    clone_or_copy(src, dest, Command::new("cp").arg("-c"))
 }
 
 #[cfg(target_os = "linux")]
-fn copy_file_platform(src: &Path, dest: &Path) -> Result<()> {
-    // Try using cp --reflink=auto for copy-on-write on Linux
-    let result = Command::new("cp")
-        .arg("--reflink=auto")
+fn copy_file_platform(src: &Path, dest: &Path) -> Result<bool> {
+    // Try using cp --reflink=always for copy-on-write on Linux; unlike
+    // --reflink=auto it fails instead of silently copying, so we know which happened
+    clone_or_copy(src, dest, Command::new("cp").arg("--reflink=always"))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "linux")))]
+fn copy_file_platform(src: &Path, dest: &Path) -> Result<bool> {
+    // Use standard copy for other platforms
+    byte_copy(src, dest)?;
+    Ok(false)
+}
+
+/// Run a cloning `cp` command, falling back to a byte copy if it fails
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn clone_or_copy(src: &Path, dest: &Path, clone: &mut Command) -> Result<bool> {
+    let result = clone
         .arg(src)
         .arg(dest)
+        .stderr(std::process::Stdio::null())
         .status()
         .map_err(|e| CopyError::CopyFailed {
             src: src.to_path_buf(),
@@ -210,21 +356,15 @@ fn copy_file_platform(src: &Path, dest: &Path) -> Result<()> {
         })?;
 
     if result.success() {
-        Ok(())
+        Ok(true)
     } else {
-        // Fallback to standard copy
-        fs::copy(src, dest).map_err(|e| CopyError::CopyFailed {
-            src: src.to_path_buf(),
-            dest: dest.to_path_buf(),
-            source: e,
-        })?;
-        Ok(())
+        debug!("cloning {} failed, copying instead", src.display());
+        byte_copy(src, dest)?;
+        Ok(false)
     }
 }
 
-#[cfg(not(any(target_os = "macos", target_os = "linux")))]
-fn copy_file_platform(src: &Path, dest: &Path) -> Result<()> {
-    // Use standard copy for other platforms
+fn byte_copy(src: &Path, dest: &Path) -> Result<()> {
     fs::copy(src, dest).map_err(|e| CopyError::CopyFailed {
         src: src.to_path_buf(),
         dest: dest.to_path_buf(),
@@ -1,7 +1,10 @@
 use git2::{Direction, Remote, RemoteCallbacks, Repository};
+use tracing::{debug, instrument};
 
 use crate::error::{DefaultBranchError, Result};
-use crate::get_remote_callbacks;
+use crate::{
+    find_worktree, get_proxy_options, get_remote_callbacks, WorkonConfig, WorktreeDescriptor,
+};
 
 pub struct DefaultBranch<'repo, 'cb> {
     repo: &'repo Repository,
@@ -28,10 +31,13 @@ impl<'repo, 'cb> DefaultBranch<'repo, 'cb> {
         self
     }
 
+    #[instrument(level = "debug", name = "default_branch", skip_all)]
     pub fn get_name(self) -> Result<String> {
         match self.remote {
             Some(mut remote) => {
-                let mut cxn = remote.connect_auth(Direction::Fetch, self.callbacks, None)?;
+                let config = self.repo.config()?;
+                let proxy = remote.url().map(|url| get_proxy_options(&config, url));
+                let mut cxn = remote.connect_auth(Direction::Fetch, self.callbacks, proxy)?;
 
                 if !cxn.connected() {
                     return Err(DefaultBranchError::NotConnected.into());
@@ -68,21 +74,32 @@ pub fn get_default_branch_name(repo: &Repository, remote: Option<Remote>) -> Res
 
 /// Get the default branch name for a repository, validated to exist.
 ///
+/// This is the single answer to "which branch is the default" shared by every command:
+/// the fallback base for `new`, the `prune --merged` target and the default copy source.
+///
 /// This function:
-/// 1. Checks the `init.defaultBranch` config
-/// 2. Falls back to "main" if it exists
-/// 3. Falls back to "master" if it exists
-/// 4. Returns an error if none exist
+/// 1. Checks the `workon.defaultBranch` config (set with `git workon default`)
+/// 2. Checks the `workon.remoteDefaultBranch` config (see
+///    [`detect_remote_default_branch`])
+/// 3. Checks the `init.defaultBranch` config
+/// 4. Falls back to "main" if it exists
+/// 5. Falls back to "master" if it exists
+/// 6. Returns an error if none exist
 pub fn get_default_branch(repo: &Repository) -> Result<String> {
-    // Check init.defaultBranch config
     if let Ok(config) = repo.config() {
-        if let Ok(default_branch) = config.get_string("init.defaultBranch") {
-            // Verify the configured branch exists
-            if repo
-                .find_branch(&default_branch, git2::BranchType::Local)
-                .is_ok()
-            {
-                return Ok(default_branch);
+        for key in [
+            "workon.defaultBranch",
+            "workon.remoteDefaultBranch",
+            "init.defaultBranch",
+        ] {
+            if let Ok(default_branch) = config.get_string(key) {
+                // Verify the configured branch exists
+                if repo
+                    .find_branch(&default_branch, git2::BranchType::Local)
+                    .is_ok()
+                {
+                    return Ok(default_branch);
+                }
             }
         }
     }
@@ -99,3 +116,64 @@ pub fn get_default_branch(repo: &Repository) -> Result<String> {
 
     Err(DefaultBranchError::NoDefaultBranch.into())
 }
+
+/// Detect the default branch of the repository's remote and cache it in
+/// `workon.remoteDefaultBranch`, where [`get_default_branch`] finds it.
+///
+/// The remote is `origin`, or the only remote if there's just one. Its default branch is
+/// read from `refs/remotes/<remote>/HEAD` (recorded by clone and `git remote set-head`),
+/// falling back to asking the remote for the branch its HEAD points to, like
+/// `git ls-remote --symref <remote> HEAD`.
+///
+/// Returns None without a remote, or when the remote can't be reached.
+#[instrument(level = "debug", skip(repo))]
+pub fn detect_remote_default_branch(repo: &Repository) -> Result<Option<String>> {
+    let Some(remote) = default_remote(repo) else {
+        return Ok(None);
+    };
+    let branch = match remote_head_branch(repo) {
+        Some(branch) => branch,
+        None => {
+            debug!("no {}/HEAD, asking the remote", remote);
+            match get_default_branch_name(repo, Some(repo.find_remote(&remote)?)) {
+                Ok(branch) => branch,
+                Err(e) => {
+                    debug!("couldn't detect the default branch of {}: {}", remote, e);
+                    return Ok(None);
+                }
+            }
+        }
+    };
+    debug!("{} defaults to {}", remote, branch);
+    WorkonConfig::new(repo)?.set_remote_default_branch(&branch)?;
+    Ok(Some(branch))
+}
+
+/// The branch `refs/remotes/<remote>/HEAD` points at, for the remote
+/// [`detect_remote_default_branch`] uses. Doesn't touch the network.
+pub fn remote_head_branch(repo: &Repository) -> Option<String> {
+    let remote = default_remote(repo)?;
+    let head = repo
+        .find_reference(&format!("refs/remotes/{}/HEAD", remote))
+        .ok()?;
+    head.symbolic_target()?
+        .strip_prefix(&format!("refs/remotes/{}/", remote))
+        .map(str::to_string)
+}
+
+/// `origin`, or the repository's only remote.
+fn default_remote(repo: &Repository) -> Option<String> {
+    let remotes = repo.remotes().ok()?;
+    let names: Vec<&str> = remotes.iter().flatten().collect();
+    match names[..] {
+        _ if names.contains(&"origin") => Some("origin".to_string()),
+        [only] => Some(only.to_string()),
+        _ => None,
+    }
+}
+
+/// Get the worktree that has the default branch (see [`get_default_branch`]) checked out.
+pub fn get_default_worktree(repo: &Repository) -> Result<WorktreeDescriptor> {
+    let default_branch = get_default_branch(repo)?;
+    find_worktree(repo, &default_branch)
+}
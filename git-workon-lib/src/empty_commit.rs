@@ -1,8 +1,10 @@
 use git2::{Oid, Repository};
 
-use crate::{error::Result, WorktreeError};
+use crate::{commit_to_head, error::Result, CommitSigner, WorktreeError};
 
-pub fn empty_commit(repo: &Repository) -> Result<Oid> {
+/// Create the initial, empty commit on HEAD, signed if `sign` is set and `commit.gpgsign`
+/// is on.
+pub fn empty_commit(repo: &Repository, sign: bool) -> Result<Oid> {
     let sig = repo.signature()?;
     let tree = repo.find_tree({
         let mut index = repo.index()?;
@@ -13,5 +15,18 @@ pub fn empty_commit(repo: &Repository) -> Result<Oid> {
         return Err(WorktreeError::NonEmptyIndex.into());
     }
 
-    Ok(repo.commit(Some("HEAD"), &sig, &sig, "Initial commit", &tree, &[])?)
+    let signer = if sign {
+        CommitSigner::from_config(repo)?
+    } else {
+        None
+    };
+    commit_to_head(
+        repo,
+        &sig,
+        &sig,
+        "Initial commit",
+        &tree,
+        &[],
+        signer.as_ref(),
+    )
 }
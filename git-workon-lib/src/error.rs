@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 /// Result type alias using WorkonError
@@ -12,7 +12,12 @@ pub enum WorkonError {
     /// Git operation failed
     #[error(transparent)]
     #[diagnostic(code(workon::git_error))]
-    Git(#[from] git2::Error),
+    Git(git2::Error),
+
+    /// Git operation failed in a recognized way
+    #[error(transparent)]
+    #[diagnostic(forward(0))]
+    GitOp(#[from] GitError),
 
     /// I/O operation failed
     #[error(transparent)]
@@ -48,6 +53,140 @@ pub enum WorkonError {
     #[error(transparent)]
     #[diagnostic(forward(0))]
     Copy(#[from] CopyError),
+
+    /// Repository registry errors
+    #[error(transparent)]
+    #[diagnostic(forward(0))]
+    Registry(#[from] RegistryError),
+
+    /// Patch application errors
+    #[error(transparent)]
+    #[diagnostic(forward(0))]
+    Patch(#[from] PatchError),
+
+    /// Commit signing errors
+    #[error(transparent)]
+    #[diagnostic(forward(0))]
+    Signing(#[from] SigningError),
+
+    /// Filter expression errors
+    #[error(transparent)]
+    #[diagnostic(forward(0))]
+    Filter(#[from] FilterError),
+}
+
+impl WorkonError {
+    /// The underlying libgit2 error, if this is one.
+    pub fn git_error(&self) -> Option<&git2::Error> {
+        match self {
+            WorkonError::Git(e) => Some(e),
+            WorkonError::GitOp(e) => Some(e.git_error()),
+            _ => None,
+        }
+    }
+}
+
+/// Translates libgit2 errors, so common failures come with help text instead of a bare
+/// libgit2 message. Anything unrecognized stays a plain [`WorkonError::Git`].
+impl From<git2::Error> for WorkonError {
+    fn from(error: git2::Error) -> Self {
+        use git2::{ErrorClass, ErrorCode};
+        let mapped = match (error.code(), error.class()) {
+            (ErrorCode::Locked, _) => GitError::Locked {
+                help: lock_help(&error),
+                error,
+            },
+            (ErrorCode::Auth, _) => GitError::AuthFailed(error),
+            (ErrorCode::Certificate, _) | (_, ErrorClass::Ssl) => GitError::Certificate(error),
+            (ErrorCode::Conflict | ErrorCode::Uncommitted | ErrorCode::MergeConflict, _) => {
+                GitError::CheckoutConflict(error)
+            }
+            (ErrorCode::UnbornBranch, _) => GitError::UnbornBranch(error),
+            (ErrorCode::Eof, _) | (_, ErrorClass::Net | ErrorClass::Http | ErrorClass::Ssh) => {
+                GitError::Network(error)
+            }
+            _ => return WorkonError::Git(error),
+        };
+        WorkonError::GitOp(mapped)
+    }
+}
+
+/// Point at the lock file, when libgit2 names it (e.g. `failed to lock file
+/// '/repo/.bare/refs/heads/main.lock' for writing`) or it's the index.
+fn lock_help(error: &git2::Error) -> String {
+    let lock_file = error
+        .message()
+        .split('\'')
+        .find(|part| part.ends_with(".lock"))
+        .unwrap_or(match error.class() {
+            git2::ErrorClass::Index => ".git/index.lock",
+            _ => "the stale .lock file",
+        });
+    format!(
+        "Another git process holds the lock; retry once it finishes, or remove {} if none is running",
+        lock_file
+    )
+}
+
+/// Common git failures, with what to do about them
+#[derive(Error, Diagnostic, Debug)]
+pub enum GitError {
+    #[error("{}", .error.message())]
+    #[diagnostic(code(workon::git::locked))]
+    Locked {
+        error: git2::Error,
+        #[help]
+        help: String,
+    },
+
+    #[error("Authentication failed: {}", .0.message())]
+    #[diagnostic(
+        code(workon::git::auth_failed),
+        help("Check that ssh-agent has your key loaded (ssh-add -l), or that a credential helper is set up for HTTPS remotes")
+    )]
+    AuthFailed(git2::Error),
+
+    #[error("Could not verify the remote: {}", .0.message())]
+    #[diagnostic(
+        code(workon::git::certificate),
+        help("Check the host key in ~/.ssh/known_hosts, or http.sslCAInfo for HTTPS remotes")
+    )]
+    Certificate(git2::Error),
+
+    #[error("{}", .0.message())]
+    #[diagnostic(
+        code(workon::git::checkout_conflict),
+        help("Local changes would be overwritten; commit or stash them and retry")
+    )]
+    CheckoutConflict(git2::Error),
+
+    #[error("{}", .0.message())]
+    #[diagnostic(
+        code(workon::git::unborn_branch),
+        help("The branch has no commits yet; make an initial commit first")
+    )]
+    UnbornBranch(git2::Error),
+
+    #[error("Network error: {}", .0.message())]
+    #[diagnostic(
+        code(workon::git::network),
+        help("Check your connection and proxy settings (http.proxy), then retry")
+    )]
+    Network(git2::Error),
+}
+
+impl GitError {
+    /// The libgit2 error this was translated from.
+    pub fn git_error(&self) -> &git2::Error {
+        match self {
+            GitError::Locked { error, .. } => error,
+            GitError::AuthFailed(e)
+            | GitError::Certificate(e)
+            | GitError::CheckoutConflict(e)
+            | GitError::UnbornBranch(e)
+            | GitError::Network(e) => e,
+        }
+    }
 }
 
 /// Repository-specific errors
@@ -59,24 +198,44 @@ pub enum RepoError {
         help("Workon commands must be run in bare repositories")
     )]
     NotBare(String),
+
+    #[error("git fetch from {remote} failed: {message}")]
+    #[diagnostic(
+        code(workon::repo::git_fetch_failed),
+        help("workon.fetchBackend runs `git fetch` for this; check that it works from the command line")
+    )]
+    GitFetchFailed { remote: String, message: String },
+
+    #[error("{0} already exists")]
+    #[diagnostic(
+        code(workon::repo::clone_target_exists),
+        help("Clone into another directory, or remove this one")
+    )]
+    CloneTargetExists(PathBuf),
+
+    #[error("Clone of {url} was interrupted: {message}")]
+    #[diagnostic(
+        code(workon::repo::clone_interrupted),
+        help("The partial clone was kept; run the same clone command again to resume it")
+    )]
+    CloneInterrupted { url: String, message: String },
 }
 
 /// Worktree-specific errors
 #[derive(Error, Diagnostic, Debug)]
 pub enum WorktreeError {
-    #[error("Invalid .git file format")]
-    #[diagnostic(
-        code(workon::worktree::invalid_git_file),
-        help("The .git file should contain 'gitdir: <path>' pointing to the git directory")
-    )]
-    InvalidGitFile,
-
-    #[error("Could not find worktree '{0}'")]
+    #[error("Could not find worktree '{name}'")]
     #[diagnostic(
         code(workon::worktree::not_found),
-        help("Use 'git workon list' to see available worktrees")
+        help(
+            "{}Use 'git workon list' to see available worktrees",
+            .suggestion.as_ref().map(|s| format!("Did you mean '{s}'? ")).unwrap_or_default()
+        )
     )]
-    NotFound(String),
+    NotFound {
+        name: String,
+        suggestion: Option<String>,
+    },
 
     #[error("Not in a worktree directory")]
     #[diagnostic(
@@ -125,6 +284,31 @@ pub enum WorktreeError {
     )]
     TargetExists { to: String },
 
+    #[error("Worktree '{name}' collides with existing worktree '{existing}' on case-insensitive filesystems")]
+    #[diagnostic(
+        code(workon::worktree::case_collision),
+        help("Names differing only in case share a directory on macOS and Windows. Use a different name, e.g. '{suggestion}'")
+    )]
+    CaseCollision {
+        name: String,
+        existing: String,
+        suggestion: String,
+    },
+
+    #[error("Directory '{path}' already exists")]
+    #[diagnostic(
+        code(workon::worktree::directory_exists),
+        help("Use --adopt to reuse it if it's a leftover worktree for this branch, or --force to move it aside")
+    )]
+    DirectoryExists { path: String },
+
+    #[error("Cannot adopt '{path}': it isn't a worktree of this repository for '{branch}'")]
+    #[diagnostic(
+        code(workon::worktree::cannot_adopt),
+        help("Use --force to move it aside instead")
+    )]
+    CannotAdopt { path: String, branch: String },
+
     #[error("Cannot move detached HEAD worktree")]
     #[diagnostic(
         code(workon::worktree::move_detached),
@@ -152,6 +336,136 @@ pub enum WorktreeError {
         help("Push commits first, or use --force to override")
     )]
     UnpushedCommits,
+
+    #[error("Worktree '{name}' is locked{}", .reason.as_ref().map(|r| format!(": {r}")).unwrap_or_default())]
+    #[diagnostic(
+        code(workon::worktree::locked),
+        help("Unlock it with 'git worktree unlock', or use --force-locked to move it and keep the lock")
+    )]
+    Locked {
+        name: String,
+        reason: Option<String>,
+    },
+
+    #[error("No git remote to push to")]
+    #[diagnostic(
+        code(workon::worktree::no_push_remote),
+        help("Add a remote with: git remote add origin <url>")
+    )]
+    NoPushRemote,
+
+    #[error("Worktree '{name}' is review-only")]
+    #[diagnostic(
+        code(workon::worktree::review_only),
+        help("It checks out someone else's PR branch (see `new --review` and workon.prReadOnly); branch off it to make your own changes")
+    )]
+    ReviewOnly { name: String },
+
+    #[error("Cannot push detached HEAD worktree")]
+    #[diagnostic(
+        code(workon::worktree::push_detached),
+        help("Detached HEAD worktrees have no branch to publish")
+    )]
+    CannotPushDetached,
+
+    #[error("Cannot set an upstream for a detached HEAD worktree")]
+    #[diagnostic(
+        code(workon::worktree::track_detached),
+        help("Detached HEAD worktrees have no branch to track a remote branch")
+    )]
+    CannotTrackDetached,
+
+    #[error("Remote branch '{0}' doesn't exist")]
+    #[diagnostic(
+        code(workon::worktree::upstream_not_found),
+        help("Publish the branch with --push, or check the remote branch name")
+    )]
+    UpstreamNotFound(String),
+
+    #[error("Failed to set up sparse checkout: {message}")]
+    #[diagnostic(
+        code(workon::worktree::sparse_checkout_failed),
+        help(
+            "Sparse checkout runs `git sparse-checkout set --cone`, which needs git 2.27 or later"
+        )
+    )]
+    SparseCheckoutFailed { message: String },
+
+    #[error("Worktree '{0}' has no commits to compare")]
+    #[diagnostic(
+        code(workon::worktree::no_head_commit),
+        help("Its branch is unborn; commit something first")
+    )]
+    NoHeadCommit(String),
+
+    #[error("Failed to push '{branch}' to {remote}: {message}")]
+    #[diagnostic(
+        code(workon::worktree::push_failed),
+        help("Check your network connection and repository access")
+    )]
+    PushFailed {
+        branch: String,
+        remote: String,
+        message: String,
+    },
+
+    #[error("Cannot relocate to '{0}': path already exists")]
+    #[diagnostic(
+        code(workon::worktree::relocate_target_exists),
+        help("Choose a path that doesn't exist yet (or an empty directory)")
+    )]
+    RelocateTargetExists(String),
+
+    #[error("Cannot relocate the workon root into itself ('{0}')")]
+    #[diagnostic(
+        code(workon::worktree::relocate_into_root),
+        help("Choose a path outside the current workon root")
+    )]
+    RelocateIntoRoot(String),
+
+    #[error("Branch '{branch}' is already checked out in worktree '{worktree}'")]
+    #[diagnostic(
+        code(workon::worktree::branch_checked_out),
+        help("Switch to the existing worktree with 'git workon {worktree}'")
+    )]
+    BranchCheckedOut { branch: String, worktree: String },
+
+    #[error("'{0}' is not a remote branch")]
+    #[diagnostic(
+        code(workon::worktree::not_a_remote_branch),
+        help("Use <remote>/<branch> with a configured remote, e.g. origin/feature")
+    )]
+    NotARemoteBranch(String),
+
+    #[error("Branch '{0}' already exists")]
+    #[diagnostic(
+        code(workon::worktree::branch_exists),
+        help("Choose another local name, or run 'git workon {0}' to use the existing branch")
+    )]
+    BranchExists(String),
+
+    #[error("'{name}' matches more than one worktree")]
+    #[diagnostic(
+        code(workon::worktree::ambiguous),
+        help("Matches: {}. Use the full name", .candidates.join(", "))
+    )]
+    Ambiguous {
+        name: String,
+        candidates: Vec<String>,
+    },
+
+    #[error("'{name}' is not a valid branch name (see git check-ref-format)")]
+    #[diagnostic(
+        code(workon::worktree::invalid_branch_name),
+        help(
+            "{}Set workon.nameStyle = kebab to normalize names automatically",
+            .suggestion.as_ref().map(|s| format!("Try '{s}'. ")).unwrap_or_default()
+        )
+    )]
+    InvalidBranchName {
+        name: String,
+        suggestion: Option<String>,
+    },
 }
 
 /// Configuration-related errors
@@ -164,9 +478,58 @@ pub enum ConfigError {
     )]
     InvalidPrFormat { format: String, reason: String },
 
+    #[error("Invalid duration for {key}: '{value}'")]
+    #[diagnostic(
+        code(workon::config::invalid_duration),
+        help("Use a number followed by s, m, h, d or w, e.g. '2d' or '12h'")
+    )]
+    InvalidDuration { key: String, value: String },
+
+    #[error("Invalid workon.nameStyle: '{0}'")]
+    #[diagnostic(
+        code(workon::config::invalid_name_style),
+        help("Valid styles: preserve, kebab")
+    )]
+    InvalidNameStyle(String),
+
+    #[error("Invalid value for {key}: '{value}' ({reason})")]
+    #[diagnostic(code(workon::config::invalid_value))]
+    InvalidValue {
+        key: String,
+        value: String,
+        reason: String,
+    },
+
+    #[error("Invalid config key '{0}'")]
+    #[diagnostic(
+        code(workon::config::invalid_key),
+        help("Keys start with a letter and contain only letters, digits and '-'")
+    )]
+    InvalidKey(String),
+
+    #[error("Could not locate the global git config")]
+    #[diagnostic(
+        code(workon::config::no_global_config),
+        help("Set HOME, or write to the repository's config instead")
+    )]
+    NoGlobalConfig,
+
     #[error("Config entry has no value")]
     #[diagnostic(code(workon::config::no_value))]
     NoValue,
+
+    #[error("No template named '{name}'")]
+    #[diagnostic(
+        code(workon::config::template_not_found),
+        help(
+            "{}Use 'git workon templates' to see available templates",
+            .suggestion.as_ref().map(|s| format!("Did you mean '{s}'? ")).unwrap_or_default()
+        )
+    )]
+    TemplateNotFound {
+        name: String,
+        suggestion: Option<String>,
+    },
 }
 
 /// Default branch detection errors
@@ -186,10 +549,10 @@ pub enum DefaultBranchError {
     )]
     NotConnected,
 
-    #[error("Could not determine default branch: neither 'main' nor 'master' exist, and init.defaultBranch is not configured")]
+    #[error("Could not determine default branch: neither 'main' nor 'master' exist, and neither workon.defaultBranch nor init.defaultBranch is configured")]
     #[diagnostic(
         code(workon::default_branch::no_default_branch),
-        help("Set init.defaultBranch in your git config, or create a 'main' or 'master' branch")
+        help("Run 'git workon default <branch>', set init.defaultBranch in your git config, or create a 'main' or 'master' branch")
     )]
     NoDefaultBranch,
 }
@@ -257,13 +620,6 @@ pub enum PrError {
 /// File copy errors
 #[derive(Error, Diagnostic, Debug)]
 pub enum CopyError {
-    #[error("Pattern path is not valid UTF-8: {}", path.display())]
-    #[diagnostic(
-        code(workon::copy::invalid_pattern_path),
-        help("Ensure file paths contain only valid UTF-8 characters")
-    )]
-    InvalidPatternPath { path: PathBuf },
-
     #[error("Invalid glob pattern '{pattern}'")]
     #[diagnostic(
         code(workon::copy::invalid_glob_pattern),
@@ -279,10 +635,6 @@ pub enum CopyError {
     #[diagnostic(code(workon::copy::invalid_path))]
     InvalidPath { path: PathBuf },
 
-    #[error("Failed to read glob entry")]
-    #[diagnostic(code(workon::copy::glob_error))]
-    GlobEntry(#[from] glob::GlobError),
-
     #[error("Failed to copy '{}' to '{}'", src.display(), dest.display())]
     #[diagnostic(code(workon::copy::copy_failed))]
     CopyFailed {
@@ -292,3 +644,124 @@ pub enum CopyError {
         source: std::io::Error,
     },
 }
+
+/// Repository registry errors
+#[derive(Error, Diagnostic, Debug)]
+pub enum RegistryError {
+    #[error("No registered repository named '{name}'")]
+    #[diagnostic(
+        code(workon::registry::unknown_repo),
+        help(
+            "{}Registered repositories are listed in {}",
+            .suggestion.as_ref().map(|s| format!("Did you mean '{s}'? ")).unwrap_or_default(),
+            .path.display()
+        )
+    )]
+    UnknownRepo {
+        name: String,
+        suggestion: Option<String>,
+        path: PathBuf,
+    },
+
+    #[error("Invalid repository registry at {}:{line}: {message}", path.display())]
+    #[diagnostic(
+        code(workon::registry::parse_error),
+        help("Entries look like: name = \"/path/to/workon/root\", under a [repos] table")
+    )]
+    Parse {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+
+    #[error("A different repository is already registered as '{name}' ({})", path.display())]
+    #[diagnostic(
+        code(workon::registry::name_taken),
+        help("Choose another name with --name, or remove the existing entry first")
+    )]
+    NameTaken { name: String, path: PathBuf },
+
+    #[error("Could not locate the config directory for the repository registry")]
+    #[diagnostic(
+        code(workon::registry::no_config_dir),
+        help("Set XDG_CONFIG_HOME or HOME")
+    )]
+    NoConfigDir,
+}
+
+/// Patch application errors
+#[derive(Error, Diagnostic, Debug)]
+pub enum PatchError {
+    #[error("Patch file contains no changes")]
+    #[diagnostic(
+        code(workon::patch::no_changes),
+        help("Expected a diff or the output of git format-patch")
+    )]
+    NoChanges,
+
+    #[error("Failed to apply {patch} ({applied} of {total} patches applied)")]
+    #[diagnostic(
+        code(workon::patch::apply_failed),
+        help("The patch may have been made against another branch; try --base")
+    )]
+    Apply {
+        patch: String,
+        applied: usize,
+        total: usize,
+        #[source]
+        source: git2::Error,
+    },
+}
+
+/// Commit signing errors
+#[derive(Error, Diagnostic, Debug)]
+pub enum SigningError {
+    #[error("Unsupported gpg.format '{0}'")]
+    #[diagnostic(
+        code(workon::signing::unknown_format),
+        help("Supported formats: openpgp, x509, ssh")
+    )]
+    UnknownFormat(String),
+
+    #[error("SSH commit signing requires user.signingKey")]
+    #[diagnostic(
+        code(workon::signing::no_ssh_key),
+        help("Set user.signingKey to a key file or public key, or pass --no-sign to 'git workon new' or 'git workon init'")
+    )]
+    NoSshKey,
+
+    #[error("Failed to run signing program '{program}'")]
+    #[diagnostic(
+        code(workon::signing::spawn_failed),
+        help("Check gpg.program / gpg.ssh.program, or pass --no-sign to 'git workon new' or 'git workon init'")
+    )]
+    Spawn {
+        program: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to sign commit with '{program}': {message}")]
+    #[diagnostic(
+        code(workon::signing::failed),
+        help("Check your signing setup (commit.gpgsign, user.signingKey), or pass --no-sign to 'git workon new' or 'git workon init'")
+    )]
+    Failed { program: String, message: String },
+}
+
+/// Filter expression errors
+#[derive(Error, Diagnostic, Debug)]
+pub enum FilterError {
+    #[error("Invalid filter expression: {message}")]
+    #[diagnostic(
+        code(workon::filter::syntax),
+        help("Combine terms like dirty, ahead, label:x or 'age > 7d' with !, && and ||, e.g. '(dirty || ahead) && !gone'")
+    )]
+    Syntax {
+        message: String,
+        #[source_code]
+        expr: String,
+        #[label("{message}")]
+        span: SourceSpan,
+    },
+}
@@ -0,0 +1,131 @@
+//! Fetching with libgit2 or, when it can't authenticate, the git CLI.
+//!
+//! libgit2 handles SSH agents, key files and credential helpers (see
+//! [`get_remote_callbacks`]), but some setups only work with system git: hardware security
+//! keys, `core.sshCommand`, exotic credential helpers. `workon.fetchBackend` picks how
+//! branch fetches (for PRs and tracked remote branches) are done:
+//!
+//! - `auto` (default) - fetch with libgit2, and retry with `git fetch` if authentication
+//!   fails
+//! - `libgit2` - only libgit2
+//! - `git` - always shell out to `git fetch`
+//!
+//! ```bash
+//! git config workon.fetchBackend git
+//! ```
+//!
+//! Both backends fetch the same refspecs, so the resulting refs are identical.
+
+use std::fmt;
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+
+use git2::{ErrorClass, ErrorCode, FetchOptions, Repository};
+use tracing::debug;
+
+use crate::error::{ConfigError, RepoError, Result};
+use crate::{get_proxy_options, get_remote_callbacks, WorkonConfig};
+
+/// How workon fetches from remotes (workon.fetchBackend).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FetchBackend {
+    /// libgit2, falling back to the git CLI when authentication fails
+    #[default]
+    Auto,
+    /// libgit2 only
+    Libgit2,
+    /// The git CLI only
+    Git,
+}
+
+impl FromStr for FetchBackend {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(FetchBackend::Auto),
+            "libgit2" => Ok(FetchBackend::Libgit2),
+            "git" => Ok(FetchBackend::Git),
+            _ => Err(ConfigError::InvalidValue {
+                key: "workon.fetchBackend".to_string(),
+                value: s.to_string(),
+                reason: "expected auto, libgit2 or git".to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for FetchBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchBackend::Auto => write!(f, "auto"),
+            FetchBackend::Libgit2 => write!(f, "libgit2"),
+            FetchBackend::Git => write!(f, "git"),
+        }
+    }
+}
+
+/// Fetch `refspecs` from `remote_name` with the configured [`FetchBackend`].
+pub fn fetch_refspecs(
+    repo: &Repository,
+    remote_name: &str,
+    refspecs: &[&str],
+    reflog_msg: Option<&str>,
+) -> Result<()> {
+    match WorkonConfig::new(repo)?.fetch_backend()? {
+        FetchBackend::Git => git_fetch(repo, remote_name, refspecs),
+        FetchBackend::Libgit2 => Ok(libgit2_fetch(repo, remote_name, refspecs, reflog_msg)?),
+        FetchBackend::Auto => match libgit2_fetch(repo, remote_name, refspecs, reflog_msg) {
+            Err(e) if is_auth_failure(&e) => {
+                debug!("libgit2 couldn't authenticate ({}), retrying with git", e);
+                git_fetch(repo, remote_name, refspecs)
+            }
+            result => Ok(result?),
+        },
+    }
+}
+
+fn libgit2_fetch(
+    repo: &Repository,
+    remote_name: &str,
+    refspecs: &[&str],
+    reflog_msg: Option<&str>,
+) -> std::result::Result<(), git2::Error> {
+    let mut remote = repo.find_remote(remote_name)?;
+    let mut fetch_options = FetchOptions::new();
+    fetch_options.remote_callbacks(
+        get_remote_callbacks().map_err(|e| git2::Error::from_str(&e.to_string()))?,
+    );
+    if let Some(url) = remote.url() {
+        fetch_options.proxy_options(get_proxy_options(&repo.config()?, url));
+    }
+    remote.fetch(refspecs, Some(&mut fetch_options), reflog_msg)
+}
+
+/// Run `git fetch` in `repo` (stdin stays attached, for key touches and PIN prompts).
+fn git_fetch(repo: &Repository, remote_name: &str, refspecs: &[&str]) -> Result<()> {
+    debug!("git fetch {} {}", remote_name, refspecs.join(" "));
+    let output = Command::new("git")
+        .arg("--git-dir")
+        .arg(repo.path())
+        .args(["fetch", "--quiet", "--no-write-fetch-head", remote_name])
+        .args(refspecs)
+        .stdin(Stdio::inherit())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()?;
+    if !output.status.success() {
+        return Err(RepoError::GitFetchFailed {
+            remote: remote_name.to_string(),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Whether a libgit2 error means it couldn't authenticate (as opposed to, say, a missing
+/// remote or a network failure, which git would fail on too).
+fn is_auth_failure(e: &git2::Error) -> bool {
+    e.code() == ErrorCode::Auth || e.class() == ErrorClass::Ssh
+}
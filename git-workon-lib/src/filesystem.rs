@@ -0,0 +1,240 @@
+//! Network filesystem detection.
+//!
+//! Worktrees on NFS or SMB shares break assumptions that hold on local disks: renaming a
+//! directory can fail or leave half-moved trees behind when files are held open on
+//! another machine, and copy-on-write cloning is never available. When the workon layout
+//! lives on a network filesystem:
+//!
+//! - `move` (and `doctor --fix` relocations) copies the worktree, verifies the copy and
+//!   only then deletes the original, instead of renaming it
+//! - copying untracked files skips the clonefile/reflink attempt and byte-copies directly
+//! - `doctor` reports the setup, with these consequences
+//!
+//! The filesystem type is read from the mount table: `/proc/self/mountinfo` on Linux, the
+//! output of `mount` on other Unix systems. Detection can be overridden with
+//! `workon.networkFilesystem` (e.g. for FUSE mounts that aren't recognized, or a fast
+//! local-like share):
+//!
+//! ```bash
+//! git config workon.networkFilesystem true   # always treat as networked
+//! git config workon.networkFilesystem false  # never
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use git2::Repository;
+use tracing::debug;
+
+use crate::error::Result;
+use crate::WorkonConfig;
+
+/// Filesystem types treated as networked.
+const NETWORK_TYPES: &[&str] = &[
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb",
+    "smb2",
+    "smb3",
+    "smbfs",
+    "afpfs",
+    "afs",
+    "ncpfs",
+    "9p",
+    "webdav",
+    "davfs",
+    "fuse.sshfs",
+    "fuse.rclone",
+];
+
+/// A network filesystem some path lives on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkFilesystem {
+    /// Filesystem type, as the mount table reports it (e.g. `nfs4`, `cifs`, `smbfs`)
+    pub fs_type: String,
+    /// Where it's mounted
+    pub mount_point: PathBuf,
+}
+
+/// Detect whether `path` (or, if it doesn't exist yet, its closest existing ancestor)
+/// is on a network filesystem.
+pub fn network_filesystem(path: &Path) -> Option<NetworkFilesystem> {
+    let path = path.ancestors().find_map(|p| p.canonicalize().ok())?;
+    let (mount_point, fs_type) = mounts()
+        .into_iter()
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.components().count())?;
+    let network = NETWORK_TYPES.contains(&fs_type.as_str());
+    debug!(
+        "{} is on {} ({}), mounted at {}",
+        path.display(),
+        fs_type,
+        if network { "network" } else { "local" },
+        mount_point.display()
+    );
+    network.then_some(NetworkFilesystem {
+        fs_type,
+        mount_point,
+    })
+}
+
+/// Whether `path` should be treated as being on a network filesystem:
+/// `workon.networkFilesystem` if it's set, [`network_filesystem`] detection otherwise.
+pub fn on_network_filesystem(repo: &Repository, path: &Path) -> Result<bool> {
+    match WorkonConfig::new(repo)?.network_filesystem()? {
+        Some(configured) => Ok(configured),
+        None => Ok(network_filesystem(path).is_some()),
+    }
+}
+
+/// Copy the directory tree at `from` to `to`, check that the copy has every file (with
+/// the same size) and only then remove `from`.
+///
+/// If the copy fails or doesn't match, it's removed and `from` is left untouched.
+pub(crate) fn copy_then_remove(from: &Path, to: &Path) -> io::Result<()> {
+    let copied = copy_dir_all(from, to).and_then(|_| {
+        if tree_listing(from)? == tree_listing(to)? {
+            Ok(())
+        } else {
+            Err(io::Error::other(format!(
+                "copy of {} doesn't match the original",
+                from.display()
+            )))
+        }
+    });
+    if let Err(e) = copied {
+        let _ = fs::remove_dir_all(to);
+        return Err(e);
+    }
+
+    // The verified copy is complete; a leftover original only costs disk space
+    if let Err(e) = fs::remove_dir_all(from) {
+        debug!("couldn't remove {} after copying: {}", from.display(), e);
+    }
+    Ok(())
+}
+
+/// Every entry under `dir`, relative to it, with each file's size (None for directories
+/// and symlinks), sorted by path.
+fn tree_listing(dir: &Path) -> io::Result<Vec<(PathBuf, Option<u64>)>> {
+    let mut listing = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        for entry in fs::read_dir(&current)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            let relative = entry
+                .path()
+                .strip_prefix(dir)
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            if file_type.is_dir() {
+                pending.push(entry.path());
+                listing.push((relative, None));
+            } else if file_type.is_symlink() {
+                listing.push((relative, None));
+            } else {
+                listing.push((relative, Some(entry.metadata()?.len())));
+            }
+        }
+    }
+    listing.sort();
+    Ok(listing)
+}
+
+pub(crate) fn copy_dir_all(src: &Path, dest: &Path) -> io::Result<()> {
+    fs::create_dir_all(dest)?;
+    fs::set_permissions(dest, fs::metadata(src)?.permissions())?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = dest.join(entry.file_name());
+        if file_type.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else if file_type.is_symlink() {
+            copy_symlink(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn copy_symlink(src: &Path, dest: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(fs::read_link(src)?, dest)
+}
+
+#[cfg(not(unix))]
+fn copy_symlink(src: &Path, dest: &Path) -> io::Result<()> {
+    fs::copy(src, dest).map(|_| ())
+}
+
+/// Mount points and their filesystem types.
+#[cfg(target_os = "linux")]
+fn mounts() -> Vec<(PathBuf, String)> {
+    // <id> <parent> <major:minor> <root> <mount point> <options> [optional...] - <type> ...
+    let Ok(mountinfo) = fs::read_to_string("/proc/self/mountinfo") else {
+        return Vec::new();
+    };
+    mountinfo
+        .lines()
+        .filter_map(|line| {
+            let (before, after) = line.split_once(" - ")?;
+            let mount_point = before.split(' ').nth(4)?;
+            let fs_type = after.split(' ').next()?;
+            Some((PathBuf::from(unescape(mount_point)), fs_type.to_string()))
+        })
+        .collect()
+}
+
+/// Mount points and their filesystem types.
+#[cfg(all(unix, not(target_os = "linux")))]
+fn mounts() -> Vec<(PathBuf, String)> {
+    // //user@server/share on /Volumes/share (smbfs, nodev, nosuid, mounted by user)
+    let Ok(output) = std::process::Command::new("mount").output() else {
+        return Vec::new();
+    };
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (_, rest) = line.split_once(" on ")?;
+            let (mount_point, options) = rest.rsplit_once(" (")?;
+            let fs_type = options.split([',', ')']).next()?.trim();
+            Some((PathBuf::from(mount_point), fs_type.to_string()))
+        })
+        .collect()
+}
+
+/// Mount points and their filesystem types.
+#[cfg(not(unix))]
+fn mounts() -> Vec<(PathBuf, String)> {
+    Vec::new()
+}
+
+/// Decode the octal escapes (`\040` for a space) mountinfo uses in paths.
+#[cfg(target_os = "linux")]
+fn unescape(field: &str) -> String {
+    let mut bytes = Vec::with_capacity(field.len());
+    let raw = field.as_bytes();
+    let mut i = 0;
+    while i < raw.len() {
+        let escaped = raw.get(i + 1..i + 4).and_then(|digits| {
+            let digits = std::str::from_utf8(digits).ok()?;
+            u8::from_str_radix(digits, 8).ok()
+        });
+        match escaped {
+            Some(byte) if raw[i] == b'\\' => {
+                bytes.push(byte);
+                i += 4;
+            }
+            _ => {
+                bytes.push(raw[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
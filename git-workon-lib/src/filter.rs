@@ -0,0 +1,384 @@
+//! Boolean filter expressions over worktree state.
+//!
+//! Status flags like `list --dirty --ahead` can only be combined with AND. A
+//! [`WorktreeFilter`] is a small expression language for everything else:
+//!
+//! ```bash
+//! git workon list --filter '(dirty || ahead) && !gone'
+//! git workon list --filter 'label:backend && age > 2w'
+//! ```
+//!
+//! ## Terms
+//!
+//! - **Status**: `dirty`, `clean`, `ahead`, `behind`, `gone`, `detached`, `locked`, with
+//!   the same meaning as the `list` flags of the same name
+//! - **Labels**: `label:<name>` (or `#<name>`) is true if the worktree carries the label
+//! - **Age**: `age <op> <duration>` compares the time since the last commit, and
+//!   `created <op> <duration>` the time since the worktree was created. `<op>` is one of
+//!   `<`, `<=`, `>`, `>=`; durations use the `pruneGracePeriod` syntax (`90m`, `2d`, `1w`)
+//!
+//! ## Operators
+//!
+//! `!` (not), `&&` (and) and `||` (or), in decreasing order of precedence, with
+//! parentheses for grouping. `and`, `or` and `not` are accepted as words too.
+//!
+//! Terms are evaluated lazily: `clean || ahead` doesn't compute ahead/behind counts for
+//! clean worktrees. A status that can't be read counts as false, like the `list` flags.
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::config::parse_duration;
+use crate::error::{FilterError, Result};
+use crate::WorktreeDescriptor;
+
+/// A parsed filter expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeFilter {
+    expr: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Expr {
+    Term(Term),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Dirty,
+    Clean,
+    Ahead,
+    Behind,
+    Gone,
+    Detached,
+    Locked,
+    Label(String),
+    Age(Comparison, Duration),
+    Created(Comparison, Duration),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+impl Comparison {
+    fn holds(self, left: u64, right: u64) -> bool {
+        match self {
+            Comparison::Less => left < right,
+            Comparison::LessOrEqual => left <= right,
+            Comparison::Greater => left > right,
+            Comparison::GreaterOrEqual => left >= right,
+        }
+    }
+}
+
+impl WorktreeFilter {
+    /// Parse a filter expression.
+    pub fn parse(expr: &str) -> Result<Self> {
+        let tokens = tokenize(expr)?;
+        let mut parser = Parser {
+            source: expr,
+            tokens,
+            position: 0,
+        };
+        let parsed = parser.or()?;
+        if let Some(token) = parser.peek() {
+            return Err(parser.error(token.span, "expected '&&', '||' or the end"));
+        }
+        Ok(Self { expr: parsed })
+    }
+
+    /// Check a worktree against the filter. `labels` are the worktree's labels.
+    pub fn matches(&self, wt: &WorktreeDescriptor, labels: &[String]) -> bool {
+        self.expr.eval(wt, labels)
+    }
+}
+
+impl Expr {
+    fn eval(&self, wt: &WorktreeDescriptor, labels: &[String]) -> bool {
+        match self {
+            Expr::Term(term) => term.eval(wt, labels),
+            Expr::Not(inner) => !inner.eval(wt, labels),
+            Expr::And(left, right) => left.eval(wt, labels) && right.eval(wt, labels),
+            Expr::Or(left, right) => left.eval(wt, labels) || right.eval(wt, labels),
+        }
+    }
+}
+
+impl Term {
+    fn eval(&self, wt: &WorktreeDescriptor, labels: &[String]) -> bool {
+        match self {
+            Term::Dirty => wt.is_dirty().unwrap_or(false),
+            Term::Clean => !wt.is_dirty().unwrap_or(true),
+            Term::Ahead => wt.has_unpushed_commits().unwrap_or(false),
+            Term::Behind => wt.is_behind_upstream().unwrap_or(false),
+            Term::Gone => wt.has_gone_upstream().unwrap_or(false),
+            Term::Detached => wt.is_detached().unwrap_or(false),
+            Term::Locked => wt.is_locked().unwrap_or(false),
+            Term::Label(label) => labels.contains(label),
+            Term::Age(cmp, duration) => {
+                compare_age(wt.last_activity().ok().flatten(), *cmp, *duration)
+            }
+            Term::Created(cmp, duration) => {
+                compare_age(wt.created_at().ok().flatten(), *cmp, *duration)
+            }
+        }
+    }
+}
+
+/// Compare the time since `timestamp` with `duration`; false without a timestamp.
+fn compare_age(timestamp: Option<i64>, cmp: Comparison, duration: Duration) -> bool {
+    let Some(timestamp) = timestamp else {
+        return false;
+    };
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+    let age = (now - timestamp).max(0) as u64;
+    cmp.holds(age, duration.as_secs())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Word(String),
+    Not,
+    And,
+    Or,
+    Open,
+    Close,
+    Compare(Comparison),
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Word(word) => write!(f, "'{}'", word),
+            TokenKind::Not => write!(f, "'!'"),
+            TokenKind::And => write!(f, "'&&'"),
+            TokenKind::Or => write!(f, "'||'"),
+            TokenKind::Open => write!(f, "'('"),
+            TokenKind::Close => write!(f, "')'"),
+            TokenKind::Compare(_) => write!(f, "a comparison"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    /// Byte offset and length in the expression
+    span: (usize, usize),
+}
+
+fn tokenize(expr: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let next = chars.peek().map(|&(_, c)| c);
+        let (kind, len) = match (c, next) {
+            (c, _) if c.is_whitespace() => continue,
+            ('(', _) => (TokenKind::Open, 1),
+            (')', _) => (TokenKind::Close, 1),
+            ('&', Some('&')) => (TokenKind::And, 2),
+            ('|', Some('|')) => (TokenKind::Or, 2),
+            ('<', Some('=')) => (TokenKind::Compare(Comparison::LessOrEqual), 2),
+            ('>', Some('=')) => (TokenKind::Compare(Comparison::GreaterOrEqual), 2),
+            ('<', _) => (TokenKind::Compare(Comparison::Less), 1),
+            ('>', _) => (TokenKind::Compare(Comparison::Greater), 1),
+            ('!', _) => (TokenKind::Not, 1),
+            (c, _) if is_word_char(c) => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(i, c)) = chars.peek() {
+                    if !is_word_char(c) {
+                        break;
+                    }
+                    end = i + c.len_utf8();
+                    chars.next();
+                }
+                let word = &expr[start..end];
+                let kind = match word {
+                    "and" => TokenKind::And,
+                    "or" => TokenKind::Or,
+                    "not" => TokenKind::Not,
+                    _ => TokenKind::Word(word.to_string()),
+                };
+                tokens.push(Token {
+                    kind,
+                    span: (start, end - start),
+                });
+                continue;
+            }
+            _ => {
+                return Err(syntax_error(
+                    expr,
+                    (start, c.len_utf8()),
+                    format!("unexpected '{}'", c),
+                ))
+            }
+        };
+        if len == 2 {
+            chars.next();
+        }
+        tokens.push(Token {
+            kind,
+            span: (start, len),
+        });
+    }
+    Ok(tokens)
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | ':' | '#')
+}
+
+/// Recursive descent parser, one method per precedence level.
+struct Parser<'a> {
+    source: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn eat(&mut self, kind: &TokenKind) -> bool {
+        if self.peek().is_some_and(|t| &t.kind == kind) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut expr = self.and()?;
+        while self.eat(&TokenKind::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut expr = self.unary()?;
+        while self.eat(&TokenKind::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.unary()?));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<Expr> {
+        if self.eat(&TokenKind::Not) {
+            return Ok(Expr::Not(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        let token = self
+            .next()
+            .ok_or_else(|| self.end_error("expected a term"))?;
+        match token.kind {
+            TokenKind::Open => {
+                let expr = self.or()?;
+                match self.next() {
+                    Some(Token {
+                        kind: TokenKind::Close,
+                        ..
+                    }) => Ok(expr),
+                    Some(other) => Err(self.error(other.span, "expected ')'")),
+                    None => Err(self.error(token.span, "unclosed '('")),
+                }
+            }
+            TokenKind::Word(word) => self.term(&word, token.span).map(Expr::Term),
+            other => Err(self.error(token.span, &format!("expected a term, found {}", other))),
+        }
+    }
+
+    fn term(&mut self, word: &str, span: (usize, usize)) -> Result<Term> {
+        if let Some(label) = word
+            .strip_prefix("label:")
+            .or_else(|| word.strip_prefix('#'))
+        {
+            if label.is_empty() {
+                return Err(self.error(span, "expected a label name"));
+            }
+            return Ok(Term::Label(label.to_string()));
+        }
+        let term = match word {
+            "dirty" => Term::Dirty,
+            "clean" => Term::Clean,
+            "ahead" => Term::Ahead,
+            "behind" => Term::Behind,
+            "gone" => Term::Gone,
+            "detached" => Term::Detached,
+            "locked" => Term::Locked,
+            "age" | "created" => {
+                let (cmp, duration) = self.comparison(span)?;
+                if word == "age" {
+                    Term::Age(cmp, duration)
+                } else {
+                    Term::Created(cmp, duration)
+                }
+            }
+            _ => return Err(self.error(span, &format!("unknown term '{}'", word))),
+        };
+        Ok(term)
+    }
+
+    /// The `<op> <duration>` after `age` or `created`.
+    fn comparison(&mut self, span: (usize, usize)) -> Result<(Comparison, Duration)> {
+        let cmp = match self.next() {
+            Some(Token {
+                kind: TokenKind::Compare(cmp),
+                ..
+            }) => cmp,
+            Some(other) => return Err(self.error(other.span, "expected <, <=, > or >=")),
+            None => return Err(self.error(span, "expected a comparison, e.g. 'age > 7d'")),
+        };
+        match self.next() {
+            Some(Token {
+                kind: TokenKind::Word(value),
+                span,
+            }) => match parse_duration(&value) {
+                Some(duration) => Ok((cmp, duration)),
+                None => Err(self.error(span, "expected a duration like 90m, 2d or 1w")),
+            },
+            Some(other) => Err(self.error(other.span, "expected a duration")),
+            None => Err(self.end_error("expected a duration")),
+        }
+    }
+
+    fn error(&self, span: (usize, usize), message: &str) -> crate::WorkonError {
+        syntax_error(self.source, span, message.to_string())
+    }
+
+    /// An error pointing just past the end of the expression.
+    fn end_error(&self, message: &str) -> crate::WorkonError {
+        self.error((self.source.len(), 0), message)
+    }
+}
+
+fn syntax_error(expr: &str, span: (usize, usize), message: String) -> crate::WorkonError {
+    FilterError::Syntax {
+        message,
+        expr: expr.to_string(),
+        span: span.into(),
+    }
+    .into()
+}
@@ -1,15 +1,273 @@
-use git2::{Config, RemoteCallbacks};
+//! Credentials for remote operations (fetch, clone, push).
+//!
+//! libgit2 asks for credentials until one works or the callback gives up. Each method
+//! is tried once, in order:
+//!
+//! - SSH: every identity loaded in ssh-agent, then the default keys in `~/.ssh`
+//!   (`id_ed25519`, `id_ecdsa`, `id_rsa`)
+//! - HTTPS: git's credential helpers (`credential.helper`), then `gh auth token` for the
+//!   URL's host (a GitHub token, if the gh CLI is logged in)
+//! - Finally, an interactive prompt for a username/password or key path
+//!
+//! The prompt is skipped when prompting is turned off (see [`set_auth_prompt`]), when
+//! `GIT_TERMINAL_PROMPT=0` is set, as git does, or when stdin isn't a terminal. When
+//! every method fails, the error names the URL and the methods tried:
+//!
+//! ```text
+//! Authentication failed for git@github.com:owner/repo.git (tried: ssh-agent, ssh key /home/me/.ssh/id_ed25519)
+//! ```
+
+use std::collections::VecDeque;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use git2::{Config, Cred, CredentialType, ErrorClass, ErrorCode, RemoteCallbacks};
 use git2_credentials::CredentialHandler;
+use tracing::debug;
 
 use crate::error::Result;
+use crate::proxy::url_host;
+
+/// Default SSH keys, in the order ssh itself tries them.
+const SSH_KEYS: &[&str] = &["id_ed25519", "id_ecdsa", "id_rsa"];
+
+static AUTH_PROMPT: AtomicBool = AtomicBool::new(true);
+
+/// Allow or forbid prompting for credentials when no other method works.
+///
+/// Non-interactive commands (`--no-interactive`, `--json`) turn prompting off so a remote
+/// needing credentials fails instead of waiting for input.
+pub fn set_auth_prompt(enabled: bool) {
+    AUTH_PROMPT.store(enabled, Ordering::Relaxed);
+}
+
+fn can_prompt() -> bool {
+    prompt_allowed(
+        AUTH_PROMPT.load(Ordering::Relaxed),
+        std::env::var("GIT_TERMINAL_PROMPT").ok().as_deref(),
+        std::io::stdin().is_terminal(),
+    )
+}
+
+/// Whether to prompt, given [`set_auth_prompt`], `GIT_TERMINAL_PROMPT` and whether stdin
+/// is a terminal.
+fn prompt_allowed(enabled: bool, terminal_prompt: Option<&str>, is_terminal: bool) -> bool {
+    enabled && terminal_prompt != Some("0") && is_terminal
+}
 
 pub fn get_remote_callbacks<'a>() -> Result<RemoteCallbacks<'a>> {
     let mut callbacks = RemoteCallbacks::new();
     let git_config = Config::open_default()?;
-    let mut credential_handler = CredentialHandler::new(git_config);
+    let mut auth = Authenticator::new(git_config)?;
 
-    callbacks.credentials(move |url, username, allowed| {
-        credential_handler.try_next_credential(url, username, allowed)
-    });
+    callbacks.credentials(move |url, username, allowed| auth.next(url, username, allowed));
     Ok(callbacks)
 }
+
+/// Works through the credential methods for one remote operation.
+struct Authenticator {
+    config: Config,
+    agent_tried: bool,
+    ssh_keys: VecDeque<PathBuf>,
+    helper_tried: bool,
+    gh_tried: bool,
+    prompt: Option<CredentialHandler>,
+    tried: Vec<String>,
+}
+
+impl Authenticator {
+    fn new(config: Config) -> Result<Self> {
+        let ssh_dir = std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".ssh"));
+        let prompt = can_prompt().then(|| Config::open_default().map(CredentialHandler::new));
+        Ok(Self::with(config, ssh_dir, prompt.transpose()?))
+    }
+
+    /// An authenticator trying the default keys in `ssh_dir`, then `prompt` if given.
+    fn with(config: Config, ssh_dir: Option<PathBuf>, prompt: Option<CredentialHandler>) -> Self {
+        let ssh_keys = ssh_dir
+            .map(|dir| {
+                SSH_KEYS
+                    .iter()
+                    .map(|key| dir.join(key))
+                    .filter(|key| key.is_file())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            config,
+            agent_tried: false,
+            ssh_keys,
+            helper_tried: false,
+            gh_tried: false,
+            prompt,
+            tried: Vec::new(),
+        }
+    }
+
+    fn next(
+        &mut self,
+        url: &str,
+        username: Option<&str>,
+        allowed: CredentialType,
+    ) -> std::result::Result<Cred, git2::Error> {
+        if allowed.contains(CredentialType::USERNAME) {
+            return Cred::username(username.unwrap_or("git"));
+        }
+
+        if allowed.contains(CredentialType::SSH_KEY) {
+            let user = username.unwrap_or("git");
+            if !self.agent_tried {
+                self.agent_tried = true;
+                self.attempt(url, "ssh-agent".to_string());
+                return Cred::ssh_key_from_agent(user);
+            }
+            if let Some(key) = self.ssh_keys.pop_front() {
+                self.attempt(url, format!("ssh key {}", key.display()));
+                return Cred::ssh_key(user, None, &key, None);
+            }
+        }
+
+        if allowed.contains(CredentialType::USER_PASS_PLAINTEXT) {
+            if !self.helper_tried {
+                self.helper_tried = true;
+                if let Ok(cred) = Cred::credential_helper(&self.config, url, username) {
+                    self.attempt(url, "credential helper".to_string());
+                    return Ok(cred);
+                }
+            }
+            if !self.gh_tried {
+                self.gh_tried = true;
+                if let Some(token) = gh_token(url) {
+                    self.attempt(url, "gh auth token".to_string());
+                    return Cred::userpass_plaintext("x-access-token", &token);
+                }
+            }
+        }
+
+        if self.prompt.is_some() && !self.tried.iter().any(|t| t == "prompt") {
+            self.attempt(url, "prompt".to_string());
+        }
+        if let Some(prompt) = self.prompt.as_mut() {
+            if let Ok(cred) = prompt.try_next_credential(url, username, allowed) {
+                return Ok(cred);
+            }
+        }
+
+        let tried = if self.tried.is_empty() {
+            "no credentials available".to_string()
+        } else {
+            format!("tried: {}", self.tried.join(", "))
+        };
+        Err(git2::Error::new(
+            ErrorCode::Auth,
+            ErrorClass::Net,
+            format!("Authentication failed for {} ({})", url, tried),
+        ))
+    }
+
+    fn attempt(&mut self, url: &str, method: String) {
+        debug!("Authenticating to {} with {}", url, method);
+        self.tried.push(method);
+    }
+}
+
+/// A token from `gh auth token` for an HTTPS URL's host, if gh is installed and logged in.
+fn gh_token(url: &str) -> Option<String> {
+    let host = gh_host(url)?;
+    let output = Command::new("gh")
+        .args(["auth", "token", "--hostname", host])
+        .output()
+        .ok()?;
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !token.is_empty()).then_some(token)
+}
+
+/// The host to ask gh for a token for: an HTTPS URL's host. SSH and other URLs have none.
+fn gh_host(url: &str) -> Option<&str> {
+    url.starts_with("https://").then(|| url_host(url)).flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_fs::prelude::*;
+
+    fn tried(auth: &mut Authenticator, url: &str, allowed: CredentialType) -> Vec<String> {
+        while auth.next(url, Some("git"), allowed).is_ok() {}
+        auth.tried.clone()
+    }
+
+    #[test]
+    fn test_gh_host_from_https_urls_only() {
+        assert_eq!(
+            gh_host("https://github.com/owner/repo.git"),
+            Some("github.com")
+        );
+        assert_eq!(
+            gh_host("https://me@ghe.example.com:8443/owner/repo"),
+            Some("ghe.example.com")
+        );
+        assert_eq!(gh_host("http://github.com/owner/repo.git"), None);
+        assert_eq!(gh_host("ssh://git@github.com/owner/repo.git"), None);
+        assert_eq!(gh_host("git@github.com:owner/repo.git"), None);
+    }
+
+    #[test]
+    fn test_ssh_tries_agent_then_default_keys_in_order() {
+        let home = assert_fs::TempDir::new().unwrap();
+        let ssh = home.child(".ssh");
+        ssh.child("id_rsa").write_str("key").unwrap();
+        ssh.child("id_ed25519").write_str("key").unwrap();
+
+        let mut auth = Authenticator::with(Config::new().unwrap(), Some(ssh.to_path_buf()), None);
+        let url = "git@github.com:owner/repo.git";
+        assert_eq!(
+            tried(&mut auth, url, CredentialType::SSH_KEY),
+            vec![
+                "ssh-agent".to_string(),
+                format!("ssh key {}", ssh.child("id_ed25519").display()),
+                format!("ssh key {}", ssh.child("id_rsa").display()),
+            ]
+        );
+
+        let Err(err) = auth.next(url, Some("git"), CredentialType::SSH_KEY) else {
+            panic!("every method was tried");
+        };
+        assert_eq!(err.code(), ErrorCode::Auth);
+        assert!(err.message().contains(url), "{}", err.message());
+        assert!(err.message().contains("tried: ssh-agent, ssh key"));
+    }
+
+    #[test]
+    fn test_username_is_answered_before_any_method() {
+        let mut auth = Authenticator::with(Config::new().unwrap(), None, None);
+        assert!(auth
+            .next("ssh://host/repo", None, CredentialType::USERNAME)
+            .is_ok());
+        assert!(auth.tried.is_empty());
+    }
+
+    #[test]
+    fn test_without_prompt_fails_once_methods_run_out() {
+        // No credential helper configured, and no gh token for an unresolvable host
+        let mut auth = Authenticator::with(Config::new().unwrap(), None, None);
+        let url = "https://example.invalid/owner/repo.git";
+        let Err(err) = auth.next(url, None, CredentialType::USER_PASS_PLAINTEXT) else {
+            panic!("no method should be available");
+        };
+        assert_eq!(err.code(), ErrorCode::Auth);
+        assert!(err.message().contains("no credentials available"));
+        assert!(!auth.tried.iter().any(|t| t == "prompt"));
+    }
+
+    #[test]
+    fn test_no_prompt_without_terminal_or_when_disabled() {
+        assert!(prompt_allowed(true, None, true));
+        assert!(prompt_allowed(true, Some("1"), true));
+        assert!(!prompt_allowed(true, Some("0"), true));
+        assert!(!prompt_allowed(true, None, false));
+        assert!(!prompt_allowed(false, None, true));
+    }
+}
@@ -1,8 +1,10 @@
 use git2::Repository;
 use std::{env, path::PathBuf};
+use tracing::instrument;
 
 use crate::{error::Result, RepoError};
 
+#[instrument(level = "debug")]
 pub fn get_repo(path: Option<PathBuf>) -> Result<Repository> {
     let path = match path {
         Some(p) => p,
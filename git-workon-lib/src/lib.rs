@@ -1,29 +1,63 @@
+mod branch_name;
 mod clone;
+mod compare;
 mod config;
 mod convert_to_bare;
 mod copy;
 mod default_branch;
 mod empty_commit;
 mod error;
+mod fetch;
+mod filesystem;
+mod filter;
 mod get_remote_callbacks;
 mod get_repo;
 mod init;
+mod matching;
 mod r#move;
+mod patch;
 mod pr;
+mod proxy;
+mod registry;
+mod relocate;
+mod rule;
+mod settings;
+mod signing;
+mod status_cache;
+mod suggest;
+mod template;
+mod visits;
 mod workon_root;
 mod worktree;
 
+pub use crate::branch_name::*;
 pub use crate::clone::*;
+pub use crate::compare::*;
 pub use crate::config::*;
 pub use crate::convert_to_bare::*;
 pub use crate::copy::*;
 pub use crate::default_branch::*;
 pub use crate::empty_commit::*;
 pub use crate::error::*;
+pub use crate::fetch::*;
+pub use crate::filesystem::*;
+pub use crate::filter::*;
 pub use crate::get_remote_callbacks::*;
 pub use crate::get_repo::*;
 pub use crate::init::*;
+pub use crate::matching::*;
+pub use crate::patch::*;
 pub use crate::pr::*;
+pub use crate::proxy::*;
 pub use crate::r#move::*;
+pub use crate::registry::*;
+pub use crate::relocate::*;
+pub use crate::rule::*;
+pub use crate::settings::*;
+pub use crate::signing::*;
+pub use crate::status_cache::*;
+pub use crate::suggest::*;
+pub use crate::template::*;
+pub use crate::visits::*;
 pub use crate::workon_root::*;
 pub use crate::worktree::*;
@@ -0,0 +1,178 @@
+//! Resolving worktrees from names typed on the command line.
+//!
+//! A query resolves in three steps:
+//!
+//! 1. **Exact match** on the worktree name, then the full branch name
+//! 2. **Fuzzy match**: case-insensitive substring of, in order, the worktree name, the
+//!    branch name, and the worktree's path relative to the workon root. `user/` matches
+//!    branches `user/feature` and `user/bugfix` even though their worktrees are named
+//!    `feature` and `bugfix`
+//! 3. **No match**, with a "did you mean" suggestion for near misses
+//!
+//! Worktrees hidden with `git workon hide` only resolve by exact name.
+//!
+//! [`find_worktrees_matching`] reports which of these happened, so interactive commands
+//! can offer a picker for ambiguous queries; [`find_worktree_fuzzy`] resolves to a single
+//! worktree or fails.
+
+use std::fmt;
+use std::path::Path;
+
+use git2::Repository;
+use tracing::debug;
+
+use crate::error::{Result, WorktreeError};
+use crate::{did_you_mean, get_worktrees, workon_root, WorkonConfig, WorktreeDescriptor};
+
+/// Which worktree field a fuzzy query matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchField {
+    Name,
+    Branch,
+    Path,
+}
+
+impl fmt::Display for MatchField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatchField::Name => write!(f, "name"),
+            MatchField::Branch => write!(f, "branch"),
+            MatchField::Path => write!(f, "path"),
+        }
+    }
+}
+
+/// The outcome of resolving a query against worktrees.
+#[derive(Debug)]
+pub enum MatchResult {
+    /// The query is a worktree name or branch name
+    Exact(WorktreeDescriptor),
+    /// The query fuzzy-matched exactly one worktree
+    Unique(WorktreeDescriptor, MatchField),
+    /// The query fuzzy-matched several worktrees
+    Ambiguous(Vec<(WorktreeDescriptor, MatchField)>),
+    /// Nothing matched; `suggestion` is a close worktree or branch name, if any
+    NoMatch { suggestion: Option<String> },
+}
+
+impl MatchResult {
+    /// The single matched worktree, or an error naming `query` if the match was
+    /// ambiguous or missing.
+    pub fn into_worktree(self, query: &str) -> Result<WorktreeDescriptor> {
+        match self {
+            MatchResult::Exact(wt) | MatchResult::Unique(wt, _) => Ok(wt),
+            MatchResult::Ambiguous(matches) => Err(WorktreeError::Ambiguous {
+                name: query.to_string(),
+                candidates: matches
+                    .iter()
+                    .filter_map(|(wt, _)| wt.name().map(str::to_string))
+                    .collect(),
+            }
+            .into()),
+            MatchResult::NoMatch { suggestion } => Err(WorktreeError::NotFound {
+                name: query.to_string(),
+                suggestion,
+            }
+            .into()),
+        }
+    }
+}
+
+/// Resolve `query` against the repository's worktrees.
+///
+/// Hidden worktrees (`workon.hiddenWorktree`) only match exactly.
+pub fn find_worktrees_matching(repo: &Repository, query: &str) -> Result<MatchResult> {
+    let worktrees = get_worktrees(repo)?;
+    let hidden = WorkonConfig::new(repo)?.hidden_worktrees()?;
+    let root = workon_root(repo)?;
+    Ok(match_worktrees(worktrees, root, query, |wt| {
+        wt.full_name().is_some_and(|name| hidden.contains(&name))
+    }))
+}
+
+/// Resolve `query` to a single worktree, by exact name or a unique fuzzy match.
+pub fn find_worktree_fuzzy(repo: &Repository, query: &str) -> Result<WorktreeDescriptor> {
+    find_worktrees_matching(repo, query)?.into_worktree(query)
+}
+
+/// Resolve `query` against `worktrees`, e.g. a list already narrowed by status filters.
+///
+/// Worktrees for which `is_hidden` returns true only match exactly.
+pub fn match_worktrees(
+    mut worktrees: Vec<WorktreeDescriptor>,
+    root: &Path,
+    query: &str,
+    is_hidden: impl Fn(&WorktreeDescriptor) -> bool,
+) -> MatchResult {
+    let exact = worktrees
+        .iter()
+        .position(|wt| wt.name() == Some(query))
+        .or_else(|| {
+            worktrees
+                .iter()
+                .position(|wt| wt.branch().ok().flatten().as_deref() == Some(query))
+        });
+    if let Some(index) = exact {
+        debug!("Found exact match: {}", query);
+        return MatchResult::Exact(worktrees.swap_remove(index));
+    }
+
+    worktrees.retain(|wt| !is_hidden(wt));
+    let branches: Vec<String> = worktrees
+        .iter()
+        .filter_map(|wt| wt.branch().ok().flatten())
+        .collect();
+    let suggestion = did_you_mean(
+        query,
+        worktrees
+            .iter()
+            .filter_map(|wt| wt.name())
+            .chain(branches.iter().map(String::as_str)),
+    );
+
+    let mut matches = fuzzy_matches(worktrees, root, query);
+    debug!("Found {} fuzzy match(es) for '{}'", matches.len(), query);
+    match matches.len() {
+        0 => MatchResult::NoMatch { suggestion },
+        1 => {
+            let (wt, field) = matches.remove(0);
+            MatchResult::Unique(wt, field)
+        }
+        _ => MatchResult::Ambiguous(matches),
+    }
+}
+
+/// Every worktree that fuzzy-matches `query`, with the field it matched on.
+///
+/// Unlike [`match_worktrees`], exact matches get no priority and nothing is hidden.
+pub fn fuzzy_matches(
+    worktrees: Vec<WorktreeDescriptor>,
+    root: &Path,
+    query: &str,
+) -> Vec<(WorktreeDescriptor, MatchField)> {
+    let query = query.to_lowercase();
+    worktrees
+        .into_iter()
+        .filter_map(|wt| {
+            let field = matched_field(&wt, root, &query)?;
+            Some((wt, field))
+        })
+        .collect()
+}
+
+/// The first field (name, branch, relative path) containing the lowercased query.
+fn matched_field(wt: &WorktreeDescriptor, root: &Path, query: &str) -> Option<MatchField> {
+    let contains = |s: &str| s.to_lowercase().contains(query);
+
+    if wt.name().is_some_and(contains) {
+        return Some(MatchField::Name);
+    }
+    if wt.branch().ok().flatten().is_some_and(|b| contains(&b)) {
+        return Some(MatchField::Branch);
+    }
+    let rel_path = wt.path().strip_prefix(root).ok()?;
+    if rel_path.to_str().is_some_and(contains) {
+        return Some(MatchField::Path);
+    }
+    None
+}
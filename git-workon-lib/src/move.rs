@@ -15,6 +15,10 @@
 //! If the directory move fails after branch rename, the operation rolls back the branch
 //! rename to maintain consistency.
 //!
+//! Steps 2 and 3 are also available on their own as [`relocate_worktree`], which
+//! `doctor --fix` uses to complete branch renames done outside workon. Step 3 alone is
+//! [`repair_worktree_links`], used by `doctor --fix` to repair broken `.git` files.
+//!
 //! ## Safety Checks
 //!
 //! By default, the operation performs several safety checks:
@@ -24,8 +28,19 @@
 //! - Source is not protected (matches `workon.pruneProtectedBranches`)
 //! - Source is not dirty (no uncommitted changes)
 //! - Source has no unpushed commits (all commits are pushed to remote)
+//! - Source is not locked (`git worktree lock`)
+//!
+//! ## Network Filesystems
+//!
+//! On NFS/SMB (see [`on_network_filesystem`](crate::on_network_filesystem)) the
+//! directory isn't renamed: it's copied, the copy is verified and only then is the
+//! original deleted. A failed or mismatched copy is removed and the move fails like a
+//! failed rename would.
 //!
-//! The `--force` flag overrides all safety checks (single flag for simplicity).
+//! The `--force` flag overrides all safety checks (single flag for simplicity), except
+//! the lock check. Locks are an explicit request to leave a worktree alone, so moving a
+//! locked worktree requires `--force-locked`. The lock (and its reason) is released for
+//! the duration of the move and re-created at the new location afterwards.
 //!
 //! ## Namespace Support
 //!
@@ -62,14 +77,17 @@
 //!
 //! # Override safety checks
 //! git workon move --force dirty-branch new-name
+//!
+//! # Move a locked worktree, keeping it locked
+//! git workon move --force-locked usb-branch new-name
 //! ```
 
 use git2::BranchType;
 use std::{fs, path::Path};
 
 use crate::{
-    error::Result, find_worktree, get_worktrees, WorkonConfig, WorkonError, WorktreeDescriptor,
-    WorktreeError,
+    error::Result, filesystem::copy_then_remove, find_worktree, get_worktrees,
+    on_network_filesystem, WorkonConfig, WorktreeDescriptor, WorktreeError,
 };
 
 /// Options for moving a worktree
@@ -77,6 +95,8 @@ use crate::{
 pub struct MoveOptions {
     /// Override safety checks (dirty, unpushed, protected)
     pub force: bool,
+    /// Move the worktree even if it is locked, re-creating the lock afterwards
+    pub force_locked: bool,
 }
 
 /// Move (rename) a worktree and its branch atomically.
@@ -104,6 +124,7 @@ pub struct MoveOptions {
 /// - Source is protected (unless force)
 /// - Source is dirty (unless force)
 /// - Source has unpushed commits (unless force)
+/// - Source is locked (unless force_locked)
 /// - Directory move fails
 pub fn move_worktree(
     repo: &git2::Repository,
@@ -123,47 +144,123 @@ pub fn move_worktree(
     let old_path = source.path().to_path_buf();
     let new_path = root.join(to);
 
-    // Calculate worktree names (basename of branch names)
-    let old_name = source.name().unwrap().to_string();
-    let new_name = Path::new(to)
+    // Step 1: Rename the branch
+    let mut branch = repo.find_branch(&branch_name, BranchType::Local)?;
+    branch.rename(to, false)?;
+
+    // Steps 2-4: Move the directory and metadata
+    match relocate_worktree(repo, &source, &new_path) {
+        Ok(worktree) => Ok(worktree),
+        Err(e) => {
+            // Attempt to rollback branch rename if the directory was not moved
+            if old_path.exists() {
+                let _ = branch.rename(&branch_name, false);
+            }
+            Err(e)
+        }
+    }
+}
+
+/// Move a worktree's directory to `new_path` and update its git metadata to match.
+///
+/// The worktree name becomes the basename of `new_path`. The branch is left untouched,
+/// which makes this useful on its own for completing a rename done outside workon
+/// (e.g. `git branch -m old new`).
+///
+/// This performs the following operations:
+/// 1. Releases the worktree lock, if any
+/// 2. Moves the worktree directory (copying and verifying it on network filesystems)
+/// 3. Renames the worktree metadata directory and rewrites the gitdir/`.git` link pair
+/// 4. Re-creates the lock at the new location
+///
+/// If any step fails, the lock is restored; if the directory move itself fails, nothing
+/// else is changed.
+pub fn relocate_worktree(
+    repo: &git2::Repository,
+    source: &WorktreeDescriptor,
+    new_path: &Path,
+) -> Result<WorktreeDescriptor> {
+    let old_path = source.path().to_path_buf();
+    let old_name = source.name().ok_or(WorktreeError::InvalidName)?.to_string();
+    let new_name = new_path
         .file_name()
         .and_then(|s| s.to_str())
         .ok_or(WorktreeError::InvalidName)?
         .to_string();
 
     // Create parent directories for namespace changes
+    let mut network = on_network_filesystem(repo, &old_path)?;
     if let Some(parent) = new_path.parent() {
-        std::fs::create_dir_all(parent)?;
+        fs::create_dir_all(parent)?;
+        network = network || on_network_filesystem(repo, parent)?;
     }
 
-    // Step 1: Rename the branch
-    let mut branch = repo.find_branch(&branch_name, BranchType::Local)?;
-    branch.rename(to, false)?;
-
-    // Step 2: Move the directory (with rollback on failure)
-    if let Err(e) = fs::rename(&old_path, &new_path) {
-        // Attempt to rollback branch rename
-        let _ = branch.rename(&branch_name, false);
-        return Err(WorkonError::Io(e));
-    }
+    // Step 1: Release the lock for the duration of the move
+    let lock = if source.is_locked()? {
+        let reason = source.lock_reason()?;
+        repo.find_worktree(&old_name)?.unlock()?;
+        Some(reason)
+    } else {
+        None
+    };
 
-    // Step 3: Rename worktree metadata directory if name changed
+    // Step 2: Move the directory, then rename the worktree metadata directory if the name
+    // changed
     let old_meta_dir = repo.path().join("worktrees").join(&old_name);
     let new_meta_dir = repo.path().join("worktrees").join(&new_name);
-    if old_meta_dir != new_meta_dir && old_meta_dir.exists() {
-        fs::rename(&old_meta_dir, &new_meta_dir)?;
+    let moved = (|| -> Result<()> {
+        if network {
+            copy_then_remove(&old_path, new_path)?;
+        } else {
+            fs::rename(&old_path, new_path)?;
+        }
+        if old_meta_dir != new_meta_dir && old_meta_dir.exists() {
+            fs::rename(&old_meta_dir, &new_meta_dir)?;
+        }
+        if new_meta_dir.exists() {
+            repair_worktree_links(repo, &new_name, new_path)?;
+        }
+        Ok(())
+    })();
+    // Restore the lock on failure, wherever the metadata directory ended up. The worktree
+    // may not be found through its links anymore, so write the lock file directly.
+    if let Err(e) = moved {
+        if let Some(reason) = &lock {
+            let meta_dir = if old_meta_dir.exists() {
+                &old_meta_dir
+            } else {
+                &new_meta_dir
+            };
+            let _ = fs::write(
+                meta_dir.join("locked"),
+                reason.as_deref().unwrap_or_default(),
+            );
+        }
+        return Err(e);
     }
-    if new_meta_dir.exists() {
-        let new_gitdir = new_meta_dir.join("gitdir");
-        let new_git = new_path.join(".git");
 
-        fs::write(&new_gitdir, format!("{}\n", new_git.display()))?;
-        fs::write(&new_git, format!("gitdir: {}\n", new_meta_dir.display()))?;
+    // Step 4: Re-create the lock at the new location
+    if let Some(reason) = lock {
+        repo.find_worktree(&new_name)?.lock(reason.as_deref())?;
     }
 
     WorktreeDescriptor::new(repo, &new_name)
 }
 
+/// Rewrite the link pair between a worktree directory and its admin directory.
+///
+/// Points `.git/worktrees/<name>/gitdir` at `<path>/.git`, and the worktree's `.git`
+/// file back at `.git/worktrees/<name>` — the in-process equivalent of
+/// `git worktree repair`.
+pub fn repair_worktree_links(repo: &git2::Repository, name: &str, path: &Path) -> Result<()> {
+    let meta_dir = repo.path().join("worktrees").join(name);
+    let git_file = path.join(".git");
+
+    fs::write(meta_dir.join("gitdir"), format!("{}\n", git_file.display()))?;
+    fs::write(&git_file, format!("gitdir: {}\n", meta_dir.display()))?;
+    Ok(())
+}
+
 pub fn validate_move(
     repo: &git2::Repository,
     source: &WorktreeDescriptor,
@@ -214,5 +311,14 @@ pub fn validate_move(
         return Err(WorktreeError::UnpushedCommits.into());
     }
 
+    // 7. Check if locked (unless --force-locked)
+    if !options.force_locked && source.is_locked()? {
+        return Err(WorktreeError::Locked {
+            name: source.name().unwrap_or_default().to_string(),
+            reason: source.lock_reason()?,
+        }
+        .into());
+    }
+
     Ok(())
 }
@@ -0,0 +1,315 @@
+//! Applying patch files to worktrees.
+//!
+//! Backs `git workon new --from-patch`, which reviews emailed or downloaded patches in
+//! their own worktree the way `git workon #123` does for pull requests. Two kinds of
+//! input are understood:
+//!
+//! - **mbox / `git format-patch` output**: one or more messages, each starting with a
+//!   `From <sha> <date>` line followed by `From:`, `Date:` and `Subject:` headers, the
+//!   commit message, a `---` separator and the diff. Each message becomes one patch that
+//!   keeps its author, date and message when committed.
+//! - **Raw diffs** (`git diff`, `diff -u`): the whole file is a single patch without
+//!   metadata.
+//!
+//! Patches are applied with libgit2, in order. [`apply_patches`] either leaves the
+//! changes uncommitted in the working tree (like `git apply`) or commits each patch
+//! (like `git am`).
+//!
+//! Encoded headers (RFC 2047) are kept as they are rather than decoded.
+
+use git2::{ApplyLocation, Diff, Oid, Repository, Signature, Time};
+
+use crate::error::{PatchError, Result};
+use crate::{commit_to_head, CommitSigner};
+
+/// One patch from a patch file: a diff, plus commit metadata when it came from an mbox.
+#[derive(Debug, Clone)]
+pub struct Patch {
+    /// Subject line, without the `[PATCH n/m]` prefix
+    pub subject: Option<String>,
+    /// Commit message body, after the subject
+    pub body: Option<String>,
+    /// Author name and email from the `From:` header
+    pub author: Option<(String, String)>,
+    /// Author time from the `Date:` header
+    pub date: Option<Time>,
+    diff: Vec<u8>,
+}
+
+impl Patch {
+    /// The commit message for this patch, or `fallback` if it has no subject.
+    pub fn message(&self, fallback: &str) -> String {
+        match (&self.subject, &self.body) {
+            (Some(subject), Some(body)) => format!("{}\n\n{}\n", subject, body),
+            (Some(subject), None) => format!("{}\n", subject),
+            _ => format!("{}\n", fallback),
+        }
+    }
+
+    /// A short description for messages: the subject, or `patch N` for raw diffs.
+    fn label(&self, index: usize) -> String {
+        self.subject
+            .clone()
+            .unwrap_or_else(|| format!("patch {}", index + 1))
+    }
+}
+
+/// Split the contents of a patch file into patches.
+///
+/// Errors if the file contains no diff at all.
+pub fn parse_patches(contents: &str) -> Result<Vec<Patch>> {
+    let lines: Vec<&str> = contents.split_inclusive('\n').collect();
+    let starts: Vec<usize> = (0..lines.len())
+        .filter(|&i| is_message_start(&lines, i))
+        .collect();
+
+    let patches: Vec<Patch> = if starts.is_empty() {
+        vec![Patch {
+            subject: None,
+            body: None,
+            author: None,
+            date: None,
+            diff: contents.as_bytes().to_vec(),
+        }]
+    } else {
+        starts
+            .iter()
+            .enumerate()
+            .map(|(n, &start)| {
+                let end = starts.get(n + 1).copied().unwrap_or(lines.len());
+                parse_message(&lines[start + 1..end])
+            })
+            .collect()
+    };
+
+    if patches.iter().all(|p| !has_diff(&p.diff)) {
+        return Err(PatchError::NoChanges.into());
+    }
+    Ok(patches)
+}
+
+/// Apply patches to a worktree's repository, in order.
+///
+/// With `commit`, each patch is staged and committed on HEAD, keeping the author, date
+/// and message recorded in the patch (raw diffs are committed as `fallback_message` by
+/// the current user), and signed if `sign` is set and `commit.gpgsign` is on. Returns
+/// the new commits, which is empty without `commit`.
+pub fn apply_patches(
+    repo: &Repository,
+    patches: &[Patch],
+    commit: bool,
+    sign: bool,
+    fallback_message: &str,
+) -> Result<Vec<Oid>> {
+    let signer = if commit && sign {
+        CommitSigner::from_config(repo)?
+    } else {
+        None
+    };
+    let location = if commit {
+        ApplyLocation::Both
+    } else {
+        ApplyLocation::WorkDir
+    };
+
+    let mut commits = Vec::new();
+    for (index, patch) in patches.iter().enumerate() {
+        // A message without a diff (e.g. a cover letter) has nothing to apply
+        if !has_diff(&patch.diff) {
+            continue;
+        }
+        let failed = |source| PatchError::Apply {
+            patch: patch.label(index),
+            applied: index,
+            total: patches.len(),
+            source,
+        };
+        let diff = Diff::from_buffer(&patch.diff).map_err(failed)?;
+        repo.apply(&diff, location, None).map_err(failed)?;
+
+        if commit {
+            commits.push(commit_patch(
+                repo,
+                patch,
+                fallback_message,
+                signer.as_ref(),
+            )?);
+        }
+    }
+    Ok(commits)
+}
+
+fn commit_patch(
+    repo: &Repository,
+    patch: &Patch,
+    fallback_message: &str,
+    signer: Option<&CommitSigner>,
+) -> Result<Oid> {
+    let committer = repo.signature()?;
+    let author = match &patch.author {
+        Some((name, email)) => {
+            let time = patch.date.unwrap_or_else(|| committer.when());
+            Signature::new(name, email, &time)?
+        }
+        None => committer.clone(),
+    };
+
+    let tree = repo.find_tree(repo.index()?.write_tree()?)?;
+    let parent = repo.head()?.peel_to_commit()?;
+    let message = patch.message(fallback_message);
+    commit_to_head(
+        repo,
+        &author,
+        &committer,
+        &message,
+        &tree,
+        &[&parent],
+        signer,
+    )
+}
+
+fn has_diff(diff: &[u8]) -> bool {
+    String::from_utf8_lossy(diff)
+        .lines()
+        .any(|line| line.starts_with("diff --git ") || line.starts_with("+++ "))
+}
+
+/// Whether `lines[i]` opens an mbox message: a `From ` line followed by a header.
+fn is_message_start(lines: &[&str], i: usize) -> bool {
+    lines[i].starts_with("From ") && lines.get(i + 1).and_then(|next| header(next)).is_some()
+}
+
+/// Split a `Name: value` header line.
+fn header(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = line.split_once(':')?;
+    let is_name = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+    is_name.then(|| (name, value.trim()))
+}
+
+/// Parse one mbox message (without its `From ` line).
+fn parse_message(lines: &[&str]) -> Patch {
+    let mut patch = Patch {
+        subject: None,
+        body: None,
+        author: None,
+        date: None,
+        diff: Vec::new(),
+    };
+
+    // Headers, with folded continuation lines joined
+    let mut headers: Vec<(String, String)> = Vec::new();
+    let mut rest = lines.len();
+    for (i, line) in lines.iter().enumerate() {
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            rest = i + 1;
+            break;
+        }
+        if line.starts_with([' ', '\t']) {
+            if let Some((_, value)) = headers.last_mut() {
+                value.push(' ');
+                value.push_str(line.trim());
+            }
+        } else if let Some((name, value)) = header(line) {
+            headers.push((name.to_ascii_lowercase(), value.to_string()));
+        }
+    }
+    for (name, value) in headers {
+        match name.as_str() {
+            "subject" => patch.subject = Some(strip_subject_prefix(&value).to_string()),
+            "from" => patch.author = parse_author(&value),
+            "date" => patch.date = parse_date(&value),
+            _ => {}
+        }
+    }
+
+    // Message body up to the `---` separator, then the diff
+    let lines = lines.get(rest..).unwrap_or_default();
+    let diff_start = lines
+        .iter()
+        .position(|l| l.starts_with("diff --git ") || l.starts_with("--- "))
+        .unwrap_or(lines.len());
+    let separator = lines[..diff_start]
+        .iter()
+        .position(|l| l.trim_end() == "---")
+        .unwrap_or(diff_start);
+    let body = lines[..separator].concat();
+    let body = body.trim();
+    if !body.is_empty() {
+        patch.body = Some(body.to_string());
+    }
+
+    // Drop the `-- ` signature format-patch appends after the last diff
+    let mut diff = &lines[diff_start..];
+    if let Some(signature) = diff.iter().rposition(|l| l.trim_end_matches('\n') == "-- ") {
+        diff = &diff[..signature];
+    }
+    patch.diff = diff.concat().into_bytes();
+    patch
+}
+
+/// Strip a leading `[PATCH]` / `[PATCH v2 3/7]` tag from a subject.
+fn strip_subject_prefix(subject: &str) -> &str {
+    match subject.strip_prefix('[').and_then(|s| s.split_once(']')) {
+        Some((tag, rest)) if tag.to_ascii_uppercase().contains("PATCH") => rest.trim(),
+        _ => subject,
+    }
+}
+
+/// Parse `Name <email>` (or a bare address) from a `From:` header.
+fn parse_author(value: &str) -> Option<(String, String)> {
+    match value.split_once('<') {
+        Some((name, email)) => {
+            let email = email.split_once('>')?.0.trim();
+            let name = name.trim().trim_matches('"');
+            let name = if name.is_empty() { email } else { name };
+            Some((name.to_string(), email.to_string()))
+        }
+        None if value.contains('@') => Some((value.to_string(), value.to_string())),
+        None => None,
+    }
+}
+
+/// Parse an RFC 2822 date such as `Tue, 13 Feb 2024 10:21:43 +0100`.
+fn parse_date(value: &str) -> Option<Time> {
+    let value = value.split_once(',').map_or(value, |(_, rest)| rest);
+    let mut fields = value.split_whitespace();
+    let day: i64 = fields.next()?.parse().ok()?;
+    let month = fields.next()?;
+    let month = MONTHS.iter().position(|m| m.eq_ignore_ascii_case(month))? as i64 + 1;
+    let year: i64 = fields.next()?.parse().ok()?;
+    let mut clock = fields.next()?.split(':').map(|f| f.parse::<i64>().ok());
+    let hour = clock.next()??;
+    let minute = clock.next()??;
+    let second = clock.next().flatten().unwrap_or(0);
+
+    let zone = fields.next().unwrap_or("+0000");
+    let (sign, digits) = match zone.split_at(1) {
+        ("+", digits) => (1, digits),
+        ("-", digits) => (-1, digits),
+        _ => return None,
+    };
+    let digits: i64 = digits.parse().ok()?;
+    let offset_minutes = sign * (digits / 100 * 60 + digits % 100);
+
+    let local = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second;
+    Some(Time::new(
+        local - offset_minutes * 60,
+        offset_minutes as i32,
+    ))
+}
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
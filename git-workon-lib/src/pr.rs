@@ -44,6 +44,13 @@
 //! For fork PRs, a fork remote is automatically added and the branch is fetched from it.
 //! For non-fork PRs, the branch is fetched from the detected remote (origin/upstream).
 //!
+//! ## Fork Remote Cleanup
+//!
+//! Fork remotes are named `pr-{number}-fork` and recorded in `workon.forkRemote`, so they
+//! don't pile up: `git workon prune` removes one once no remaining worktree's branch
+//! tracks it, and `git workon doctor --fix` removes any left over. Remotes added by hand
+//! aren't recorded and are never removed.
+//!
 //! ## Worktree Naming
 //!
 //! Worktree names are generated from `workon.prFormat` config (default: `pr-{number}`):
@@ -53,6 +60,11 @@
 //!
 //! The format must contain `{number}` placeholder.
 //!
+//! The PR number, title and author are recorded in `workon-worktree.<worktree>.pr`,
+//! `.prTitle` and `.prAuthor` ([`record_pr`]), so `list` and `find` can show what a review worktree is
+//! about, and running `git workon #123` again switches to the existing worktree instead
+//! of failing ([`find_pr_worktree`]).
+//!
 //! ## Example Usage
 //!
 //! ```bash
@@ -78,12 +90,12 @@
 //! - **Metadata**: Fetches PR title, author, branch names, and state
 //! - **Validation**: Checks PR exists before creating worktree
 
-use git2::{FetchOptions, Repository};
-use log::debug;
+use git2::Repository;
+use tracing::{debug, instrument};
 
 use crate::{
     error::{PrError, Result},
-    get_remote_callbacks,
+    fetch_refspecs, get_worktrees, sanitize_for_branch_name, WorkonConfig, WorktreeDescriptor,
 };
 
 /// Represents a pull request reference
@@ -320,36 +332,6 @@ pub fn fetch_pr_metadata(pr_number: u32) -> Result<PrMetadata> {
     })
 }
 
-/// Sanitize a string for use in branch/worktree names
-fn sanitize_for_branch_name(s: &str) -> String {
-    let sanitized = s
-        .chars()
-        .map(|c| match c {
-            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => c,
-            ' ' | '/' => '-',
-            _ => '-',
-        })
-        .collect::<String>()
-        .to_lowercase();
-
-    // Collapse multiple dashes into single dash
-    let mut result = String::new();
-    let mut last_was_dash = false;
-    for c in sanitized.chars() {
-        if c == '-' {
-            if !last_was_dash {
-                result.push(c);
-            }
-            last_was_dash = true;
-        } else {
-            result.push(c);
-            last_was_dash = false;
-        }
-    }
-
-    result.trim_matches(|c| c == '-' || c == '_').to_string()
-}
-
 /// Format PR name with metadata placeholders
 pub fn format_pr_name_with_metadata(format: &str, metadata: &PrMetadata) -> String {
     format
@@ -359,6 +341,37 @@ pub fn format_pr_name_with_metadata(format: &str, metadata: &PrMetadata) -> Stri
         .replace("{branch}", &sanitize_for_branch_name(&metadata.head_ref))
 }
 
+/// Find the worktree already created for a PR.
+///
+/// Looks for a worktree with the PR number recorded in `workon-worktree.<worktree>.pr`,
+/// then, if `pr_format` only uses `{number}`, for a worktree with the formatted name.
+/// Formats using the title, author or branch need gh metadata to resolve, so aren't
+/// matched by name.
+pub fn find_pr_worktree(
+    repo: &Repository,
+    pr_number: u32,
+    pr_format: &str,
+) -> Result<Option<WorktreeDescriptor>> {
+    let config = WorkonConfig::new(repo)?;
+    let mut worktrees = get_worktrees(repo)?;
+    for index in 0..worktrees.len() {
+        if let Some(full_name) = worktrees[index].full_name() {
+            if config.pr_number(&full_name)? == Some(pr_number) {
+                return Ok(Some(worktrees.swap_remove(index)));
+            }
+        }
+    }
+
+    let name = format_pr_name(pr_format, pr_number);
+    if ["{title}", "{author}", "{branch}"]
+        .iter()
+        .any(|placeholder| name.contains(placeholder))
+    {
+        return Ok(None);
+    }
+    Ok(worktrees.into_iter().find(|wt| wt.name() == Some(&name)))
+}
+
 /// Check if a string looks like a PR reference
 ///
 /// This is a quick check used for routing decisions.
@@ -421,10 +434,47 @@ pub fn setup_fork_remote(repo: &Repository, metadata: &PrMetadata) -> Result<Str
             remote: fork_remote_name.clone(),
             message: format!("Failed to add fork remote: {}", e),
         })?;
+    WorkonConfig::new(repo)?.set_fork_remote(&fork_remote_name, true)?;
 
     Ok(fork_remote_name)
 }
 
+/// Fork remotes added by the PR flow that no local branch tracks anymore.
+///
+/// A branch kept after its worktree was pruned still counts: removing the remote would
+/// drop its upstream. Includes names still recorded in `workon.forkRemote` whose remote
+/// was already removed.
+pub fn unused_fork_remotes(repo: &Repository) -> Result<Vec<String>> {
+    let owned = WorkonConfig::new(repo)?.fork_remotes()?;
+    if owned.is_empty() {
+        return Ok(owned);
+    }
+
+    let config = repo.config()?.snapshot()?;
+    let mut in_use = Vec::new();
+    let mut entries = config.entries(Some(r"^branch\..*\.remote$"))?;
+    while let Some(entry) = entries.next() {
+        if let Some(remote) = entry?.value() {
+            in_use.push(remote.to_string());
+        }
+    }
+    Ok(owned
+        .into_iter()
+        .filter(|remote| !in_use.contains(remote))
+        .collect())
+}
+
+/// Remove a fork remote added by the PR flow, along with its remote-tracking branches
+/// and the upstream config of branches tracking it.
+pub fn remove_fork_remote(repo: &Repository, remote_name: &str) -> Result<()> {
+    if repo.find_remote(remote_name).is_ok() {
+        debug!("Removing fork remote {}", remote_name);
+        repo.remote_delete(remote_name)?;
+    }
+    WorkonConfig::new(repo)?.set_fork_remote(remote_name, false)?;
+    Ok(())
+}
+
 /// Fetch a branch from a remote
 ///
 /// This fetches the specified branch from the remote, making it available
@@ -432,6 +482,7 @@ pub fn setup_fork_remote(repo: &Repository, metadata: &PrMetadata) -> Result<Str
 ///
 /// This is used for both fork and non-fork PRs to fetch the actual branch
 /// that was used to create the PR (using gh CLI metadata).
+#[instrument(level = "debug", skip(repo))]
 pub fn fetch_branch(repo: &Repository, remote_name: &str, branch: &str) -> Result<()> {
     // Check if branch already exists locally
     let branch_ref = format!("refs/remotes/{}/{}", remote_name, branch);
@@ -447,19 +498,19 @@ pub fn fetch_branch(repo: &Repository, remote_name: &str, branch: &str) -> Resul
         branch, remote_name, branch
     );
 
-    let mut fetch_options = FetchOptions::new();
-    fetch_options.remote_callbacks(get_remote_callbacks()?);
-
-    repo.find_remote(remote_name)?
-        .fetch(
-            &[refspec.as_str()],
-            Some(&mut fetch_options),
-            Some("Fetching PR branch"),
-        )
-        .map_err(|e| PrError::FetchFailed {
-            remote: remote_name.to_string(),
-            message: e.message().to_string(),
-        })?;
+    fetch_refspecs(
+        repo,
+        remote_name,
+        &[refspec.as_str()],
+        Some("Fetching PR branch"),
+    )
+    .map_err(|e| PrError::FetchFailed {
+        remote: remote_name.to_string(),
+        message: match e.git_error() {
+            Some(git_error) => git_error.message().to_string(),
+            None => e.to_string(),
+        },
+    })?;
 
     debug!("Successfully fetched branch {}", branch);
     Ok(())
@@ -481,12 +532,12 @@ pub fn format_pr_name(format: &str, pr_number: u32) -> String {
 /// 4. Fetch PR branch
 /// 5. Format worktree name using metadata
 ///
-/// Returns (worktree_name, remote_ref, base_branch) for use with add_worktree
+/// Returns (worktree_name, remote_ref, metadata) for use with add_worktree
 pub fn prepare_pr_worktree(
     repo: &Repository,
     pr_number: u32,
     pr_format: &str,
-) -> Result<(String, String, String)> {
+) -> Result<(String, String, PrMetadata)> {
     debug!("Preparing PR worktree for PR #{}", pr_number);
 
     // Fetch PR metadata from gh CLI
@@ -516,7 +567,18 @@ pub fn prepare_pr_worktree(
     let remote_ref = format!("{}/{}", remote_name, metadata.head_ref);
     debug!("Remote ref: {}", remote_ref);
 
-    Ok((worktree_name, remote_ref, metadata.base_ref))
+    Ok((worktree_name, remote_ref, metadata))
+}
+
+/// Record which PR a worktree was created for, in its per-worktree config.
+///
+/// `worktree_name` is the worktree's [full name](WorktreeDescriptor::full_name).
+pub fn record_pr(repo: &Repository, worktree_name: &str, metadata: &PrMetadata) -> Result<()> {
+    let config = WorkonConfig::new(repo)?;
+    config.set_worktree_meta(worktree_name, "pr", Some(&metadata.number.to_string()))?;
+    config.set_worktree_meta(worktree_name, "prTitle", Some(&metadata.title))?;
+    config.set_worktree_meta(worktree_name, "prAuthor", Some(&metadata.author))?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -0,0 +1,109 @@
+//! HTTP(S) proxy settings for remote operations.
+//!
+//! libgit2 only uses a proxy it's told about, so every fetch, clone, push and remote
+//! connection passes [`get_proxy_options`]. The proxy for an `http://` or `https://` URL
+//! is looked up the way git does it:
+//!
+//! 1. `http.<url>.proxy`, for the longest `<url>` prefix of the remote URL
+//! 2. `http.proxy`
+//! 3. `https_proxy`/`HTTPS_PROXY` (HTTPS URLs) or `http_proxy` (HTTP URLs)
+//! 4. `all_proxy`/`ALL_PROXY`
+//!
+//! An empty config value turns the proxy off, and hosts listed in `no_proxy`/`NO_PROXY`
+//! (comma-separated; `.example.com` or `example.com` covers subdomains, `*` covers
+//! everything) are reached directly. SSH and local remotes never use a proxy.
+//!
+//! ```bash
+//! git config --global http.proxy http://proxy.corp.example:3128
+//! git config --global http.https://github.example.com.proxy ""   # direct
+//! ```
+
+use git2::{Config, ProxyOptions};
+use tracing::debug;
+
+/// Proxy options for connecting to `url`, from git config and the environment.
+pub fn get_proxy_options<'a>(config: &Config, url: &str) -> ProxyOptions<'a> {
+    let mut options = ProxyOptions::new();
+    if let Some(proxy) = proxy_url(config, url) {
+        debug!("Using proxy {} for {}", proxy, url);
+        options.url(&proxy);
+    }
+    options
+}
+
+/// The proxy to reach `url` through, or None to connect directly.
+pub fn proxy_url(config: &Config, url: &str) -> Option<String> {
+    let scheme = url.split_once("://").map(|(scheme, _)| scheme)?;
+    if scheme != "http" && scheme != "https" {
+        return None;
+    }
+
+    let proxy = configured_proxy(config, url).or_else(|| {
+        let names: &[&str] = if scheme == "https" {
+            &["https_proxy", "HTTPS_PROXY", "all_proxy", "ALL_PROXY"]
+        } else {
+            &["http_proxy", "all_proxy", "ALL_PROXY"]
+        };
+        names.iter().find_map(|name| std::env::var(name).ok())
+    })?;
+    if proxy.trim().is_empty() || bypasses_proxy(url) {
+        return None;
+    }
+    Some(proxy)
+}
+
+/// `http.<url>.proxy` for the longest matching URL prefix, else `http.proxy`.
+fn configured_proxy(config: &Config, url: &str) -> Option<String> {
+    let mut best: Option<(usize, String)> = None;
+    if let Ok(mut entries) = config.entries(Some(r"^http\..+\.proxy$")) {
+        while let Some(Ok(entry)) = entries.next() {
+            let (Some(name), Some(value)) = (entry.name(), entry.value()) else {
+                continue;
+            };
+            let Some(prefix) = name
+                .strip_prefix("http.")
+                .and_then(|n| n.strip_suffix(".proxy"))
+            else {
+                continue;
+            };
+            let longer = best.as_ref().is_none_or(|(len, _)| prefix.len() >= *len);
+            if url.starts_with(prefix) && longer {
+                best = Some((prefix.len(), value.to_string()));
+            }
+        }
+    }
+    best.map(|(_, value)| value)
+        .or_else(|| config.get_string("http.proxy").ok())
+}
+
+/// Whether `no_proxy`/`NO_PROXY` lists `url`'s host.
+fn bypasses_proxy(url: &str) -> bool {
+    let Some(host) = url_host(url) else {
+        return false;
+    };
+    let Some(no_proxy) = ["no_proxy", "NO_PROXY"]
+        .iter()
+        .find_map(|name| std::env::var(name).ok())
+    else {
+        return false;
+    };
+    no_proxy
+        .split(',')
+        .map(|entry| entry.trim().trim_start_matches('.'))
+        .filter(|entry| !entry.is_empty())
+        .any(|entry| {
+            entry == "*"
+                || host.eq_ignore_ascii_case(entry)
+                || host
+                    .to_ascii_lowercase()
+                    .ends_with(&format!(".{}", entry.to_ascii_lowercase()))
+        })
+}
+
+/// The host of a `scheme://[user@]host[:port]/...` URL.
+pub(crate) fn url_host(url: &str) -> Option<&str> {
+    let (_, rest) = url.split_once("://")?;
+    let authority = rest.split('/').next()?;
+    let host = authority.rsplit('@').next()?.split(':').next()?;
+    (!host.is_empty()).then_some(host)
+}
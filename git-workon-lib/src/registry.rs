@@ -0,0 +1,298 @@
+//! Registry of workon repositories.
+//!
+//! An optional, user-level list of named workon roots, so commands can target a
+//! repository from anywhere with `git workon -R <name> ...`. The registry lives at
+//! `$XDG_CONFIG_HOME/workon/repos.toml` (falling back to `~/.config/workon/repos.toml`):
+//!
+//! ```toml
+//! [repos]
+//! api = "~/src/api"
+//! web = "/home/me/src/web"
+//! ```
+//!
+//! Only this small subset of TOML is understood: a `[repos]` table of `name = "path"`
+//! entries, with basic (`"..."`) or literal (`'...'`) strings, `#` comments, and a leading
+//! `~/` expanded to the home directory. Other tables are ignored so the file can grow.
+//! A missing file is an empty registry. `git workon repos add|remove` edit the `[repos]`
+//! table and leave the rest of the file alone.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use crate::did_you_mean;
+use crate::error::{RegistryError, Result};
+
+const REPOS_TABLE: &str = "repos";
+
+/// Named workon roots, loaded from the user's registry file.
+#[derive(Debug, Clone)]
+pub struct RepoRegistry {
+    path: PathBuf,
+    repos: BTreeMap<String, PathBuf>,
+}
+
+impl RepoRegistry {
+    /// Location of the user's registry file.
+    pub fn default_path() -> Result<PathBuf> {
+        let config_dir = match env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+            Some(dir) => PathBuf::from(dir),
+            None => home_dir()
+                .ok_or(RegistryError::NoConfigDir)?
+                .join(".config"),
+        };
+        Ok(config_dir.join("workon").join("repos.toml"))
+    }
+
+    /// Load the user's registry.
+    pub fn load() -> Result<Self> {
+        Self::load_from(Self::default_path()?)
+    }
+
+    /// Load a registry from `path`. A missing file is an empty registry.
+    pub fn load_from(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let repos = parse(&path, &contents)?;
+        Ok(Self { path, repos })
+    }
+
+    /// The file this registry was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.repos.is_empty()
+    }
+
+    /// The workon root registered as `name`, if any.
+    pub fn get(&self, name: &str) -> Option<&Path> {
+        self.repos.get(name).map(PathBuf::as_path)
+    }
+
+    /// The workon root registered as `name`, or an error suggesting a close match.
+    pub fn resolve(&self, name: &str) -> Result<&Path> {
+        self.get(name).ok_or_else(|| {
+            RegistryError::UnknownRepo {
+                name: name.to_string(),
+                suggestion: did_you_mean(name, self.repos.keys().map(String::as_str)),
+                path: self.path.clone(),
+            }
+            .into()
+        })
+    }
+
+    /// Register `root` as `name`, returning the root previously registered under that
+    /// name, if any. Call [`save`](Self::save) to persist the change.
+    pub fn insert(&mut self, name: &str, root: impl Into<PathBuf>) -> Option<PathBuf> {
+        self.repos.insert(name.to_string(), root.into())
+    }
+
+    /// Unregister `name`, returning its root if it was registered. Call
+    /// [`save`](Self::save) to persist the change.
+    pub fn remove(&mut self, name: &str) -> Option<PathBuf> {
+        self.repos.remove(name)
+    }
+
+    /// Write the registry back to its file.
+    ///
+    /// The `[repos]` table is rewritten in place; everything else in the file (comments,
+    /// other tables) is kept as is.
+    pub fn save(&self) -> Result<()> {
+        let existing = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut contents = String::new();
+        let mut in_repos = false;
+        let mut written = false;
+        for line in existing.lines() {
+            if let Some(table) = table_header(line.trim()) {
+                if in_repos {
+                    // Keep a blank line between the rewritten table and the next one
+                    contents.push('\n');
+                }
+                in_repos = table == REPOS_TABLE;
+                if in_repos {
+                    contents.push_str(&self.repos_table());
+                    written = true;
+                    continue;
+                }
+            }
+            if !in_repos {
+                contents.push_str(line);
+                contents.push('\n');
+            }
+        }
+        if !written {
+            if !contents.is_empty() && !contents.ends_with("\n\n") {
+                contents.push('\n');
+            }
+            contents.push_str(&self.repos_table());
+        }
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = self
+            .path
+            .with_extension(format!("toml.tmp.{}", std::process::id()));
+        fs::write(&tmp, contents)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+
+    fn repos_table(&self) -> String {
+        let mut table = format!("[{}]\n", REPOS_TABLE);
+        for (name, root) in &self.repos {
+            let key = if is_bare_key(name) {
+                name.clone()
+            } else {
+                quote(name)
+            };
+            table.push_str(&format!("{} = {}\n", key, quote(&root.to_string_lossy())));
+        }
+        table
+    }
+
+    /// Registered repositories, ordered by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Path)> {
+        self.repos
+            .iter()
+            .map(|(name, path)| (name.as_str(), path.as_path()))
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+fn expand_home(value: &str) -> PathBuf {
+    match (value.strip_prefix("~/"), home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(value),
+    }
+}
+
+fn parse(path: &Path, contents: &str) -> Result<BTreeMap<String, PathBuf>> {
+    let error = |line: usize, message: &str| RegistryError::Parse {
+        path: path.to_path_buf(),
+        line,
+        message: message.to_string(),
+    };
+
+    let mut repos = BTreeMap::new();
+    let mut table: Option<String> = None;
+
+    for (index, raw) in contents.lines().enumerate() {
+        let line_no = index + 1;
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            let name =
+                table_header(line).ok_or_else(|| error(line_no, "unterminated table header"))?;
+            table = Some(name.to_string());
+            continue;
+        }
+
+        let (key, rest) = parse_key(line).ok_or_else(|| error(line_no, "expected a key"))?;
+        let rest = rest
+            .trim_start()
+            .strip_prefix('=')
+            .ok_or_else(|| error(line_no, "expected '=' after the key"))?;
+        let (value, rest) =
+            parse_string(rest.trim_start()).ok_or_else(|| error(line_no, "expected a string"))?;
+        if !strip_comment(rest).is_empty() {
+            return Err(error(line_no, "unexpected text after the value").into());
+        }
+
+        if table.as_deref() == Some(REPOS_TABLE)
+            && repos.insert(key.clone(), expand_home(&value)).is_some()
+        {
+            return Err(error(line_no, &format!("duplicate repository '{}'", key)).into());
+        }
+    }
+
+    Ok(repos)
+}
+
+/// The name of the table a `[table]` header line opens.
+fn table_header(line: &str) -> Option<&str> {
+    let header = strip_comment(line.strip_prefix('[')?);
+    header.strip_suffix(']').map(str::trim)
+}
+
+fn is_bare_key_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_'
+}
+
+fn is_bare_key(key: &str) -> bool {
+    !key.is_empty() && key.chars().all(is_bare_key_char)
+}
+
+/// Drop a trailing `# comment` (and surrounding whitespace) from the remainder of a line.
+fn strip_comment(rest: &str) -> &str {
+    rest.split_once('#')
+        .map_or(rest, |(before, _)| before)
+        .trim()
+}
+
+/// Parse a bare or quoted key, returning it and the rest of the line.
+fn parse_key(line: &str) -> Option<(String, &str)> {
+    if line.starts_with('"') || line.starts_with('\'') {
+        return parse_string(line);
+    }
+    let end = line
+        .find(|c: char| !is_bare_key_char(c))
+        .unwrap_or(line.len());
+    (end > 0).then(|| (line[..end].to_string(), &line[end..]))
+}
+
+/// Parse a basic (`"..."`) or literal (`'...'`) string, returning it and the rest of the line.
+fn parse_string(input: &str) -> Option<(String, &str)> {
+    let mut chars = input.char_indices();
+    let quote = match chars.next()? {
+        (_, q @ ('"' | '\'')) => q,
+        _ => return None,
+    };
+
+    let mut value = String::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            c if c == quote => return Some((value, &input[i + 1..])),
+            '\\' if quote == '"' => match chars.next()?.1 {
+                '"' => value.push('"'),
+                '\\' => value.push('\\'),
+                't' => value.push('\t'),
+                'n' => value.push('\n'),
+                _ => return None,
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// Quote `value` as a basic string.
+fn quote(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
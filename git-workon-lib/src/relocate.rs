@@ -0,0 +1,225 @@
+//! Relocating an entire workon layout.
+//!
+//! Moves the workon root - the bare repository and every worktree inside it - to a new
+//! directory, e.g. onto a new disk or after renaming a project folder:
+//!
+//! ```bash
+//! git workon relocate ~/src/new-project-name
+//! git workon relocate --dry-run /mnt/fast/project
+//! ```
+//!
+//! ## Strategy
+//!
+//! 1. Plan: compute every worktree's new location ([`plan_root_relocation`]). Worktrees
+//!    living outside the root keep their location.
+//! 2. Move the root with a single rename. When that fails (typically because the target
+//!    is on another filesystem), the root is copied instead and the original is only
+//!    removed once everything else has succeeded.
+//! 3. Rewrite both sides of every worktree link (`worktrees/<name>/gitdir` and the
+//!    worktree's `.git` file) with [`repair_worktree_links`]. External worktrees are
+//!    relinked too, since the admin directories they point to have moved. An absolute
+//!    `commondir` in an admin directory is repointed at the new bare repository.
+//!
+//! If relinking fails, the move is undone and the links are restored to the original
+//! locations, leaving the layout as it was.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use git2::Repository;
+use tracing::{debug, instrument};
+
+use crate::{
+    error::Result, filesystem::copy_dir_all, get_worktrees, repair_worktree_links, workon_root,
+    WorktreeError,
+};
+
+/// A worktree affected by a root relocation.
+#[derive(Debug, Clone)]
+pub struct RelocatedWorktree {
+    pub name: String,
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
+impl RelocatedWorktree {
+    /// Whether the worktree lives outside the workon root and stays where it is.
+    pub fn is_external(&self) -> bool {
+        self.from == self.to
+    }
+}
+
+/// The full set of changes made by [`relocate_root`].
+#[derive(Debug, Clone)]
+pub struct RootRelocation {
+    /// Current workon root
+    pub from: PathBuf,
+    /// New workon root
+    pub to: PathBuf,
+    /// Bare repository location before the move
+    pub git_dir_from: PathBuf,
+    /// Bare repository location after the move
+    pub git_dir_to: PathBuf,
+    pub worktrees: Vec<RelocatedWorktree>,
+}
+
+/// Work out where the bare repository and each worktree end up when the workon root
+/// moves to `new_root`, without touching anything.
+///
+/// `new_root` must be an absolute path that doesn't exist yet (or is an empty directory)
+/// and isn't inside the current root.
+#[instrument(level = "debug", skip(repo))]
+pub fn plan_root_relocation(repo: &Repository, new_root: &Path) -> Result<RootRelocation> {
+    let from: PathBuf = workon_root(repo)?.components().collect();
+    let to = normalize_target(new_root)?;
+
+    if to.starts_with(&from) {
+        return Err(WorktreeError::RelocateIntoRoot(to.display().to_string()).into());
+    }
+    if to.exists() && (!to.is_dir() || fs::read_dir(&to)?.next().is_some()) {
+        return Err(WorktreeError::RelocateTargetExists(to.display().to_string()).into());
+    }
+
+    let git_dir_from: PathBuf = repo.path().components().collect();
+    let git_dir_to = rebase(&git_dir_from, &from, &to);
+
+    let worktrees = get_worktrees(repo)?
+        .into_iter()
+        .filter_map(|wt| {
+            let name = wt.name()?.to_string();
+            let from_path = wt.path().to_path_buf();
+            let to_path = rebase(&from_path, &from, &to);
+            Some(RelocatedWorktree {
+                name,
+                from: from_path,
+                to: to_path,
+            })
+        })
+        .collect();
+
+    Ok(RootRelocation {
+        from,
+        to,
+        git_dir_from,
+        git_dir_to,
+        worktrees,
+    })
+}
+
+/// Move the workon root as described by `plan`, rewriting every worktree link.
+///
+/// On failure the layout is restored to its original location before the error is
+/// returned.
+#[instrument(level = "debug", skip_all)]
+pub fn relocate_root(plan: &RootRelocation) -> Result<()> {
+    if let Some(parent) = plan.to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    // An empty target directory is allowed; rename can't replace it on every platform
+    if plan.to.is_dir() {
+        fs::remove_dir(&plan.to)?;
+    }
+
+    let copied = match fs::rename(&plan.from, &plan.to) {
+        Ok(()) => false,
+        Err(e) => {
+            debug!("rename failed ({}), copying the workon root instead", e);
+            if let Err(e) = copy_dir_all(&plan.from, &plan.to) {
+                let _ = fs::remove_dir_all(&plan.to);
+                return Err(e.into());
+            }
+            true
+        }
+    };
+
+    if let Err(e) = relink(&plan.git_dir_to, &plan.worktrees, |wt| &wt.to) {
+        debug!("relinking failed ({}), restoring the original layout", e);
+        if copied {
+            let _ = fs::remove_dir_all(&plan.to);
+        } else {
+            let _ = fs::rename(&plan.to, &plan.from);
+        }
+        let _ = relink(&plan.git_dir_from, &plan.worktrees, |wt| &wt.from);
+        return Err(e);
+    }
+
+    if copied {
+        fs::remove_dir_all(&plan.from)?;
+    }
+    Ok(())
+}
+
+/// Rewrite the link pair between each worktree (at the location chosen by `path`) and its
+/// admin directory in the bare repository at `git_dir`.
+fn relink(
+    git_dir: &Path,
+    worktrees: &[RelocatedWorktree],
+    path: impl Fn(&RelocatedWorktree) -> &PathBuf,
+) -> Result<()> {
+    let repo = Repository::open_bare(git_dir)?;
+    for wt in worktrees {
+        let path = path(wt);
+        // Missing worktrees are left for `doctor` to report and prune
+        if !path.is_dir() {
+            debug!(
+                "skipping missing worktree '{}' at {}",
+                wt.name,
+                path.display()
+            );
+            continue;
+        }
+        repair_worktree_links(&repo, &wt.name, path)?;
+        repoint_commondir(&repo.path().join("worktrees").join(&wt.name), git_dir)?;
+    }
+    Ok(())
+}
+
+/// Worktrees created by libgit2 record the bare repository as an absolute `commondir`
+/// (git itself writes `../..`), which has to follow the bare repository as well.
+fn repoint_commondir(meta_dir: &Path, git_dir: &Path) -> Result<()> {
+    let file = meta_dir.join("commondir");
+    let Ok(contents) = fs::read_to_string(&file) else {
+        return Ok(());
+    };
+    if Path::new(contents.trim_end()).is_absolute() {
+        fs::write(&file, format!("{}\n", git_dir.display()))?;
+    }
+    Ok(())
+}
+
+/// Resolve `path` against its closest existing ancestor so it compares equal to the
+/// canonical paths git reports.
+fn normalize_target(path: &Path) -> Result<PathBuf> {
+    let path: PathBuf = if path.is_absolute() {
+        path.components().collect()
+    } else {
+        std::env::current_dir()?.join(path).components().collect()
+    };
+
+    let mut existing = path.as_path();
+    let mut rest = Vec::new();
+    while !existing.exists() {
+        match (existing.parent(), existing.file_name()) {
+            (Some(parent), Some(name)) => {
+                rest.push(name.to_os_string());
+                existing = parent;
+            }
+            _ => return Ok(path),
+        }
+    }
+
+    let mut resolved = existing.canonicalize()?;
+    resolved.extend(rest.iter().rev());
+    Ok(resolved)
+}
+
+/// Translate `path` from under `from` to the same place under `to`. Paths outside
+/// `from` are returned unchanged.
+fn rebase(path: &Path, from: &Path, to: &Path) -> PathBuf {
+    match path.strip_prefix(from) {
+        Ok(relative) => to.join(relative),
+        Err(_) => path.to_path_buf(),
+    }
+}
@@ -0,0 +1,89 @@
+//! Branch-name rules.
+//!
+//! A rule applies settings to every new worktree whose branch name matches a glob, so
+//! naming conventions (`hotfix/*`, `spike-*`) drive them without per-invocation flags:
+//!
+//! ```gitconfig
+//! [workon "rule.hotfix/*"]
+//!   base = release/current
+//!   postCreateHook = ./scripts/hotfix-setup.sh
+//!   protect = true
+//! ```
+//!
+//! - **base** - Branch to start from, unless `--base` or a template's base is given (string)
+//! - **postCreateHook** - Hooks to run after workon.postCreateHook's (multi-value)
+//! - **protect** - Protect matching branches like workon.pruneProtectedBranches does:
+//!   `prune` skips them and `move` refuses to rename them (bool, default: false)
+//!
+//! Globs use `*`, `?` and `[...]`; `*` also matches `/`. When several rules match, their
+//! hooks all run, in config order, and the first rule with a base sets it.
+
+use git2::Config;
+use glob::Pattern;
+use tracing::debug;
+
+use crate::error::{CopyError, Result};
+
+/// Every `workon.rule.<glob>.<key>` key, as documented.
+pub(crate) const RULE_KEYS: &[&str] = &["base", "postCreateHook", "protect"];
+
+/// Settings for branches matching a glob (`workon.rule.<glob>.*`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorktreeRule {
+    pub glob: String,
+    /// Branch to start from
+    pub base: Option<String>,
+    /// Hooks run after the configured post-create hooks
+    pub post_create_hooks: Vec<String>,
+    /// Whether matching branches are protected from pruning and renaming
+    pub protect: bool,
+}
+
+impl WorktreeRule {
+    /// Whether `branch_name` matches this rule's glob.
+    pub fn matches(&self, branch_name: &str) -> Result<bool> {
+        let pattern = Pattern::new(&self.glob).map_err(|source| CopyError::InvalidGlobPattern {
+            pattern: self.glob.clone(),
+            source,
+        })?;
+        Ok(pattern.matches(branch_name))
+    }
+}
+
+/// Read every rule in `config`, in the order they're first defined. Unknown keys are
+/// ignored here; `doctor` reports them.
+pub(crate) fn read_rules(config: &Config) -> Result<Vec<WorktreeRule>> {
+    let mut rules: Vec<WorktreeRule> = Vec::new();
+    let mut entries = config.entries(Some(r"^workon\.rule\..+\..+$"))?;
+    while let Some(entry) = entries.next() {
+        let entry = entry?;
+        let (Some(name), Some(value)) = (entry.name(), entry.value()) else {
+            continue;
+        };
+        let Some((glob, key)) = name
+            .strip_prefix("workon.rule.")
+            .and_then(|rest| rest.rsplit_once('.'))
+        else {
+            continue;
+        };
+        let position = match rules.iter().position(|r| r.glob == glob) {
+            Some(position) => position,
+            None => {
+                rules.push(WorktreeRule {
+                    glob: glob.to_string(),
+                    ..Default::default()
+                });
+                rules.len() - 1
+            }
+        };
+        let rule = &mut rules[position];
+        // Git lowercases variable names
+        match key {
+            "base" => rule.base = Some(value.to_string()).filter(|b| !b.trim().is_empty()),
+            "postcreatehook" => rule.post_create_hooks.push(value.to_string()),
+            "protect" => rule.protect = Config::parse_bool(value)?,
+            _ => debug!("Ignoring unknown rule key {}", name),
+        }
+    }
+    Ok(rules)
+}
@@ -0,0 +1,512 @@
+//! Load-once snapshot of workon configuration.
+//!
+//! [`WorkonConfig`](crate::WorkonConfig) reads one key at a time, reopening the git config
+//! for every lookup. That's fine for a command that needs one or two values, but `list`
+//! and `find` ask for the labels, note and hidden state of every worktree.
+//! [`WorkonSettings::load`] instead reads every `workon.*` and `workon-worktree.*` entry
+//! in a single pass and parses it into typed fields, each recording which config files
+//! it came from:
+//!
+//! ```no_run
+//! # fn main() -> workon::Result<()> {
+//! let repo = workon::get_repo(None)?;
+//! let settings = workon::WorkonSettings::load(&repo)?;
+//! println!("hooks: {:?}", settings.post_create_hooks.value);
+//! if settings.is_hidden("gh-pages") {
+//!     println!("gh-pages is hidden");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Values are resolved the way git resolves them: the last value of a single-valued key
+//! wins (local over global over system), and multi-value keys collect every value.
+//! An invalid value (an unknown name style, a malformed duration) is skipped with a
+//! warning, so one bad key doesn't break every command: the key keeps a valid value from
+//! a lower-precedence file, or its default. The snapshot doesn't see later config writes;
+//! load it again after changing config.
+//!
+//! [`WorkonSettings::problems`] instead reports every invalid value and every `workon.*`
+//! key workon doesn't know (likely a typo), for `doctor`.
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use git2::{Config, ConfigLevel, Repository};
+use tracing::warn;
+
+use crate::config::{
+    parse_duration, parse_size, validate_pr_format, WORKON_SUBSECTIONS, WORKTREE_SECTION,
+};
+use crate::error::{ConfigError, Result};
+use crate::rule::RULE_KEYS;
+use crate::template::TEMPLATE_KEYS;
+use crate::{did_you_mean, FetchBackend, NameStyle};
+
+/// Version of the `workon.*` configuration schema, reported by `git workon version`.
+///
+/// Bumped when a key is renamed or removed, or its value changes meaning, so integrations
+/// that write config can tell which keys this build understands.
+pub const CONFIG_SCHEMA_VERSION: u32 = 2;
+
+/// Every `workon.<key>` setting, as documented (see config.rs).
+const KEYS: &[&str] = &[
+    "defaultBranch",
+    "remoteDefaultBranch",
+    "prFormat",
+    "postCreateHook",
+    "copyPattern",
+    "copyExclude",
+    "autoCopyUntracked",
+    "copySource",
+    "copyMaxFileSize",
+    "copyMaxTotal",
+    "pruneProtectedBranches",
+    "pruneGracePeriod",
+    "nameStyle",
+    "hookTimeout",
+    "hookShell",
+    "prReadOnly",
+    "autoCreate",
+    "statusCache",
+    "networkFilesystem",
+    "fetchBackend",
+    "externalWorktree",
+    "hiddenWorktree",
+    "forkRemote",
+];
+
+/// Every `workon-worktree.<worktree>.<key>` metadata key.
+const WORKTREE_KEYS: &[&str] = &["label", "note", "pr", "prTitle", "prAuthor", "review"];
+
+/// A workon config entry that's invalid or not recognized (see [`WorkonSettings::problems`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigProblem {
+    /// The value can't be parsed; `message` says why
+    Invalid {
+        key: String,
+        value: String,
+        message: String,
+    },
+    /// `workon.<key>` isn't a workon setting; `suggestion` is a close one, if any
+    UnknownKey {
+        key: String,
+        suggestion: Option<String>,
+    },
+    /// `workon-worktree.<worktree>.<key>` isn't metadata workon records, or
+    /// `workon.<name>.<key>` isn't in one of the `workon` subsections. Metadata keys left
+    /// in the `workon` section by older versions suggest their `workon-worktree` key.
+    UnknownWorktreeKey {
+        key: String,
+        suggestion: Option<String>,
+    },
+}
+
+/// A configured value and the config files it was read from.
+#[derive(Debug, Clone)]
+pub struct Setting<T> {
+    pub value: T,
+    /// Levels that set this key, lowest precedence first. Empty for the default.
+    pub sources: Vec<ConfigLevel>,
+}
+
+impl<T> Setting<T> {
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            sources: Vec::new(),
+        }
+    }
+
+    /// Whether no config file sets this key.
+    pub fn is_default(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// The config file whose value is in effect: the highest-precedence source.
+    pub fn source(&self) -> Option<ConfigLevel> {
+        self.sources.last().copied()
+    }
+}
+
+/// Per-worktree metadata (`workon-worktree.<worktree>.*`).
+#[derive(Debug, Clone, Default)]
+pub struct WorktreeSettings {
+    pub labels: Vec<String>,
+    pub note: Option<String>,
+    pub pr: Option<u32>,
+    pub pr_title: Option<String>,
+    pub pr_author: Option<String>,
+    /// `workon-worktree.<worktree>.review`, if set
+    pub review: Option<bool>,
+}
+
+/// Every workon setting, read from git config at once.
+///
+/// See git-workon-lib/src/config.rs for what each key does.
+#[derive(Debug, Clone)]
+pub struct WorkonSettings {
+    pub default_branch: Setting<Option<String>>,
+    pub remote_default_branch: Setting<Option<String>>,
+    pub pr_format: Setting<String>,
+    pub post_create_hooks: Setting<Vec<String>>,
+    pub copy_patterns: Setting<Vec<String>>,
+    pub copy_excludes: Setting<Vec<String>>,
+    pub auto_copy_untracked: Setting<bool>,
+    pub copy_source: Setting<Option<String>>,
+    pub copy_max_file_size: Setting<Option<u64>>,
+    pub copy_max_total: Setting<Option<u64>>,
+    pub prune_protected_branches: Setting<Vec<String>>,
+    pub prune_grace_period: Setting<Option<Duration>>,
+    pub name_style: Setting<NameStyle>,
+    pub hook_timeout: Setting<Duration>,
+    pub hook_shell: Setting<Option<String>>,
+    pub pr_read_only: Setting<bool>,
+    pub auto_create: Setting<bool>,
+    pub status_cache: Setting<bool>,
+    pub network_filesystem: Setting<Option<bool>>,
+    pub fetch_backend: Setting<FetchBackend>,
+    pub external_worktrees: Setting<Vec<String>>,
+    pub hidden_worktrees: Setting<Vec<String>>,
+    pub fork_remotes: Setting<Vec<String>>,
+    worktrees: BTreeMap<String, WorktreeSettings>,
+}
+
+impl Default for WorkonSettings {
+    fn default() -> Self {
+        Self {
+            default_branch: Setting::new(None),
+            remote_default_branch: Setting::new(None),
+            pr_format: Setting::new("pr-{number}".to_string()),
+            post_create_hooks: Setting::new(Vec::new()),
+            copy_patterns: Setting::new(Vec::new()),
+            copy_excludes: Setting::new(Vec::new()),
+            auto_copy_untracked: Setting::new(false),
+            copy_source: Setting::new(None),
+            copy_max_file_size: Setting::new(None),
+            copy_max_total: Setting::new(None),
+            prune_protected_branches: Setting::new(Vec::new()),
+            prune_grace_period: Setting::new(None),
+            name_style: Setting::new(NameStyle::default()),
+            hook_timeout: Setting::new(Duration::from_secs(300)),
+            hook_shell: Setting::new(None),
+            pr_read_only: Setting::new(false),
+            auto_create: Setting::new(false),
+            status_cache: Setting::new(false),
+            network_filesystem: Setting::new(None),
+            fetch_backend: Setting::new(FetchBackend::default()),
+            external_worktrees: Setting::new(Vec::new()),
+            hidden_worktrees: Setting::new(Vec::new()),
+            fork_remotes: Setting::new(Vec::new()),
+            worktrees: BTreeMap::new(),
+        }
+    }
+}
+
+impl WorkonSettings {
+    /// Read all workon settings for `repo`.
+    pub fn load(repo: &Repository) -> Result<Self> {
+        Self::from_config(&repo.config()?.snapshot()?)
+    }
+
+    /// Read all workon settings from `config`.
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let mut settings = Self::default();
+        let mut entries = config.entries(Some(r"^workon(-worktree)?\."))?;
+        while let Some(entry) = entries.next() {
+            let entry = entry?;
+            let (Some(name), level) = (entry.name(), entry.level()) else {
+                continue;
+            };
+            // A key without `= value` is a boolean true
+            let value = entry.value().unwrap_or("true");
+            if let Err(e) = settings.apply(name, value, level) {
+                warn!(
+                    "Ignoring {} = {}: {} (see 'git workon doctor')",
+                    name, value, e
+                );
+            }
+        }
+        Ok(settings)
+    }
+
+    /// Check every workon entry in `config`, returning the invalid and unknown ones in
+    /// config file order.
+    pub fn problems(config: &Config) -> Result<Vec<ConfigProblem>> {
+        let mut problems = Vec::new();
+        let mut entries = config.entries(Some(r"^workon(-worktree)?\."))?;
+        while let Some(entry) = entries.next() {
+            let entry = entry?;
+            let (Some(name), level) = (entry.name(), entry.level()) else {
+                continue;
+            };
+            let value = entry.value().unwrap_or("true");
+            // Check each entry on its own, so one bad value doesn't hide the rest
+            match Self::default().apply(name, value, level) {
+                Ok(true) => {}
+                Ok(false) => problems.push(unknown_key(name)),
+                Err(e) => problems.push(ConfigProblem::Invalid {
+                    key: name.to_string(),
+                    value: value.to_string(),
+                    message: e.to_string(),
+                }),
+            }
+        }
+        Ok(problems)
+    }
+
+    /// Record one config entry. Entries arrive lowest precedence first.
+    ///
+    /// Returns false for keys workon doesn't use.
+    fn apply(&mut self, name: &str, value: &str, level: ConfigLevel) -> Result<bool> {
+        // Per-worktree metadata: workon-worktree.<worktree>.<key>
+        if let Some((worktree, key)) = name
+            .strip_prefix(WORKTREE_SECTION)
+            .and_then(|rest| rest.strip_prefix('.'))
+            .and_then(|rest| rest.rsplit_once('.'))
+        {
+            let meta = self.worktrees.entry(worktree.to_string()).or_default();
+            match key {
+                "label" => meta.labels.push(value.to_string()),
+                "note" => meta.note = Some(value.to_string()),
+                "pr" => meta.pr = value.trim().parse().ok(),
+                "prtitle" => meta.pr_title = Some(value.to_string()),
+                "prauthor" => meta.pr_author = Some(value.to_string()),
+                "review" => meta.review = Config::parse_bool(value).ok(),
+                _ => return Ok(false),
+            }
+            return Ok(true);
+        }
+
+        let Some(rest) = name.strip_prefix("workon.") else {
+            return Ok(false);
+        };
+        if let Some((subsection, key)) = rest.rsplit_once('.') {
+            let (family, nested) = match subsection.split_once('.') {
+                Some((family, _)) => (family, true),
+                None => (subsection, false),
+            };
+            return Ok(match (family, nested) {
+                // Aliases and tasks, which the CLI reads itself
+                ("alias" | "task", false) => true,
+                // Templates: workon.template.<name>.<key>
+                ("template", true) => TEMPLATE_KEYS.iter().any(|k| k.eq_ignore_ascii_case(key)),
+                // Rules: workon.rule.<glob>.<key>
+                ("rule", true) => RULE_KEYS.iter().any(|k| k.eq_ignore_ascii_case(key)),
+                _ => false,
+            });
+        }
+
+        // Git lowercases section and variable names
+        match rest {
+            "defaultbranch" => set(&mut self.default_branch, Some(value.to_string()), level),
+            "remotedefaultbranch" => set(
+                &mut self.remote_default_branch,
+                Some(value.to_string()),
+                level,
+            ),
+            "prformat" => set(
+                &mut self.pr_format,
+                validate_pr_format(value.to_string())?,
+                level,
+            ),
+            "postcreatehook" => push(&mut self.post_create_hooks, value, level),
+            "copypattern" => push(&mut self.copy_patterns, value, level),
+            "copyexclude" => push(&mut self.copy_excludes, value, level),
+            "autocopyuntracked" => set(
+                &mut self.auto_copy_untracked,
+                parse_bool("workon.autoCopyUntracked", value)?,
+                level,
+            ),
+            "copysource" => set(&mut self.copy_source, Some(value.to_string()), level),
+            "copymaxfilesize" => set(
+                &mut self.copy_max_file_size,
+                parse_size("workon.copyMaxFileSize", value)?,
+                level,
+            ),
+            "copymaxtotal" => set(
+                &mut self.copy_max_total,
+                parse_size("workon.copyMaxTotal", value)?,
+                level,
+            ),
+            "pruneprotectedbranches" => push(&mut self.prune_protected_branches, value, level),
+            "prunegraceperiod" => {
+                let period = parse_duration(value).ok_or_else(|| ConfigError::InvalidDuration {
+                    key: "workon.pruneGracePeriod".to_string(),
+                    value: value.to_string(),
+                })?;
+                set(
+                    &mut self.prune_grace_period,
+                    Some(period).filter(|p| !p.is_zero()),
+                    level,
+                )
+            }
+            "namestyle" => set(&mut self.name_style, value.parse()?, level),
+            "hooktimeout" => {
+                let seconds = Config::parse_i64(value).map_err(|_| ConfigError::InvalidValue {
+                    key: "workon.hookTimeout".to_string(),
+                    value: value.to_string(),
+                    reason: "expected a number of seconds".to_string(),
+                })?;
+                set(
+                    &mut self.hook_timeout,
+                    Duration::from_secs(seconds.max(0) as u64),
+                    level,
+                )
+            }
+            "hookshell" => set(
+                &mut self.hook_shell,
+                Some(value.to_string()).filter(|shell| !shell.trim().is_empty()),
+                level,
+            ),
+            "prreadonly" => set(
+                &mut self.pr_read_only,
+                parse_bool("workon.prReadOnly", value)?,
+                level,
+            ),
+            "autocreate" => set(
+                &mut self.auto_create,
+                parse_bool("workon.autoCreate", value)?,
+                level,
+            ),
+            "statuscache" => set(
+                &mut self.status_cache,
+                parse_bool("workon.statusCache", value)?,
+                level,
+            ),
+            "networkfilesystem" => set(
+                &mut self.network_filesystem,
+                Some(parse_bool("workon.networkFilesystem", value)?),
+                level,
+            ),
+            "fetchbackend" => set(&mut self.fetch_backend, value.parse()?, level),
+            "externalworktree" => push(&mut self.external_worktrees, value, level),
+            "hiddenworktree" => push(&mut self.hidden_worktrees, value, level),
+            "forkremote" => push(&mut self.fork_remotes, value, level),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    /// Metadata for a worktree, if any is configured.
+    pub fn worktree(&self, worktree_name: &str) -> Option<&WorktreeSettings> {
+        self.worktrees.get(worktree_name)
+    }
+
+    /// Labels attached to a worktree.
+    pub fn labels(&self, worktree_name: &str) -> &[String] {
+        self.worktree(worktree_name)
+            .map(|meta| meta.labels.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Check if a worktree carries every one of the given labels.
+    pub fn has_labels(&self, worktree_name: &str, labels: &[String]) -> bool {
+        let own = self.labels(worktree_name);
+        labels.iter().all(|label| own.contains(label))
+    }
+
+    /// The note attached to a worktree.
+    pub fn note(&self, worktree_name: &str) -> Option<&str> {
+        self.worktree(worktree_name)?.note.as_deref()
+    }
+
+    /// Check if a worktree is hidden from listings.
+    pub fn is_hidden(&self, worktree_name: &str) -> bool {
+        self.hidden_worktrees
+            .value
+            .iter()
+            .any(|n| n == worktree_name)
+    }
+
+    /// Check if a worktree is review-only (see `WorkonConfig::is_review_only`).
+    pub fn is_review_only(&self, worktree_name: &str) -> bool {
+        let Some(meta) = self.worktree(worktree_name) else {
+            return false;
+        };
+        meta.review
+            .unwrap_or(self.pr_read_only.value && meta.pr.is_some())
+    }
+
+    /// Check if a worktree is registered as external.
+    pub fn is_external(&self, worktree_name: &str) -> bool {
+        self.external_worktrees
+            .value
+            .iter()
+            .any(|n| n == worktree_name)
+    }
+}
+
+/// Describe an unknown `workon.*` or `workon-worktree.*` key, suggesting the known key
+/// it's closest to.
+fn unknown_key(name: &str) -> ConfigProblem {
+    if let Some((worktree, key)) = name
+        .strip_prefix(WORKTREE_SECTION)
+        .and_then(|rest| rest.strip_prefix('.'))
+        .and_then(|rest| rest.rsplit_once('.'))
+    {
+        return ConfigProblem::UnknownWorktreeKey {
+            key: name.to_string(),
+            suggestion: did_you_mean(key, WORKTREE_KEYS.iter().copied())
+                .map(|key| format!("{}.{}.{}", WORKTREE_SECTION, worktree, key)),
+        };
+    }
+    let rest = name.strip_prefix("workon.").unwrap_or(name);
+    match rest.rsplit_once('.') {
+        Some((subsection, key)) => {
+            let family = subsection.split('.').next().unwrap_or(subsection);
+            let suggestion = match family {
+                "template" => did_you_mean(key, TEMPLATE_KEYS.iter().copied())
+                    .map(|key| format!("workon.{}.{}", subsection, key)),
+                "rule" => did_you_mean(key, RULE_KEYS.iter().copied())
+                    .map(|key| format!("workon.{}.{}", subsection, key)),
+                _ if WORKON_SUBSECTIONS.contains(&family) => None,
+                // Most likely metadata from before it moved to its own section
+                _ => WORKTREE_KEYS
+                    .iter()
+                    .copied()
+                    .find(|known| known.eq_ignore_ascii_case(key))
+                    .map(str::to_string)
+                    .or_else(|| did_you_mean(key, WORKTREE_KEYS.iter().copied()))
+                    .map(|key| format!("{}.{}.{}", WORKTREE_SECTION, subsection, key)),
+            };
+            ConfigProblem::UnknownWorktreeKey {
+                key: name.to_string(),
+                suggestion,
+            }
+        }
+        None => ConfigProblem::UnknownKey {
+            key: name.to_string(),
+            suggestion: did_you_mean(rest, KEYS.iter().copied())
+                .map(|key| format!("workon.{}", key)),
+        },
+    }
+}
+
+fn add_source<T>(setting: &mut Setting<T>, level: ConfigLevel) {
+    if setting.sources.last() != Some(&level) {
+        setting.sources.retain(|l| *l != level);
+        setting.sources.push(level);
+    }
+}
+
+fn set<T>(setting: &mut Setting<T>, value: T, level: ConfigLevel) {
+    setting.value = value;
+    add_source(setting, level);
+}
+
+fn push(setting: &mut Setting<Vec<String>>, value: &str, level: ConfigLevel) {
+    setting.value.push(value.to_string());
+    add_source(setting, level);
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool> {
+    Config::parse_bool(value).map_err(|_| {
+        ConfigError::InvalidValue {
+            key: key.to_string(),
+            value: value.to_string(),
+            reason: "expected a boolean".to_string(),
+        }
+        .into()
+    })
+}
@@ -0,0 +1,250 @@
+//! Commit signing for commits created by workon.
+//!
+//! libgit2 never signs commits on its own, so commits workon creates (the initial commit
+//! of orphan branches and new repositories, patches committed by `new --from-patch
+//! --commit`) would be unsigned even with `commit.gpgsign = true`. This module reads the
+//! same config git does and signs the commit with the configured program:
+//!
+//! - **commit.gpgsign** - Sign commits (bool, default: false)
+//! - **gpg.format** - `openpgp` (default), `x509` or `ssh`
+//! - **gpg.program** / **gpg.openpgp.program** - OpenPGP signer (default: `gpg`)
+//! - **gpg.x509.program** - X.509 signer (default: `gpgsm`)
+//! - **gpg.ssh.program** - SSH signer (default: `ssh-keygen`)
+//! - **user.signingKey** - Key to sign with. Defaults to the committer identity for
+//!   OpenPGP and X.509; required for SSH, either as a key file path or a literal
+//!   `ssh-...`/`key::...` public key whose private half is in the ssh-agent
+//!
+//! `gpg.ssh.defaultKeyCommand` is not supported. `new` and `init` take `--no-sign` to
+//! skip signing.
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use git2::{Commit, Oid, Repository, Signature, Tree};
+use tracing::debug;
+
+use crate::error::{Result, SigningError};
+
+/// Signature format, from `gpg.format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningFormat {
+    OpenPgp,
+    X509,
+    Ssh,
+}
+
+/// Signs commit buffers the way git would for this repository.
+#[derive(Debug, Clone)]
+pub struct CommitSigner {
+    format: SigningFormat,
+    program: String,
+    key: Option<String>,
+}
+
+impl CommitSigner {
+    /// The signer for `repo`, or None if `commit.gpgsign` is off.
+    pub fn from_config(repo: &Repository) -> Result<Option<Self>> {
+        let config = repo.config()?;
+        if !config.get_bool("commit.gpgsign").unwrap_or(false) {
+            return Ok(None);
+        }
+
+        let format = match config.get_string("gpg.format") {
+            Ok(format) => match format.as_str() {
+                "openpgp" => SigningFormat::OpenPgp,
+                "x509" => SigningFormat::X509,
+                "ssh" => SigningFormat::Ssh,
+                _ => return Err(SigningError::UnknownFormat(format).into()),
+            },
+            Err(_) => SigningFormat::OpenPgp,
+        };
+        let program = match format {
+            SigningFormat::OpenPgp => config
+                .get_string("gpg.openpgp.program")
+                .or_else(|_| config.get_string("gpg.program"))
+                .unwrap_or_else(|_| "gpg".to_string()),
+            SigningFormat::X509 => config
+                .get_string("gpg.x509.program")
+                .unwrap_or_else(|_| "gpgsm".to_string()),
+            SigningFormat::Ssh => config
+                .get_string("gpg.ssh.program")
+                .unwrap_or_else(|_| "ssh-keygen".to_string()),
+        };
+        let key = config.get_string("user.signingkey").ok();
+        if format == SigningFormat::Ssh && key.is_none() {
+            return Err(SigningError::NoSshKey.into());
+        }
+
+        Ok(Some(Self {
+            format,
+            program,
+            key,
+        }))
+    }
+
+    /// Produce a detached signature for a commit buffer.
+    pub fn sign(&self, buffer: &str, committer: &Signature) -> Result<String> {
+        debug!("signing commit with {} ({:?})", self.program, self.format);
+        match self.format {
+            SigningFormat::OpenPgp | SigningFormat::X509 => {
+                let key = match &self.key {
+                    Some(key) => key.clone(),
+                    None => format!(
+                        "{} <{}>",
+                        committer.name().unwrap_or_default(),
+                        committer.email().unwrap_or_default()
+                    ),
+                };
+                self.run_gpg(buffer, &key)
+            }
+            SigningFormat::Ssh => self.run_ssh_keygen(buffer),
+        }
+    }
+
+    fn run_gpg(&self, buffer: &str, key: &str) -> Result<String> {
+        let mut child = Command::new(&self.program)
+            .args(["--status-fd=2", "-bsau", key])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|source| SigningError::Spawn {
+                program: self.program.clone(),
+                source,
+            })?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(buffer.as_bytes())?;
+        }
+        let output = child.wait_with_output()?;
+        let signature = String::from_utf8_lossy(&output.stdout).into_owned();
+        if !output.status.success() || signature.trim().is_empty() {
+            return Err(self.failed(&output.stderr));
+        }
+        Ok(signature)
+    }
+
+    fn run_ssh_keygen(&self, buffer: &str) -> Result<String> {
+        let key = self.key.as_deref().unwrap_or_default();
+        let mut scratch = ScratchFiles::new();
+        let buffer_file = scratch.path("commit");
+        fs::write(&buffer_file, buffer)?;
+
+        // A literal public key signs with its private half from the ssh-agent
+        let literal = key
+            .strip_prefix("key::")
+            .or_else(|| key.starts_with("ssh-").then_some(key));
+        let mut command = Command::new(&self.program);
+        command.args(["-Y", "sign", "-n", "git", "-f"]);
+        match literal {
+            Some(public_key) => {
+                let key_file = scratch.path("key.pub");
+                fs::write(&key_file, format!("{}\n", public_key))?;
+                command.arg(&key_file).arg("-U");
+            }
+            None => {
+                command.arg(expand_home(key));
+            }
+        }
+        let output = command
+            .arg(&buffer_file)
+            .stdin(Stdio::null())
+            .output()
+            .map_err(|source| SigningError::Spawn {
+                program: self.program.clone(),
+                source,
+            })?;
+        if !output.status.success() {
+            return Err(self.failed(&output.stderr));
+        }
+        Ok(fs::read_to_string(scratch.path("commit.sig"))?)
+    }
+
+    fn failed(&self, stderr: &[u8]) -> crate::WorkonError {
+        SigningError::Failed {
+            program: self.program.clone(),
+            message: String::from_utf8_lossy(stderr).trim().to_string(),
+        }
+        .into()
+    }
+}
+
+/// Create a commit and point HEAD (or the branch it refers to) at it, signing it when
+/// `signer` is given.
+pub fn commit_to_head(
+    repo: &Repository,
+    author: &Signature,
+    committer: &Signature,
+    message: &str,
+    tree: &Tree,
+    parents: &[&Commit],
+    signer: Option<&CommitSigner>,
+) -> Result<Oid> {
+    let Some(signer) = signer else {
+        return Ok(repo.commit(Some("HEAD"), author, committer, message, tree, parents)?);
+    };
+
+    let buffer = repo.commit_create_buffer(author, committer, message, tree, parents)?;
+    let buffer = String::from_utf8_lossy(&buffer).into_owned();
+    let signature = signer.sign(&buffer, committer)?;
+    let oid = repo.commit_signed(&buffer, &signature, None)?;
+
+    // commit_signed doesn't move any refs; update HEAD like `repo.commit` would
+    let summary = message.lines().next().unwrap_or_default();
+    let reflog = if parents.is_empty() {
+        format!("commit (initial): {}", summary)
+    } else {
+        format!("commit: {}", summary)
+    };
+    let head = repo.find_reference("HEAD")?;
+    match head.symbolic_target() {
+        Some(branch) => {
+            repo.reference(branch, oid, true, &reflog)?;
+        }
+        None => repo.set_head_detached(oid)?,
+    }
+    Ok(oid)
+}
+
+/// Uniquely named files in the temp directory, removed on drop.
+struct ScratchFiles {
+    prefix: String,
+    created: Vec<PathBuf>,
+}
+
+impl ScratchFiles {
+    fn new() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or_default();
+        Self {
+            prefix: format!("git-workon-sign-{}-{}", std::process::id(), nanos),
+            created: Vec::new(),
+        }
+    }
+
+    fn path(&mut self, name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("{}.{}", self.prefix, name));
+        self.created.push(path.clone());
+        path
+    }
+}
+
+impl Drop for ScratchFiles {
+    fn drop(&mut self) {
+        for path in &self.created {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
@@ -0,0 +1,281 @@
+//! Persistent worktree status cache.
+//!
+//! Comparing a worktree with its upstream walks the commit graph, which adds up for
+//! `list`, `find` and `prompt` in a large workspace. With `workon.statusCache` enabled,
+//! each worktree's status is stored in the repository's common git directory, at
+//! `<git-dir>/workon/status-cache`, one tab-separated line per worktree:
+//!
+//! ```text
+//! feature<TAB>1f0c9e2...<TAB>8a41b07...<TAB>dirty,ahead
+//! ```
+//!
+//! Fields are the worktree name, its HEAD commit, its upstream's commit (`-` without an
+//! upstream, `gone` when the upstream reference is deleted) and the statuses that held.
+//! The ahead/behind/gone statuses are reused only while both keys still match, so
+//! commits, checkouts and fetches invalidate them automatically. Lines that don't parse
+//! are ignored.
+//!
+//! Whether a worktree is dirty is always computed afresh: edits to the working tree
+//! don't show up in anything cheaper to check than the status itself. The `dirty` flag
+//! records the last status seen, which `prompt` shows when it runs out of time (see
+//! [`StatusCache::last_known`]); it's never reused as current.
+//!
+//! ```no_run
+//! # fn main() -> workon::Result<()> {
+//! let repo = workon::get_repo(None)?;
+//! let worktrees = workon::get_worktrees(&repo)?;
+//! let mut cache = workon::StatusCache::load(&repo)?;
+//! cache.prime_all(&worktrees);
+//! cache.save()?;
+//! // Served from the cache while HEAD and the upstream are unchanged
+//! println!("ahead: {}", worktrees[0].has_unpushed_commits()?);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use git2::Repository;
+use tracing::debug;
+
+use crate::error::Result;
+use crate::WorktreeDescriptor;
+
+/// The statuses of a worktree that are expensive to compute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WorktreeStatus {
+    /// Has uncommitted changes
+    pub dirty: bool,
+    /// Has commits not on its upstream (or the upstream is gone)
+    pub ahead: bool,
+    /// Its upstream has commits not in HEAD
+    pub behind: bool,
+    /// Its upstream branch was deleted
+    pub gone: bool,
+}
+
+impl WorktreeStatus {
+    /// Compute a worktree's status from scratch.
+    pub fn compute(wt: &WorktreeDescriptor) -> Result<Self> {
+        Ok(Self {
+            dirty: wt.is_dirty()?,
+            ahead: wt.has_unpushed_commits()?,
+            behind: wt.is_behind_upstream()?,
+            gone: wt.has_gone_upstream()?,
+        })
+    }
+
+    fn flags(&self) -> String {
+        let flags: Vec<&str> = [
+            (self.dirty, "dirty"),
+            (self.ahead, "ahead"),
+            (self.behind, "behind"),
+            (self.gone, "gone"),
+        ]
+        .into_iter()
+        .filter_map(|(set, flag)| set.then_some(flag))
+        .collect();
+        if flags.is_empty() {
+            "-".to_string()
+        } else {
+            flags.join(",")
+        }
+    }
+
+    fn parse_flags(s: &str) -> Option<Self> {
+        let mut status = Self::default();
+        for flag in s.split(',').filter(|f| *f != "-") {
+            match flag {
+                "dirty" => status.dirty = true,
+                "ahead" => status.ahead = true,
+                "behind" => status.behind = true,
+                "gone" => status.gone = true,
+                _ => return None,
+            }
+        }
+        Some(status)
+    }
+}
+
+/// What a cached status was computed from; it's valid while these are unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct StatusKey {
+    head: String,
+    upstream: String,
+}
+
+impl StatusKey {
+    fn of(wt: &WorktreeDescriptor) -> Result<Self> {
+        let repo = wt.repo()?;
+        let head = match repo.head() {
+            Ok(head) => head.target().map(|oid| oid.to_string()),
+            Err(_) => None, // Unborn branch
+        };
+        let upstream = match wt.upstream()? {
+            None => "-".to_string(),
+            Some((_, None)) => "gone".to_string(),
+            Some((_, Some(upstream))) => upstream
+                .get()
+                .target()
+                .map_or_else(|| "-".to_string(), |oid| oid.to_string()),
+        };
+        Ok(Self {
+            head: head.unwrap_or_else(|| "-".to_string()),
+            upstream,
+        })
+    }
+}
+
+/// Cached worktree statuses for one repository, loaded from and saved to disk.
+pub struct StatusCache {
+    path: PathBuf,
+    entries: BTreeMap<String, (StatusKey, WorktreeStatus)>,
+    /// Worktrees that exist; entries for any others are dropped on save
+    worktrees: Vec<String>,
+    changed: bool,
+}
+
+impl StatusCache {
+    /// Load the status cache for a repository. A missing cache is empty.
+    pub fn load(repo: &Repository) -> Result<Self> {
+        let path = repo.commondir().join("workon").join("status-cache");
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+
+        let entries = contents
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split('\t').collect();
+                let [name, head, upstream, flags] = fields[..] else {
+                    return None;
+                };
+                let key = StatusKey {
+                    head: head.to_string(),
+                    upstream: upstream.to_string(),
+                };
+                Some((name.to_string(), (key, WorktreeStatus::parse_flags(flags)?)))
+            })
+            .collect();
+        let worktrees = repo
+            .worktrees()?
+            .iter()
+            .flatten()
+            .map(str::to_string)
+            .collect();
+
+        Ok(Self {
+            path,
+            entries,
+            worktrees,
+            changed: false,
+        })
+    }
+
+    /// Give a worktree its status, so its status methods don't compute it again.
+    ///
+    /// Whether it's dirty is computed; the rest comes from the cache when valid, and is
+    /// computed (and cached) otherwise. A worktree whose status can't be determined is
+    /// left alone.
+    pub fn prime(&mut self, wt: &WorktreeDescriptor) {
+        self.store(wt, true);
+    }
+
+    /// Compute a worktree's whole status, give it to the worktree and cache it, without
+    /// reusing anything cached.
+    pub fn refresh(&mut self, wt: &WorktreeDescriptor) {
+        self.store(wt, false);
+    }
+
+    /// The status last stored for a worktree, whether or not it still holds.
+    pub fn last_known(&self, name: &str) -> Option<WorktreeStatus> {
+        self.entries.get(name).map(|(_, status)| *status)
+    }
+
+    fn store(&mut self, wt: &WorktreeDescriptor, reuse: bool) {
+        let Some(name) = wt.name() else {
+            return;
+        };
+        let key = match StatusKey::of(wt) {
+            Ok(key) => key,
+            Err(e) => {
+                debug!("Not caching status of '{}': {}", name, e);
+                return;
+            }
+        };
+        let cached = self
+            .entries
+            .get(name)
+            .filter(|(cached_key, _)| reuse && *cached_key == key)
+            .map(|(_, status)| *status);
+        let status = match cached {
+            Some(cached) => {
+                debug!("Upstream status of '{}' served from cache", name);
+                wt.is_dirty()
+                    .map(|dirty| WorktreeStatus { dirty, ..cached })
+            }
+            None => WorktreeStatus::compute(wt),
+        };
+        match status {
+            Ok(status) => {
+                wt.set_status(status);
+                if self.entries.get(name) != Some(&(key.clone(), status)) {
+                    self.entries.insert(name.to_string(), (key, status));
+                    self.changed = true;
+                }
+            }
+            Err(e) => debug!("Not caching status of '{}': {}", name, e),
+        }
+    }
+
+    /// [`prime`](Self::prime) every worktree.
+    pub fn prime_all(&mut self, worktrees: &[WorktreeDescriptor]) {
+        for wt in worktrees {
+            self.prime(wt);
+        }
+    }
+
+    /// Write the cache back if anything changed, dropping entries for removed worktrees.
+    ///
+    /// Written to a temp file and renamed into place so concurrent readers never see a
+    /// partial file.
+    pub fn save(&mut self) -> Result<()> {
+        let before = self.entries.len();
+        let worktrees = &self.worktrees;
+        self.entries.retain(|name, _| worktrees.contains(name));
+        if !self.changed && self.entries.len() == before {
+            return Ok(());
+        }
+
+        let contents: String = self
+            .entries
+            .iter()
+            .map(|(name, (key, status))| {
+                format!(
+                    "{}\t{}\t{}\t{}\n",
+                    name,
+                    key.head,
+                    key.upstream,
+                    status.flags()
+                )
+            })
+            .collect();
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let tmp = self
+            .path
+            .with_extension(format!("tmp.{}", std::process::id()));
+        if let Err(e) = fs::write(&tmp, contents).and_then(|_| fs::rename(&tmp, &self.path)) {
+            let _ = fs::remove_file(&tmp);
+            return Err(e.into());
+        }
+        self.changed = false;
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+//! "Did you mean" suggestions for mistyped worktree names.
+//!
+//! When a command is given a name that matches nothing, the closest existing worktree or
+//! branch name (by edit distance) is offered in the error's help text:
+//!
+//! ```text
+//! Error: workon::worktree::not_found
+//!   × Could not find worktree 'feature-auht'
+//!   help: Did you mean 'feature-auth'? Use 'git workon list' to see available worktrees
+//! ```
+
+use git2::{BranchType, Repository};
+
+use crate::get_worktrees;
+
+/// Returns the candidate closest to `name`, if it's close enough to be a plausible typo.
+///
+/// Comparison is case-insensitive. A candidate qualifies when its edit distance is at most
+/// a third of the name's length (and at least 1), so short names only tolerate small slips.
+pub fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<String> {
+    let needle = name.to_lowercase();
+    let threshold = (needle.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(&needle, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+/// Suggest an existing worktree or local branch name close to `name`.
+pub fn suggest_worktree_name(repo: &Repository, name: &str) -> Option<String> {
+    let mut candidates: Vec<String> = get_worktrees(repo)
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|wt| {
+            let branch = wt.branch().ok().flatten();
+            wt.name().map(str::to_string).into_iter().chain(branch)
+        })
+        .collect();
+    if let Ok(branches) = repo.branches(Some(BranchType::Local)) {
+        for (branch, _) in branches.flatten() {
+            if let Ok(Some(branch_name)) = branch.name() {
+                candidates.push(branch_name.to_string());
+            }
+        }
+    }
+    did_you_mean(name, candidates.iter().map(String::as_str))
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
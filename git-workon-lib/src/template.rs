@@ -0,0 +1,132 @@
+//! Worktree templates.
+//!
+//! A template bundles the settings a kind of worktree needs, so `new --template <name>`
+//! applies them in one go instead of a handful of flags:
+//!
+//! ```gitconfig
+//! [workon "template.hotfix"]
+//!   base = release/current
+//!   copyPattern = .env.local
+//!   postCreateHook = ./scripts/deploy-setup.sh
+//!   sparse = services/api
+//!   sparse = libs/shared
+//!   label = hotfix
+//! ```
+//!
+//! - **base** - Branch to start from, unless `--base` is given (string)
+//! - **copyPattern** - Untracked files to copy into the new worktree, instead of
+//!   workon.copyPattern; copying is on unless `--no-copy-untracked` is given (multi-value)
+//! - **postCreateHook** - Hooks to run after workon.postCreateHook's (multi-value)
+//! - **sparse** - Directories to check out, with `git sparse-checkout` in cone mode; the
+//!   rest of the tree is left out (multi-value)
+//! - **label** - Labels to attach to the worktree (multi-value)
+//!
+//! Templates can live in global config to be shared across repositories. Git keeps the
+//! template name's case (`workon.template.Hotfix.base` is a different template), but
+//! lookups fall back to a case-insensitive match.
+
+use std::collections::BTreeMap;
+use std::process::Command;
+
+use git2::Config;
+use tracing::debug;
+
+use crate::error::{ConfigError, Result, WorktreeError};
+use crate::{did_you_mean, WorktreeDescriptor};
+
+/// Every `workon.template.<name>.<key>` key, as documented.
+pub(crate) const TEMPLATE_KEYS: &[&str] =
+    &["base", "copyPattern", "postCreateHook", "sparse", "label"];
+
+/// A named bundle of settings for new worktrees (`workon.template.<name>.*`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorktreeTemplate {
+    pub name: String,
+    /// Branch to start from
+    pub base: Option<String>,
+    /// Untracked files to copy, replacing workon.copyPattern
+    pub copy_patterns: Vec<String>,
+    /// Hooks run after the configured post-create hooks
+    pub post_create_hooks: Vec<String>,
+    /// Directories for a cone-mode sparse checkout
+    pub sparse_paths: Vec<String>,
+    /// Labels for the new worktree
+    pub labels: Vec<String>,
+}
+
+/// Read every template in `config`, sorted by name. Unknown keys are ignored here;
+/// `doctor` reports them.
+pub(crate) fn read_templates(config: &Config) -> Result<Vec<WorktreeTemplate>> {
+    let mut templates: BTreeMap<String, WorktreeTemplate> = BTreeMap::new();
+    let mut entries = config.entries(Some(r"^workon\.template\..+\..+$"))?;
+    while let Some(entry) = entries.next() {
+        let entry = entry?;
+        let (Some(name), Some(value)) = (entry.name(), entry.value()) else {
+            continue;
+        };
+        let Some((template, key)) = name
+            .strip_prefix("workon.template.")
+            .and_then(|rest| rest.rsplit_once('.'))
+        else {
+            continue;
+        };
+        let template = templates
+            .entry(template.to_string())
+            .or_insert_with(|| WorktreeTemplate {
+                name: template.to_string(),
+                ..Default::default()
+            });
+        // Git lowercases variable names
+        match key {
+            "base" => template.base = Some(value.to_string()).filter(|b| !b.trim().is_empty()),
+            "copypattern" => template.copy_patterns.push(value.to_string()),
+            "postcreatehook" => template.post_create_hooks.push(value.to_string()),
+            "sparse" => template.sparse_paths.push(value.to_string()),
+            "label" => template.labels.push(value.to_string()),
+            _ => debug!("Ignoring unknown template key {}", name),
+        }
+    }
+    Ok(templates.into_values().collect())
+}
+
+/// Find the template called `name` among `templates`, exactly or ignoring case.
+pub(crate) fn find_template(
+    mut templates: Vec<WorktreeTemplate>,
+    name: &str,
+) -> Result<WorktreeTemplate> {
+    let position = templates.iter().position(|t| t.name == name).or_else(|| {
+        templates
+            .iter()
+            .position(|t| t.name.eq_ignore_ascii_case(name))
+    });
+    match position {
+        Some(position) => Ok(templates.swap_remove(position)),
+        None => Err(ConfigError::TemplateNotFound {
+            name: name.to_string(),
+            suggestion: did_you_mean(name, templates.iter().map(|t| t.name.as_str())),
+        }
+        .into()),
+    }
+}
+
+/// Restrict a worktree's checkout to `paths` (cone mode), with `git sparse-checkout set`.
+pub fn set_sparse_checkout(worktree: &WorktreeDescriptor, paths: &[String]) -> Result<()> {
+    debug!(
+        "sparse-checkout set {} in {}",
+        paths.join(" "),
+        worktree.path().display()
+    );
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(worktree.path())
+        .args(["sparse-checkout", "set", "--cone"])
+        .args(paths)
+        .output()?;
+    if !output.status.success() {
+        return Err(WorktreeError::SparseCheckoutFailed {
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
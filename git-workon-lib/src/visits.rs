@@ -0,0 +1,206 @@
+//! Visit history for worktrees.
+//!
+//! Records when worktrees are created and switched to, so commands can prefer recently
+//! used worktrees. The history lives in the repository's common git directory (shared by
+//! every worktree), at `<git-dir>/workon/visits`, as an append-only log with one
+//! tab-separated entry per line:
+//!
+//! ```text
+//! 1735689600<TAB>create<TAB>feature
+//! 1735689725<TAB>visit<TAB>feature
+//! ```
+//!
+//! Fields are a unix timestamp, the kind of event, and the worktree name. Lines that
+//! don't parse are skipped, so the format can grow new kinds without breaking readers.
+
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use git2::Repository;
+
+use crate::error::Result;
+
+/// What happened to a worktree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisitKind {
+    /// The worktree was created
+    Create,
+    /// The worktree was switched to (e.g. chosen in `find`)
+    Visit,
+}
+
+impl VisitKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            VisitKind::Create => "create",
+            VisitKind::Visit => "visit",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "create" => Some(VisitKind::Create),
+            "visit" => Some(VisitKind::Visit),
+            _ => None,
+        }
+    }
+}
+
+/// A single recorded event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Visit {
+    /// Unix timestamp (seconds)
+    pub timestamp: i64,
+    pub kind: VisitKind,
+    pub worktree: String,
+}
+
+/// Visit history summarized for one worktree (see [`VisitStore::usage`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorktreeUsage {
+    pub worktree: String,
+    /// When the worktree was last created, if that was recorded
+    pub created: Option<i64>,
+    /// Number of times the worktree was switched to
+    pub visits: usize,
+    pub last_visit: Option<i64>,
+    /// Mean seconds between consecutive events (creation and visits), once there are two
+    pub average_interval: Option<i64>,
+}
+
+impl WorktreeUsage {
+    /// Created by workon but never switched to afterwards.
+    pub fn never_revisited(&self) -> bool {
+        self.created.is_some() && self.visits == 0
+    }
+}
+
+/// Append-only visit log stored in the repository's git directory.
+pub struct VisitStore {
+    path: PathBuf,
+}
+
+impl VisitStore {
+    /// Open the visit log for a repository. The file is created on first write.
+    pub fn new(repo: &Repository) -> Self {
+        Self {
+            path: repo.commondir().join("workon").join("visits"),
+        }
+    }
+
+    /// Record an event for a worktree, timestamped now.
+    pub fn record(&self, worktree: &str, kind: VisitKind) -> Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(std::io::Error::other)?
+            .as_secs() as i64;
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}\t{}\t{}", timestamp, kind.as_str(), worktree)?;
+        Ok(())
+    }
+
+    /// All recorded events, oldest first. Returns an empty list if nothing was recorded.
+    pub fn entries(&self) -> Result<Vec<Visit>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let timestamp = fields.next()?.parse().ok()?;
+                let kind = VisitKind::parse(fields.next()?)?;
+                let worktree = fields.next()?.to_string();
+                Some(Visit {
+                    timestamp,
+                    kind,
+                    worktree,
+                })
+            })
+            .collect())
+    }
+
+    /// Drop every event recorded for a worktree (e.g. one removed outside workon).
+    pub fn forget(&self, worktree: &str) -> Result<()> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let kept: String = contents
+            .lines()
+            .filter(|line| line.splitn(3, '\t').nth(2) != Some(worktree))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        fs::write(&self.path, kept)?;
+        Ok(())
+    }
+
+    /// Per-worktree usage, most visited first (ties by name).
+    pub fn usage(&self) -> Result<Vec<WorktreeUsage>> {
+        let mut events: BTreeMap<String, Vec<Visit>> = BTreeMap::new();
+        for visit in self.entries()? {
+            events
+                .entry(visit.worktree.clone())
+                .or_default()
+                .push(visit);
+        }
+
+        let mut usage: Vec<WorktreeUsage> = events
+            .into_iter()
+            .map(|(worktree, events)| {
+                let created = events
+                    .iter()
+                    .filter(|v| v.kind == VisitKind::Create)
+                    .map(|v| v.timestamp)
+                    .max();
+                let visit_times: Vec<i64> = events
+                    .iter()
+                    .filter(|v| v.kind == VisitKind::Visit)
+                    .map(|v| v.timestamp)
+                    .collect();
+                let mut timestamps: Vec<i64> = events.iter().map(|v| v.timestamp).collect();
+                timestamps.sort_unstable();
+                let average_interval = match (timestamps.first(), timestamps.last()) {
+                    (Some(first), Some(last)) if timestamps.len() > 1 => {
+                        Some((last - first) / (timestamps.len() as i64 - 1))
+                    }
+                    _ => None,
+                };
+                WorktreeUsage {
+                    worktree,
+                    created,
+                    visits: visit_times.len(),
+                    last_visit: visit_times.iter().copied().max(),
+                    average_interval,
+                }
+            })
+            .collect();
+        usage.sort_by(|a, b| b.visits.cmp(&a.visits).then(a.worktree.cmp(&b.worktree)));
+        Ok(usage)
+    }
+
+    /// Name of the most recently visited worktree, if any.
+    pub fn last_visited(&self) -> Result<Option<String>> {
+        Ok(self
+            .entries()?
+            .into_iter()
+            .rev()
+            .find(|visit| visit.kind == VisitKind::Visit)
+            .map(|visit| visit.worktree))
+    }
+}
@@ -7,13 +7,16 @@
 //!
 //! The following metadata is fully implemented and working:
 //! - **Basic info**: `name()`, `path()`, `branch()`
-//! - **State detection**: `is_detached()`, `is_dirty()`, `is_valid()`, `is_locked()`
+//! - **State detection**: `is_detached()`, `is_dirty()`, `is_valid()`, `is_locked()`, `lock_reason()`
 //! - **Remote tracking**: `remote()`, `remote_branch()`, `remote_url()`, `remote_fetch_url()`, `remote_push_url()`
 //! - **Commit info**: `head_commit()`
 //! - **Status checks**: `has_unpushed_commits()`, `is_behind_upstream()`, `has_gone_upstream()`, `is_merged_into()`
 //!
 //! These methods enable status filtering (`--dirty`, `--ahead`, `--behind`, `--gone`) and
-//! interactive display with status indicators.
+//! interactive display with status indicators. They share one repository handle per
+//! descriptor (`repo()`), opened on first use, so checking every status of a worktree
+//! opens it once. A status primed from the status cache (see status_cache.rs) is
+//! returned without recomputing it.
 //!
 //! ## Branch Types
 //!
@@ -22,7 +25,13 @@
 //! - **Orphan**: Independent history with initial empty commit (for documentation, gh-pages, etc.)
 //! - **Detached**: Detached HEAD state (for exploring specific commits)
 //!
-//! - **Activity tracking**: `last_activity()`, `is_stale()`
+//! - **Activity tracking**: `last_activity()`, `is_stale()`, `created_at()`
+//!
+//! ## Publishing
+//!
+//! `push_branch()` pushes a worktree's branch to a remote (see `detect_push_remote()`)
+//! and sets it as the upstream, so new branches get a remote counterpart right away.
+//! Review-only PR worktrees (see `WorkonConfig::is_review_only()`) are never pushed.
 //!
 //! ## Future Extensions
 //!
@@ -33,14 +42,22 @@
 //! - Help remember why a worktree was created
 //! - Storage strategy TBD (git notes, config, or metadata file)
 
-use std::{fmt, fs::create_dir_all, path::Path};
+use std::{
+    cell::OnceCell,
+    fmt,
+    fs::create_dir_all,
+    path::{Path, PathBuf},
+};
 
-use git2::WorktreeAddOptions;
-use git2::{Repository, Worktree};
-use log::debug;
+use git2::{Branch, Oid, Repository, Worktree};
+use git2::{WorktreeAddOptions, WorktreeLockStatus};
+use tracing::{debug, instrument};
 
 use crate::error::{Result, WorktreeError};
-use crate::workon_root;
+use crate::{
+    commit_to_head, fetch_refspecs, suggest_worktree_name, workon_root, CommitSigner, WorkonConfig,
+    WorktreeStatus,
+};
 
 /// Type of branch to create for a new worktree
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -56,17 +73,23 @@ pub enum BranchType {
 
 pub struct WorktreeDescriptor {
     worktree: Worktree,
+    /// The worktree's repository, opened on first use and shared by every query
+    repo: OnceCell<Repository>,
+    /// Status primed from the status cache (see status_cache.rs), if any
+    status: OnceCell<WorktreeStatus>,
 }
 
 impl WorktreeDescriptor {
     pub fn new(repo: &Repository, name: &str) -> Result<Self> {
-        Ok(Self {
-            worktree: repo.find_worktree(name)?,
-        })
+        Ok(Self::of(repo.find_worktree(name)?))
     }
 
     pub fn of(worktree: Worktree) -> Self {
-        Self { worktree }
+        Self {
+            worktree,
+            repo: OnceCell::new(),
+            status: OnceCell::new(),
+        }
     }
 
     pub fn name(&self) -> Option<&str> {
@@ -77,40 +100,54 @@ impl WorktreeDescriptor {
         self.worktree.path()
     }
 
-    /// Returns the branch name if the worktree is on a branch, or None if detached.
+    /// The worktree's path relative to the workon root, e.g. `alice/fix` for branch
+    /// `alice/fix`. Unlike [`name`](Self::name), which is only the last component, it's
+    /// unique among the repository's worktrees, so per-worktree metadata is keyed on it.
     ///
-    /// This reads the HEAD file from the worktree's git directory to determine
-    /// if HEAD points to a branch reference or directly to a commit SHA.
-    pub fn branch(&self) -> Result<Option<String>> {
-        use std::fs;
+    /// Falls back to the name for worktrees outside the root, or whose directory is gone.
+    pub fn full_name(&self) -> Option<String> {
+        let relative = self.repo().ok().and_then(|repo| {
+            let root = repo.commondir().parent()?;
+            let relative = self.path().strip_prefix(root).ok()?;
+            relative.to_str().map(|s| s.replace('\\', "/"))
+        });
+        relative
+            .filter(|name| !name.is_empty())
+            .or_else(|| self.name().map(str::to_string))
+    }
 
-        // Get the path to the worktree's HEAD file
-        let git_dir = self.worktree.path().join(".git");
-        let head_path = if git_dir.is_file() {
-            // Linked worktree - read .git file to find actual git directory
-            let git_file_content = fs::read_to_string(&git_dir)?;
-            let git_dir_path = git_file_content
-                .strip_prefix("gitdir: ")
-                .and_then(|s| s.trim().strip_suffix('\n').or(Some(s.trim())))
-                .ok_or(WorktreeError::InvalidGitFile)?;
-            Path::new(git_dir_path).join("HEAD")
-        } else {
-            // Main worktree
-            git_dir.join("HEAD")
-        };
+    /// The worktree's repository.
+    ///
+    /// Opened once, on first use, and reused by every status query on this descriptor;
+    /// prefer it over opening `path()` again.
+    pub fn repo(&self) -> Result<&Repository> {
+        if let Some(repo) = self.repo.get() {
+            return Ok(repo);
+        }
+        let repo = Repository::open(self.path())?;
+        Ok(self.repo.get_or_init(|| repo))
+    }
 
-        let head_content = fs::read_to_string(&head_path)?;
+    /// Use an already known status instead of computing it. Only the first one sticks.
+    pub(crate) fn set_status(&self, status: WorktreeStatus) {
+        let _ = self.status.set(status);
+    }
 
-        // HEAD file contains either:
-        // - "ref: refs/heads/branch-name" for a branch
-        // - A direct SHA for detached HEAD
-        if let Some(ref_line) = head_content.strip_prefix("ref: ") {
-            let ref_name = ref_line.trim();
-            Ok(ref_name.strip_prefix("refs/heads/").map(|s| s.to_string()))
-        } else {
-            // Direct SHA - detached HEAD
-            Ok(None)
-        }
+    /// Returns the branch name if the worktree is on a branch, or None if detached.
+    ///
+    /// This reads the worktree's own HEAD to determine if it points to a branch
+    /// reference or directly to a commit SHA. An unborn branch (e.g. a new orphan
+    /// branch) still counts as a branch.
+    pub fn branch(&self) -> Result<Option<String>> {
+        let head = self.repo()?.find_reference("HEAD")?;
+
+        // HEAD is either:
+        // - symbolic, "refs/heads/branch-name" for a branch
+        // - a direct SHA for detached HEAD
+        Ok(head
+            .symbolic_target()
+            .and_then(|target| target.strip_prefix("refs/heads/"))
+            .map(str::to_string))
     }
 
     /// Returns true if the worktree has a detached HEAD (not on a branch).
@@ -118,64 +155,70 @@ impl WorktreeDescriptor {
         Ok(self.branch()?.is_none())
     }
 
+    /// Returns true if the worktree is locked (see `git worktree lock`).
+    pub fn is_locked(&self) -> Result<bool> {
+        Ok(matches!(
+            self.worktree.is_locked()?,
+            WorktreeLockStatus::Locked(_)
+        ))
+    }
+
+    /// Returns the reason recorded when the worktree was locked.
+    ///
+    /// Returns None if the worktree is not locked, or was locked without a reason.
+    pub fn lock_reason(&self) -> Result<Option<String>> {
+        match self.worktree.is_locked()? {
+            WorktreeLockStatus::Locked(reason) => Ok(reason
+                .map(|r| r.trim().to_string())
+                .filter(|r| !r.is_empty())),
+            WorktreeLockStatus::Unlocked => Ok(None),
+        }
+    }
+
     /// Returns true if the worktree has uncommitted changes (dirty working tree).
     ///
     /// This includes:
     /// - Modified files (staged or unstaged)
     /// - New untracked files
     /// - Deleted files
+    #[instrument(level = "debug", skip(self), fields(worktree = self.name()))]
     pub fn is_dirty(&self) -> Result<bool> {
-        let repo = Repository::open(self.path())?;
-        let statuses = repo.statuses(None)?;
+        if let Some(status) = self.status.get() {
+            return Ok(status.dirty);
+        }
+        let statuses = self.repo()?.statuses(None)?;
         Ok(!statuses.is_empty())
     }
 
-    /// Returns true if the worktree's branch has unpushed commits (ahead of upstream).
+    /// The worktree's local branch and its upstream.
     ///
-    /// Returns false if:
-    /// - The worktree is detached (no branch)
-    /// - The branch has no upstream configured
-    /// - The branch is up to date with upstream
-    ///
-    /// Returns true if:
-    /// - The branch has commits ahead of its upstream
-    /// - The upstream is configured but the remote reference is gone (conservative)
-    pub fn has_unpushed_commits(&self) -> Result<bool> {
-        // Get the branch name - return false if detached
+    /// Returns None if the worktree is detached, or its branch doesn't exist or has no
+    /// upstream configured (`branch.<name>.remote`). The upstream is None if it's
+    /// configured but the remote reference is gone.
+    pub(crate) fn upstream(&self) -> Result<Option<(Branch<'_>, Option<Branch<'_>>)>> {
         let branch_name = match self.branch()? {
             Some(name) => name,
-            None => return Ok(false), // Detached HEAD, no branch to check
+            None => return Ok(None), // Detached HEAD, no branch to check
         };
 
-        // Open the repository (use the bare repo, not the worktree)
-        let repo = Repository::open(self.path())?;
-
-        // Find the local branch
+        let repo = self.repo()?;
         let branch = match repo.find_branch(&branch_name, git2::BranchType::Local) {
             Ok(b) => b,
-            Err(_) => return Ok(false), // Branch doesn't exist
+            Err(_) => return Ok(None), // Branch doesn't exist
         };
 
         // Check if upstream is configured via git config
-        let config = repo.config()?;
         let remote_key = format!("branch.{}.remote", branch_name);
+        if repo.config()?.get_string(&remote_key).is_err() {
+            return Ok(None); // No remote configured
+        }
 
-        // If no upstream is configured, there can't be unpushed commits
-        let _remote = match config.get_string(&remote_key) {
-            Ok(r) => r,
-            Err(_) => return Ok(false), // No remote configured
-        };
-
-        // Get the upstream branch
-        let upstream = match branch.upstream() {
-            Ok(u) => u,
-            Err(_) => {
-                // Upstream is configured but ref is gone - conservatively assume unpushed
-                return Ok(true);
-            }
-        };
+        let upstream = branch.upstream().ok();
+        Ok(Some((branch, upstream)))
+    }
 
-        // Get the local and upstream commit OIDs
+    /// Count the commits a branch is ahead of and behind its upstream.
+    fn ahead_behind(&self, branch: &Branch, upstream: &Branch) -> Result<(usize, usize)> {
         let local_oid = branch
             .get()
             .target()
@@ -184,11 +227,33 @@ impl WorktreeDescriptor {
             .get()
             .target()
             .ok_or(WorktreeError::NoBranchTarget)?;
+        Ok(self.repo()?.graph_ahead_behind(local_oid, upstream_oid)?)
+    }
 
-        // Check if local is ahead of upstream
-        let (ahead, _behind) = repo.graph_ahead_behind(local_oid, upstream_oid)?;
-
-        Ok(ahead > 0)
+    /// Returns true if the worktree's branch has unpushed commits (ahead of upstream).
+    ///
+    /// Returns false if:
+    /// - The worktree is detached (no branch)
+    /// - The branch has no upstream configured
+    /// - The branch is up to date with upstream
+    ///
+    /// Returns true if:
+    /// - The branch has commits ahead of its upstream
+    /// - The upstream is configured but the remote reference is gone (conservative)
+    #[instrument(level = "debug", skip(self), fields(worktree = self.name()))]
+    pub fn has_unpushed_commits(&self) -> Result<bool> {
+        if let Some(status) = self.status.get() {
+            return Ok(status.ahead);
+        }
+        match self.upstream()? {
+            None => Ok(false),
+            // Upstream is configured but ref is gone - conservatively assume unpushed
+            Some((_, None)) => Ok(true),
+            Some((branch, Some(upstream))) => {
+                let (ahead, _behind) = self.ahead_behind(&branch, &upstream)?;
+                Ok(ahead > 0)
+            }
+        }
     }
 
     /// Returns true if the worktree's branch is behind its upstream.
@@ -201,55 +266,19 @@ impl WorktreeDescriptor {
     ///
     /// Returns true if:
     /// - The branch has commits behind its upstream
+    #[instrument(level = "debug", skip(self), fields(worktree = self.name()))]
     pub fn is_behind_upstream(&self) -> Result<bool> {
-        // Get the branch name - return false if detached
-        let branch_name = match self.branch()? {
-            Some(name) => name,
-            None => return Ok(false), // Detached HEAD, no branch to check
-        };
-
-        // Open the repository
-        let repo = Repository::open(self.path())?;
-
-        // Find the local branch
-        let branch = match repo.find_branch(&branch_name, git2::BranchType::Local) {
-            Ok(b) => b,
-            Err(_) => return Ok(false), // Branch doesn't exist
-        };
-
-        // Check if upstream is configured via git config
-        let config = repo.config()?;
-        let remote_key = format!("branch.{}.remote", branch_name);
-
-        // If no upstream is configured, can't be behind
-        let _remote = match config.get_string(&remote_key) {
-            Ok(r) => r,
-            Err(_) => return Ok(false), // No remote configured
-        };
-
-        // Get the upstream branch
-        let upstream = match branch.upstream() {
-            Ok(u) => u,
-            Err(_) => {
-                // Upstream is configured but ref is gone - can't be behind non-existent branch
-                return Ok(false);
+        if let Some(status) = self.status.get() {
+            return Ok(status.behind);
+        }
+        match self.upstream()? {
+            // Can't be behind a non-existent branch
+            None | Some((_, None)) => Ok(false),
+            Some((branch, Some(upstream))) => {
+                let (_ahead, behind) = self.ahead_behind(&branch, &upstream)?;
+                Ok(behind > 0)
             }
-        };
-
-        // Get the local and upstream commit OIDs
-        let local_oid = branch
-            .get()
-            .target()
-            .ok_or(WorktreeError::NoLocalBranchTarget)?;
-        let upstream_oid = upstream
-            .get()
-            .target()
-            .ok_or(WorktreeError::NoBranchTarget)?;
-
-        // Check if local is behind upstream
-        let (_ahead, behind) = repo.graph_ahead_behind(local_oid, upstream_oid)?;
-
-        Ok(behind > 0)
+        }
     }
 
     /// Returns true if the worktree's upstream branch reference is gone (deleted on remote).
@@ -262,37 +291,12 @@ impl WorktreeDescriptor {
     /// Returns true if:
     /// - Upstream is configured (branch.{name}.remote exists in config)
     /// - But the upstream branch reference cannot be found
+    #[instrument(level = "debug", skip(self), fields(worktree = self.name()))]
     pub fn has_gone_upstream(&self) -> Result<bool> {
-        // Get the branch name - return false if detached
-        let branch_name = match self.branch()? {
-            Some(name) => name,
-            None => return Ok(false), // Detached HEAD, no branch to check
-        };
-
-        // Open the repository
-        let repo = Repository::open(self.path())?;
-
-        // Find the local branch
-        let branch = match repo.find_branch(&branch_name, git2::BranchType::Local) {
-            Ok(b) => b,
-            Err(_) => return Ok(false), // Branch doesn't exist
-        };
-
-        // Check if upstream is configured via git config
-        let config = repo.config()?;
-        let remote_key = format!("branch.{}.remote", branch_name);
-
-        // If no upstream is configured, it's not "gone"
-        match config.get_string(&remote_key) {
-            Ok(_) => {
-                // Upstream is configured - check if the reference exists
-                match branch.upstream() {
-                    Ok(_) => Ok(false), // Upstream exists
-                    Err(_) => Ok(true), // Upstream configured but ref is gone
-                }
-            }
-            Err(_) => Ok(false), // No upstream configured
+        if let Some(status) = self.status.get() {
+            return Ok(status.gone);
         }
+        Ok(matches!(self.upstream()?, Some((_, None))))
     }
 
     /// Returns true if the worktree's branch has been merged into the target branch.
@@ -307,6 +311,7 @@ impl WorktreeDescriptor {
     ///
     /// Returns true if:
     /// - All commits in this branch are reachable from the target branch
+    #[instrument(level = "debug", skip(self), fields(worktree = self.name()))]
     pub fn is_merged_into(&self, target_branch: &str) -> Result<bool> {
         // Get the branch name - return false if detached
         let branch_name = match self.branch()? {
@@ -319,11 +324,9 @@ impl WorktreeDescriptor {
             return Ok(false);
         }
 
-        // Open the bare repository (not the worktree) to check actual branch states
-        // The worktree's .git points to the commondir (bare repo)
-        let worktree_repo = Repository::open(self.path())?;
-        let commondir = worktree_repo.commondir();
-        let repo = Repository::open(commondir)?;
+        // Branches are shared by every worktree, so the worktree's repository sees the
+        // same branch states as the bare repository
+        let repo = self.repo()?;
 
         // Find the current branch
         let current_branch = match repo.find_branch(&branch_name, git2::BranchType::Local) {
@@ -358,18 +361,7 @@ impl WorktreeDescriptor {
     ///
     /// Returns None if HEAD cannot be resolved (e.g., empty repository).
     pub fn head_commit(&self) -> Result<Option<String>> {
-        let repo = Repository::open(self.path())?;
-
-        // Try to resolve HEAD to a commit and extract the OID immediately
-        let commit_oid = match repo.head() {
-            Ok(head) => match head.peel_to_commit() {
-                Ok(commit) => Some(commit.id()),
-                Err(_) => return Ok(None), // HEAD exists but can't resolve to commit
-            },
-            Err(_) => return Ok(None), // No HEAD (unborn branch)
-        };
-
-        Ok(commit_oid.map(|oid| oid.to_string()))
+        Ok(self.head_commit_info()?.map(|(oid, _)| oid.to_string()))
     }
 
     /// Returns the timestamp of the HEAD commit as the last activity time.
@@ -377,16 +369,35 @@ impl WorktreeDescriptor {
     /// Returns None if:
     /// - HEAD cannot be resolved (empty/unborn repository)
     /// - HEAD cannot be peeled to a commit
+    #[instrument(level = "debug", skip(self), fields(worktree = self.name()))]
     pub fn last_activity(&self) -> Result<Option<i64>> {
-        let repo = Repository::open(self.path())?;
-        let seconds = match repo.head() {
+        Ok(self.head_commit_info()?.map(|(_, seconds)| seconds))
+    }
+
+    /// The HEAD commit's id and commit time, if HEAD resolves to a commit.
+    pub(crate) fn head_commit_info(&self) -> Result<Option<(Oid, i64)>> {
+        let commit = match self.repo()?.head() {
             Ok(head) => match head.peel_to_commit() {
-                Ok(commit) => Some(commit.time().seconds()),
-                Err(_) => None,
+                Ok(commit) => commit,
+                Err(_) => return Ok(None), // HEAD exists but can't resolve to commit
             },
-            Err(_) => None,
+            Err(_) => return Ok(None), // No HEAD (unborn branch)
         };
-        Ok(seconds)
+        Ok(Some((commit.id(), commit.time().seconds())))
+    }
+
+    /// Returns when the worktree was added, as a unix timestamp.
+    ///
+    /// Git doesn't record this, so it's approximated by the modification time of the
+    /// worktree's `commondir` file, which is written once when the worktree is added.
+    /// Returns None if the file is missing or its time can't be read.
+    pub fn created_at(&self) -> Result<Option<i64>> {
+        let created = std::fs::metadata(self.repo()?.path().join("commondir"))
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs() as i64);
+        Ok(created)
     }
 
     /// Returns true if the worktree's last activity is older than `days` days.
@@ -419,8 +430,7 @@ impl WorktreeDescriptor {
             None => return Ok(None), // Detached HEAD, no branch to check
         };
 
-        let repo = Repository::open(self.path())?;
-        let config = repo.config()?;
+        let config = self.repo()?.config()?;
 
         // Check for branch.<name>.remote in git config
         let remote_key = format!("branch.{}.remote", branch_name);
@@ -442,10 +452,11 @@ impl WorktreeDescriptor {
             None => return Ok(None), // Detached HEAD, no branch to check
         };
 
-        let repo = Repository::open(self.path())?;
-
         // Find the local branch and get its upstream, extracting the name immediately
-        let branch = match repo.find_branch(&branch_name, git2::BranchType::Local) {
+        let branch = match self
+            .repo()?
+            .find_branch(&branch_name, git2::BranchType::Local)
+        {
             Ok(b) => b,
             Err(_) => return Ok(None), // Branch doesn't exist
         };
@@ -468,21 +479,7 @@ impl WorktreeDescriptor {
     /// - The branch has no upstream configured
     /// - The remote has no URL configured
     pub fn remote_url(&self) -> Result<Option<String>> {
-        // Get the remote name
-        let remote_name = match self.remote()? {
-            Some(name) => name,
-            None => return Ok(None),
-        };
-
-        let repo = Repository::open(self.path())?;
-
-        // Find the remote and extract the URL immediately
-        let url = match repo.find_remote(&remote_name) {
-            Ok(remote) => remote.url().map(|s| s.to_string()),
-            Err(_) => return Ok(None), // Remote doesn't exist
-        };
-
-        Ok(url)
+        self.remote_fetch_url()
     }
 
     /// Returns the fetch URL for the remote.
@@ -498,10 +495,8 @@ impl WorktreeDescriptor {
             None => return Ok(None),
         };
 
-        let repo = Repository::open(self.path())?;
-
         // Find the remote and extract the fetch URL immediately
-        let url = match repo.find_remote(&remote_name) {
+        let url = match self.repo()?.find_remote(&remote_name) {
             Ok(remote) => remote.url().map(|s| s.to_string()),
             Err(_) => return Ok(None), // Remote doesn't exist
         };
@@ -522,10 +517,8 @@ impl WorktreeDescriptor {
             None => return Ok(None),
         };
 
-        let repo = Repository::open(self.path())?;
-
         // Find the remote and extract the push URL (or fallback to fetch URL) immediately
-        let url = match repo.find_remote(&remote_name) {
+        let url = match self.repo()?.find_remote(&remote_name) {
             Ok(remote) => remote
                 .pushurl()
                 .or_else(|| remote.url())
@@ -549,6 +542,7 @@ impl fmt::Display for WorktreeDescriptor {
     }
 }
 
+#[instrument(level = "debug", skip_all)]
 pub fn get_worktrees(repo: &Repository) -> Result<Vec<WorktreeDescriptor>> {
     repo.worktrees()?
         .into_iter()
@@ -577,7 +571,13 @@ pub fn find_worktree(repo: &Repository, name: &str) -> Result<WorktreeDescriptor
             // Match by worktree name or branch name
             wt.name() == Some(name) || wt.branch().ok().flatten().as_deref() == Some(name)
         })
-        .ok_or_else(|| WorktreeError::NotFound(name.to_string()).into())
+        .ok_or_else(|| {
+            WorktreeError::NotFound {
+                name: name.to_string(),
+                suggestion: suggest_worktree_name(repo, name),
+            }
+            .into()
+        })
 }
 
 pub fn add_worktree(
@@ -585,6 +585,50 @@ pub fn add_worktree(
     branch_name: &str,
     branch_type: BranchType,
     base_branch: Option<&str>,
+) -> Result<WorktreeDescriptor> {
+    add_worktree_with_options(
+        repo,
+        branch_name,
+        branch_type,
+        base_branch,
+        &NewWorktreeOptions::default(),
+    )
+}
+
+/// Options for [`add_worktree_with_options`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewWorktreeOptions {
+    /// Populate the working directory. Without it, only the worktree and its branch are
+    /// registered (`git worktree add --no-checkout`) and no index is written;
+    /// `git reset --hard` or a sparse-checkout selection in the worktree populates it later.
+    pub checkout: bool,
+    /// Sign commits created along the way (the orphan initial commit) when
+    /// `commit.gpgsign` is set.
+    pub sign: bool,
+    /// Reuse a directory already at the worktree's path instead of failing, keeping its
+    /// files. Only a leftover worktree of this repository for the same branch (see
+    /// [`can_adopt_directory`]) is reused; the index is reset to the branch, so changed
+    /// files show up as modifications.
+    pub adopt: bool,
+}
+
+impl Default for NewWorktreeOptions {
+    fn default() -> Self {
+        Self {
+            checkout: true,
+            sign: true,
+            adopt: false,
+        }
+    }
+}
+
+/// [`add_worktree`] with control over checkout and commit signing.
+pub fn add_worktree_with_options(
+    repo: &Repository,
+    branch_name: &str,
+    branch_type: BranchType,
+    base_branch: Option<&str>,
+    options: &NewWorktreeOptions,
 ) -> Result<WorktreeDescriptor> {
     // git worktree add <branch>
     debug!(
@@ -592,6 +636,31 @@ pub fn add_worktree(
         branch_name, branch_type
     );
 
+    let root = workon_root(repo)?;
+
+    // Git does not support worktree names with slashes in them,
+    // so take the base of the branch name as the worktree name.
+    let worktree_name = match Path::new(&branch_name).file_name() {
+        Some(basename) => basename.to_str().ok_or(WorktreeError::InvalidName)?,
+        None => branch_name,
+    };
+
+    let worktree_path = root.join(branch_name);
+
+    // `Feature` and `feature` are distinct branches but the same directory on macOS and
+    // Windows, so refuse them everywhere to keep layouts portable
+    check_case_collision(repo, root, branch_name, worktree_name)?;
+
+    // Check for a leftover directory before creating the branch
+    let adopt = prepare_worktree_dir(
+        repo,
+        &worktree_path,
+        worktree_name,
+        branch_name,
+        branch_type,
+        options.adopt,
+    )?;
+
     let reference = match branch_type {
         BranchType::Orphan => {
             debug!("creating orphan branch {:?}", branch_name);
@@ -644,34 +713,45 @@ pub fn add_worktree(
         }
     };
 
-    let root = workon_root(repo)?;
-
-    // Git does not support worktree names with slashes in them,
-    // so take the base of the branch name as the worktree name.
-    let worktree_name = match Path::new(&branch_name).file_name() {
-        Some(basename) => basename.to_str().ok_or(WorktreeError::InvalidName)?,
-        None => branch_name,
-    };
-
-    let worktree_path = root.join(branch_name);
-
     // Create parent directories if the branch name contains slashes
     if let Some(parent) = worktree_path.parent() {
         create_dir_all(parent)?;
     }
 
-    let mut opts = WorktreeAddOptions::new();
-    if let Some(ref r) = reference {
-        opts.reference(Some(r));
-    }
-
     debug!(
         "adding worktree {} at {}",
         worktree_name,
         worktree_path.display()
     );
 
-    let worktree = repo.worktree(worktree_name, worktree_path.as_path(), Some(&opts))?;
+    let worktree = if adopt {
+        let worktree = register_worktree(
+            repo,
+            worktree_name,
+            &worktree_path,
+            reference.as_ref(),
+            true,
+        )?;
+        // Keep the files, but match the index to the branch
+        let worktree_repo = Repository::open_from_worktree(&worktree)?;
+        let head = worktree_repo.head()?.peel_to_commit()?;
+        worktree_repo.reset(head.as_object(), git2::ResetType::Mixed, None)?;
+        worktree
+    } else if options.checkout {
+        let mut opts = WorktreeAddOptions::new();
+        if let Some(ref r) = reference {
+            opts.reference(Some(r));
+        }
+        repo.worktree(worktree_name, worktree_path.as_path(), Some(&opts))?
+    } else {
+        register_worktree(
+            repo,
+            worktree_name,
+            &worktree_path,
+            reference.as_ref(),
+            false,
+        )?
+    };
 
     // For detached worktrees, set HEAD to point directly to a commit SHA
     if branch_type == BranchType::Detached {
@@ -759,13 +839,19 @@ pub fn add_worktree(
         })?;
 
         // Create initial commit with no parents (orphan)
-        worktree_repo.commit(
-            Some("HEAD"),
+        let signer = if options.sign {
+            CommitSigner::from_config(&worktree_repo)?
+        } else {
+            None
+        };
+        commit_to_head(
+            &worktree_repo,
             &sig,
             &sig,
             "Initial commit",
             &tree,
             &[], // No parents - this makes it an orphan
+            signer.as_ref(),
         )?;
 
         debug!("orphan branch setup complete for {:?}", branch_name);
@@ -774,6 +860,274 @@ pub fn add_worktree(
     Ok(WorktreeDescriptor::of(worktree))
 }
 
+/// Fail if an existing worktree's path or name matches `branch_name`'s ignoring case only.
+fn check_case_collision(
+    repo: &Repository,
+    root: &Path,
+    branch_name: &str,
+    worktree_name: &str,
+) -> Result<()> {
+    let mut existing: Vec<(String, String)> = Vec::new();
+    for name in repo.worktrees()?.iter().flatten() {
+        let Ok(worktree) = repo.find_worktree(name) else {
+            continue;
+        };
+        let path = worktree.path();
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        existing.push((name.to_string(), relative.to_string_lossy().into_owned()));
+    }
+
+    let same_ignoring_case = |a: &str, b: &str| a.to_lowercase() == b.to_lowercase();
+    let collides = |a: &str, b: &str| a != b && same_ignoring_case(a, b);
+    let collision = existing
+        .iter()
+        .find(|(name, path)| collides(path, branch_name) || collides(name, worktree_name));
+    let Some((_, existing_path)) = collision else {
+        return Ok(());
+    };
+
+    let taken = |candidate: &str| {
+        existing.iter().any(|(name, path)| {
+            same_ignoring_case(path, candidate) || same_ignoring_case(name, candidate)
+        }) || repo.find_branch(candidate, git2::BranchType::Local).is_ok()
+    };
+    let suggestion = (2..)
+        .map(|n| format!("{}-{}", branch_name, n))
+        .find(|candidate| !taken(candidate))
+        .unwrap_or_default();
+    Err(WorktreeError::CaseCollision {
+        name: branch_name.to_string(),
+        existing: existing_path.clone(),
+        suggestion,
+    }
+    .into())
+}
+
+/// Deal with a directory already at a new worktree's path.
+///
+/// An empty directory is removed so the worktree can be created in its place. Anything
+/// else fails with [`WorktreeError::DirectoryExists`], unless `adopt` is set and the
+/// directory can be adopted. Returns whether the worktree should be registered around
+/// the existing directory.
+fn prepare_worktree_dir(
+    repo: &Repository,
+    worktree_path: &Path,
+    worktree_name: &str,
+    branch_name: &str,
+    branch_type: BranchType,
+    adopt: bool,
+) -> Result<bool> {
+    use std::fs;
+
+    if !worktree_path.is_dir() {
+        if worktree_path.exists() {
+            return Err(WorktreeError::DirectoryExists {
+                path: worktree_path.display().to_string(),
+            }
+            .into());
+        }
+        return Ok(false);
+    }
+    if fs::read_dir(worktree_path)?.next().is_none() {
+        debug!("removing empty directory {}", worktree_path.display());
+        fs::remove_dir(worktree_path)?;
+        return Ok(false);
+    }
+    if !adopt {
+        return Err(WorktreeError::DirectoryExists {
+            path: worktree_path.display().to_string(),
+        }
+        .into());
+    }
+    if branch_type != BranchType::Normal
+        || !can_adopt_directory(repo, worktree_path, worktree_name, branch_name)
+    {
+        return Err(WorktreeError::CannotAdopt {
+            path: worktree_path.display().to_string(),
+            branch: branch_name.to_string(),
+        }
+        .into());
+    }
+    debug!("adopting existing directory {}", worktree_path.display());
+    Ok(true)
+}
+
+/// Whether `path` is a leftover worktree of `repo` for `branch_name`, e.g. after a prune
+/// that removed the worktree's registration but not its directory.
+///
+/// The directory's `.git` file must point into this repository's worktrees. If that
+/// administrative directory still exists, its HEAD must be `branch_name`; otherwise it
+/// must have been named `worktree_name`. A copy of another worktree doesn't qualify.
+pub fn can_adopt_directory(
+    repo: &Repository,
+    path: &Path,
+    worktree_name: &str,
+    branch_name: &str,
+) -> bool {
+    use std::fs;
+
+    let Ok(contents) = fs::read_to_string(path.join(".git")) else {
+        return false;
+    };
+    let Some(gitdir) = contents.trim().strip_prefix("gitdir:") else {
+        return false;
+    };
+    let gitdir = PathBuf::from(gitdir.trim());
+    let worktrees_dir = repo.commondir().join("worktrees");
+    if !gitdir
+        .parent()
+        .is_some_and(|parent| same_dir(parent, &worktrees_dir))
+    {
+        return false;
+    }
+    match fs::read_to_string(gitdir.join("HEAD")) {
+        Ok(head) => head.trim() == format!("ref: refs/heads/{}", branch_name),
+        Err(_) => gitdir.file_name().and_then(|n| n.to_str()) == Some(worktree_name),
+    }
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    a == b || matches!((a.canonicalize(), b.canonicalize()), (Ok(a), Ok(b)) if a == b)
+}
+
+/// Rename `path` out of the way, to `<name>.bak` or the first free `<name>.bak.<n>`.
+///
+/// Returns the new location.
+pub fn move_aside(path: &Path) -> Result<PathBuf> {
+    let name = path
+        .file_name()
+        .ok_or(WorktreeError::InvalidName)?
+        .to_string_lossy()
+        .into_owned();
+    let target = (1..)
+        .map(|n| match n {
+            1 => path.with_file_name(format!("{}.bak", name)),
+            n => path.with_file_name(format!("{}.bak.{}", name, n)),
+        })
+        .find(|candidate| !candidate.exists())
+        .expect("unbounded range");
+    debug!("moving {} aside to {}", path.display(), target.display());
+    std::fs::rename(path, &target)?;
+    Ok(target)
+}
+
+/// Write a worktree's administrative files without checking anything out.
+///
+/// libgit2 always checks out new worktrees, so this lays out what `git worktree add
+/// --no-checkout` does: `<common>/worktrees/<name>/{HEAD,commondir,gitdir}` and the
+/// worktree's `.git` file. Without a branch reference, HEAD is detached at the
+/// repository's HEAD commit. With `existing_dir`, the worktree may be registered around a
+/// directory that already exists.
+fn register_worktree(
+    repo: &Repository,
+    worktree_name: &str,
+    worktree_path: &Path,
+    reference: Option<&git2::Reference>,
+    existing_dir: bool,
+) -> Result<Worktree> {
+    use std::fs;
+
+    let admin_dir = repo.commondir().join("worktrees").join(worktree_name);
+    if admin_dir.exists() || (worktree_path.exists() && !existing_dir) {
+        return Err(WorktreeError::TargetExists {
+            to: worktree_name.to_string(),
+        }
+        .into());
+    }
+
+    if let Some(branch) = reference.and_then(|r| r.shorthand()) {
+        let checked_out = get_worktrees(repo)?
+            .into_iter()
+            .find(|wt| wt.branch().ok().flatten().as_deref() == Some(branch));
+        if let Some(wt) = checked_out {
+            return Err(WorktreeError::BranchCheckedOut {
+                branch: branch.to_string(),
+                worktree: wt.name().unwrap_or_default().to_string(),
+            }
+            .into());
+        }
+    }
+
+    let head = match reference.and_then(|r| r.name()) {
+        Some(name) => format!("ref: {}\n", name),
+        None => format!("{}\n", repo.head()?.peel_to_commit()?.id()),
+    };
+
+    create_dir_all(&admin_dir)?;
+    create_dir_all(worktree_path)?;
+    let worktree_path = worktree_path.canonicalize()?;
+    let admin_dir = admin_dir.canonicalize()?;
+    fs::write(admin_dir.join("HEAD"), head)?;
+    fs::write(admin_dir.join("commondir"), "../..\n")?;
+    fs::write(
+        admin_dir.join("gitdir"),
+        format!("{}\n", worktree_path.join(".git").display()),
+    )?;
+    fs::write(
+        worktree_path.join(".git"),
+        format!("gitdir: {}\n", admin_dir.display()),
+    )?;
+
+    Ok(repo.find_worktree(worktree_name)?)
+}
+
+/// Split `<remote>/<branch>` into a configured remote and the branch on it.
+///
+/// Remote names may contain slashes, so the longest configured remote that prefixes
+/// `remote_ref` wins.
+pub fn split_remote_ref(repo: &Repository, remote_ref: &str) -> Result<(String, String)> {
+    let remotes = repo.remotes()?;
+    remotes
+        .iter()
+        .flatten()
+        .filter_map(|remote| {
+            let branch = remote_ref.strip_prefix(remote)?.strip_prefix('/')?;
+            (!branch.is_empty()).then(|| (remote.to_string(), branch.to_string()))
+        })
+        .max_by_key(|(remote, _)| remote.len())
+        .ok_or_else(|| WorktreeError::NotARemoteBranch(remote_ref.to_string()).into())
+}
+
+/// Create local branch `local_name` from the remote branch `remote_ref`
+/// (`<remote>/<branch>`) and set it to track that branch.
+///
+/// The remote branch is fetched first so the new branch starts from its latest commit;
+/// if the fetch fails, an already fetched copy is used instead.
+#[instrument(level = "debug", skip(repo))]
+pub fn create_tracking_branch(repo: &Repository, local_name: &str, remote_ref: &str) -> Result<()> {
+    let (remote_name, branch) = split_remote_ref(repo, remote_ref)?;
+    if repo
+        .find_branch(local_name, git2::BranchType::Local)
+        .is_ok()
+    {
+        return Err(WorktreeError::BranchExists(local_name.to_string()).into());
+    }
+
+    let tracking_ref = fetch_remote_branch(repo, &remote_name, &branch)?;
+    let commit = repo
+        .find_reference(&tracking_ref)
+        .map_err(|_| WorktreeError::NotARemoteBranch(remote_ref.to_string()))?
+        .peel_to_commit()?;
+    let mut local = repo.branch(local_name, &commit, false)?;
+    local.set_upstream(Some(&format!("{}/{}", remote_name, branch)))?;
+    debug!("created {} tracking {}/{}", local_name, remote_name, branch);
+    Ok(())
+}
+
+/// Fetch `branch` from `remote_name` into its remote-tracking reference, which is
+/// returned. If the fetch fails, an already fetched copy is used instead.
+fn fetch_remote_branch(repo: &Repository, remote_name: &str, branch: &str) -> Result<String> {
+    let tracking_ref = format!("refs/remotes/{}/{}", remote_name, branch);
+    let refspec = format!("+refs/heads/{}:{}", branch, tracking_ref);
+    if let Err(e) = fetch_refspecs(repo, remote_name, &[refspec.as_str()], None) {
+        if repo.find_reference(&tracking_ref).is_err() {
+            return Err(e);
+        }
+        debug!("fetch failed, using existing {}: {}", tracking_ref, e);
+    }
+    Ok(tracking_ref)
+}
+
 /// Set upstream tracking for a worktree branch
 ///
 /// Configures the branch in the worktree to track a remote branch by setting
@@ -786,7 +1140,7 @@ pub fn set_upstream_tracking(
     remote: &str,
     remote_ref: &str,
 ) -> Result<()> {
-    let repo = Repository::open(worktree.path())?;
+    let repo = worktree.repo()?;
     let mut config = repo.config()?;
 
     let head = repo.head()?;
@@ -808,3 +1162,133 @@ pub fn set_upstream_tracking(
     );
     Ok(())
 }
+
+/// Detect which remote to publish new branches to
+///
+/// Priority: remote.pushDefault > origin > first remote
+pub fn detect_push_remote(repo: &Repository) -> Result<String> {
+    if let Ok(name) = repo.config()?.get_string("remote.pushDefault") {
+        debug!("Using remote.pushDefault: {}", name);
+        return Ok(name);
+    }
+
+    let remotes = repo.remotes()?;
+    if remotes.iter().flatten().any(|r| r == "origin") {
+        return Ok("origin".to_string());
+    }
+
+    remotes
+        .get(0)
+        .map(str::to_string)
+        .ok_or_else(|| WorktreeError::NoPushRemote.into())
+}
+
+/// Push a worktree's branch to a remote and track it as the branch's upstream
+///
+/// Pushes `refs/heads/<branch>` to the same name on the remote, then sets
+/// `branch.*.remote` and `branch.*.merge` so ahead/behind and gone detection work
+/// immediately.
+#[instrument(level = "debug", skip(worktree), fields(worktree = worktree.name()))]
+pub fn push_branch(worktree: &WorktreeDescriptor, remote_name: &str) -> Result<()> {
+    let branch = worktree
+        .branch()?
+        .ok_or(WorktreeError::CannotPushDetached)?;
+    push_to(worktree, &branch, remote_name, &branch)?;
+    set_upstream_tracking(worktree, remote_name, &format!("refs/heads/{}", branch))?;
+    debug!("Pushed {} to {}", branch, remote_name);
+    Ok(())
+}
+
+/// Make a worktree's branch track an upstream branch after the fact, so ahead/behind
+/// and gone detection work for branches created without one (or adopted worktrees).
+///
+/// `upstream` is `<remote>/<branch>`; without it, the branch tracks the same name on the
+/// push remote ([`detect_push_remote`]). With `push`, the branch is pushed there first.
+/// Otherwise the remote branch must exist: it's fetched (falling back to an already
+/// fetched copy) so the tracking information is current.
+///
+/// Returns the upstream as `<remote>/<branch>`.
+#[instrument(level = "debug", skip(worktree), fields(worktree = worktree.name()))]
+pub fn set_upstream(
+    worktree: &WorktreeDescriptor,
+    upstream: Option<&str>,
+    push: bool,
+) -> Result<String> {
+    let local = worktree
+        .branch()?
+        .ok_or(WorktreeError::CannotTrackDetached)?;
+    let repo = worktree.repo()?;
+    let (remote_name, branch) = match upstream {
+        Some(upstream) => split_remote_ref(repo, upstream)?,
+        None => (detect_push_remote(repo)?, local.clone()),
+    };
+    let upstream = format!("{}/{}", remote_name, branch);
+
+    if push {
+        push_to(worktree, &local, &remote_name, &branch)?;
+    } else {
+        // A fetch of a branch the remote doesn't have can succeed without creating it
+        let found = fetch_remote_branch(repo, &remote_name, &branch)
+            .is_ok_and(|tracking_ref| repo.find_reference(&tracking_ref).is_ok());
+        if !found {
+            return Err(WorktreeError::UpstreamNotFound(upstream).into());
+        }
+    }
+
+    set_upstream_tracking(worktree, &remote_name, &format!("refs/heads/{}", branch))?;
+    debug!("{} now tracks {}", local, upstream);
+    Ok(upstream)
+}
+
+/// Push local branch `local` to `remote_branch` on `remote_name`.
+fn push_to(
+    worktree: &WorktreeDescriptor,
+    local: &str,
+    remote_name: &str,
+    remote_branch: &str,
+) -> Result<()> {
+    let refspec = format!("refs/heads/{}:refs/heads/{}", local, remote_branch);
+
+    let repo = worktree.repo()?;
+    if let (Some(name), Some(full_name)) = (worktree.name(), worktree.full_name()) {
+        if WorkonConfig::new(repo)?.is_review_only(&full_name) {
+            return Err(WorktreeError::ReviewOnly {
+                name: name.to_string(),
+            }
+            .into());
+        }
+    }
+    let mut remote = repo.find_remote(remote_name)?;
+
+    // The server reports per-ref rejections (e.g. non-fast-forward) through this callback
+    let mut rejection: Option<String> = None;
+    let result = {
+        let mut callbacks = crate::get_remote_callbacks()?;
+        callbacks.push_update_reference(|_refname, status| {
+            if let Some(message) = status {
+                rejection = Some(message.to_string());
+            }
+            Ok(())
+        });
+        let mut push_options = git2::PushOptions::new();
+        push_options.remote_callbacks(callbacks);
+        if let Some(url) = remote.pushurl().or(remote.url()) {
+            push_options.proxy_options(crate::get_proxy_options(&repo.config()?, url));
+        }
+        remote.push(&[refspec.as_str()], Some(&mut push_options))
+    };
+
+    let failure = match result {
+        Err(e) => Some(e.message().to_string()),
+        Ok(()) => rejection,
+    };
+    if let Some(message) = failure {
+        return Err(WorktreeError::PushFailed {
+            branch: local.to_string(),
+            remote: remote_name.to_string(),
+            message,
+        }
+        .into());
+    }
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::error::Error;
+
+use git_workon_fixture::prelude::*;
+use workon::{is_valid_branch_name, normalize_branch_name, NameStyle, WorkonConfig};
+
+#[test]
+fn preserve_keeps_valid_names() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        normalize_branch_name("Lettertwo/Feature_X", NameStyle::Preserve)?,
+        "Lettertwo/Feature_X"
+    );
+    Ok(())
+}
+
+#[test]
+fn preserve_rejects_invalid_names_with_suggestion() {
+    assert!(!is_valid_branch_name("my feature"));
+    let err = normalize_branch_name("My Feature..v2", NameStyle::Preserve).unwrap_err();
+    assert!(err.to_string().contains("not a valid branch name"));
+    match err {
+        workon::WorkonError::Worktree(workon::WorktreeError::InvalidBranchName {
+            suggestion,
+            ..
+        }) => assert_eq!(suggestion.as_deref(), Some("my-feature-v2")),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[test]
+fn kebab_normalizes_each_segment() -> Result<(), Box<dyn Error>> {
+    assert_eq!(
+        normalize_branch_name("Lettertwo/Fix Login: Part 2", NameStyle::Kebab)?,
+        "lettertwo/fix-login-part-2"
+    );
+    assert!(normalize_branch_name("???", NameStyle::Kebab).is_err());
+    Ok(())
+}
+
+#[test]
+fn name_style_reads_from_config() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().build()?;
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+    assert_eq!(workon_config.name_style()?, NameStyle::Preserve);
+
+    repo.config()?.set_str("workon.nameStyle", "kebab")?;
+    assert_eq!(workon_config.name_style()?, NameStyle::Kebab);
+
+    repo.config()?.set_str("workon.nameStyle", "camel")?;
+    assert!(workon_config.name_style().is_err());
+    Ok(())
+}
@@ -2,7 +2,8 @@
 mod tests {
     use assert_fs::TempDir;
     use git_workon_fixture::prelude::*;
-    use workon::clone;
+    use std::time::Duration;
+    use workon::{clone, clone_with_options, CloneOptions, RepoError, WorkonError};
 
     #[test]
     fn test_clone_basic() -> Result<(), Box<dyn std::error::Error>> {
@@ -33,4 +34,92 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_clone_does_not_retry_an_unreachable_remote() -> Result<(), Box<dyn std::error::Error>> {
+        let dir = TempDir::new()?;
+        // A retry would sleep far past any reasonable test time
+        let options = CloneOptions {
+            attempts: 2,
+            retry_delay: Duration::from_secs(600),
+        };
+
+        // Nothing listens on port 1, so the first attempt can't connect at all
+        let url = "http://127.0.0.1:1/repo.git";
+        let result = clone_with_options(dir.to_path_buf(), url, &options);
+
+        let Err(err) = result else {
+            panic!("clone of an unreachable remote succeeded")
+        };
+        assert!(err.git_error().is_some(), "{:?}", err);
+        dir.child(".bare").assert(predicate::path::missing());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone_keeps_partial_clone_when_interrupted() -> Result<(), Box<dyn std::error::Error>> {
+        // What an earlier interrupted clone leaves behind
+        let url = "http://127.0.0.1:1/repo.git";
+        let dir = TempDir::new()?;
+        let partial = git2::Repository::init_bare(dir.child(".bare").path())?;
+        partial.remote("origin", url)?;
+        dir.child(".bare/workon/clone-in-progress").write_str(url)?;
+        let options = CloneOptions {
+            attempts: 2,
+            retry_delay: Duration::ZERO,
+        };
+
+        let result = clone_with_options(dir.to_path_buf(), url, &options);
+
+        assert!(matches!(
+            result,
+            Err(WorkonError::Repo(RepoError::CloneInterrupted { .. }))
+        ));
+        dir.child(".bare/workon/clone-in-progress")
+            .assert(predicate::str::contains(url));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone_resumes_partial_clone() -> Result<(), Box<dyn std::error::Error>> {
+        let remote = FixtureBuilder::new().bare(true).build()?;
+        let url = remote.cwd()?.to_str().unwrap().to_string();
+
+        // What an interrupted clone leaves behind
+        let dir = TempDir::new()?;
+        let partial = git2::Repository::init_bare(dir.child(".bare").path())?;
+        partial.remote("origin", &url)?;
+        dir.child(".bare/workon/clone-in-progress")
+            .write_str(&url)?;
+
+        let repo = clone(dir.to_path_buf(), &url)?;
+
+        repo.assert(predicate::repo::has_branch("main"));
+        repo.assert(predicate::repo::has_remote_branch("origin/main"));
+        dir.child(".bare/workon/clone-in-progress")
+            .assert(predicate::path::missing());
+        dir.child(".git").assert(predicate::path::is_file());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clone_refuses_existing_repository() -> Result<(), Box<dyn std::error::Error>> {
+        let remote = FixtureBuilder::new().bare(true).build()?;
+        let url = remote.cwd()?.to_str().unwrap().to_string();
+        let dir = TempDir::new()?;
+        git2::Repository::init_bare(dir.child(".bare").path())?;
+
+        let result = clone(dir.to_path_buf(), &url);
+
+        assert!(matches!(
+            result,
+            Err(WorkonError::Repo(RepoError::CloneTargetExists(_)))
+        ));
+        dir.child(".bare/config").assert(predicate::path::is_file());
+
+        Ok(())
+    }
 }
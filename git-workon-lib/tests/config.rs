@@ -1,6 +1,6 @@
 use git_workon_fixture::prelude::*;
 use std::error::Error;
-use workon::WorkonConfig;
+use workon::{ConfigScope, WorkonConfig};
 
 #[test]
 fn read_default_branch_config() -> Result<(), Box<dyn Error>> {
@@ -170,6 +170,22 @@ fn read_copy_excludes_multi_value() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn copy_source_cli_override_takes_precedence() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon.copySource", "main")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+    assert_eq!(workon_config.copy_source(None)?, Some("main".to_string()));
+    assert_eq!(
+        workon_config.copy_source(Some("golden"))?,
+        Some("golden".to_string())
+    );
+    Ok(())
+}
+
 #[test]
 fn read_prune_protected_branches_multi_value() -> Result<(), Box<dyn Error>> {
     let fixture = FixtureBuilder::new()
@@ -187,3 +203,165 @@ fn read_prune_protected_branches_multi_value() -> Result<(), Box<dyn Error>> {
     assert_eq!(protected[2], "release/*");
     Ok(())
 }
+
+#[test]
+fn prune_grace_period_parses_units() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().build()?;
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+    assert_eq!(workon_config.prune_grace_period()?, None);
+
+    let mut config = repo.config()?;
+    for (value, secs) in [
+        ("2d", 172800),
+        ("12h", 43200),
+        ("90m", 5400),
+        ("1w", 604800),
+    ] {
+        config.set_str("workon.pruneGracePeriod", value)?;
+        assert_eq!(
+            workon_config.prune_grace_period()?,
+            Some(std::time::Duration::from_secs(secs))
+        );
+    }
+
+    config.set_str("workon.pruneGracePeriod", "0d")?;
+    assert_eq!(workon_config.prune_grace_period()?, None);
+
+    config.set_str("workon.pruneGracePeriod", "2 days")?;
+    let err = workon_config.prune_grace_period().unwrap_err();
+    assert!(err.to_string().contains("Invalid duration"));
+    Ok(())
+}
+
+#[test]
+fn read_external_worktrees_multi_value() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon.externalWorktree", "scratch")
+        .config("workon.externalWorktree", "vendor")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+    assert_eq!(
+        workon_config.external_worktrees()?,
+        vec!["scratch".to_string(), "vendor".to_string()]
+    );
+    assert!(workon_config.is_external("scratch"));
+    assert!(!workon_config.is_external("feature"));
+    Ok(())
+}
+
+#[test]
+fn read_worktree_note() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon-worktree.feature.note", "waiting on API review")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+    assert_eq!(
+        workon_config.note("feature")?,
+        Some("waiting on API review".to_string())
+    );
+    assert_eq!(workon_config.note("other")?, None);
+    Ok(())
+}
+
+#[test]
+fn read_hidden_worktrees_multi_value() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon.hiddenWorktree", "docs")
+        .config("workon.hiddenWorktree", "gh-pages")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+    assert_eq!(
+        workon_config.hidden_worktrees()?,
+        vec!["docs".to_string(), "gh-pages".to_string()]
+    );
+    assert!(workon_config.is_hidden("docs"));
+    assert!(!workon_config.is_hidden("feature"));
+    Ok(())
+}
+
+#[test]
+fn setters_validate_and_write_local_config() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().build()?;
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+
+    workon_config.set_default_branch("develop", ConfigScope::Local)?;
+    assert_eq!(
+        workon_config.default_branch(None)?,
+        Some("develop".to_string())
+    );
+    assert!(workon_config
+        .set_default_branch("bad..name", ConfigScope::Local)
+        .is_err());
+
+    assert!(workon_config.add_copy_pattern(".env*", ConfigScope::Local)?);
+    assert!(!workon_config.add_copy_pattern(".env*", ConfigScope::Local)?);
+    assert!(workon_config
+        .add_copy_pattern("[", ConfigScope::Local)
+        .is_err());
+    assert_eq!(workon_config.copy_patterns()?, vec![".env*"]);
+
+    assert!(workon_config.set_worktree_meta("feature", "note", Some("wip"))?);
+    assert_eq!(workon_config.note("feature")?, Some("wip".to_string()));
+    assert!(workon_config.set_worktree_meta("feature", "note", None)?);
+    assert!(!workon_config.set_worktree_meta("feature", "note", None)?);
+    assert!(workon_config
+        .set_worktree_meta("feature", "not.a.key", Some("x"))
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn label_and_hidden_setters_report_changes() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().build()?;
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+
+    assert!(workon_config.add_label("feature", "backend")?);
+    assert!(!workon_config.add_label("feature", "backend")?);
+    assert!(workon_config.add_label("feature", "c++")?);
+    assert!(workon_config.remove_label("feature", "c++")?);
+    assert!(!workon_config.remove_label("feature", "c++")?);
+    assert_eq!(workon_config.labels("feature")?, vec!["backend"]);
+
+    assert!(workon_config.set_hidden("gh-pages", true)?);
+    assert!(workon_config.is_hidden("gh-pages"));
+    assert!(workon_config.set_hidden("gh-pages", false)?);
+    assert!(!workon_config.is_hidden("gh-pages"));
+
+    workon_config.set_worktree_meta("feature", "note", Some("wip"))?;
+    workon_config.clear_worktree_meta("feature")?;
+    assert!(workon_config.labels("feature")?.is_empty());
+    assert_eq!(workon_config.note("feature")?, None);
+    Ok(())
+}
+
+#[test]
+fn review_only_worktrees() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon-worktree.pr-1.pr", "1")
+        .config("workon-worktree.pr-2.pr", "2")
+        .config("workon-worktree.pr-2.review", "true")
+        .config("workon-worktree.pr-3.pr", "3")
+        .config("workon-worktree.pr-3.review", "false")
+        .build()?;
+    let repo = fixture.repo()?;
+    let workon_config = WorkonConfig::new(repo)?;
+
+    assert!(!workon_config.is_review_only("pr-1"));
+    assert!(workon_config.is_review_only("pr-2"));
+    assert!(!workon_config.is_review_only("feature"));
+
+    repo.config()?.set_bool("workon.prReadOnly", true)?;
+    assert!(workon_config.is_review_only("pr-1"));
+    assert!(!workon_config.is_review_only("pr-3"));
+    assert!(!workon_config.is_review_only("feature"));
+    Ok(())
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use git2::{ErrorClass, ErrorCode};
+    use git_workon_fixture::prelude::*;
+    use miette::Diagnostic;
+    use workon::{GitError, WorkonError};
+
+    #[test]
+    fn test_locked_index_points_at_the_lock_file() -> Result<(), Box<dyn std::error::Error>> {
+        let fixture = FixtureBuilder::new().build()?;
+        let repo = fixture.repo()?;
+        std::fs::write(repo.path().join("index.lock"), "")?;
+
+        let err = WorkonError::from(repo.index()?.write().unwrap_err());
+        assert!(matches!(err, WorkonError::GitOp(GitError::Locked { .. })));
+        assert_eq!(
+            err.code().map(|c| c.to_string()),
+            Some("workon::git::locked".to_string())
+        );
+        let help = err.help().map(|h| h.to_string()).unwrap_or_default();
+        assert!(
+            help.contains("index.lock"),
+            "help should name the lock file: {}",
+            help
+        );
+        assert_eq!(err.git_error().map(|e| e.code()), Some(ErrorCode::Locked));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_git_error_classes_map_to_diagnostics() {
+        let cases = [
+            (ErrorCode::Auth, ErrorClass::Net, "workon::git::auth_failed"),
+            (
+                ErrorCode::Certificate,
+                ErrorClass::Ssh,
+                "workon::git::certificate",
+            ),
+            (
+                ErrorCode::Conflict,
+                ErrorClass::Checkout,
+                "workon::git::checkout_conflict",
+            ),
+            (
+                ErrorCode::UnbornBranch,
+                ErrorClass::Reference,
+                "workon::git::unborn_branch",
+            ),
+            (
+                ErrorCode::GenericError,
+                ErrorClass::Net,
+                "workon::git::network",
+            ),
+            (
+                ErrorCode::NotFound,
+                ErrorClass::Reference,
+                "workon::git_error",
+            ),
+        ];
+        for (code, class, expected) in cases {
+            let err = WorkonError::from(git2::Error::new(code, class, "boom"));
+            assert_eq!(
+                err.code().map(|c| c.to_string()).as_deref(),
+                Some(expected),
+                "{:?}/{:?}",
+                code,
+                class
+            );
+            assert_eq!(err.git_error().map(|e| e.message()), Some("boom"));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use git_workon_fixture::prelude::*;
+use std::error::Error;
+use workon::{fetch_refspecs, FetchBackend, RepoError, WorkonConfig, WorkonError};
+
+fn upstream_with_branch(branch: &str) -> Result<Fixture, Box<dyn Error>> {
+    let upstream = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let tip = upstream
+        .commit("main")
+        .file("work.txt", "in progress")
+        .create("Work in progress")?;
+    let repo = upstream.repo()?;
+    repo.branch(branch, &repo.find_commit(tip)?, false)?;
+    Ok(upstream)
+}
+
+fn fixture_with_backend(upstream: &Fixture, backend: &str) -> Result<Fixture, Box<dyn Error>> {
+    FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote(
+            "origin",
+            RemoteSource::Path(upstream.repo()?.commondir().to_path_buf()),
+        )
+        .config("workon.fetchBackend", backend)
+        .build()
+}
+
+#[test]
+fn fetch_backend_defaults_to_auto() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().build()?;
+    assert_eq!(
+        WorkonConfig::new(fixture.repo()?)?.fetch_backend()?,
+        FetchBackend::Auto
+    );
+    Ok(())
+}
+
+#[test]
+fn fetch_backend_rejects_unknown_values() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon.fetchBackend", "curl")
+        .build()?;
+    assert!(WorkonConfig::new(fixture.repo()?)?.fetch_backend().is_err());
+    Ok(())
+}
+
+#[test]
+fn git_and_libgit2_backends_fetch_the_same_refs() -> Result<(), Box<dyn Error>> {
+    let upstream = upstream_with_branch("feature")?;
+    let expected = upstream
+        .repo()?
+        .find_reference("refs/heads/feature")?
+        .target();
+
+    for backend in ["git", "libgit2"] {
+        let fixture = fixture_with_backend(&upstream, backend)?;
+        let repo = fixture.repo()?;
+        fetch_refspecs(
+            repo,
+            "origin",
+            &["+refs/heads/feature:refs/remotes/origin/feature"],
+            None,
+        )?;
+        assert_eq!(
+            repo.find_reference("refs/remotes/origin/feature")?.target(),
+            expected,
+            "{}",
+            backend
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn git_backend_reports_fetch_failures() -> Result<(), Box<dyn Error>> {
+    let upstream = upstream_with_branch("feature")?;
+    let fixture = fixture_with_backend(&upstream, "git")?;
+
+    let result = fetch_refspecs(
+        fixture.repo()?,
+        "origin",
+        &["+refs/heads/missing:refs/remotes/origin/missing"],
+        None,
+    );
+    assert!(matches!(
+        result,
+        Err(WorkonError::Repo(RepoError::GitFetchFailed { ref remote, .. })) if remote == "origin"
+    ));
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use git_workon_fixture::prelude::*;
+use std::error::Error;
+use workon::{find_worktree, WorktreeFilter};
+
+#[test]
+fn parses_operators_and_terms() {
+    for expr in [
+        "dirty",
+        "!gone",
+        "(dirty || ahead) && !gone",
+        "dirty and not (behind or locked)",
+        "label:backend && #urgent",
+        "age > 7d || created <= 90m",
+        "age>=1w",
+    ] {
+        assert!(WorktreeFilter::parse(expr).is_ok(), "{}", expr);
+    }
+}
+
+#[test]
+fn rejects_malformed_expressions() {
+    for (expr, message) in [
+        ("", "expected a term"),
+        ("dirty &&", "expected a term"),
+        ("(dirty", "unclosed '('"),
+        ("dirty)", "expected '&&', '||' or the end"),
+        ("dirty ahead", "expected '&&', '||' or the end"),
+        ("stale", "unknown term 'stale'"),
+        ("age", "expected a comparison"),
+        ("age > soon", "expected a duration"),
+        ("label:", "expected a label name"),
+        ("dirty & ahead", "unexpected '&'"),
+    ] {
+        let err = WorktreeFilter::parse(expr).expect_err(expr).to_string();
+        assert!(err.contains(message), "{}: {}", expr, err);
+    }
+}
+
+#[test]
+fn matches_status_label_and_age() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let repo = fixture.repo()?;
+    let main = find_worktree(repo, "main")?;
+    std::fs::write(main.path().join("scratch.txt"), "uncommitted")?;
+    let labels = vec!["backend".to_string()];
+
+    let matches = |expr: &str| -> Result<bool, Box<dyn Error>> {
+        Ok(WorktreeFilter::parse(expr)?.matches(&main, &labels))
+    };
+    assert!(matches("dirty")?);
+    assert!(!matches("clean")?);
+    assert!(matches("clean || label:backend")?);
+    assert!(!matches("dirty && !#backend")?);
+    assert!(matches("!(gone || detached)")?);
+    assert!(matches("age < 1d")?);
+    assert!(!matches("age > 1d")?);
+    assert!(matches("created <= 1h")?);
+
+    Ok(())
+}
@@ -8,7 +8,7 @@ mod tests {
     fn test_init_basic() -> Result<(), Box<dyn std::error::Error>> {
         // Create a new directory and initialize it
         let dir = TempDir::new()?;
-        let repo = init(dir.to_path_buf())?;
+        let repo = init(dir.to_path_buf(), true)?;
 
         repo.assert(predicate::repo::is_bare());
         repo.assert(predicate::repo::has_branch("main"));
@@ -0,0 +1,86 @@
+use git_workon_fixture::prelude::*;
+use std::error::Error;
+use workon::{find_worktree_fuzzy, find_worktrees_matching, MatchField, MatchResult};
+
+fn fixture() -> Result<Fixture, Box<dyn Error>> {
+    FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature-auth")
+        .worktree("feature-billing")
+        .build()
+}
+
+#[test]
+fn exact_match_wins_over_fuzzy() -> Result<(), Box<dyn Error>> {
+    let fixture = fixture()?;
+    let repo = fixture.repo()?;
+
+    match find_worktrees_matching(repo, "main")? {
+        MatchResult::Exact(wt) => assert_eq!(wt.name(), Some("main")),
+        other => panic!("expected an exact match, got {:?}", other),
+    }
+    Ok(())
+}
+
+#[test]
+fn unique_and_ambiguous_fuzzy_matches() -> Result<(), Box<dyn Error>> {
+    let fixture = fixture()?;
+    let repo = fixture.repo()?;
+
+    match find_worktrees_matching(repo, "BILL")? {
+        MatchResult::Unique(wt, field) => {
+            assert_eq!(wt.name(), Some("feature-billing"));
+            assert_eq!(field, MatchField::Name);
+        }
+        other => panic!("expected a unique match, got {:?}", other),
+    }
+
+    match find_worktrees_matching(repo, "feature")? {
+        MatchResult::Ambiguous(matches) => assert_eq!(matches.len(), 2),
+        other => panic!("expected an ambiguous match, got {:?}", other),
+    }
+    let err = find_worktree_fuzzy(repo, "feature").unwrap_err();
+    let report = miette::Report::new(err);
+    let help = report.help().map(|h| h.to_string()).unwrap_or_default();
+    assert!(help.contains("feature-auth"), "help: {help}");
+    assert!(help.contains("feature-billing"), "help: {help}");
+    Ok(())
+}
+
+#[test]
+fn hidden_worktrees_only_match_exactly() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("gh-pages")
+        .config("workon.hiddenWorktree", "gh-pages")
+        .build()?;
+    let repo = fixture.repo()?;
+
+    assert!(matches!(
+        find_worktrees_matching(repo, "pages")?,
+        MatchResult::NoMatch { .. }
+    ));
+    assert_eq!(
+        find_worktree_fuzzy(repo, "gh-pages")?.name(),
+        Some("gh-pages")
+    );
+    Ok(())
+}
+
+#[test]
+fn no_match_suggests_a_close_name() -> Result<(), Box<dyn Error>> {
+    let fixture = fixture()?;
+    let repo = fixture.repo()?;
+
+    match find_worktrees_matching(repo, "feature-auht")? {
+        MatchResult::NoMatch { suggestion } => {
+            assert_eq!(suggestion.as_deref(), Some("feature-auth"))
+        }
+        other => panic!("expected no match, got {:?}", other),
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use std::error::Error;
+
+use workon::parse_patches;
+
+#[test]
+fn parse_format_patch_series() -> Result<(), Box<dyn Error>> {
+    let series = "\
+From 1111111111111111111111111111111111111111 Mon Sep 17 00:00:00 2001
+From: \"Ada Lovelace\" <ada@example.com>
+Date: Tue, 13 Feb 2024 10:21:43 +0100
+Subject: [PATCH v2 1/2] Add notes about
+ the engine
+
+Explain the engine.
+---
+ notes.txt | 1 +
+
+diff --git a/notes.txt b/notes.txt
+new file mode 100644
+--- /dev/null
++++ b/notes.txt
+@@ -0,0 +1 @@
++analytical engine
+-- 
+2.43.0
+
+From 2222222222222222222222222222222222222222 Mon Sep 17 00:00:00 2001
+From: ada@example.com
+Subject: Fix typo
+
+diff --git a/notes.txt b/notes.txt
+--- a/notes.txt
++++ b/notes.txt
+@@ -1 +1 @@
+-analytical engine
++Analytical Engine
+";
+    let patches = parse_patches(series)?;
+    assert_eq!(patches.len(), 2);
+
+    let first = &patches[0];
+    assert_eq!(first.subject.as_deref(), Some("Add notes about the engine"));
+    assert_eq!(first.body.as_deref(), Some("Explain the engine."));
+    assert_eq!(
+        first.author,
+        Some(("Ada Lovelace".to_string(), "ada@example.com".to_string()))
+    );
+    let date = first.date.expect("date parsed");
+    assert_eq!(date.seconds(), 1707816103);
+    assert_eq!(date.offset_minutes(), 60);
+    assert_eq!(
+        first.message("unused"),
+        "Add notes about the engine\n\nExplain the engine.\n"
+    );
+
+    let second = &patches[1];
+    assert_eq!(second.subject.as_deref(), Some("Fix typo"));
+    assert_eq!(second.body, None);
+    assert_eq!(second.date, None);
+    Ok(())
+}
+
+#[test]
+fn parse_raw_diff_as_single_patch() -> Result<(), Box<dyn Error>> {
+    let diff = "\
+diff --git a/notes.txt b/notes.txt
+--- a/notes.txt
++++ b/notes.txt
+@@ -1 +1 @@
+-old
++new
+";
+    let patches = parse_patches(diff)?;
+    assert_eq!(patches.len(), 1);
+    assert_eq!(patches[0].subject, None);
+    assert_eq!(patches[0].message("Apply fix.diff"), "Apply fix.diff\n");
+
+    assert!(parse_patches("just some text\n").is_err());
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use git_workon_fixture::prelude::*;
+use std::error::Error;
+use workon::proxy_url;
+
+#[test]
+fn http_proxy_config_applies_to_http_remotes() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("http.proxy", "http://proxy.example.com:3128")
+        .build()?;
+    let config = fixture.repo()?.config()?;
+
+    assert_eq!(
+        proxy_url(&config, "https://example.com/owner/repo.git").as_deref(),
+        Some("http://proxy.example.com:3128")
+    );
+    assert_eq!(proxy_url(&config, "git@example.com:owner/repo.git"), None);
+    assert_eq!(
+        proxy_url(&config, "ssh://git@example.com/owner/repo.git"),
+        None
+    );
+    assert_eq!(proxy_url(&config, "/srv/git/repo.git"), None);
+
+    Ok(())
+}
+
+#[test]
+fn url_specific_proxy_config_wins() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("http.proxy", "http://proxy.example.com:3128")
+        .config(
+            "http.https://example.com.proxy",
+            "http://other.example.com:8080",
+        )
+        .config("http.https://example.com/direct.proxy", "")
+        .build()?;
+    let config = fixture.repo()?.config()?;
+
+    assert_eq!(
+        proxy_url(&config, "https://example.com/owner/repo.git").as_deref(),
+        Some("http://other.example.com:8080")
+    );
+    assert_eq!(
+        proxy_url(&config, "https://example.com/direct/repo.git"),
+        None
+    );
+    assert_eq!(
+        proxy_url(&config, "https://example.org/owner/repo.git").as_deref(),
+        Some("http://proxy.example.com:3128")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn proxy_environment_and_no_proxy() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().build()?;
+    let config = fixture.repo()?.config()?;
+
+    // Environment variables are process-wide, so every env case lives in this one test
+    for name in [
+        "http_proxy",
+        "all_proxy",
+        "ALL_PROXY",
+        "HTTPS_PROXY",
+        "NO_PROXY",
+    ] {
+        std::env::remove_var(name);
+    }
+    std::env::set_var("https_proxy", "http://env-proxy.test:3128");
+    std::env::set_var("no_proxy", "localhost,.internal.test");
+
+    assert_eq!(
+        proxy_url(&config, "https://git.example.test/repo.git").as_deref(),
+        Some("http://env-proxy.test:3128")
+    );
+    assert_eq!(proxy_url(&config, "http://git.example.test/repo.git"), None);
+    assert_eq!(
+        proxy_url(&config, "https://git.internal.test/repo.git"),
+        None
+    );
+    assert_eq!(proxy_url(&config, "https://localhost:8443/repo.git"), None);
+
+    std::env::remove_var("https_proxy");
+    std::env::remove_var("no_proxy");
+    Ok(())
+}
@@ -0,0 +1,71 @@
+use std::error::Error;
+use std::path::Path;
+
+use assert_fs::prelude::*;
+use workon::RepoRegistry;
+
+#[test]
+fn registry_missing_file_is_empty() -> Result<(), Box<dyn Error>> {
+    let dir = assert_fs::TempDir::new()?;
+
+    let registry = RepoRegistry::load_from(dir.path().join("repos.toml"))?;
+    assert!(registry.is_empty());
+    Ok(())
+}
+
+#[test]
+fn registry_reads_repos_table() -> Result<(), Box<dyn Error>> {
+    let dir = assert_fs::TempDir::new()?;
+    let file = dir.child("repos.toml");
+    file.write_str(
+        r#"# My projects
+[repos]
+api = "/src/api"   # backend
+"web-app" = '/src/web'
+escaped = "/src/with \"quotes\""
+
+[settings]
+ignored = "value"
+"#,
+    )?;
+
+    let registry = RepoRegistry::load_from(file.path())?;
+    let names: Vec<_> = registry.iter().map(|(name, _)| name).collect();
+    assert_eq!(names, vec!["api", "escaped", "web-app"]);
+    assert_eq!(registry.get("api"), Some(Path::new("/src/api")));
+    assert_eq!(registry.get("web-app"), Some(Path::new("/src/web")));
+    assert_eq!(
+        registry.get("escaped"),
+        Some(Path::new("/src/with \"quotes\""))
+    );
+    assert_eq!(registry.get("ignored"), None);
+    Ok(())
+}
+
+#[test]
+fn registry_resolve_suggests_close_name() -> Result<(), Box<dyn Error>> {
+    let dir = assert_fs::TempDir::new()?;
+    let file = dir.child("repos.toml");
+    file.write_str("[repos]\napi = \"/src/api\"\n")?;
+
+    let registry = RepoRegistry::load_from(file.path())?;
+    let err = registry.resolve("apj").unwrap_err();
+    match err {
+        workon::WorkonError::Registry(workon::RegistryError::UnknownRepo {
+            suggestion, ..
+        }) => assert_eq!(suggestion.as_deref(), Some("api")),
+        other => panic!("unexpected error: {other}"),
+    }
+    Ok(())
+}
+
+#[test]
+fn registry_reports_invalid_lines() -> Result<(), Box<dyn Error>> {
+    let dir = assert_fs::TempDir::new()?;
+    let file = dir.child("repos.toml");
+    file.write_str("[repos]\napi = /src/api\n")?;
+
+    let err = RepoRegistry::load_from(file.path()).unwrap_err();
+    assert!(err.to_string().contains(":2: expected a string"), "{err}");
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use git_workon_fixture::prelude::*;
+use std::error::Error;
+use std::time::Duration;
+use workon::{ConfigProblem, NameStyle, WorkonSettings};
+
+#[test]
+fn load_reads_every_workon_key() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon.defaultBranch", "develop")
+        .config("workon.copyPattern", ".env*")
+        .config("workon.autoCopyUntracked", "yes")
+        .config("workon.pruneGracePeriod", "2d")
+        .config("workon.nameStyle", "kebab")
+        .config("workon.hookTimeout", "60")
+        .config("workon.hiddenWorktree", "gh-pages")
+        .config("workon-worktree.feature-auth.label", "backend")
+        .config("workon-worktree.feature-auth.note", "waiting on review")
+        .build()?;
+
+    let settings = WorkonSettings::load(fixture.repo()?)?;
+    assert_eq!(settings.default_branch.value.as_deref(), Some("develop"));
+    assert_eq!(settings.copy_patterns.value, vec![".env*"]);
+    assert!(settings.auto_copy_untracked.value);
+    assert_eq!(
+        settings.prune_grace_period.value,
+        Some(Duration::from_secs(2 * 24 * 60 * 60))
+    );
+    assert_eq!(settings.name_style.value, NameStyle::Kebab);
+    assert_eq!(settings.hook_timeout.value, Duration::from_secs(60));
+    assert!(settings.is_hidden("gh-pages"));
+    assert_eq!(settings.labels("feature-auth"), ["backend"]);
+    assert_eq!(settings.note("feature-auth"), Some("waiting on review"));
+
+    assert_eq!(
+        settings.default_branch.source(),
+        Some(git2::ConfigLevel::Local)
+    );
+    assert!(settings.pr_format.is_default());
+    assert_eq!(settings.pr_format.value, "pr-{number}");
+    Ok(())
+}
+
+#[test]
+fn load_skips_invalid_values() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon.pruneGracePeriod", "soon")
+        .config("workon.hookTimeout", "60")
+        .build()?;
+
+    let settings = WorkonSettings::load(fixture.repo()?)?;
+    assert_eq!(
+        settings.prune_grace_period.value,
+        WorkonSettings::default().prune_grace_period.value
+    );
+    assert_eq!(settings.hook_timeout.value, Duration::from_secs(60));
+    Ok(())
+}
+
+#[test]
+fn problems_reports_each_invalid_and_unknown_key() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .config("workon.pruneGracePeriod", "soon")
+        .config("workon.nameStyle", "shouty")
+        .config("workon.hookTimout", "60")
+        .config("workon-worktree.feature.labels", "backend")
+        .config("workon-worktree.feature.label", "backend")
+        .config("workon.feature.note", "from before metadata moved")
+        .config("workon.alias.p", "prune --merged")
+        .build()?;
+
+    let problems = WorkonSettings::problems(&fixture.repo()?.config()?.snapshot()?)?;
+    assert!(matches!(
+        &problems[0],
+        ConfigProblem::Invalid { key, value, .. } if key == "workon.prunegraceperiod" && value == "soon"
+    ));
+    assert!(
+        matches!(&problems[1], ConfigProblem::Invalid { key, .. } if key == "workon.namestyle")
+    );
+    assert_eq!(
+        problems[2],
+        ConfigProblem::UnknownKey {
+            key: "workon.hooktimout".to_string(),
+            suggestion: Some("workon.hookTimeout".to_string()),
+        }
+    );
+    assert_eq!(
+        problems[3],
+        ConfigProblem::UnknownWorktreeKey {
+            key: "workon-worktree.feature.labels".to_string(),
+            suggestion: Some("workon-worktree.feature.label".to_string()),
+        }
+    );
+    assert_eq!(
+        problems[4],
+        ConfigProblem::UnknownWorktreeKey {
+            key: "workon.feature.note".to_string(),
+            suggestion: Some("workon-worktree.feature.note".to_string()),
+        }
+    );
+    assert_eq!(problems.len(), 5);
+    Ok(())
+}
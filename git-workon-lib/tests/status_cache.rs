@@ -0,0 +1,93 @@
+use git_workon_fixture::prelude::*;
+use std::error::Error;
+use workon::{find_worktree, StatusCache};
+
+/// Load the cache, prime a fresh descriptor for `name` from it, save it, and report
+/// whether the worktree is dirty and ahead of its upstream.
+fn cached_status(repo: &git2::Repository, name: &str) -> Result<(bool, bool), Box<dyn Error>> {
+    let wt = find_worktree(repo, name)?;
+    let mut cache = StatusCache::load(repo)?;
+    cache.prime(&wt);
+    cache.save()?;
+    Ok((wt.is_dirty()?, wt.has_unpushed_commits()?))
+}
+
+fn cached_is_dirty(repo: &git2::Repository, name: &str) -> Result<bool, Box<dyn Error>> {
+    Ok(cached_status(repo, name)?.0)
+}
+
+#[test]
+fn status_cache_never_serves_a_stale_dirty_status() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let repo = fixture.repo()?;
+    let path = find_worktree(repo, "main")?.path().to_path_buf();
+
+    assert!(!cached_is_dirty(repo, "main")?);
+    assert!(repo
+        .commondir()
+        .join("workon")
+        .join("status-cache")
+        .exists());
+
+    // Neither HEAD nor the index changes, but the edit still shows
+    std::fs::write(path.join("scratch.txt"), "uncommitted")?;
+    assert!(cached_is_dirty(repo, "main")?);
+    let cache = StatusCache::load(repo)?;
+    assert!(cache.last_known("main").is_some_and(|status| status.dirty));
+
+    std::fs::remove_file(path.join("scratch.txt"))?;
+    assert!(!cached_is_dirty(repo, "main")?);
+
+    Ok(())
+}
+
+#[test]
+fn status_cache_is_invalidated_by_a_new_head() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let repo = fixture.repo()?;
+
+    assert_eq!(cached_status(repo, "main")?, (false, false));
+
+    // Rewrite the cached entry as ahead, keeping its keys
+    let cache_path = repo.commondir().join("workon").join("status-cache");
+    let contents = std::fs::read_to_string(&cache_path)?;
+    std::fs::write(&cache_path, contents.replace("\t-\n", "\tahead\n"))?;
+    assert_eq!(cached_status(repo, "main")?, (false, true));
+
+    // A commit moves HEAD, so the status is computed again
+    fixture
+        .commit("main")
+        .file("other.txt", "x")
+        .create("Add file")?;
+    assert_eq!(cached_status(repo, "main")?, (false, false));
+
+    Ok(())
+}
+
+#[test]
+fn status_cache_ignores_malformed_lines() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let repo = fixture.repo()?;
+
+    let cache_path = repo.commondir().join("workon").join("status-cache");
+    std::fs::create_dir_all(cache_path.parent().unwrap())?;
+    std::fs::write(&cache_path, "main\tgarbage\nmain\ta\tb\tsparkly\n")?;
+
+    assert!(!cached_is_dirty(repo, "main")?);
+    let contents = std::fs::read_to_string(&cache_path)?;
+    assert!(!contents.contains("garbage") && !contents.contains("sparkly"));
+
+    Ok(())
+}
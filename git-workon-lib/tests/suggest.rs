@@ -0,0 +1,48 @@
+use git_workon_fixture::prelude::*;
+use std::error::Error;
+use workon::{did_you_mean, find_worktree, suggest_worktree_name};
+
+#[test]
+fn did_you_mean_finds_close_typo() {
+    let candidates = ["main", "feature-auth", "feature-billing"];
+    assert_eq!(
+        did_you_mean("feature-auht", candidates),
+        Some("feature-auth".to_string())
+    );
+    assert_eq!(
+        did_you_mean("Feature-Auth", candidates),
+        Some("feature-auth".to_string())
+    );
+}
+
+#[test]
+fn did_you_mean_ignores_distant_names() {
+    let candidates = ["main", "feature-auth"];
+    assert_eq!(did_you_mean("release", candidates), None);
+    assert_eq!(did_you_mean("xy", candidates), None);
+}
+
+#[test]
+fn suggest_worktree_name_considers_branches() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature-auth")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    assert_eq!(
+        suggest_worktree_name(repo, "feature-aut"),
+        Some("feature-auth".to_string())
+    );
+
+    let err = find_worktree(repo, "feature-aut").unwrap_err();
+    let report = miette::Report::new(err);
+    let help = report.help().map(|h| h.to_string()).unwrap_or_default();
+    assert!(
+        help.contains("Did you mean 'feature-auth'?"),
+        "help: {help}"
+    );
+    Ok(())
+}
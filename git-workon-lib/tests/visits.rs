@@ -0,0 +1,115 @@
+use git_workon_fixture::prelude::*;
+use std::error::Error;
+use workon::{VisitKind, VisitStore, WorktreeUsage};
+
+#[test]
+fn visit_store_is_empty_before_first_record() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().bare(true).build()?;
+
+    let store = VisitStore::new(fixture.repo()?);
+    assert!(store.entries()?.is_empty());
+    assert_eq!(store.last_visited()?, None);
+    Ok(())
+}
+
+#[test]
+fn visit_store_records_events_in_order() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    let store = VisitStore::new(fixture.repo()?);
+    store.record("feature", VisitKind::Create)?;
+    store.record("feature", VisitKind::Visit)?;
+    store.record("main", VisitKind::Visit)?;
+    store.record("other", VisitKind::Create)?;
+
+    let entries = store.entries()?;
+    let events: Vec<_> = entries
+        .iter()
+        .map(|v| (v.kind, v.worktree.as_str()))
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            (VisitKind::Create, "feature"),
+            (VisitKind::Visit, "feature"),
+            (VisitKind::Visit, "main"),
+            (VisitKind::Create, "other"),
+        ]
+    );
+
+    // Creating a worktree isn't a visit
+    assert_eq!(store.last_visited()?, Some("main".to_string()));
+    Ok(())
+}
+
+#[test]
+fn visit_store_forget_drops_only_that_worktree() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().bare(true).build()?;
+
+    let store = VisitStore::new(fixture.repo()?);
+    store.record("feature", VisitKind::Create)?;
+    store.record("main", VisitKind::Visit)?;
+    store.record("feature", VisitKind::Visit)?;
+
+    store.forget("feature")?;
+
+    let worktrees: Vec<_> = store.entries()?.into_iter().map(|v| v.worktree).collect();
+    assert_eq!(worktrees, vec!["main".to_string()]);
+    Ok(())
+}
+
+#[test]
+fn visit_store_usage_summarizes_each_worktree() -> Result<(), Box<dyn Error>> {
+    let fixture = FixtureBuilder::new().bare(true).build()?;
+    let repo = fixture.repo()?;
+
+    let log = repo.commondir().join("workon").join("visits");
+    std::fs::create_dir_all(log.parent().unwrap())?;
+    std::fs::write(
+        &log,
+        "1000\tcreate\tfeature\n\
+         1100\tvisit\tfeature\n\
+         1200\tcreate\tspike\n\
+         1400\tvisit\tfeature\n\
+         1500\tvisit\tmain\n",
+    )?;
+
+    let usage = VisitStore::new(repo).usage()?;
+    assert_eq!(
+        usage,
+        vec![
+            WorktreeUsage {
+                worktree: "feature".to_string(),
+                created: Some(1000),
+                visits: 2,
+                last_visit: Some(1400),
+                average_interval: Some(200),
+            },
+            WorktreeUsage {
+                worktree: "main".to_string(),
+                created: None,
+                visits: 1,
+                last_visit: Some(1500),
+                average_interval: None,
+            },
+            WorktreeUsage {
+                worktree: "spike".to_string(),
+                created: Some(1200),
+                visits: 0,
+                last_visit: None,
+                average_interval: None,
+            },
+        ]
+    );
+    let never: Vec<_> = usage
+        .iter()
+        .filter(|u| u.never_revisited())
+        .map(|u| u.worktree.as_str())
+        .collect();
+    assert_eq!(never, vec!["spike"]);
+    Ok(())
+}
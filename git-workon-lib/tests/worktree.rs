@@ -52,6 +52,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_add_worktree_case_collision() -> Result<(), Box<dyn std::error::Error>> {
+        let fixture = FixtureBuilder::new()
+            .bare(true)
+            .default_branch("main")
+            .build()?;
+
+        let repo = fixture.repo()?;
+        add_worktree(repo, "Feature", BranchType::Normal, None)?;
+
+        // Differs only in case: the same directory on macOS and Windows
+        let err = add_worktree(repo, "feature", BranchType::Normal, None).unwrap_err();
+        match err {
+            workon::WorkonError::Worktree(workon::WorktreeError::CaseCollision {
+                name,
+                existing,
+                suggestion,
+            }) => {
+                assert_eq!(name, "feature");
+                assert_eq!(existing, "Feature");
+                assert_eq!(suggestion, "feature-2");
+            }
+            other => panic!("expected a case collision, got {:?}", other),
+        }
+        assert!(repo
+            .find_branch("feature", git2::BranchType::Local)
+            .is_err());
+
+        // The worktree name is the same ignoring case, even in another directory
+        assert!(add_worktree(repo, "user/FEATURE", BranchType::Normal, None).is_err());
+        add_worktree(repo, "feature-2", BranchType::Normal, None)?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_add_worktree_orphan() -> Result<(), Box<dyn std::error::Error>> {
         // Create a bare fixture with a default branch
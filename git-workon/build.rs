@@ -1,4 +1,4 @@
-use std::{env::var_os, fs::create_dir_all, fs::write, io, process::Command};
+use std::{env::var_os, fs::create_dir_all, io, process::Command};
 
 #[path = "src/cli.rs"]
 mod cli;
@@ -40,23 +40,21 @@ fn git_version() -> Option<String> {
     String::from_utf8(output.stdout).ok()
 }
 
+/// Man pages for the main command and every subcommand (`git-workon-new.1`, ...).
 fn generate_manpages() -> io::Result<()> {
     use clap::CommandFactory;
-    use clap_mangen::Man;
 
     use crate::cli::Cli;
 
     let dir = "man";
-    let path = format!("{}/{}.1", dir, env!("CARGO_PKG_NAME"));
-    let cmd = Cli::command();
-    let man = Man::new(cmd);
-    let mut buffer: Vec<u8> = Default::default();
-    man.render(&mut buffer)?;
-
     create_dir_all(dir)?;
-    write(&path, buffer)?;
+    clap_mangen::generate_to(Cli::command(), dir)?;
 
-    println!("cargo:warning=generated manpage: {:?}", &path);
+    println!(
+        "cargo:warning=generated manpages: \"{}/{}.1\" and subcommands",
+        dir,
+        env!("CARGO_PKG_NAME")
+    );
 
     Ok(())
 }
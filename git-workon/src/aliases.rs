@@ -0,0 +1,223 @@
+//! User-defined command aliases.
+//!
+//! Aliases work like git's own: `workon.alias.<name>` maps a short name to a longer
+//! invocation, and is expanded before the command line is parsed:
+//!
+//! ```bash
+//! git config workon.alias.p "prune --merged --yes"
+//! git workon p                # git workon prune --merged --yes
+//! git workon p --dry-run      # extra arguments are appended
+//!
+//! git config workon.alias.open '!code "$(git workon find --no-interactive "$1")"'
+//! git workon open feature     # runs the shell command with "feature" as $1
+//! ```
+//!
+//! - Only the first word after the global options is looked up, so aliases can't be
+//!   passed as arguments to other commands
+//! - Built-in commands always win: an alias named `list` is ignored. So do external
+//!   `git-workon-<name>` commands on PATH (see src/plugins.rs)
+//! - An alias may expand to another alias; loops are reported as errors
+//! - A value starting with `!` runs in `sh` with the remaining arguments as `$1`, `$2`, ...
+//! - Alias names are case-insensitive, like every git config variable name
+//!
+//! Aliases are read from the repository in the current directory (or the one selected
+//! with `-R`), falling back to the global config outside a repository.
+
+use std::ffi::OsString;
+use std::process::Command;
+
+use clap::CommandFactory;
+use git2::Config;
+use miette::{miette, IntoDiagnostic, Result};
+
+use crate::cli::Cli;
+
+/// What an invocation turned into after alias expansion.
+pub enum Expansion {
+    /// Arguments to parse, with any alias replaced by its expansion
+    Args(Vec<OsString>),
+    /// A `!` alias: a shell command to run with the remaining arguments
+    Shell {
+        command: String,
+        args: Vec<OsString>,
+    },
+}
+
+/// Expand an alias in `args` (the full command line, including the program name).
+pub fn expand(mut args: Vec<OsString>) -> Result<Expansion> {
+    let mut cli = Cli::command();
+    cli.build();
+
+    let Some((position, repo)) = command_position(&cli, &args) else {
+        return Ok(Expansion::Args(args));
+    };
+    let Some(config) = alias_config(repo) else {
+        return Ok(Expansion::Args(args));
+    };
+
+    let mut seen: Vec<String> = Vec::new();
+    while let Some(name) = args[position].to_str().map(str::to_lowercase) {
+        if cli.find_subcommand(&name).is_some() {
+            break;
+        }
+        let Ok(value) = config.get_string(&format!("workon.alias.{}", name)) else {
+            break;
+        };
+        if seen.contains(&name) {
+            seen.push(name);
+            return Err(miette!("Alias loop: {}", seen.join(" -> ")));
+        }
+        seen.push(name);
+
+        let rest = args.split_off(position + 1);
+        if let Some(command) = value.strip_prefix('!') {
+            return Ok(Expansion::Shell {
+                command: command.to_string(),
+                args: rest,
+            });
+        }
+
+        let words = split_words(&value).ok_or_else(|| {
+            miette!(
+                "Unterminated quote in alias '{}': {}",
+                args[position].to_string_lossy(),
+                value
+            )
+        })?;
+        if words.is_empty() {
+            return Err(miette!(
+                "Alias '{}' is empty",
+                args[position].to_string_lossy()
+            ));
+        }
+        args.truncate(position);
+        args.extend(words.into_iter().map(OsString::from));
+        args.extend(rest);
+    }
+    Ok(Expansion::Args(args))
+}
+
+/// Run a `!` alias with `sh`, passing `args` as positional parameters. Returns its exit
+/// code.
+pub fn run_shell(command: &str, args: &[OsString]) -> Result<i32> {
+    // Like git, refer to the arguments as "$@" so they're appended to the command
+    let script = if args.is_empty() {
+        command.to_string()
+    } else {
+        format!("{} \"$@\"", command)
+    };
+    let status = Command::new("sh")
+        .arg("-c")
+        .arg(script)
+        .arg(command)
+        .args(args)
+        .status()
+        .into_diagnostic()?;
+    Ok(status.code().unwrap_or(1))
+}
+
+/// The index of the first argument after the global options, and the `-R` repository if
+/// one was given.
+pub fn command_position(cli: &clap::Command, args: &[OsString]) -> Option<(usize, Option<String>)> {
+    let takes_value = |arg: Option<&clap::Arg>| arg.is_some_and(|a| a.get_action().takes_values());
+    let mut repo = None;
+    let mut i = 1;
+    while i < args.len() {
+        let arg = args[i].to_str()?;
+        if arg == "--" {
+            return None;
+        }
+        if let Some(long) = arg.strip_prefix("--") {
+            let (long, inline) = match long.split_once('=') {
+                Some((long, value)) => (long, Some(value)),
+                None => (long, None),
+            };
+            let found = cli.get_arguments().find(|a| a.get_long() == Some(long));
+            if takes_value(found) && inline.is_none() {
+                i += 1;
+            }
+            if long == "repo" {
+                repo = inline.or_else(|| args.get(i)?.to_str()).map(str::to_string);
+            }
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|s| !s.is_empty()) {
+            // A cluster of short flags; the first one taking a value consumes the rest,
+            // or the next argument
+            for (offset, short) in shorts.char_indices() {
+                let found = cli.get_arguments().find(|a| a.get_short() == Some(short));
+                if takes_value(found) {
+                    let inline = &shorts[offset + short.len_utf8()..];
+                    let value = if inline.is_empty() {
+                        i += 1;
+                        args.get(i)?.to_str()
+                    } else {
+                        Some(inline)
+                    };
+                    if short == 'R' {
+                        repo = value.map(str::to_string);
+                    }
+                    break;
+                }
+            }
+        } else {
+            return Some((i, repo));
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The config to look aliases up in: the target repository's, or the global config.
+fn alias_config(repo: Option<String>) -> Option<Config> {
+    let path = match repo {
+        Some(name) => Some(
+            workon::RepoRegistry::load()
+                .ok()?
+                .resolve(&name)
+                .ok()?
+                .to_path_buf(),
+        ),
+        None => None,
+    };
+    match workon::get_repo(path) {
+        Ok(repo) => repo.config().ok()?.snapshot().ok(),
+        Err(_) => Config::open_default().ok()?.snapshot().ok(),
+    }
+}
+
+/// Split an alias value into words, honoring single quotes, double quotes and
+/// backslash escapes. Returns None for an unterminated quote.
+fn split_words(value: &str) -> Option<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                words.extend(word.take());
+            }
+            '\'' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next()? {
+                        '\'' => break,
+                        c => word.push(c),
+                    }
+                }
+            }
+            '"' => {
+                let word = word.get_or_insert_with(String::new);
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => word.push(chars.next()?),
+                        c => word.push(c),
+                    }
+                }
+            }
+            '\\' => word.get_or_insert_with(String::new).push(chars.next()?),
+            c => word.get_or_insert_with(String::new).push(c),
+        }
+    }
+    words.extend(word);
+    Some(words)
+}
@@ -16,10 +16,27 @@ use clap_verbosity_flag::{InfoLevel, Verbosity};
 pub struct Cli {
     #[clap(flatten)]
     pub verbose: Verbosity<InfoLevel>,
-    #[arg(long, global = true, help = "Output results as JSON")]
+    #[arg(long, global = true, help = "Output results, and errors, as JSON")]
     pub json: bool,
     #[arg(long, global = true, help = "Disable color output")]
     pub no_color: bool,
+    #[arg(
+        long,
+        global = true,
+        env = "WORKON_TRACE",
+        action = clap::ArgAction::SetTrue,
+        value_parser = clap::builder::FalseyValueParser::new(),
+        help = "Print timings for repo discovery, status checks, fetches, hooks and copies"
+    )]
+    pub trace: bool,
+    #[arg(
+        short = 'R',
+        long = "repo",
+        global = true,
+        value_name = "NAME",
+        help = "Run against a repository from the registry (~/.config/workon/repos.toml)"
+    )]
+    pub repo: Option<String>,
     #[command(subcommand)]
     pub command: Option<Cmd>,
     #[clap(flatten)]
@@ -29,35 +46,66 @@ pub struct Cli {
 #[derive(Debug, Subcommand)]
 pub enum Cmd {
     Clone(Clone),
+    Compare(Compare),
+    Completions(Completions),
     CopyUntracked(CopyUntracked),
+    Default(DefaultWorktree),
     /// Detect and repair workspace issues
     #[command(visible_alias = "check")]
     Doctor(Doctor),
+    Env(Env),
     Find(Find),
+    Hide(Hide),
     Init(Init),
+    Label(Label),
     #[command(visible_alias = "ls")]
     List(List),
+    Man(Man),
     #[command(visible_alias = "mv")]
     Move(Move),
     New(New),
+    Note(Note),
+    Prompt(Prompt),
     Prune(Prune),
+    Relocate(Relocate),
+    Repos(Repos),
+    Root(Root),
+    Run(RunTask),
+    SetUpstream(SetUpstream),
     ShellInit(ShellInit),
+    Stats(Stats),
+    Templates(Templates),
+    Unhide(Unhide),
+    Version(Version),
+    Why(Why),
     #[command(name = "_complete", hide = true)]
     Complete(Complete),
 }
 
 /// Perform a bare clone of a repository and create an initial worktree.
+///
+/// Network failures are retried with backoff. If the clone still can't finish, what was
+/// fetched is kept; run the same command again to resume it.
 #[derive(Debug, Args)]
 pub struct Clone {
     pub url: String,
     pub path: Option<PathBuf>,
     #[arg(long, help = "Skip post-create hooks")]
     pub no_hooks: bool,
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
 }
 
 /// Copy any untracked files in <from> to <to>.
 ///
+/// <from> defaults to the default branch's worktree, and <to> to the current worktree:
+///   git workon copy-untracked <from> <to>
+///   git workon copy-untracked <to>
+///   git workon copy-untracked
+///
 /// Untracked files are files that are ignored by git, or files that are not in the git index.
+/// They are read from `git status` in <from>, so tracked files are never copied.
 ///
 /// This util is a useful complement to a git worktree workflow. Git worktrees provide
 /// a mechanism for maintaining multiple branches of a repository simultaneously, without
@@ -74,12 +122,28 @@ pub struct Clone {
 /// which is a copy-on-write optimization over a potentially much slower copy operation.
 #[derive(Debug, Args)]
 pub struct CopyUntracked {
-    pub from: String,
-    pub to: String,
+    /// Worktree name(s): [from] [to], or just [to]
+    #[arg(num_args = 0..=2, value_name = "WORKTREE")]
+    pub names: Vec<String>,
     #[arg(short, long, help = "Override patterns for one-off copy")]
     pub pattern: Option<String>,
     #[arg(short, long, help = "Overwrite existing files in destination")]
     pub force: bool,
+    #[arg(
+        long,
+        value_enum,
+        help = "Copy only untracked (not ignored) or only ignored files"
+    )]
+    pub only: Option<CopyKind>,
+}
+
+/// Which files `copy-untracked --only` selects.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum CopyKind {
+    /// Files not in the index and not ignored
+    Untracked,
+    /// Files matched by gitignore rules
+    Ignored,
 }
 
 /// Create a new bare repository and an initial worktree.
@@ -88,6 +152,14 @@ pub struct Init {
     pub path: Option<PathBuf>,
     #[arg(long, help = "Skip post-create hooks")]
     pub no_hooks: bool,
+    #[arg(
+        long,
+        help = "Don't sign the initial commit, even with commit.gpgsign set"
+    )]
+    pub no_sign: bool,
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
 }
 
 /// List worktrees.
@@ -97,6 +169,10 @@ pub struct List {
     #[allow(dead_code)]
     pub json: bool,
 
+    /// Print the JSON Schema of --json output and exit
+    #[arg(long)]
+    pub schema: bool,
+
     #[arg(long, help = "Show only worktrees with uncommitted changes")]
     pub dirty: bool,
 
@@ -111,6 +187,41 @@ pub struct List {
 
     #[arg(long, help = "Show only worktrees whose upstream branch is deleted")]
     pub gone: bool,
+
+    #[arg(
+        long,
+        help = "Emit one JSON object per line as each worktree's status is computed"
+    )]
+    pub json_lines: bool,
+
+    #[arg(long, help = "Include worktrees hidden with 'git workon hide'")]
+    pub all: bool,
+
+    #[arg(
+        long,
+        value_name = "LABEL",
+        help = "Show only worktrees with this label (repeatable)"
+    )]
+    pub label: Vec<String>,
+
+    #[arg(
+        long,
+        value_name = "EXPR",
+        help = "Show only worktrees matching an expression, e.g. '(dirty || ahead) && !gone'"
+    )]
+    pub filter: Option<String>,
+
+    #[arg(
+        long,
+        help = "List worktrees from every repository in the registry (~/.config/workon/repos.toml)"
+    )]
+    pub all_repos: bool,
+
+    #[arg(
+        long,
+        help = "Compute every status instead of reusing workon.statusCache"
+    )]
+    pub no_cache: bool,
 }
 
 /// Rename a worktree and its branch atomically.
@@ -133,10 +244,16 @@ pub struct Move {
         help = "Override all safety checks (dirty, unpushed, protected)"
     )]
     pub force: bool,
+
+    #[arg(
+        long,
+        help = "Move the worktree even if it is locked, re-locking it at the new location"
+    )]
+    pub force_locked: bool,
 }
 
 /// Create a new worktree.
-#[derive(Debug, Args)]
+#[derive(Debug, Default, Args)]
 pub struct New {
     pub name: Option<String>,
     #[arg(short, long, help = "Base branch to branch from")]
@@ -159,8 +276,79 @@ pub struct New {
         help = "Do not copy untracked files (overrides config)"
     )]
     pub no_copy_untracked: bool,
+    #[arg(
+        long,
+        value_name = "WORKTREE",
+        help = "Worktree to copy untracked files from (implies --copy-untracked)"
+    )]
+    pub copy_from: Option<String>,
+    #[arg(
+        long,
+        conflicts_with = "detach",
+        help = "Push the new branch to the remote and set it as upstream"
+    )]
+    pub push: bool,
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with = "orphan",
+        help = "Apply a patch, git format-patch series or mbox to the new worktree"
+    )]
+    pub from_patch: Option<PathBuf>,
+    #[arg(
+        long,
+        requires = "from_patch",
+        help = "Commit the applied patches, keeping their authors and messages"
+    )]
+    pub commit: bool,
+    #[arg(
+        long,
+        conflicts_with = "from_patch",
+        help = "Register the worktree and branch without populating the working directory"
+    )]
+    pub no_checkout: bool,
+    #[arg(
+        long,
+        help = "Don't sign commits created for the worktree, even with commit.gpgsign set"
+    )]
+    pub no_sign: bool,
+    #[arg(
+        long,
+        value_name = "REMOTE/BRANCH",
+        requires = "name",
+        conflicts_with_all = ["base", "orphan", "detach"],
+        help = "Create the branch from a remote branch and track it, under the given name"
+    )]
+    pub from: Option<String>,
+    #[arg(
+        long,
+        help = "Mark a PR worktree review-only: workon won't push from it, and prune --prs removes it even with unmerged commits"
+    )]
+    pub review: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["orphan", "detach", "force"],
+        help = "Reuse a leftover directory at the worktree path if it was this branch's worktree"
+    )]
+    pub adopt: bool,
+    #[arg(
+        long,
+        help = "Move a leftover directory at the worktree path aside (to <name>.bak)"
+    )]
+    pub force: bool,
+    #[arg(
+        long,
+        value_name = "NAME",
+        conflicts_with_all = ["from", "orphan"],
+        help = "Create from a template (workon.template.<name>.*), see 'git workon templates'"
+    )]
+    pub template: Option<String>,
     #[arg(long, help = "Disable interactive mode (for testing/scripting)")]
     pub no_interactive: bool,
+
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
 }
 
 /// Prune stale worktrees.
@@ -170,6 +358,10 @@ pub struct Prune {
     #[allow(dead_code)]
     pub json: bool,
 
+    /// Print the JSON Schema of --json output and exit
+    #[arg(long)]
+    pub schema: bool,
+
     /// Specific worktree names to prune
     pub names: Vec<String>,
     #[arg(
@@ -211,6 +403,27 @@ pub struct Prune {
         help = "Override all safety checks (protection, default branch, dirty, unmerged)"
     )]
     pub force: bool,
+    #[arg(
+        long,
+        value_name = "LABEL",
+        help = "Only consider worktrees with this label for --gone/--merged (repeatable)"
+    )]
+    pub label: Vec<String>,
+    #[arg(
+        long,
+        help = "Also prune worktrees created from pull requests (review-only ones skip the unmerged check)"
+    )]
+    pub prs: bool,
+    #[arg(
+        long,
+        help = "Also delete the local branch of each pruned worktree, if it's merged"
+    )]
+    pub delete_branch: bool,
+    #[arg(
+        long,
+        help = "Also delete local branches no worktree has checked out, if merged or pushed"
+    )]
+    pub orphaned_branches: bool,
 }
 
 /// Find a worktree to work on.
@@ -235,8 +448,44 @@ pub struct Find {
     #[arg(long, help = "Show only worktrees whose upstream branch is deleted")]
     pub gone: bool,
 
+    #[arg(
+        long,
+        help = "Show worktrees matching any of the status filters instead of all of them"
+    )]
+    pub any: bool,
+
+    #[arg(long, help = "Print the paths of all matching worktrees, one per line")]
+    pub all: bool,
+
+    #[arg(long, help = "Include worktrees hidden with 'git workon hide'")]
+    pub include_hidden: bool,
+
+    #[arg(
+        long,
+        value_name = "LABEL",
+        help = "Show only worktrees with this label (repeatable)"
+    )]
+    pub label: Vec<String>,
+
     #[arg(long, help = "Disable interactive mode (for testing/scripting)")]
     pub no_interactive: bool,
+
+    #[arg(
+        long,
+        conflicts_with = "all",
+        help = "Create the worktree, like 'new', when nothing matches the name"
+    )]
+    pub create: bool,
+
+    #[arg(
+        long,
+        help = "Compute every status instead of reusing workon.statusCache"
+    )]
+    pub no_cache: bool,
+
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
 }
 
 /// Detect and repair workspace issues.
@@ -250,11 +499,40 @@ pub struct Doctor {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Also check that each configured remote is reachable
+    #[arg(long)]
+    pub network: bool,
+
+    /// Which issues cause a non-zero exit code
+    #[arg(long, value_enum, default_value_t = FailOn::Error)]
+    pub fail_on: FailOn,
+
+    /// With --fix, register worktrees outside the workon root as external instead of moving them
+    #[arg(long, requires = "fix")]
+    pub register_external: bool,
+
+    /// With --fix, ask before fixing each issue
+    #[arg(short, long, requires = "fix", conflicts_with = "dry_run")]
+    pub interactive: bool,
+
+    /// Print the JSON Schema of --json output and exit
+    #[arg(long)]
+    pub schema: bool,
+
     #[clap(skip)]
     #[allow(dead_code)]
     pub json: bool,
 }
 
+/// Minimum severity that makes `doctor` exit non-zero.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum FailOn {
+    /// Warnings and errors
+    Warn,
+    /// Errors only
+    Error,
+}
+
 #[derive(Debug, Clone, Copy, clap::ValueEnum)]
 pub enum Shell {
     Bash,
@@ -262,6 +540,66 @@ pub enum Shell {
     Fish,
 }
 
+/// Print a worktree's WORKON_* environment variables as shell exports.
+///
+/// Prints the variables post-create hooks see, for `eval "$(git workon env)"` in scripts
+/// and direnv files.
+#[derive(Debug, Args)]
+pub struct Env {
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
+
+    /// Worktree or branch name (defaults to the current worktree)
+    pub name: Option<String>,
+    #[arg(long, help = "Shell syntax to print (default: POSIX sh exports)")]
+    pub shell: Option<Shell>,
+}
+
+/// Print man pages generated from the command-line definitions.
+///
+/// Prints the page for the main command, or for a subcommand (`git workon man new | man -l -`).
+/// With --dir, writes a page for every command into a directory instead, for packaging.
+#[derive(Debug, Args)]
+pub struct Man {
+    /// Subcommand to print the page for, e.g. `new` or `repos add`
+    pub command: Vec<String>,
+    #[arg(
+        long,
+        value_name = "DIR",
+        conflicts_with = "command",
+        help = "Write man pages for all commands into this directory"
+    )]
+    pub dir: Option<PathBuf>,
+}
+
+/// Show how two worktrees' branches have diverged.
+///
+/// Prints how far <a> is ahead of and behind <b>, their merge base, and the commits only
+/// on each side: `git log b..a` and `git log a..b` without spelling out the refs.
+#[derive(Debug, Args)]
+pub struct Compare {
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
+
+    /// First worktree or branch name
+    pub a: String,
+    /// Second worktree or branch name
+    pub b: String,
+}
+
+/// Print a static completion script for flags and subcommands.
+///
+/// Unlike `shell-init`, the script is generated once and doesn't call back into
+/// `git workon`, so it can't complete worktree names; install it where your shell loads
+/// completions from, e.g. `git workon completions zsh > ~/.zfunc/_git-workon`.
+#[derive(Debug, Args)]
+pub struct Completions {
+    /// Shell to generate the completion script for
+    pub shell: clap_complete::Shell,
+}
+
 /// Generate shell integration script (wrapper function + completions).
 #[derive(Debug, Args)]
 pub struct ShellInit {
@@ -272,6 +610,257 @@ pub struct ShellInit {
     pub cmd: String,
 }
 
+/// Show or set the default branch and its worktree.
+///
+/// The default branch is the fallback base for `new`, the `prune --merged` target and the
+/// default copy source. Without a name, prints the default worktree's path.
+#[derive(Debug, Args)]
+pub struct DefaultWorktree {
+    /// Worktree or branch to make the default (stored in workon.defaultBranch)
+    pub name: Option<String>,
+}
+
+/// Print a compact status segment for shell prompts.
+///
+/// Prints the current worktree's name and status indicators (e.g. `feature *↑`), or
+/// nothing outside a worktree. Status that takes longer than the time budget is replaced
+/// by the last known status, refreshed in the background.
+#[derive(Debug, Args)]
+pub struct Prompt {
+    #[arg(
+        long,
+        value_name = "MS",
+        default_value_t = 100,
+        help = "Time budget in milliseconds before falling back to cached status"
+    )]
+    pub timeout: u64,
+
+    /// Recompute status and update the cache without a time budget (used internally)
+    #[arg(long, hide = true)]
+    pub refresh: bool,
+
+    #[arg(
+        long,
+        help = "Compute the status instead of reusing workon.statusCache"
+    )]
+    pub no_cache: bool,
+}
+
+/// Run a task (workon.task.<name>) in worktrees.
+///
+/// Tasks are shell commands saved in config, run like post-create hooks (same shell,
+/// environment variables, `cwd=` prefixes and timeout):
+///   git config workon.task.setup "npm ci && cp ../.env ."
+///   git workon run setup                 # in the current worktree
+///   git workon run setup feature main    # in each named worktree
+///   git workon run                       # list the configured tasks
+#[derive(Debug, Args)]
+pub struct RunTask {
+    /// Task to run; lists the configured tasks when omitted
+    pub task: Option<String>,
+
+    /// Worktrees to run it in (default: the current worktree)
+    #[arg(value_name = "WORKTREE")]
+    pub worktrees: Vec<String>,
+}
+
+/// Move the workon root (bare repo and all worktrees) to a new directory.
+///
+/// Every worktree link is rewritten for the new location. If anything fails partway,
+/// the layout is moved back.
+#[derive(Debug, Args)]
+pub struct Relocate {
+    /// New workon root; must not exist yet (or be an empty directory)
+    pub new_root: PathBuf,
+
+    #[arg(short = 'n', long, help = "Preview changes without executing")]
+    pub dry_run: bool,
+}
+
+/// Manage the repository registry used by `-R` and `list --all-repos`.
+#[derive(Debug, Args)]
+pub struct Repos {
+    #[command(subcommand)]
+    pub command: ReposCmd,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ReposCmd {
+    Add(ReposAdd),
+    Remove(ReposRemove),
+    #[command(visible_alias = "ls")]
+    List(ReposList),
+}
+
+/// Register a workon repository.
+#[derive(Debug, Args)]
+pub struct ReposAdd {
+    /// The workon root or any worktree in it (default: current directory)
+    pub path: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Name to register under (default: the root directory's name)"
+    )]
+    pub name: Option<String>,
+}
+
+/// Unregister a repository. Nothing on disk is touched.
+#[derive(Debug, Args)]
+pub struct ReposRemove {
+    /// Registered repository name
+    pub name: String,
+}
+
+/// List registered repositories.
+#[derive(Debug, Args)]
+pub struct ReposList {
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
+}
+
+/// Print the workon root (the directory containing the bare repo and worktrees).
+#[derive(Debug, Args)]
+pub struct Root {
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
+
+    #[arg(long, help = "Print the bare repository's path instead")]
+    pub bare: bool,
+}
+
+/// Hide a worktree from `list` and `find`.
+///
+/// Hidden worktrees are still reachable by exact name and are shown with `--all`.
+#[derive(Debug, Args)]
+pub struct Hide {
+    /// Worktree or branch name
+    pub name: String,
+}
+
+/// Show a hidden worktree in `list` and `find` again.
+#[derive(Debug, Args)]
+pub struct Unhide {
+    /// Worktree or branch name
+    pub name: String,
+}
+
+/// Attach labels to worktrees to organize them.
+///
+/// Labels are shown in `list` and can be filtered on with `--label` in `list`, `find`
+/// and `prune`.
+#[derive(Debug, Args)]
+pub struct Label {
+    #[command(subcommand)]
+    pub command: LabelCmd,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LabelCmd {
+    Add(LabelAdd),
+    Remove(LabelRemove),
+}
+
+/// Add labels to a worktree.
+#[derive(Debug, Args)]
+pub struct LabelAdd {
+    /// Worktree or branch name
+    pub name: String,
+    /// Labels to add
+    #[arg(required = true)]
+    pub labels: Vec<String>,
+}
+
+/// Remove labels from a worktree.
+#[derive(Debug, Args)]
+pub struct LabelRemove {
+    /// Worktree or branch name
+    pub name: String,
+    /// Labels to remove
+    #[arg(required = true)]
+    pub labels: Vec<String>,
+}
+
+/// Attach a note to a worktree, shown in `list` and the interactive picker.
+///
+/// Without text, prints the worktree's current note.
+#[derive(Debug, Args)]
+pub struct Note {
+    /// Worktree or branch name
+    pub name: String,
+    /// Note text (replaces any existing note)
+    pub text: Option<String>,
+    #[arg(long, conflicts_with = "text", help = "Remove the worktree's note")]
+    pub clear: bool,
+}
+
+/// Make an existing worktree's branch track a remote branch.
+///
+/// For branches created without an upstream (or adopted worktrees), so list, find and
+/// prune can show whether they're ahead, behind or gone.
+#[derive(Debug, Args)]
+pub struct SetUpstream {
+    /// Worktree or branch name
+    pub name: String,
+    /// Remote branch to track (default: the branch's name on the push remote)
+    #[arg(value_name = "REMOTE/BRANCH")]
+    pub upstream: Option<String>,
+    #[arg(long, help = "Push the branch to the upstream first")]
+    pub push: bool,
+}
+
+/// Summarize the workspace: worktree states, or visit history with --usage.
+#[derive(Debug, Args)]
+pub struct Stats {
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
+
+    #[arg(
+        long,
+        help = "Report visits per worktree, time between visits, and worktrees never revisited"
+    )]
+    pub usage: bool,
+}
+
+/// Explain why prune would keep or remove a worktree.
+///
+/// Prints every criterion prune evaluates for the worktree (branch, upstream, merged,
+/// dirty, unpushed, protected, locked, age) and what each form of prune would do with it.
+#[derive(Debug, Args)]
+pub struct Why {
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
+
+    /// Worktree or branch name
+    pub name: String,
+}
+
+/// List worktree templates.
+///
+/// Templates are defined with `workon.template.<name>.<key>` config (base, copyPattern,
+/// postCreateHook, sparse, label) and applied with `git workon new --template <name>`.
+#[derive(Debug, Args)]
+pub struct Templates {
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
+}
+
+/// Print version and capability information.
+///
+/// With --json, reports the build's optional features, which external tools (gh, fzf,
+/// tmux) were found on PATH and the config schema version, so integrations can adapt.
+#[derive(Debug, Args)]
+pub struct Version {
+    #[clap(skip)]
+    #[allow(dead_code)]
+    pub json: bool,
+}
+
 /// List worktree names for shell completion (hidden).
 #[derive(Debug, Args)]
 pub struct Complete {
@@ -1,38 +1,137 @@
 mod clone;
+mod compare;
 mod complete;
+mod completions;
 mod copy_untracked;
+mod default;
 mod doctor;
+mod env;
 mod find;
+mod hide;
 mod init;
+mod label;
 mod list;
+mod man;
 mod r#move; // r#move because "move" is a reserved keyword
 mod new;
+mod note;
+mod prompt;
 mod prune;
+mod relocate;
+mod repos;
+mod root;
+mod run;
+mod set_upstream;
 mod shell_init;
+mod stats;
+mod templates;
+mod version;
+mod why;
 
-use miette::Result;
-use workon::WorktreeDescriptor;
+use git2::Repository;
+use miette::{IntoDiagnostic, Result};
+use tracing::debug;
+use workon::{StatusCache, WorkonConfig, WorktreeDescriptor};
 
 use crate::cli::Cmd;
+use crate::hooks::HookRun;
+use crate::json::worktree_to_json;
 
 pub trait Run {
     fn run(&self) -> Result<Option<WorktreeDescriptor>>;
 }
 
+/// Hand back a worktree a command created. With `--json`, print it with the post-create
+/// hooks that ran instead, since only the command knows about those.
+fn created(
+    worktree: WorktreeDescriptor,
+    hooks: HookRun,
+    json: bool,
+) -> Result<Option<WorktreeDescriptor>> {
+    if !json {
+        return Ok(Some(worktree));
+    }
+    let mut value = worktree_to_json(&worktree);
+    if !hooks.results.is_empty() {
+        value["hooks"] = hooks.to_json();
+    }
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&value).into_diagnostic()?
+    );
+    Ok(None)
+}
+
+/// The status cache for `repo`, if workon.statusCache is enabled and `--no-cache` wasn't
+/// given. A cache that can't be read is skipped, never an error.
+fn status_cache(repo: &Repository, no_cache: bool) -> Option<StatusCache> {
+    if !status_cache_enabled(repo, no_cache) {
+        return None;
+    }
+    StatusCache::load(repo)
+        .inspect_err(|e| debug!("Failed to read status cache: {}", e))
+        .ok()
+}
+
+/// Whether workon.statusCache is enabled and `--no-cache` wasn't given.
+fn status_cache_enabled(repo: &Repository, no_cache: bool) -> bool {
+    WorkonConfig::new(repo)
+        .and_then(|config| config.status_cache(no_cache.then_some(false)))
+        .unwrap_or(false)
+}
+
+/// Give `worktrees` their statuses from the status cache (see [`status_cache`]),
+/// computing and storing any that aren't cached.
+fn prime_statuses(repo: &Repository, worktrees: &[WorktreeDescriptor], no_cache: bool) {
+    let mut cache = status_cache(repo, no_cache);
+    if let Some(cache) = cache.as_mut() {
+        cache.prime_all(worktrees);
+    }
+    save_status_cache(cache);
+}
+
+/// Write back a status cache from [`status_cache`]; failing to is not an error.
+fn save_status_cache(cache: Option<StatusCache>) {
+    if let Some(mut cache) = cache {
+        if let Err(e) = cache.save() {
+            debug!("Failed to write status cache: {}", e);
+        }
+    }
+}
+
 impl Run for Cmd {
     fn run(&self) -> Result<Option<WorktreeDescriptor>> {
         match self {
             Cmd::Clone(cmd) => cmd.run(),
+            Cmd::Compare(cmd) => cmd.run(),
             Cmd::Complete(cmd) => cmd.run(),
+            Cmd::Completions(cmd) => cmd.run(),
             Cmd::CopyUntracked(cmd) => cmd.run(),
+            Cmd::Default(cmd) => cmd.run(),
             Cmd::Doctor(cmd) => cmd.run(),
+            Cmd::Env(cmd) => cmd.run(),
             Cmd::Find(cmd) => cmd.run(),
+            Cmd::Hide(cmd) => cmd.run(),
             Cmd::Init(cmd) => cmd.run(),
+            Cmd::Label(cmd) => cmd.run(),
             Cmd::List(cmd) => cmd.run(),
+            Cmd::Man(cmd) => cmd.run(),
             Cmd::Move(cmd) => cmd.run(),
             Cmd::New(cmd) => cmd.run(),
+            Cmd::Note(cmd) => cmd.run(),
+            Cmd::Prompt(cmd) => cmd.run(),
             Cmd::Prune(cmd) => cmd.run(),
+            Cmd::Relocate(cmd) => cmd.run(),
+            Cmd::Repos(cmd) => cmd.run(),
+            Cmd::Root(cmd) => cmd.run(),
+            Cmd::Run(cmd) => cmd.run(),
+            Cmd::SetUpstream(cmd) => cmd.run(),
             Cmd::ShellInit(cmd) => cmd.run(),
+            Cmd::Stats(cmd) => cmd.run(),
+            Cmd::Templates(cmd) => cmd.run(),
+            Cmd::Unhide(cmd) => cmd.run(),
+            Cmd::Version(cmd) => cmd.run(),
+            Cmd::Why(cmd) => cmd.run(),
         }
     }
 }
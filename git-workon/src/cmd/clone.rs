@@ -4,9 +4,9 @@ use miette::{Result, WrapErr};
 use workon::{add_worktree, clone, get_default_branch_name, BranchType, WorktreeDescriptor};
 
 use crate::cli::Clone;
-use crate::hooks::execute_post_create_hooks;
+use crate::hooks::{execute_post_create_hooks, HookRun};
 
-use super::Run;
+use super::{created, Run};
 
 impl Run for Clone {
     fn run(&self) -> Result<Option<WorktreeDescriptor>> {
@@ -33,13 +33,15 @@ impl Run for Clone {
             ))?;
 
         // Execute post-create hooks after successful worktree creation
+        let mut hooks = HookRun::default();
         if !self.no_hooks {
-            if let Err(e) = execute_post_create_hooks(&worktree, None, &config) {
+            hooks = execute_post_create_hooks(&repo, &worktree, None, &config, &[]);
+            if let Some(e) = &hooks.error {
                 eprintln!("Warning: Post-create hook failed: {}", e);
                 // Continue - worktree is still valid
             }
         }
 
-        Ok(Some(worktree))
+        created(worktree, hooks, self.json)
     }
 }
@@ -0,0 +1,107 @@
+//! Compare command - show how two worktrees' branches have diverged.
+//!
+//! A quicker check than spelling out `git log a..b` and `git log b..a`:
+//!
+//! ```text
+//! $ git workon compare feature main
+//! feature is 2 ahead, 1 behind main
+//! merge base: 3f2c1a9 Add settings page
+//!
+//! Only on feature (2):
+//!   9b1e0d2 Handle empty input
+//!   71ac5f3 Add login form
+//!
+//! Only on main (1):
+//!   c04d8e7 Bump dependencies
+//! ```
+//!
+//! Worktrees are matched like `find` matches them (name, branch, or a fuzzy match), and
+//! their HEADs are compared, so detached worktrees work too (see
+//! git-workon-lib/src/compare.rs). `--json` prints the same as an object.
+
+use miette::{IntoDiagnostic, Result};
+use serde_json::{json, Value};
+use workon::{compare_worktrees, find_worktree_fuzzy, get_repo, CommitSummary, WorktreeDescriptor};
+
+use crate::cli::Compare;
+
+use super::Run;
+
+impl Run for Compare {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let a = find_worktree_fuzzy(&repo, &self.a)?;
+        let b = find_worktree_fuzzy(&repo, &self.b)?;
+        let a_name = a.name().unwrap_or(&self.a);
+        let b_name = b.name().unwrap_or(&self.b);
+        let divergence = compare_worktrees(&a, &b)?;
+        let merge_base = divergence
+            .merge_base
+            .map(|oid| repo.find_commit(oid))
+            .transpose()
+            .into_diagnostic()?;
+
+        if self.json {
+            let side = |wt: &WorktreeDescriptor, name: &str, commits: &[CommitSummary]| {
+                json!({
+                    "name": name,
+                    "branch": wt.branch().ok().flatten(),
+                    "head": wt.head_commit().ok().flatten(),
+                    "commits": commits.iter().map(commit_to_json).collect::<Vec<_>>(),
+                })
+            };
+            let result = json!({
+                "a": side(&a, a_name, &divergence.only_a),
+                "b": side(&b, b_name, &divergence.only_b),
+                "ahead": divergence.ahead(),
+                "behind": divergence.behind(),
+                "merge_base": merge_base.as_ref().map(|c| c.id().to_string()),
+            });
+            let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        if divergence.ahead() == 0 && divergence.behind() == 0 {
+            println!("{} and {} are at the same commit", a_name, b_name);
+        } else {
+            println!(
+                "{} is {} ahead, {} behind {}",
+                a_name,
+                divergence.ahead(),
+                divergence.behind(),
+                b_name
+            );
+        }
+        match &merge_base {
+            Some(commit) => println!(
+                "merge base: {} {}",
+                short_id(commit.id()),
+                commit.summary().unwrap_or_default()
+            ),
+            None => println!("merge base: none (unrelated histories)"),
+        }
+        for (name, commits) in [(a_name, &divergence.only_a), (b_name, &divergence.only_b)] {
+            if commits.is_empty() {
+                continue;
+            }
+            println!();
+            println!("Only on {} ({}):", name, commits.len());
+            for commit in commits {
+                println!("  {} {}", short_id(commit.id), commit.summary);
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn commit_to_json(commit: &CommitSummary) -> Value {
+    json!({
+        "id": commit.id.to_string(),
+        "summary": commit.summary,
+    })
+}
+
+fn short_id(oid: git2::Oid) -> String {
+    oid.to_string()[..7].to_string()
+}
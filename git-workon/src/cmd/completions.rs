@@ -0,0 +1,36 @@
+//! Completions command - static completion scripts.
+//!
+//! Generates a completion script with clap_complete for the CLI's subcommands and flags,
+//! for package managers and users who'd rather not `eval` shell-init at startup:
+//!
+//! ```bash
+//! git workon completions bash > ~/.local/share/bash-completion/completions/git-workon
+//! git workon completions zsh > ~/.zfunc/_git-workon
+//! git workon completions fish > ~/.config/fish/completions/git-workon.fish
+//! git workon completions powershell >> $PROFILE
+//! ```
+//!
+//! Worktree and branch names are only completed by the dynamic completer that
+//! `shell-init` installs (see src/cmd/complete.rs).
+
+use std::io;
+
+use clap::CommandFactory;
+use miette::Result;
+use workon::WorktreeDescriptor;
+
+use crate::cli::{Cli, Completions};
+
+use super::Run;
+
+impl Run for Completions {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        clap_complete::generate(
+            self.shell,
+            &mut Cli::command(),
+            env!("CARGO_PKG_NAME"),
+            &mut io::stdout(),
+        );
+        Ok(None)
+    }
+}
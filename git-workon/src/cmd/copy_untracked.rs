@@ -1,7 +1,25 @@
+//! Copy-untracked command - copy untracked and ignored files between worktrees.
+//!
+//! ## Invocation Modes
+//!
+//! - `git workon copy-untracked <from> <to>` - explicit source and destination
+//! - `git workon copy-untracked <to>` - copy from the default branch's worktree
+//! - `git workon copy-untracked` - copy from the default branch's worktree into the
+//!   current worktree
+//!
+//! Defaulting to the default branch's worktree matches how auto-copy during `new`
+//! picks its source. See git-workon-lib/src/copy.rs for pattern and copy details.
+
+use std::path::PathBuf;
+
 use miette::{Result, WrapErr};
-use workon::{copy_files, get_repo, workon_root, WorkonConfig, WorktreeDescriptor};
+use workon::{
+    copy_files, current_worktree, get_default_worktree, get_repo, workon_root, UntrackedFilter,
+    WorkonConfig, WorktreeDescriptor,
+};
 
-use crate::cli::CopyUntracked;
+use crate::cli::{CopyKind, CopyUntracked};
+use crate::display::format_copy_summary;
 
 use super::Run;
 
@@ -13,22 +31,28 @@ impl Run for CopyUntracked {
         // Get worktree root directory
         let root = workon_root(&repo)?;
 
-        // Resolve worktree paths from names
-        let from_path = root.join(&self.from);
-        let to_path = root.join(&self.to);
+        // Parse arguments: [from] [to], [to], or nothing. Named worktrees are resolved
+        // under the root; defaulted ones are wherever they actually are.
+        let named = |name: &String| (name.clone(), root.join(name));
+        let ((from, from_path), (to, to_path)) = match self.names.as_slice() {
+            [from, to] => (named(from), named(to)),
+            [to] => (default_worktree(&repo)?, named(to)),
+            [] => (default_worktree(&repo)?, current(&repo)?),
+            _ => unreachable!("clap limits copy-untracked to two names"),
+        };
 
         // Verify both worktrees exist
         if !from_path.exists() {
             return Err(miette::miette!(
                 "Source worktree '{}' does not exist at {:?}",
-                self.from,
+                from,
                 from_path
             ));
         }
         if !to_path.exists() {
             return Err(miette::miette!(
                 "Destination worktree '{}' does not exist at {:?}",
-                self.to,
+                to,
                 to_path
             ));
         }
@@ -36,23 +60,54 @@ impl Run for CopyUntracked {
         // Determine patterns: --pattern flag > config > error
         let patterns = determine_patterns(self, &config)?;
         let excludes = config.copy_excludes()?;
+        let filter = match self.only {
+            Some(CopyKind::Untracked) => UntrackedFilter {
+                untracked: true,
+                ignored: false,
+            },
+            Some(CopyKind::Ignored) => UntrackedFilter {
+                untracked: false,
+                ignored: true,
+            },
+            None => UntrackedFilter::default(),
+        };
 
         // Copy files
-        let copied = copy_files(&from_path, &to_path, &patterns, &excludes, self.force).wrap_err(
-            format!("Failed to copy files from '{}' to '{}'", self.from, self.to),
-        )?;
+        let copied = copy_files(
+            &from_path, &to_path, &patterns, &excludes, filter, self.force,
+        )
+        .wrap_err(format!("Failed to copy files from '{}' to '{}'", from, to))?;
 
         // Print results
-        for file in &copied {
-            println!("Copied: {}", file.display());
+        for entry in &copied {
+            println!("Copied: {}", entry.path.display());
         }
-        println!("\nCopied {} file(s)", copied.len());
+        println!("\nCopied {}", format_copy_summary(&copied));
 
         // Return the destination worktree descriptor
-        Ok(Some(WorktreeDescriptor::new(&repo, &self.to)?))
+        Ok(Some(WorktreeDescriptor::new(&repo, &to)?))
     }
 }
 
+/// Name and path of the worktree that has the default branch checked out
+fn default_worktree(repo: &git2::Repository) -> Result<(String, PathBuf)> {
+    let worktree =
+        get_default_worktree(repo).wrap_err("No worktree for the default branch to copy from")?;
+    name_and_path(&worktree, "Default")
+}
+
+/// Name and path of the worktree containing the current directory
+fn current(repo: &git2::Repository) -> Result<(String, PathBuf)> {
+    name_and_path(&current_worktree(repo)?, "Current")
+}
+
+fn name_and_path(worktree: &WorktreeDescriptor, which: &str) -> Result<(String, PathBuf)> {
+    let name = worktree
+        .name()
+        .ok_or_else(|| miette::miette!("{} worktree has no name", which))?;
+    Ok((name.to_string(), worktree.path().to_path_buf()))
+}
+
 /// Determine which patterns to use for copying
 ///
 /// Priority: --pattern flag > config > default **/*
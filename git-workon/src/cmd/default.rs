@@ -0,0 +1,60 @@
+//! Default command - show or set the default branch.
+//!
+//! The default branch is resolved in one place (`get_default_branch` in
+//! git-workon-lib/src/default_branch.rs): `workon.defaultBranch`, then the remote's
+//! default cached in `workon.remoteDefaultBranch`, then `init.defaultBranch`, then `main`,
+//! then `master`. Every command that needs a default (the base for `new`, the
+//! `prune --merged` target, the copy source) asks it.
+//!
+//! ```bash
+//! git workon default            # print the default worktree's path
+//! git workon default develop    # store develop in workon.defaultBranch
+//! ```
+//!
+//! A worktree name resolves to the branch it has checked out.
+
+use miette::{bail, Result};
+use workon::{
+    find_worktree, get_default_branch, get_repo, ConfigScope, WorkonConfig, WorktreeDescriptor,
+};
+
+use crate::cli::DefaultWorktree;
+use crate::output;
+
+use super::Run;
+
+impl Run for DefaultWorktree {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+
+        let Some(name) = &self.name else {
+            let default_branch = get_default_branch(&repo)?;
+            output::info(&format!("Default branch: {}", default_branch));
+            return match find_worktree(&repo, &default_branch) {
+                Ok(worktree) => Ok(Some(worktree)),
+                Err(_) => {
+                    output::notice(&format!("No worktree has '{}' checked out", default_branch));
+                    Ok(None)
+                }
+            };
+        };
+
+        let branch = match find_worktree(&repo, name) {
+            Ok(worktree) => match worktree.branch()? {
+                Some(branch) => branch,
+                None => bail!("Worktree '{}' has a detached HEAD", name),
+            },
+            // Allow branches without a worktree
+            Err(_) if repo.find_branch(name, git2::BranchType::Local).is_ok() => name.clone(),
+            Err(e) => return Err(e.into()),
+        };
+
+        WorkonConfig::new(&repo)?.set_default_branch(&branch, ConfigScope::Local)?;
+
+        output::success(&format!("Default branch set to '{}'", branch));
+        if find_worktree(&repo, &branch).is_err() {
+            output::notice(&format!("No worktree has '{}' checked out", branch));
+        }
+        Ok(None)
+    }
+}
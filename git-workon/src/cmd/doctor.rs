@@ -5,42 +5,213 @@
 //!
 //! ### Worktree Checks (per-worktree):
 //! - Missing worktree directories (in git list but directory deleted) — fixable with --fix
-//! - Broken git links (.git file pointing to non-existent location) — manual fix needed
+//! - Broken git links (.git file not pointing back at the worktree's admin directory) —
+//!   fixable with --fix (rewrites the `.git` file and `gitdir` link pair)
+//! - Worktrees living outside the workon root (e.g. `git worktree add ../elsewhere`) —
+//!   fixable with --fix (moves the directory under the root), or with
+//!   `--fix --register-external` (records the worktree in workon.externalWorktree)
+//! - Branches renamed outside workon (`git branch -m`), leaving the worktree directory
+//!   under the old name — fixable with --fix (moves the directory to match the branch)
 //! - Worktrees whose upstream branch is gone — informational
 //!
+//! ### Metadata Checks (once):
+//! - workon metadata left behind for worktrees that no longer exist (after a raw
+//!   `git worktree move/remove` or a deleted directory): labels and notes
+//!   (`workon-worktree.<worktree>.*`), workon.hiddenWorktree and workon.externalWorktree entries, and
+//!   visit history — fixable with --fix (removes the orphaned entries)
+//! - `pr-N-fork` remotes the PR flow added (workon.forkRemote) that no worktree tracks
+//!   anymore — fixable with --fix (removes the remote)
+//!
 //! ### Dependency Checks (once):
 //! - Hook commands not found in PATH (from workon.postCreateHook config)
+//! - Hooks with an unknown `cwd=` prefix, and a workon.hookShell missing from PATH
+//! - Hook entries referencing local scripts (e.g. `./scripts/setup.sh`, resolved against the
+//!   current worktree) that don't exist or aren't executable
+//! - `.workon/hooks/*` scripts in the current worktree missing the exec bit — fixable with
+//!   --fix (equivalent to `chmod +x`)
 //! - gh CLI not available (required for PR workflow features)
 //!
+//! ### Configuration Checks (once):
+//! - Invalid workon config values (a malformed workon.prFormat, an unknown nameStyle) —
+//!   errors, since the commands reading them fail
+//! - Unknown `workon.*` keys, with the known key they're closest to — warnings, as they're
+//!   usually typos
+//! - Unknown `workon-worktree.<worktree>.*` keys, and metadata still in the `workon`
+//!   section from older versions — informational
+//! - A workon root on a network filesystem (NFS/SMB, or workon.networkFilesystem) —
+//!   informational, noting what workon does differently there
+//!
+//! ### Network Checks (with --network):
+//! - Remotes that can't be reached — a lightweight ls-remote against each configured remote,
+//!   reporting authentication failures, dead URLs and timeouts. An unreachable remote is the
+//!   most common reason `prune --gone` and the PR features silently misbehave.
+//!
 //! ## Flags:
-//! - `--fix` - Automatically repair fixable issues (missing directory entries)
+//! - `--fix` - Automatically repair fixable issues (missing directory entries, broken git
+//!   links, renamed branches, worktrees outside the root, stale metadata, leftover fork
+//!   remotes, non-executable hook scripts)
+//! - `--register-external` - With `--fix`, keep worktrees outside the root where they are and
+//!   register them as external instead of moving them
+//! - `--interactive` - With `--fix`, walk through the fixable issues one at a time, asking
+//!   whether to fix each (yes / no / all remaining / quit). Skipped issues still count
+//!   toward the exit code.
 //! - `--dry-run` - Preview fixes without applying
+//! - `--network` - Also check that each configured remote is reachable
+//! - `--fail-on warn|error` - Which issues affect the exit code (default: error)
+//!
+//! ## Severity:
+//! Every issue is an `error`, a `warning` or `info` (the `severity` field of `--json`
+//! output). Informational findings never affect the exit code, so CI can gate on errors
+//! alone, or on warnings too with `--fail-on warn`.
+//!
+//! ## Exit Codes:
+//! - `0` - No issues (or only warnings, unless `--fail-on warn`)
+//! - `1` - Only fixable issues remain
+//! - `2` - At least one issue can't be fixed automatically
+//!
+//! Issues repaired by `--fix` no longer count. Warnings (a gone upstream, a missing gh CLI)
+//! are reported but only affect the exit code with `--fail-on warn`.
 
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
-use log::debug;
-use miette::{IntoDiagnostic, Result};
+use dialoguer::console::Term;
+use dialoguer::Select;
+use miette::{bail, IntoDiagnostic, Result};
 use serde_json::json;
-use workon::{get_repo, get_worktrees, WorkonConfig, WorktreeDescriptor};
+use tracing::debug;
+use workon::{
+    current_worktree, get_default_worktree, get_proxy_options, get_remote_callbacks, get_repo,
+    get_worktrees, network_filesystem, on_network_filesystem, relocate_worktree,
+    remote_head_branch, remove_fork_remote, repair_worktree_links, unused_fork_remotes,
+    workon_root, ConfigError, ConfigProblem, HookDir, NetworkFilesystem, PostCreateHook,
+    VisitStore, WorkonConfig, WorkonSettings, WorktreeDescriptor, WORKTREE_SECTION,
+};
 
-use crate::cli::Doctor;
+use crate::cli::{Doctor, FailOn};
+use crate::display::format_size;
+use crate::json::print_schema;
 use crate::output;
 
 use super::Run;
 
+/// How long `--network` waits on each remote before reporting it as unreachable.
+const NETWORK_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[derive(Debug)]
 enum IssueKind {
     MissingDirectory,
     BrokenGitLink,
+    RenamedBranch {
+        branch: String,
+    },
+    /// `target` is where `--fix` would move the worktree, or None if that path is taken.
+    OutsideRoot {
+        target: Option<PathBuf>,
+    },
     GoneUpstream,
-    HookNotFound { hook: String, command: String },
+    StaleMetadata {
+        store: MetadataStore,
+    },
+    UnusedForkRemote {
+        remote: String,
+    },
+    HookNotFound {
+        hook: String,
+        command: String,
+    },
+    HookScriptMissing {
+        hook: String,
+        script: PathBuf,
+    },
+    HookNotExecutable {
+        hook: Option<String>,
+        script: PathBuf,
+    },
+    InvalidHook {
+        hook: String,
+        reason: String,
+    },
+    HookShellNotFound {
+        shell: String,
+    },
     GhNotFound,
+    RemoteUnreachable {
+        remote: String,
+        url: String,
+        reason: String,
+    },
+    InvalidConfig {
+        key: String,
+        value: String,
+        message: String,
+    },
+    UnknownConfigKey {
+        key: String,
+        suggestion: Option<String>,
+    },
+    UnknownWorktreeConfigKey {
+        key: String,
+        suggestion: Option<String>,
+    },
+    /// `detected` is None when only workon.networkFilesystem says so.
+    NetworkFilesystem {
+        detected: Option<NetworkFilesystem>,
+    },
+}
+
+/// Where workon keeps per-worktree metadata outside git's own worktree records.
+#[derive(Debug, Clone, Copy)]
+enum MetadataStore {
+    /// `workon-worktree.<worktree>.*` keys (labels, notes)
+    WorktreeConfig,
+    /// A value of a multi-value key listing worktree names
+    NameList(&'static str),
+    /// Events in the visit history
+    Visits,
+}
+
+impl MetadataStore {
+    fn as_str(self) -> &'static str {
+        match self {
+            MetadataStore::WorktreeConfig => "worktree_config",
+            MetadataStore::NameList(key) => key,
+            MetadataStore::Visits => "visits",
+        }
+    }
+}
+
+const HIDDEN_WORKTREE: &str = "workon.hiddenWorktree";
+
+/// Config keys whose values are worktree names.
+const NAME_LISTS: [&str; 2] = [HIDDEN_WORKTREE, "workon.externalWorktree"];
+
+/// How much an issue matters, least first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn as_str(self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
 }
 
 struct Issue {
     kind: IssueKind,
     name: Option<String>,
     path: Option<PathBuf>,
+    fixed: bool,
 }
 
 impl Issue {
@@ -49,6 +220,16 @@ impl Issue {
             kind,
             name: Some(name.to_string()),
             path: Some(path),
+            fixed: false,
+        }
+    }
+
+    fn metadata(store: MetadataStore, name: &str) -> Self {
+        Self {
+            kind: IssueKind::StaleMetadata { store },
+            name: Some(name.to_string()),
+            path: None,
+            fixed: false,
         }
     }
 
@@ -57,26 +238,145 @@ impl Issue {
             kind,
             name: None,
             path: None,
+            fixed: false,
         }
     }
 
-    fn fixable(&self) -> bool {
-        matches!(self.kind, IssueKind::MissingDirectory)
+    /// Whether `--fix` can fix the issue. A worktree outside the root can always be
+    /// registered with `--register-external`, but only moved if its destination is free.
+    fn fixable(&self, register_external: bool) -> bool {
+        matches!(
+            self.kind,
+            IssueKind::MissingDirectory
+                | IssueKind::BrokenGitLink
+                | IssueKind::RenamedBranch { .. }
+                | IssueKind::OutsideRoot { target: Some(_) }
+                | IssueKind::StaleMetadata { .. }
+                | IssueKind::UnusedForkRemote { .. }
+                | IssueKind::HookNotExecutable { .. }
+        ) || (register_external && matches!(self.kind, IssueKind::OutsideRoot { .. }))
+    }
+
+    fn severity(&self) -> Severity {
+        match self.kind {
+            IssueKind::GoneUpstream
+            | IssueKind::StaleMetadata { .. }
+            | IssueKind::UnusedForkRemote { .. }
+            | IssueKind::UnknownConfigKey { .. }
+            | IssueKind::GhNotFound => Severity::Warning,
+            IssueKind::UnknownWorktreeConfigKey { .. } | IssueKind::NetworkFilesystem { .. } => {
+                Severity::Info
+            }
+            _ => Severity::Error,
+        }
+    }
+
+    /// Print the issue under `label`, marked by its severity.
+    fn report(&self, label: &str) {
+        match self.severity() {
+            Severity::Info => output::check_info(label, &self.message()),
+            Severity::Warning => output::check_warn(label, &self.message()),
+            Severity::Error => output::check_fail(label, &self.message()),
+        }
     }
 
     fn message(&self) -> String {
         match &self.kind {
             IssueKind::MissingDirectory => "missing directory".to_string(),
-            IssueKind::BrokenGitLink => {
-                "broken git link (run 'git worktree repair' to fix)".to_string()
+            IssueKind::BrokenGitLink => "broken git link".to_string(),
+            IssueKind::RenamedBranch { branch } => {
+                format!("branch was renamed to '{branch}' outside workon")
+            }
+            IssueKind::OutsideRoot { target: Some(_) } => {
+                "outside workon root (run with --fix to move it, or --fix --register-external to keep it)"
+                    .to_string()
+            }
+            IssueKind::OutsideRoot { target: None } => {
+                "outside workon root (destination already exists; move it manually, or run with --fix --register-external to keep it)"
+                    .to_string()
             }
             IssueKind::GoneUpstream => {
                 "upstream branch is gone (suggest: git workon prune --gone)".to_string()
             }
+            IssueKind::StaleMetadata { store } => match store {
+                MetadataStore::WorktreeConfig => format!(
+                    "labels/notes left in {}.{}.* for a worktree that no longer exists",
+                    WORKTREE_SECTION,
+                    self.name.as_deref().unwrap_or_default()
+                ),
+                MetadataStore::NameList(key) => {
+                    format!("listed in {key} but the worktree no longer exists")
+                }
+                MetadataStore::Visits => {
+                    "visit history left for a worktree that no longer exists".to_string()
+                }
+            },
+            IssueKind::UnusedForkRemote { .. } => {
+                "fork remote added for a PR worktree that no longer exists".to_string()
+            }
             IssueKind::HookNotFound { hook, command } => {
                 format!("hook command '{command}' not found in PATH (from hook \"{hook}\")")
             }
+            IssueKind::HookScriptMissing { hook, script } => format!(
+                "hook script '{}' not found (from hook \"{hook}\")",
+                script.display()
+            ),
+            IssueKind::HookNotExecutable { script, .. } => format!(
+                "hook script '{}' is not executable (run: chmod +x {})",
+                script.display(),
+                script.display()
+            ),
+            IssueKind::InvalidHook { hook, reason } => format!("invalid hook \"{hook}\": {reason}"),
+            IssueKind::HookShellNotFound { shell } => {
+                format!("hook shell '{shell}' not found in PATH (from workon.hookShell)")
+            }
             IssueKind::GhNotFound => "gh CLI not found (PR features unavailable)".to_string(),
+            IssueKind::RemoteUnreachable {
+                remote,
+                url,
+                reason,
+            } => format!("remote '{remote}' ({url}) unreachable: {reason}"),
+            IssueKind::InvalidConfig { message, .. } => message.clone(),
+            IssueKind::UnknownConfigKey { suggestion, .. } => match suggestion {
+                Some(suggestion) => format!("unknown config key (did you mean {suggestion}?)"),
+                None => "unknown config key".to_string(),
+            },
+            IssueKind::UnknownWorktreeConfigKey { suggestion, .. } => match suggestion {
+                Some(suggestion) => {
+                    format!("not a worktree setting workon uses (did you mean {suggestion}?)")
+                }
+                None => "not a worktree setting workon uses".to_string(),
+            },
+            IssueKind::NetworkFilesystem { detected } => {
+                let consequences = "move copies worktrees instead of renaming them and copy-on-write is skipped; a local disk is faster";
+                match detected {
+                    Some(fs) => format!(
+                        "on a network filesystem ({} at {}): {consequences} (set workon.networkFilesystem=false if it's local)",
+                        fs.fs_type,
+                        fs.mount_point.display()
+                    ),
+                    None => format!(
+                        "treated as a network filesystem (workon.networkFilesystem): {consequences}"
+                    ),
+                }
+            }
+        }
+    }
+
+    /// What the issue is about, for prompts: the worktree, or the script or dependency.
+    fn subject(&self) -> String {
+        match (&self.name, &self.kind) {
+            (Some(name), _) => name.clone(),
+            (None, IssueKind::HookNotExecutable { script, .. }) => abbreviate_home(script),
+            (None, IssueKind::UnusedForkRemote { remote }) => remote.clone(),
+            (None, IssueKind::InvalidConfig { key, .. })
+            | (None, IssueKind::UnknownConfigKey { key, .. })
+            | (None, IssueKind::UnknownWorktreeConfigKey { key, .. }) => key.clone(),
+            (None, IssueKind::NetworkFilesystem { .. }) => match &self.path {
+                Some(path) => abbreviate_home(path),
+                None => self.kind_str().to_string(),
+            },
+            (None, _) => self.kind_str().to_string(),
         }
     }
 
@@ -84,18 +384,44 @@ impl Issue {
         match self.kind {
             IssueKind::MissingDirectory => "missing_directory",
             IssueKind::BrokenGitLink => "broken_git_link",
+            IssueKind::RenamedBranch { .. } => "renamed_branch",
+            IssueKind::OutsideRoot { .. } => "outside_root",
             IssueKind::GoneUpstream => "gone_upstream",
+            IssueKind::StaleMetadata { .. } => "stale_metadata",
+            IssueKind::UnusedForkRemote { .. } => "unused_fork_remote",
             IssueKind::HookNotFound { .. } => "hook_not_found",
+            IssueKind::HookScriptMissing { .. } => "hook_script_missing",
+            IssueKind::HookNotExecutable { .. } => "hook_not_executable",
+            IssueKind::InvalidHook { .. } => "invalid_hook",
+            IssueKind::HookShellNotFound { .. } => "hook_shell_not_found",
             IssueKind::GhNotFound => "gh_not_found",
+            IssueKind::RemoteUnreachable { .. } => "remote_unreachable",
+            IssueKind::InvalidConfig { .. } => "invalid_config",
+            IssueKind::UnknownConfigKey { .. } => "unknown_config_key",
+            IssueKind::UnknownWorktreeConfigKey { .. } => "unknown_worktree_config_key",
+            IssueKind::NetworkFilesystem { .. } => "network_filesystem",
         }
     }
 }
 
 impl Run for Doctor {
     fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        if self.schema {
+            print_schema("git workon doctor --json", json_schema())?;
+            return Ok(None);
+        }
+
+        if self.interactive && !Term::stderr().is_term() {
+            bail!(
+                help = "Run it from a terminal, or drop --interactive to fix everything",
+                "doctor --interactive needs a terminal to prompt on"
+            );
+        }
+
         let repo = get_repo(None)?;
         let worktrees = get_worktrees(&repo)?;
         let config = WorkonConfig::new(&repo)?;
+        let root = workon_root(&repo)?;
 
         debug!("found {} worktree(s)", worktrees.len());
         output::status(&format!("Checking {} worktree(s)...", worktrees.len()));
@@ -104,36 +430,33 @@ impl Run for Doctor {
         let mut issues: Vec<Issue> = Vec::new();
         for wt in &worktrees {
             if let Some(name) = wt.name() {
-                let path = wt.path().to_path_buf();
-                debug!("'{}': checking at {}", name, path.display());
-                let raw = repo.find_worktree(name).into_diagnostic()?;
-                if raw.validate().is_err() {
-                    if !path.exists() {
-                        debug!("'{}': validate failed, directory missing", name);
-                        let issue = Issue::worktree(IssueKind::MissingDirectory, name, path);
-                        output::check_fail(name, &issue.message());
-                        issues.push(issue);
-                    } else {
-                        debug!("'{}': validate failed, broken git link", name);
-                        let issue = Issue::worktree(IssueKind::BrokenGitLink, name, path);
-                        output::check_fail(name, &issue.message());
-                        issues.push(issue);
-                    }
-                } else {
-                    debug!("'{}': validate ok, checking upstream", name);
-                    if wt.has_gone_upstream().unwrap_or(false) {
-                        debug!("'{}': upstream is gone", name);
-                        let issue = Issue::worktree(IssueKind::GoneUpstream, name, path);
-                        output::check_warn(name, &issue.message());
+                match check_worktree(&repo, &config, root, wt, name)? {
+                    None => output::check_pass(name),
+                    Some(issue) => {
+                        issue.report(name);
                         issues.push(issue);
-                    } else {
-                        debug!("'{}': ok", name);
-                        output::check_pass(name);
                     }
                 }
             }
         }
 
+        // Metadata checks — workon's own records for worktrees git no longer knows about
+        output::status("\nChecking workon metadata...");
+        let stale = stale_metadata(&repo, &worktrees)?;
+        let fork_remotes = unused_fork_remotes(&repo)?;
+        if stale.is_empty() && fork_remotes.is_empty() {
+            output::check_pass("no stale entries");
+        }
+        for issue in stale {
+            output::check_warn(issue.name.as_deref().unwrap_or_default(), &issue.message());
+            issues.push(issue);
+        }
+        for remote in fork_remotes {
+            let issue = Issue::dependency(IssueKind::UnusedForkRemote { remote });
+            output::check_warn(&issue.subject(), &issue.message());
+            issues.push(issue);
+        }
+
         // Dependency checks — print section header then check inline
         output::status("\nChecking dependencies...");
 
@@ -144,14 +467,66 @@ impl Run for Doctor {
         } else {
             debug!("gh CLI not found in PATH");
             let issue = Issue::dependency(IssueKind::GhNotFound);
-            output::check_fail("gh", "not found in PATH");
+            output::check_warn("gh", "not found in PATH");
             issues.push(issue);
         }
 
+        // Relative hook scripts run from the new worktree; resolve them against this one
+        let worktree_dir = current_worktree(&repo)
+            .ok()
+            .map(|wt| wt.path().to_path_buf());
+        // ...or, for cwd=base, against the default branch's worktree
+        let base_dir = get_default_worktree(&repo)
+            .ok()
+            .map(|wt| wt.path().to_path_buf());
+
+        if let Some(shell) = config.hook_shell()? {
+            let program = shell.split_whitespace().next().unwrap_or_default();
+            debug!("checking hook shell '{}' in PATH", program);
+            if command_in_path(program) {
+                output::check_pass(&format!("{program} (hook shell)"));
+            } else {
+                output::check_fail(program, "not found in PATH (from workon.hookShell)");
+                issues.push(Issue::dependency(IssueKind::HookShellNotFound {
+                    shell: program.to_string(),
+                }));
+            }
+        }
+
         let hooks = config.post_create_hooks()?;
         debug!("checking {} configured hook(s)", hooks.len());
         for hook in &hooks {
-            if let Some(command) = hook.split_whitespace().next() {
+            let parsed: PostCreateHook = match hook.parse() {
+                Ok(parsed) => parsed,
+                Err(ConfigError::InvalidValue { reason, .. }) => {
+                    output::check_fail(hook, &reason);
+                    issues.push(Issue::dependency(IssueKind::InvalidHook {
+                        hook: hook.clone(),
+                        reason,
+                    }));
+                    continue;
+                }
+                Err(e) => return Err(e.into()),
+            };
+            let run_dir = match parsed.dir {
+                HookDir::Worktree => worktree_dir.clone(),
+                HookDir::Root => Some(root.to_path_buf()),
+                HookDir::Base => base_dir.clone(),
+            };
+            if let Some(command) = parsed.command.split_whitespace().next() {
+                if command.contains('/') {
+                    let script = if Path::new(command).is_absolute() {
+                        PathBuf::from(command)
+                    } else if let Some(dir) = &run_dir {
+                        dir.join(command)
+                    } else {
+                        debug!("not in a worktree, skipping hook script '{}'", command);
+                        continue;
+                    };
+                    debug!("checking hook script '{}'", script.display());
+                    check_hook_script(command, &script, Some(hook), &mut issues);
+                    continue;
+                }
                 debug!("checking hook command '{}' in PATH", command);
                 if command_in_path(command) {
                     debug!("hook command '{}': ok", command);
@@ -171,7 +546,57 @@ impl Run for Doctor {
             }
         }
 
-        // Configuration section — informational only, not included in fixable issues
+        if let Some(dir) = &worktree_dir {
+            let hooks_dir = dir.join(".workon").join("hooks");
+            if let Ok(entries) = std::fs::read_dir(&hooks_dir) {
+                let mut scripts: Vec<PathBuf> = entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_file())
+                    .collect();
+                scripts.sort();
+                debug!(
+                    "checking {} script(s) in {}",
+                    scripts.len(),
+                    hooks_dir.display()
+                );
+                for script in &scripts {
+                    let label = script.strip_prefix(dir).unwrap_or(script).display();
+                    check_hook_script(&label.to_string(), script, None, &mut issues);
+                }
+            }
+        }
+
+        if self.network {
+            output::status("\nChecking remotes...");
+            let remotes = repo.remotes().into_diagnostic()?;
+            debug!("checking {} remote(s)", remotes.len());
+            for remote in remotes.iter().flatten() {
+                let url = repo
+                    .find_remote(remote)
+                    .ok()
+                    .and_then(|r| r.url().map(str::to_string))
+                    .unwrap_or_default();
+                debug!("probing remote '{}' at {}", remote, url);
+                match probe_remote(repo.path(), remote, NETWORK_TIMEOUT) {
+                    Ok(()) => {
+                        debug!("remote '{}': ok", remote);
+                        output::check_pass(&format!("{remote} ({url})"));
+                    }
+                    Err(reason) => {
+                        debug!("remote '{}' unreachable: {}", remote, reason);
+                        output::check_fail(remote, &format!("{url}: {reason}"));
+                        issues.push(Issue::dependency(IssueKind::RemoteUnreachable {
+                            remote: remote.to_string(),
+                            url,
+                            reason,
+                        }));
+                    }
+                }
+            }
+        }
+
+        // Configuration section — the effective values, then any invalid or unknown keys
         output::status("\nChecking configuration...");
         let config_entries = read_config_entries(&repo, &config)?;
         for (key, value, source) in &config_entries {
@@ -180,13 +605,50 @@ impl Run for Doctor {
                 None => output::check_pass(&format!("{key} = {value}")),
             }
         }
+        let git_config = repo.config().into_diagnostic()?;
+        for problem in WorkonSettings::problems(&git_config)? {
+            let issue = Issue::dependency(match problem {
+                ConfigProblem::Invalid {
+                    key,
+                    value,
+                    message,
+                } => IssueKind::InvalidConfig {
+                    key,
+                    value,
+                    message,
+                },
+                ConfigProblem::UnknownKey { key, suggestion } => {
+                    IssueKind::UnknownConfigKey { key, suggestion }
+                }
+                ConfigProblem::UnknownWorktreeKey { key, suggestion } => {
+                    IssueKind::UnknownWorktreeConfigKey { key, suggestion }
+                }
+            });
+            issue.report(&issue.subject());
+            issues.push(issue);
+        }
+        if on_network_filesystem(&repo, root)? {
+            let issue = Issue {
+                kind: IssueKind::NetworkFilesystem {
+                    detected: network_filesystem(root),
+                },
+                name: None,
+                path: Some(root.to_path_buf()),
+                fixed: false,
+            };
+            issue.report(&issue.subject());
+            issues.push(issue);
+        }
 
         debug!("found {} issue(s) total", issues.len());
 
         // JSON output: serialize all collected issues
         if self.json {
             let fixed_names: Vec<String> = if self.fix && !self.dry_run {
-                fix_issues(&repo, &issues)?
+                fix_issues(&repo, &mut issues, self.register_external, self.interactive)?
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect()
             } else {
                 Vec::new()
             };
@@ -196,7 +658,8 @@ impl Run for Doctor {
                 .map(|issue| {
                     let mut obj = json!({
                         "kind": issue.kind_str(),
-                        "fixable": issue.fixable(),
+                        "severity": issue.severity().as_str(),
+                        "fixable": issue.fixable(self.register_external),
                         "message": issue.message(),
                     });
                     if let Some(name) = &issue.name {
@@ -209,6 +672,54 @@ impl Run for Doctor {
                         obj["hook"] = json!(hook);
                         obj["command"] = json!(command);
                     }
+                    if let IssueKind::HookScriptMissing { hook, script } = &issue.kind {
+                        obj["hook"] = json!(hook);
+                        obj["script"] = json!(script.to_str());
+                    }
+                    if let IssueKind::HookNotExecutable { hook, script } = &issue.kind {
+                        obj["hook"] = json!(hook);
+                        obj["script"] = json!(script.to_str());
+                    }
+                    if let IssueKind::InvalidHook { hook, .. } = &issue.kind {
+                        obj["hook"] = json!(hook);
+                    }
+                    if let IssueKind::HookShellNotFound { shell } = &issue.kind {
+                        obj["command"] = json!(shell);
+                    }
+                    if let IssueKind::RemoteUnreachable { remote, url, .. } = &issue.kind {
+                        obj["remote"] = json!(remote);
+                        obj["url"] = json!(url);
+                    }
+                    if let IssueKind::UnusedForkRemote { remote } = &issue.kind {
+                        obj["remote"] = json!(remote);
+                    }
+                    if let IssueKind::InvalidConfig { key, value, .. } = &issue.kind {
+                        obj["key"] = json!(key);
+                        obj["value"] = json!(value);
+                    }
+                    if let IssueKind::UnknownConfigKey { key, suggestion }
+                    | IssueKind::UnknownWorktreeConfigKey { key, suggestion } = &issue.kind
+                    {
+                        obj["key"] = json!(key);
+                        obj["suggestion"] = json!(suggestion);
+                    }
+                    if let IssueKind::NetworkFilesystem { detected } = &issue.kind {
+                        obj["filesystem"] = json!(detected.as_ref().map(|fs| &fs.fs_type));
+                        obj["mount_point"] =
+                            json!(detected.as_ref().and_then(|fs| fs.mount_point.to_str()));
+                    }
+                    if let IssueKind::RenamedBranch { branch } = &issue.kind {
+                        obj["branch"] = json!(branch);
+                    }
+                    if let IssueKind::StaleMetadata { store } = &issue.kind {
+                        obj["store"] = json!(store.as_str());
+                    }
+                    if let IssueKind::OutsideRoot {
+                        target: Some(target),
+                    } = &issue.kind
+                    {
+                        obj["target"] = json!(target.to_str());
+                    }
                     obj
                 })
                 .collect();
@@ -226,7 +737,7 @@ impl Run for Doctor {
             });
             let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
             println!("{}", output);
-            return Ok(None);
+            return finish(&issues, self.fail_on, self.register_external);
         }
 
         // Text output: summary / action
@@ -234,10 +745,13 @@ impl Run for Doctor {
 
         if issues.is_empty() {
             output::success("All checks passed.");
-            return Ok(None);
+            return finish(&issues, self.fail_on, self.register_external);
         }
 
-        let fixable_count = issues.iter().filter(|i| i.fixable()).count();
+        let fixable_count = issues
+            .iter()
+            .filter(|i| i.fixable(self.register_external))
+            .count();
 
         if self.dry_run {
             if fixable_count == 0 {
@@ -248,17 +762,29 @@ impl Run for Doctor {
                     fixable_count
                 ));
             }
-            return Ok(None);
+            return finish(&issues, self.fail_on, self.register_external);
         }
 
         if self.fix {
             if fixable_count == 0 {
                 output::status("No issues can be automatically fixed.");
             } else {
-                output::info(&format!("Fixing {} issue(s)...", fixable_count));
-                let fixed = fix_issues(&repo, &issues)?;
-                for name in &fixed {
-                    output::success(&format!("  ✓ Pruned: {name}"));
+                if self.interactive {
+                    output::info(&format!("Reviewing {} fixable issue(s)...", fixable_count));
+                } else {
+                    output::info(&format!("Fixing {} issue(s)...", fixable_count));
+                }
+                let fixed =
+                    fix_issues(&repo, &mut issues, self.register_external, self.interactive)?;
+                for (_, action) in &fixed {
+                    output::success(&format!("  ✓ {action}"));
+                }
+                let skipped = issues
+                    .iter()
+                    .filter(|i| i.fixable(self.register_external) && !i.fixed)
+                    .count();
+                if self.interactive && skipped > 0 {
+                    output::notice(&format!("Left {} issue(s) unfixed.", skipped));
                 }
             }
         } else if fixable_count > 0 {
@@ -268,10 +794,168 @@ impl Run for Doctor {
             ));
         }
 
-        Ok(None)
+        finish(&issues, self.fail_on, self.register_external)
     }
 }
 
+/// Schema of `--json` output.
+fn json_schema() -> serde_json::Value {
+    let string = |description: &str| json!({ "type": "string", "description": description });
+    json!({
+        "type": "object",
+        "properties": {
+            "issues": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "kind": {
+                            "enum": [
+                                "missing_directory", "broken_git_link", "renamed_branch",
+                                "outside_root", "gone_upstream", "stale_metadata",
+                                "unused_fork_remote", "hook_not_found", "hook_script_missing", "hook_not_executable",
+                                "invalid_hook", "hook_shell_not_found", "gh_not_found",
+                                "remote_unreachable", "invalid_config", "unknown_config_key",
+                                "unknown_worktree_config_key", "network_filesystem",
+                            ],
+                        },
+                        "severity": {
+                            "enum": ["error", "warning", "info"],
+                            "description": "Errors and warnings affect the exit code (warnings only with --fail-on warn); info never does",
+                        },
+                        "fixable": { "type": "boolean", "description": "Whether --fix can repair it" },
+                        "message": string("Human-readable description"),
+                        "name": string("Affected worktree"),
+                        "path": { "type": ["string", "null"], "description": "Affected worktree's path" },
+                        "hook": { "type": ["string", "null"], "description": "Hook entry (hook issues)" },
+                        "command": string("Missing command (hook_not_found, hook_shell_not_found)"),
+                        "script": { "type": ["string", "null"], "description": "Hook script (hook script issues)" },
+                        "remote": string("Remote name (remote_unreachable, unused_fork_remote)"),
+                        "url": string("Remote URL (remote_unreachable)"),
+                        "branch": string("New branch name (renamed_branch)"),
+                        "store": string("Where the stale entries live (stale_metadata)"),
+                        "target": { "type": ["string", "null"], "description": "Where --fix would move the worktree (outside_root)" },
+                        "key": string("Config key, as git reports it (config issues)"),
+                        "value": string("Invalid value (invalid_config)"),
+                        "suggestion": { "type": ["string", "null"], "description": "Known config key it's closest to (unknown config keys)" },
+                        "filesystem": { "type": ["string", "null"], "description": "Detected filesystem type, null if only configured (network_filesystem)" },
+                        "mount_point": { "type": ["string", "null"], "description": "Where that filesystem is mounted (network_filesystem)" },
+                    },
+                    "required": ["kind", "severity", "fixable", "message"],
+                },
+            },
+            "fixed": {
+                "type": "array",
+                "items": { "type": "string" },
+                "description": "Worktrees, scripts or remotes repaired by --fix",
+            },
+            "dry_run": { "type": "boolean" },
+            "configuration": {
+                "type": "object",
+                "description": "Effective workon config, keyed by config key",
+                "additionalProperties": {
+                    "type": "object",
+                    "properties": {
+                        "value": { "type": "string" },
+                        "source": { "type": ["string", "null"], "description": "Config file the value came from" },
+                    },
+                    "required": ["value", "source"],
+                },
+            },
+        },
+        "required": ["issues", "fixed", "dry_run", "configuration"],
+    })
+}
+
+/// Check a worktree's git links, location, branch and upstream, returning its first issue.
+fn check_worktree(
+    repo: &git2::Repository,
+    config: &WorkonConfig,
+    root: &Path,
+    wt: &WorktreeDescriptor,
+    name: &str,
+) -> Result<Option<Issue>> {
+    let path = wt.path().to_path_buf();
+    debug!("'{}': checking at {}", name, path.display());
+    let raw = repo.find_worktree(name).into_diagnostic()?;
+    let kind = if raw.validate().is_err() || !git_link_ok(repo, name, &path) {
+        if !path.exists() {
+            debug!("'{}': validate failed, directory missing", name);
+            IssueKind::MissingDirectory
+        } else {
+            debug!("'{}': validate failed, broken git link", name);
+            IssueKind::BrokenGitLink
+        }
+    } else if !path.starts_with(root) && !config.is_external(name) {
+        debug!("'{}': outside workon root {}", name, root.display());
+        let target = Some(relocation_target(wt, name, root)).filter(|t| !t.exists());
+        IssueKind::OutsideRoot { target }
+    } else if let Some(branch) = renamed_branch(wt, root) {
+        debug!("'{}': branch renamed to '{}'", name, branch);
+        IssueKind::RenamedBranch { branch }
+    } else {
+        debug!("'{}': validate ok, checking upstream", name);
+        if wt.has_gone_upstream().unwrap_or(false) {
+            debug!("'{}': upstream is gone", name);
+            IssueKind::GoneUpstream
+        } else {
+            debug!("'{}': ok", name);
+            return Ok(None);
+        }
+    };
+    Ok(Some(Issue::worktree(kind, name, path)))
+}
+
+/// Run doctor's worktree checks on a repository without printing anything, returning
+/// `(worktree, problem)` for each worktree that needs attention (see `repos add`).
+pub(crate) fn worktree_problems(repo: &git2::Repository) -> Result<Vec<(String, String)>> {
+    let config = WorkonConfig::new(repo)?;
+    let root = workon_root(repo)?;
+    let mut problems = Vec::new();
+    for wt in get_worktrees(repo)? {
+        if let Some(name) = wt.name() {
+            if let Some(issue) = check_worktree(repo, &config, root, &wt, name)? {
+                problems.push((name.to_string(), issue.message()));
+            }
+        }
+    }
+    Ok(problems)
+}
+
+/// Exit with a status code reflecting the issues that remain.
+///
+/// 0 = clean, 1 = only fixable issues, 2 = at least one unfixable issue. Issues fixed
+/// by `--fix` are ignored, warnings only count with `--fail-on warn`, and informational
+/// issues never do.
+fn finish(
+    issues: &[Issue],
+    fail_on: FailOn,
+    register_external: bool,
+) -> Result<Option<WorktreeDescriptor>> {
+    let code = issues
+        .iter()
+        .filter(|issue| !issue.fixed)
+        .filter(|issue| match fail_on {
+            FailOn::Warn => issue.severity() >= Severity::Warning,
+            FailOn::Error => issue.severity() == Severity::Error,
+        })
+        .map(|issue| {
+            if issue.fixable(register_external) {
+                1
+            } else {
+                2
+            }
+        })
+        .max()
+        .unwrap_or(0);
+    debug!("exiting with code {}", code);
+    if code != 0 {
+        std::io::stdout().flush().into_diagnostic()?;
+        std::process::exit(code);
+    }
+    Ok(None)
+}
+
 /// Abbreviate the home directory as `~` in a path string.
 fn abbreviate_home(path: &std::path::Path) -> String {
     if let Ok(home) = std::env::var("HOME") {
@@ -353,6 +1037,18 @@ fn read_config_entries(
     };
     entries.push(("workon.defaultBranch".to_string(), val, src));
 
+    let (val, src) = match config.remote_default_branch()? {
+        Some(val) => (
+            val,
+            scalar_source(repo, &git_config, "workon.remoteDefaultBranch"),
+        ),
+        None => match remote_head_branch(repo) {
+            Some(branch) => (format!("(not cached, remote HEAD: {branch})"), None),
+            None => ("(not detected)".to_string(), None),
+        },
+    };
+    entries.push(("workon.remoteDefaultBranch".to_string(), val, src));
+
     let auto_copy = config.auto_copy_untracked(None)?;
     let src = scalar_source(repo, &git_config, "workon.autoCopyUntracked");
     entries.push((
@@ -361,6 +1057,26 @@ fn read_config_entries(
         src,
     ));
 
+    let (val, src) = match config.copy_source(None)? {
+        Some(val) => (val, scalar_source(repo, &git_config, "workon.copySource")),
+        None => ("(not set)".to_string(), None),
+    };
+    entries.push(("workon.copySource".to_string(), val, src));
+
+    let limits = [
+        ("workon.copyMaxFileSize", config.copy_max_file_size()),
+        ("workon.copyMaxTotal", config.copy_max_total()),
+    ];
+    for (key, limit) in limits {
+        let src = scalar_source(repo, &git_config, key);
+        let val = match limit {
+            Ok(Some(size)) => format_size(size),
+            Ok(None) => "(no limit)".to_string(),
+            Err(_) => "(invalid)".to_string(),
+        };
+        entries.push((key.to_string(), val, src));
+    }
+
     let (val, src) = match config.pr_format(None) {
         Ok(val) => (val, scalar_source(repo, &git_config, "workon.prFormat")),
         Err(_) => (
@@ -370,6 +1086,13 @@ fn read_config_entries(
     };
     entries.push(("workon.prFormat".to_string(), val, src));
 
+    let src = scalar_source(repo, &git_config, "workon.nameStyle");
+    let val = match config.name_style() {
+        Ok(style) => style.to_string(),
+        Err(_) => "(invalid)".to_string(),
+    };
+    entries.push(("workon.nameStyle".to_string(), val, src));
+
     let timeout = config.hook_timeout()?;
     let src = scalar_source(repo, &git_config, "workon.hookTimeout");
     entries.push((
@@ -378,6 +1101,45 @@ fn read_config_entries(
         src,
     ));
 
+    let (val, src) = match config.hook_shell()? {
+        Some(val) => (val, scalar_source(repo, &git_config, "workon.hookShell")),
+        None => ("(not set)".to_string(), None),
+    };
+    entries.push(("workon.hookShell".to_string(), val, src));
+
+    let auto_create = config.auto_create(None)?;
+    let src = scalar_source(repo, &git_config, "workon.autoCreate");
+    entries.push((
+        "workon.autoCreate".to_string(),
+        auto_create.to_string(),
+        src,
+    ));
+
+    let status_cache = config.status_cache(None)?;
+    let src = scalar_source(repo, &git_config, "workon.statusCache");
+    entries.push((
+        "workon.statusCache".to_string(),
+        status_cache.to_string(),
+        src,
+    ));
+
+    let src = scalar_source(repo, &git_config, "workon.networkFilesystem");
+    let val = match config.network_filesystem()? {
+        Some(val) => val.to_string(),
+        None => match workon_root(repo).ok().and_then(network_filesystem) {
+            Some(fs) => format!("(not set, detected {})", fs.fs_type),
+            None => "(not set)".to_string(),
+        },
+    };
+    entries.push(("workon.networkFilesystem".to_string(), val, src));
+
+    let src = scalar_source(repo, &git_config, "workon.fetchBackend");
+    let val = match config.fetch_backend() {
+        Ok(backend) => backend.to_string(),
+        Err(_) => "(invalid)".to_string(),
+    };
+    entries.push(("workon.fetchBackend".to_string(), val, src));
+
     let patterns = config.copy_patterns()?;
     let src = multivar_source(repo, &git_config, "workon.copyPattern");
     let val = if patterns.is_empty() {
@@ -405,6 +1167,14 @@ fn read_config_entries(
     };
     entries.push(("workon.pruneProtectedBranches".to_string(), val, src));
 
+    let src = scalar_source(repo, &git_config, "workon.pruneGracePeriod");
+    let val = match config.prune_grace_period() {
+        Ok(Some(period)) => format!("{}s", period.as_secs()),
+        Ok(None) => "(not set)".to_string(),
+        Err(_) => "(invalid)".to_string(),
+    };
+    entries.push(("workon.pruneGracePeriod".to_string(), val, src));
+
     let hooks = config.post_create_hooks()?;
     let src = multivar_source(repo, &git_config, "workon.postCreateHook");
     let val = if hooks.is_empty() {
@@ -414,24 +1184,390 @@ fn read_config_entries(
     };
     entries.push(("workon.postCreateHook".to_string(), val, src));
 
+    let external = config.external_worktrees()?;
+    let src = multivar_source(repo, &git_config, "workon.externalWorktree");
+    let val = if external.is_empty() {
+        "(not set)".to_string()
+    } else {
+        external.join(", ")
+    };
+    entries.push(("workon.externalWorktree".to_string(), val, src));
+
+    let hidden = config.hidden_worktrees()?;
+    let src = multivar_source(repo, &git_config, "workon.hiddenWorktree");
+    let val = if hidden.is_empty() {
+        "(not set)".to_string()
+    } else {
+        hidden.join(", ")
+    };
+    entries.push(("workon.hiddenWorktree".to_string(), val, src));
+
+    let fork_remotes = config.fork_remotes()?;
+    let src = multivar_source(repo, &git_config, "workon.forkRemote");
+    let val = if fork_remotes.is_empty() {
+        "(not set)".to_string()
+    } else {
+        fork_remotes.join(", ")
+    };
+    entries.push(("workon.forkRemote".to_string(), val, src));
+
     Ok(entries)
 }
 
-/// Prune worktrees with missing directories. Returns the names of pruned worktrees.
-fn fix_issues(repo: &git2::Repository, issues: &[Issue]) -> Result<Vec<String>> {
+/// Apply fixes for all fixable issues.
+///
+/// Returns `(name, action)` pairs for each fixed worktree, where `action` describes
+/// what was done (e.g. `Pruned: feature`), and marks those issues as fixed. A fix that
+/// fails is reported and the rest still run. When `register_external` is set, worktrees
+/// outside the workon root are recorded in workon.externalWorktree instead of moved.
+/// When `interactive` is set, asks before each fix.
+fn fix_issues(
+    repo: &git2::Repository,
+    issues: &mut [Issue],
+    register_external: bool,
+    interactive: bool,
+) -> Result<Vec<(String, String)>> {
     let mut fixed = Vec::new();
-    for issue in issues.iter().filter(|i| i.fixable()) {
-        if let Some(name) = &issue.name {
+    let mut fix_all = !interactive;
+    for issue in issues.iter_mut().filter(|i| i.fixable(register_external)) {
+        if !fix_all {
+            match ask_to_fix(issue)? {
+                FixAnswer::Yes => {}
+                FixAnswer::No => continue,
+                FixAnswer::All => fix_all = true,
+                FixAnswer::Quit => break,
+            }
+        }
+        let count = fixed.len();
+        // Report per issue and keep going; one failed fix shouldn't block the rest
+        if let Err(e) = fix_issue(repo, issue, register_external, &mut fixed) {
+            output::warn(&format!("Could not fix '{}': {e}", issue.subject()));
+        }
+        issue.fixed = fixed.len() > count;
+    }
+    Ok(fixed)
+}
+
+/// Answer to the `--interactive` prompt for one issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixAnswer {
+    Yes,
+    No,
+    All,
+    Quit,
+}
+
+/// Ask whether to fix one issue.
+fn ask_to_fix(issue: &Issue) -> Result<FixAnswer> {
+    const ANSWERS: [(&str, FixAnswer); 4] = [
+        ("yes", FixAnswer::Yes),
+        ("no", FixAnswer::No),
+        ("all remaining", FixAnswer::All),
+        ("quit", FixAnswer::Quit),
+    ];
+    let selection = Select::new()
+        .with_prompt(format!("Fix {}: {}?", issue.subject(), issue.message()))
+        .items(&ANSWERS.map(|(label, _)| label))
+        .default(0)
+        .interact()
+        .into_diagnostic()?;
+    Ok(ANSWERS[selection].1)
+}
+
+/// Apply the fix for a single issue, recording what was done in `fixed`.
+fn fix_issue(
+    repo: &git2::Repository,
+    issue: &Issue,
+    register_external: bool,
+    fixed: &mut Vec<(String, String)>,
+) -> Result<()> {
+    if let IssueKind::HookNotExecutable { script, .. } = &issue.kind {
+        debug!("making '{}' executable", script.display());
+        make_executable(script)?;
+        let script = abbreviate_home(script);
+        fixed.push((script.clone(), format!("Made executable: {script}")));
+        return Ok(());
+    }
+    if let IssueKind::UnusedForkRemote { remote } = &issue.kind {
+        debug!("removing fork remote '{}'", remote);
+        remove_fork_remote(repo, remote)?;
+        fixed.push((remote.clone(), format!("Removed fork remote: {remote}")));
+        return Ok(());
+    }
+    let Some(name) = &issue.name else {
+        return Ok(());
+    };
+    match &issue.kind {
+        IssueKind::MissingDirectory => {
             debug!("pruning worktree '{}'", name);
             let worktree = repo.find_worktree(name).into_diagnostic()?;
             let mut opts = git2::WorktreePruneOptions::new();
             opts.valid(true);
             worktree.prune(Some(&mut opts)).into_diagnostic()?;
             debug!("pruned worktree '{}'", name);
-            fixed.push(name.clone());
+            fixed.push((name.clone(), format!("Pruned: {name}")));
+        }
+        IssueKind::BrokenGitLink => {
+            debug!("repairing git links for worktree '{}'", name);
+            let Some(path) = &issue.path else {
+                return Ok(());
+            };
+            repair_worktree_links(repo, name, path)?;
+            repo.find_worktree(name)
+                .and_then(|wt| wt.validate())
+                .into_diagnostic()?;
+            debug!("repaired worktree '{}'", name);
+            fixed.push((name.clone(), format!("Repaired: {name}")));
+        }
+        IssueKind::RenamedBranch { branch } => {
+            debug!("moving worktree '{}' to match branch '{}'", name, branch);
+            let worktree = WorktreeDescriptor::new(repo, name)?;
+            let root = workon_root(repo)?;
+            relocate_worktree(repo, &worktree, &root.join(branch))?;
+            debug!("moved worktree '{}'", name);
+            fixed.push((name.clone(), format!("Moved: {name} → {branch}")));
+        }
+        IssueKind::OutsideRoot { .. } if register_external => {
+            debug!("registering worktree '{}' as external", name);
+            WorkonConfig::new(repo)?.set_external(name, true)?;
+            fixed.push((name.clone(), format!("Registered as external: {name}")));
+        }
+        IssueKind::OutsideRoot {
+            target: Some(target),
+        } => {
+            debug!("moving worktree '{}' to {}", name, target.display());
+            let worktree = WorktreeDescriptor::new(repo, name)?;
+            relocate_worktree(repo, &worktree, target)?;
+            debug!("moved worktree '{}'", name);
+            fixed.push((
+                name.clone(),
+                format!("Moved: {name} → {}", abbreviate_home(target)),
+            ));
+        }
+        IssueKind::StaleMetadata { store } => {
+            debug!("removing stale {} metadata for '{}'", store.as_str(), name);
+            remove_metadata(repo, *store, name)?;
+            let action = match store {
+                MetadataStore::WorktreeConfig => format!("Removed labels/notes: {name}"),
+                MetadataStore::NameList(key) => format!("Removed from {key}: {name}"),
+                MetadataStore::Visits => format!("Removed visit history: {name}"),
+            };
+            fixed.push((name.clone(), action));
         }
+        _ => {}
     }
-    Ok(fixed)
+    Ok(())
+}
+
+/// Find workon metadata in the local config and visit history for worktrees that no
+/// longer exist.
+fn stale_metadata(repo: &git2::Repository, worktrees: &[WorktreeDescriptor]) -> Result<Vec<Issue>> {
+    let names: Vec<&str> = worktrees.iter().filter_map(|wt| wt.name()).collect();
+    let full_names: Vec<String> = worktrees.iter().filter_map(|wt| wt.full_name()).collect();
+    let exists = |name: &str| names.contains(&name);
+    let mut issues = Vec::new();
+
+    let config = repo.config().into_diagnostic()?;
+    let local = config
+        .open_level(git2::ConfigLevel::Local)
+        .into_diagnostic()?;
+
+    // Per-worktree metadata, keyed by full name: workon-worktree.<worktree>.<key>
+    let mut orphaned: Vec<String> = Vec::new();
+    let pattern = format!(r"^{}\..+\..+$", WORKTREE_SECTION);
+    let mut entries = local.entries(Some(&pattern)).into_diagnostic()?;
+    while let Some(Ok(entry)) = entries.next() {
+        let Some((name, _)) = entry
+            .name()
+            .and_then(|key| key.strip_prefix(WORKTREE_SECTION))
+            .and_then(|rest| rest.strip_prefix('.'))
+            .and_then(|rest| rest.rsplit_once('.'))
+        else {
+            continue;
+        };
+        if !full_names.iter().any(|n| n == name) && !orphaned.iter().any(|o| o == name) {
+            orphaned.push(name.to_string());
+        }
+    }
+    for name in orphaned {
+        issues.push(Issue::metadata(MetadataStore::WorktreeConfig, &name));
+    }
+
+    for key in NAME_LISTS {
+        // Hidden worktrees are keyed by full name, external ones by name
+        let still_exists = |name: &str| match key {
+            HIDDEN_WORKTREE => full_names.iter().any(|n| n == name),
+            _ => exists(name),
+        };
+        if let Ok(mut values) = local.multivar(key, None) {
+            while let Some(Ok(entry)) = values.next() {
+                if let Some(name) = entry.value().filter(|name| !still_exists(name)) {
+                    issues.push(Issue::metadata(MetadataStore::NameList(key), name));
+                }
+            }
+        }
+    }
+
+    let mut visited: Vec<String> = Vec::new();
+    for visit in VisitStore::new(repo).entries()? {
+        if !exists(&visit.worktree) && !visited.contains(&visit.worktree) {
+            visited.push(visit.worktree);
+        }
+    }
+    for name in visited {
+        issues.push(Issue::metadata(MetadataStore::Visits, &name));
+    }
+
+    Ok(issues)
+}
+
+/// Remove one worktree's entries from a metadata store.
+fn remove_metadata(repo: &git2::Repository, store: MetadataStore, name: &str) -> Result<()> {
+    let config = WorkonConfig::new(repo)?;
+    match store {
+        MetadataStore::WorktreeConfig => config.clear_worktree_meta(name)?,
+        MetadataStore::NameList(HIDDEN_WORKTREE) => {
+            config.set_hidden(name, false)?;
+        }
+        MetadataStore::NameList(_) => {
+            config.set_external(name, false)?;
+        }
+        MetadataStore::Visits => VisitStore::new(repo).forget(name)?,
+    }
+    Ok(())
+}
+
+/// Returns true if the worktree's `.git` file points back at its admin directory.
+///
+/// `git2::Worktree::validate` only checks the admin side of the link, so a `.git` file
+/// that was copied from elsewhere or left dangling by a moved repository goes unnoticed.
+fn git_link_ok(repo: &git2::Repository, name: &str, path: &Path) -> bool {
+    let Ok(contents) = std::fs::read_to_string(path.join(".git")) else {
+        // A missing directory is reported on its own; anything else is broken
+        return !path.exists();
+    };
+    let Some(gitdir) = contents.trim().strip_prefix("gitdir:") else {
+        return false;
+    };
+    let gitdir = path.join(gitdir.trim());
+    let meta_dir = repo.path().join("worktrees").join(name);
+    match (gitdir.canonicalize(), meta_dir.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Returns the worktree's branch name if the branch was renamed outside workon.
+///
+/// Workon keeps each worktree at `<root>/<branch>`. A worktree under the root whose
+/// path no longer matches its branch was most likely renamed with `git branch -m`.
+fn renamed_branch(wt: &WorktreeDescriptor, root: &Path) -> Option<String> {
+    let branch = wt.branch().ok().flatten()?;
+    let expected = root.join(&branch);
+    if wt.path().starts_with(root) && wt.path() != expected && !expected.exists() {
+        Some(branch)
+    } else {
+        None
+    }
+}
+
+/// Returns where a worktree outside the root belongs: `<root>/<branch>`, or
+/// `<root>/<name>` for a detached worktree.
+fn relocation_target(wt: &WorktreeDescriptor, name: &str, root: &Path) -> PathBuf {
+    match wt.branch().ok().flatten() {
+        Some(branch) => root.join(branch),
+        None => root.join(name),
+    }
+}
+
+/// Attempt a lightweight ls-remote against `remote`, giving up after `timeout`.
+///
+/// libgit2 has no per-call timeout, so the connection runs on its own thread and is
+/// abandoned if it hangs. Returns a human-readable reason on failure.
+fn probe_remote(git_dir: &Path, remote: &str, timeout: Duration) -> Result<(), String> {
+    let (tx, rx) = mpsc::channel();
+    let git_dir = git_dir.to_path_buf();
+    let remote = remote.to_string();
+    thread::spawn(move || {
+        let _ = tx.send(ls_remote(&git_dir, &remote));
+    });
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(_) => Err(format!("timed out after {}s", timeout.as_secs())),
+    }
+}
+
+/// Connect to `remote` and list its refs, without fetching anything.
+fn ls_remote(git_dir: &Path, remote: &str) -> Result<(), String> {
+    let describe = |e: git2::Error| match e.code() {
+        git2::ErrorCode::Auth => format!("authentication failed ({})", e.message()),
+        _ => e.message().to_string(),
+    };
+    let repo = git2::Repository::open(git_dir).map_err(describe)?;
+    let mut remote = repo.find_remote(remote).map_err(describe)?;
+    let callbacks = get_remote_callbacks().map_err(|e| e.to_string())?;
+    let config = repo.config().map_err(describe)?;
+    let proxy = remote.url().map(|url| get_proxy_options(&config, url));
+    let connection = remote
+        .connect_auth(git2::Direction::Fetch, Some(callbacks), proxy)
+        .map_err(describe)?;
+    connection.list().map_err(describe)?;
+    Ok(())
+}
+
+/// Check that a hook script exists and is executable, reporting the result inline.
+fn check_hook_script(label: &str, script: &Path, hook: Option<&str>, issues: &mut Vec<Issue>) {
+    if !script.is_file() {
+        debug!("hook script '{}' not found", script.display());
+        output::check_fail(label, "script not found");
+        if let Some(hook) = hook {
+            issues.push(Issue::dependency(IssueKind::HookScriptMissing {
+                hook: hook.to_string(),
+                script: script.to_path_buf(),
+            }));
+        }
+    } else if !is_executable(script) {
+        debug!("hook script '{}' is not executable", script.display());
+        output::check_fail(
+            label,
+            &format!("not executable (run: chmod +x {})", script.display()),
+        );
+        issues.push(Issue::dependency(IssueKind::HookNotExecutable {
+            hook: hook.map(str::to_string),
+            script: script.to_path_buf(),
+        }));
+    } else {
+        debug!("hook script '{}': ok", script.display());
+        output::check_pass(&format!("{label} (hook script)"));
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .map(|m| m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    true
+}
+
+/// Add the exec bit for everyone who can read the file, like `chmod +x`.
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = path.metadata().into_diagnostic()?.permissions();
+    let mode = permissions.mode();
+    permissions.set_mode(mode | (mode & 0o444) >> 2);
+    std::fs::set_permissions(path, permissions).into_diagnostic()
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
 }
 
 /// Check if a command is available in PATH (or as a path).
@@ -0,0 +1,88 @@
+//! Env command - print a worktree's WORKON_* variables for `eval`.
+//!
+//! Emits the variables post-create hooks receive (see src/hooks.rs), plus the workon
+//! root, so scripts and direnv files can reuse them:
+//!
+//! ```bash
+//! eval "$(git workon env)"               # the worktree containing the current directory
+//! eval "$(git workon env feature)"       # a named worktree
+//! git workon env --shell fish | source   # fish syntax
+//! ```
+//!
+//! ```text
+//! export WORKON_ROOT='/home/me/src/project'
+//! export WORKON_WORKTREE_PATH='/home/me/src/project/feature'
+//! export WORKON_BRANCH_NAME='feature'
+//! export WORKON_BASE_BRANCH='main'
+//! ```
+//!
+//! Workon doesn't record which branch a worktree was created from, so
+//! `WORKON_BASE_BRANCH` is the default branch (the base `new` uses without `--base`). It
+//! and `WORKON_BRANCH_NAME` are unset rather than exported when they don't apply: in the
+//! default worktree and on a detached HEAD, respectively.
+
+use miette::{IntoDiagnostic, Result};
+use serde_json::{json, Map, Value};
+use workon::{
+    current_worktree, find_worktree_fuzzy, get_default_branch, get_repo, workon_root,
+    WorktreeDescriptor,
+};
+
+use crate::cli::{Env, Shell};
+
+use super::Run;
+
+impl Run for Env {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let wt = match &self.name {
+            Some(name) => find_worktree_fuzzy(&repo, name)?,
+            None => current_worktree(&repo)?,
+        };
+        let branch = wt.branch().ok().flatten();
+        let base = get_default_branch(&repo)
+            .ok()
+            .filter(|base| branch.as_ref() != Some(base));
+
+        let vars = [
+            (
+                "WORKON_ROOT",
+                workon_root(&repo)?.to_str().map(str::to_string),
+            ),
+            (
+                "WORKON_WORKTREE_PATH",
+                wt.path().to_str().map(str::to_string),
+            ),
+            ("WORKON_BRANCH_NAME", branch),
+            ("WORKON_BASE_BRANCH", base),
+        ];
+
+        if self.json {
+            let result: Map<String, Value> = vars
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), json!(value)))
+                .collect();
+            let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        for (name, value) in &vars {
+            println!("{}", assignment(self.shell, name, value.as_deref()));
+        }
+        Ok(None)
+    }
+}
+
+/// One line setting (or, without a value, unsetting) a variable in `shell`'s syntax.
+fn assignment(shell: Option<Shell>, name: &str, value: Option<&str>) -> String {
+    match (shell, value) {
+        (Some(Shell::Fish), Some(value)) => {
+            let escaped = value.replace('\\', "\\\\").replace('\'', "\\'");
+            format!("set -gx {} '{}'", name, escaped)
+        }
+        (Some(Shell::Fish), None) => format!("set -e {}", name),
+        (_, Some(value)) => format!("export {}='{}'", name, value.replace('\'', r"'\''")),
+        (_, None) => format!("unset {}", name),
+    }
+}
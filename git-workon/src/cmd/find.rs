@@ -11,10 +11,16 @@
 //!
 //! ## Fuzzy Matching Algorithm
 //!
-//! Simple case-insensitive substring matching:
+//! Matching is shared with the other name-taking commands (see
+//! git-workon-lib/src/matching.rs): case-insensitive substring matching against, in
+//! order, the worktree name, the full branch name, and the worktree's path relative to
+//! the workon root:
 //! - `feat` matches `feature`, `feat-branch`, `new-feature`
-//! - `user/` matches `user/feature`, `user/bugfix`
-//! - Exact matches take priority over fuzzy matches
+//! - `user/` matches branches `user/feature`, `user/bugfix` (whose worktree names are
+//!   just `feature` and `bugfix`)
+//! - Exact matches (worktree name or branch name) take priority over fuzzy matches
+//!
+//! The interactive picker notes which field matched when it isn't the worktree name.
 //!
 //! ## Status Filter Integration
 //!
@@ -25,136 +31,351 @@
 //! git workon find --clean --behind  # Interactive select from clean, behind worktrees
 //! ```
 //!
+//! Status filters combine with AND; `--any` combines them with OR instead, for "anything
+//! that needs attention". Labels are still all required:
+//! ```bash
+//! git workon find --any --dirty --ahead --gone
+//! ```
+//!
+//! With `workon.statusCache` enabled, upstream statuses for the filters and the picker
+//! are reused from the previous run while nothing changed (see git-workon-lib/src/status_cache.rs);
+//! `--no-cache` computes them afresh.
+//!
+//! ## Hidden Worktrees
+//!
+//! Worktrees hidden with `git workon hide` never fuzzy-match or appear in the picker, but
+//! an exact name still finds them. `--include-hidden` includes them.
+//!
+//! ## Labels
+//!
+//! `--label <label>` (repeatable) narrows the search to worktrees carrying every given
+//! label (see `git workon label`); the picker shows each worktree's labels and note, and
+//! the PR title and author of worktrees created with `git workon #123`.
+//!
+//! ## Creating What Isn't There
+//!
+//! When a name matches no worktree, find offers to create it (through `new`, so hooks,
+//! rules and copying apply as usual), making `git workon <name>` a single
+//! "take me to this branch, making it if needed" gesture. The prompt defaults to no when
+//! the name is close to an existing one, so Enter on a typo doesn't create it.
+//! `--create` or `workon.autoCreate=true` creates it without asking; without a terminal
+//! or with `--no-interactive`, find fails as before unless one of those is set. Names are
+//! only created when no status or label filter is given, since a filter may just be
+//! hiding the worktree.
+//!
+//! ## Printing Every Match
+//!
+//! `--all` prints the path of every matching worktree, one per line, instead of picking
+//! one (with `--json`, a JSON array). It never prompts, so scripts can pipe the result set
+//! into their own tools:
+//! ```bash
+//! git workon find user/ --all | xargs -I{} git -C {} fetch
+//! git workon find --all --dirty
+//! ```
+//!
 //! ## Interactive Mode
 //!
 //! Uses dialoguer's FuzzySelect widget with:
 //! - Status indicators from display.rs (`*`, `↑`, `↓`, `✗`)
 //! - Fuzzy searchable selection
+//! - The last chosen worktree highlighted by default (from the visit history, see
+//!   git-workon-lib/src/visits.rs), falling back to the active worktree
 //! - `--no-interactive` bypass for testing and scripting
 //!
 
+use std::io::{ErrorKind, IsTerminal, Write};
+
 use dialoguer::console::{style, Style};
 use dialoguer::theme::ColorfulTheme;
-use dialoguer::FuzzySelect;
-use log::debug;
+use dialoguer::{Confirm, FuzzySelect};
 use miette::{bail, IntoDiagnostic, Result, WrapErr};
-use workon::{get_repo, get_worktrees, WorktreeDescriptor};
+use tracing::debug;
+use workon::{
+    did_you_mean, fuzzy_matches, get_repo, get_worktrees, match_worktrees, workon_root, MatchField,
+    MatchResult, VisitKind, VisitStore, WorkonConfig, WorkonSettings, WorktreeDescriptor,
+};
 
-use crate::cli::Find;
+use crate::cli::{Find, New};
 use crate::display::{format_aligned_rows, worktree_display_row};
+use crate::json::worktree_to_json;
+use crate::output::style as out_style;
 
-use super::Run;
+use super::{prime_statuses, Run};
 
 impl Run for Find {
     fn run(&self) -> Result<Option<WorktreeDescriptor>> {
         let repo = get_repo(None).wrap_err("Failed to find git repository")?;
-        let mut worktrees = get_worktrees(&repo).wrap_err("Failed to list worktrees")?;
+        let found = self.find(&repo)?;
 
-        // Apply status filters
+        // Remember the choice so the picker can default to it next time
+        if let Some(name) = found.as_ref().and_then(|wt| wt.name()) {
+            if let Err(e) = VisitStore::new(&repo).record(name, VisitKind::Visit) {
+                debug!("Failed to record visit to '{}': {}", name, e);
+            }
+        }
+
+        Ok(found)
+    }
+}
+
+impl Find {
+    fn find(&self, repo: &git2::Repository) -> Result<Option<WorktreeDescriptor>> {
+        let mut worktrees = get_worktrees(repo).wrap_err("Failed to list worktrees")?;
+
+        let settings = WorkonSettings::load(repo)?;
+
+        prime_statuses(repo, &worktrees, self.no_cache);
+
+        // Apply status and label filters
         worktrees.retain(|wt| matches_filters(self, wt));
+        if !self.label.is_empty() {
+            worktrees.retain(|wt| {
+                wt.full_name()
+                    .is_some_and(|name| settings.has_labels(&name, &self.label))
+            });
+        }
 
-        if worktrees.is_empty() {
+        // With nothing to search, a plain name can still be created below
+        if worktrees.is_empty() && (self.all || self.has_filters() || self.name.is_none()) {
             bail!("No worktrees match the specified filters");
         }
 
+        let is_hidden = |wt: &WorktreeDescriptor| {
+            !self.include_hidden && wt.full_name().is_some_and(|name| settings.is_hidden(&name))
+        };
+
         match &self.name {
+            Some(name) if self.all => {
+                worktrees.retain(|wt| !is_hidden(wt));
+                // --all wants every match, so exact matches get no priority
+                let branches: Vec<String> = worktrees
+                    .iter()
+                    .filter_map(|wt| wt.branch().ok().flatten())
+                    .collect();
+                let suggestion = did_you_mean(
+                    name,
+                    worktrees
+                        .iter()
+                        .filter_map(|wt| wt.name())
+                        .chain(branches.iter().map(String::as_str)),
+                );
+                let matches = fuzzy_matches(worktrees, workon_root(repo)?, name);
+                if matches.is_empty() {
+                    return no_match(name, suggestion);
+                }
+                print_all(matches.iter().map(|(wt, _)| wt), self.json)
+            }
             Some(name) => {
                 debug!("Searching for worktree '{}'", name);
-
-                // Try exact match first
-                for (idx, worktree) in worktrees.iter().enumerate() {
-                    if let Some(wt_name) = worktree.name() {
-                        if wt_name == name {
-                            debug!("Found exact match: {}", wt_name);
-                            // Return the worktree by consuming the vec
-                            return Ok(Some(worktrees.into_iter().nth(idx).unwrap()));
-                        }
-                    }
-                }
-
-                // No exact match - try fuzzy matching (case-insensitive substring)
-                debug!("No exact match, trying fuzzy match");
-                let fuzzy_matches: Vec<_> = worktrees
-                    .into_iter()
-                    .enumerate()
-                    .filter(|(_, wt)| {
-                        if let Some(wt_name) = wt.name() {
-                            wt_name.to_lowercase().contains(&name.to_lowercase())
-                        } else {
-                            false
-                        }
-                    })
-                    .collect();
-
-                debug!("Found {} fuzzy match(es)", fuzzy_matches.len());
-
-                match fuzzy_matches.len() {
-                    0 => bail!("No matching worktree found for '{}'", name),
-                    1 => {
-                        let (_, worktree) = fuzzy_matches.into_iter().next().unwrap();
+                match match_worktrees(worktrees, workon_root(repo)?, name, is_hidden) {
+                    MatchResult::Exact(worktree) => Ok(Some(worktree)),
+                    MatchResult::Unique(worktree, field) => {
+                        debug!("Single fuzzy match on {}", field);
                         Ok(Some(worktree))
                     }
-                    _ => {
+                    MatchResult::Ambiguous(matches) => {
                         if self.no_interactive {
                             bail!(
                                 "Multiple worktrees match '{}'. Use full name or remove --no-interactive.",
                                 name
                             );
                         }
-                        // Extract just the worktrees from the (index, worktree) tuples
-                        let matched_worktrees: Vec<WorktreeDescriptor> =
-                            fuzzy_matches.into_iter().map(|(_, wt)| wt).collect();
-                        select_from_list(matched_worktrees)
+                        let (matched_worktrees, fields): (Vec<_>, Vec<_>) =
+                            matches.into_iter().unzip();
+                        select_from_list(repo, matched_worktrees, Some(&fields))
+                    }
+                    MatchResult::NoMatch { suggestion } => {
+                        self.create_or_fail(repo, name, suggestion)
                     }
                 }
             }
             None => {
+                worktrees.retain(|wt| !is_hidden(wt));
+                if worktrees.is_empty() {
+                    bail!("No worktrees match the specified filters");
+                }
+                if self.all {
+                    return print_all(worktrees.iter(), self.json);
+                }
                 if self.no_interactive {
                     bail!("No worktree name provided. Specify a name or remove --no-interactive.");
                 }
-                select_from_list(worktrees)
+                select_from_list(repo, worktrees, None)
             }
         }
     }
-}
 
-/// Returns true if the worktree matches all active filters
-fn matches_filters(find: &Find, wt: &WorktreeDescriptor) -> bool {
-    if !find.dirty && !find.clean && !find.ahead && !find.behind && !find.gone {
-        return true;
+    /// Whether any status or label filter narrows the search.
+    fn has_filters(&self) -> bool {
+        self.dirty || self.clean || self.ahead || self.behind || self.gone || !self.label.is_empty()
     }
 
-    if find.dirty && !wt.is_dirty().unwrap_or(false) {
-        return false;
-    }
-    if find.clean && wt.is_dirty().unwrap_or(true) {
-        return false;
+    /// Create `name`, which matched no worktree, if `--create` or workon.autoCreate says
+    /// to or the user agrees; otherwise fail as a plain lookup does.
+    fn create_or_fail(
+        &self,
+        repo: &git2::Repository,
+        name: &str,
+        suggestion: Option<String>,
+    ) -> Result<Option<WorktreeDescriptor>> {
+        let create = if self.has_filters() {
+            false
+        } else if WorkonConfig::new(repo)?.auto_create(self.create.then_some(true))? {
+            true
+        } else if !self.no_interactive && std::io::stdin().is_terminal() {
+            let prompt = match &suggestion {
+                Some(suggestion) => format!(
+                    "No worktree matches '{}' (did you mean '{}'?). Create it?",
+                    name, suggestion
+                ),
+                None => format!("No worktree matches '{}'. Create it?", name),
+            };
+            // Enter on a likely typo shouldn't create it
+            Confirm::new()
+                .with_prompt(prompt)
+                .default(suggestion.is_none())
+                .interact()
+                .into_diagnostic()?
+        } else {
+            false
+        };
+
+        if !create {
+            if suggestion.is_none() && !self.has_filters() {
+                bail!(
+                    help = "Pass --create, or set workon.autoCreate, to create it",
+                    "No matching worktree found for '{}'",
+                    name
+                );
+            }
+            return no_match(name, suggestion);
+        }
+
+        debug!("Creating worktree '{}'", name);
+        New {
+            name: Some(name.to_string()),
+            no_interactive: self.no_interactive,
+            json: self.json,
+            ..Default::default()
+        }
+        .run()
     }
-    if find.ahead && !wt.has_unpushed_commits().unwrap_or(false) {
-        return false;
+}
+
+fn no_match(name: &str, suggestion: Option<String>) -> Result<Option<WorktreeDescriptor>> {
+    match suggestion {
+        Some(suggestion) => bail!(
+            help = format!("Did you mean '{suggestion}'?"),
+            "No matching worktree found for '{}'",
+            name
+        ),
+        None => bail!("No matching worktree found for '{}'", name),
     }
-    if find.behind && !wt.is_behind_upstream().unwrap_or(false) {
-        return false;
+}
+
+/// Print every worktree's path on its own line (or a JSON array in JSON mode)
+fn print_all<'a>(
+    worktrees: impl Iterator<Item = &'a WorktreeDescriptor>,
+    json: bool,
+) -> Result<Option<WorktreeDescriptor>> {
+    if json {
+        let items: Vec<_> = worktrees.map(worktree_to_json).collect();
+        let output = serde_json::to_string_pretty(&items).into_diagnostic()?;
+        println!("{}", output);
+        return Ok(None);
     }
-    if find.gone && !wt.has_gone_upstream().unwrap_or(false) {
-        return false;
+
+    let mut stdout = std::io::stdout().lock();
+    for wt in worktrees {
+        match writeln!(stdout, "{}", wt.path().display()) {
+            Ok(()) => {}
+            // The consumer stopped reading (e.g. `| head`); that's not an error
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => break,
+            Err(e) => return Err(e).into_diagnostic(),
+        }
     }
+    Ok(None)
+}
+
+type StatusCheck = fn(&WorktreeDescriptor) -> bool;
 
-    true
+/// Returns true if the worktree matches all active status filters, or any of them with
+/// `--any`
+fn matches_filters(find: &Find, wt: &WorktreeDescriptor) -> bool {
+    let checks: [(bool, StatusCheck); 5] = [
+        (find.dirty, |wt| wt.is_dirty().unwrap_or(false)),
+        (find.clean, |wt| !wt.is_dirty().unwrap_or(true)),
+        (find.ahead, |wt| wt.has_unpushed_commits().unwrap_or(false)),
+        (find.behind, |wt| wt.is_behind_upstream().unwrap_or(false)),
+        (find.gone, |wt| wt.has_gone_upstream().unwrap_or(false)),
+    ];
+    let mut active = checks
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, check)| check)
+        .peekable();
+
+    // No filters = show all
+    if active.peek().is_none() {
+        return true;
+    }
+    if find.any {
+        active.any(|check| check(wt))
+    } else {
+        active.all(|check| check(wt))
+    }
 }
 
 /// Show interactive fuzzy selection list
-fn select_from_list(worktrees: Vec<WorktreeDescriptor>) -> Result<Option<WorktreeDescriptor>> {
-    let repo = get_repo(None)?;
-    let root = workon::workon_root(&repo)?;
+///
+/// `fields` (parallel to `worktrees`) labels rows whose match wasn't on the worktree name.
+/// The last selection is highlighted by default, falling back to the active worktree.
+fn select_from_list(
+    repo: &git2::Repository,
+    worktrees: Vec<WorktreeDescriptor>,
+    fields: Option<&[MatchField]>,
+) -> Result<Option<WorktreeDescriptor>> {
+    let root = workon_root(repo)?;
     let current_dir = std::env::current_dir().into_diagnostic()?;
+    let settings = WorkonSettings::load(repo)?;
 
-    let rows: Vec<_> = worktrees
-        .iter()
-        .filter_map(|wt| worktree_display_row(wt, root, &current_dir).ok())
-        .collect();
-    let active_index = rows.iter().position(|r| r.is_active).unwrap_or(0);
-    let items = format_aligned_rows(&rows, false);
+    // Worktrees without a row aren't shown, so keep each shown one with its row and
+    // matched field; every index below is into these
+    let mut shown = Vec::new();
+    let mut rows = Vec::new();
+    let mut shown_fields = Vec::new();
+    for (i, wt) in worktrees.into_iter().enumerate() {
+        let full_name = wt.full_name();
+        let labels = full_name
+            .as_deref()
+            .map(|name| settings.labels(name).to_vec())
+            .unwrap_or_default();
+        let note = full_name
+            .as_deref()
+            .and_then(|name| settings.note(name))
+            .map(str::to_string);
+        let Ok(row) = worktree_display_row(&wt, root, &current_dir) else {
+            continue;
+        };
+        rows.push(
+            row.with_labels(labels)
+                .with_note(note)
+                .with_pr(&settings, &wt),
+        );
+        shown_fields.push(fields.and_then(|fields| fields.get(i)));
+        shown.push(wt);
+    }
+    let last_visited = VisitStore::new(repo).last_visited().unwrap_or_default();
+    let default_index = last_visited
+        .and_then(|name| shown.iter().position(|wt| wt.name() == Some(&name)))
+        .or_else(|| rows.iter().position(|r| r.is_active))
+        .unwrap_or(0);
+    let mut items = format_aligned_rows(&rows, false);
+    for (item, field) in items.iter_mut().zip(shown_fields) {
+        if let Some(field) = field.filter(|field| **field != MatchField::Name) {
+            item.push_str(&out_style::dim(&format!("  (matched {})", field)));
+        }
+    }
 
     let theme = ColorfulTheme {
         active_item_prefix: style("→".to_string()).for_stderr().green(),
@@ -167,11 +388,11 @@ fn select_from_list(worktrees: Vec<WorktreeDescriptor>) -> Result<Option<Worktre
     let selection = FuzzySelect::with_theme(&theme)
         .with_prompt("Select a worktree")
         .items(&items)
-        .default(active_index)
+        .default(default_index)
         .interact()
         .into_diagnostic()
         .wrap_err("Failed to show interactive selection")?;
 
     // Consume the vec and return the selected worktree
-    Ok(Some(worktrees.into_iter().nth(selection).unwrap()))
+    Ok(Some(shown.into_iter().nth(selection).unwrap()))
 }
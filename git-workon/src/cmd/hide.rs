@@ -0,0 +1,74 @@
+//! Hide and unhide commands - keep rarely used worktrees out of listings.
+//!
+//! Hidden worktrees are recorded by full name (e.g. `alice/fix`) in the multi-value
+//! `workon.hiddenWorktree` key of the repository's local config:
+//!
+//! ```bash
+//! git workon hide gh-pages     # git config --add workon.hiddenWorktree gh-pages
+//! git workon unhide gh-pages
+//! ```
+//!
+//! `list` leaves hidden worktrees out unless `--all` is given, `find` unless
+//! `--include-hidden` is. An exact name still resolves in `find`, so `git workon gh-pages`
+//! keeps working.
+
+use miette::{bail, Result};
+use workon::{find_worktree_fuzzy, get_repo, WorkonConfig, WorktreeDescriptor};
+
+use crate::cli::{Hide, Unhide};
+use crate::output;
+
+use super::Run;
+
+const HIDDEN_KEY: &str = "workon.hiddenWorktree";
+
+impl Run for Hide {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let wt = find_worktree_fuzzy(&repo, &self.name)?;
+        let name = wt.full_name().unwrap_or_else(|| self.name.clone());
+
+        if WorkonConfig::new(&repo)?.is_hidden(&name) {
+            output::notice(&format!("'{}' is already hidden", name));
+            return Ok(None);
+        }
+
+        WorkonConfig::new(&repo)?.set_hidden(&name, true)?;
+        output::success(&format!(
+            "Hid '{}' (use 'git workon list --all' to see it)",
+            name
+        ));
+        Ok(None)
+    }
+}
+
+impl Run for Unhide {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let workon_config = WorkonConfig::new(&repo)?;
+
+        // Entries for worktrees that no longer exist can still be removed by name
+        let name = if workon_config.is_hidden(&self.name) {
+            self.name.clone()
+        } else {
+            let wt = find_worktree_fuzzy(&repo, &self.name)?;
+            wt.full_name().unwrap_or_else(|| self.name.clone())
+        };
+
+        if !workon_config.is_hidden(&name) {
+            output::notice(&format!("'{}' is not hidden", name));
+            return Ok(None);
+        }
+
+        if !workon_config.set_hidden(&name, false)? {
+            bail!(
+                help = format!("Remove it with 'git config --global --unset {HIDDEN_KEY} {name}'"),
+                "'{}' is hidden by global config, not this repository",
+                name
+            );
+        }
+
+        output::success(&format!("Unhid '{}'", name));
+        Ok(None)
+    }
+}
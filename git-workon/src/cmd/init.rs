@@ -4,14 +4,14 @@ use miette::{Result, WrapErr};
 use workon::{add_worktree, get_default_branch_name, init, BranchType, WorktreeDescriptor};
 
 use crate::cli::Init;
-use crate::hooks::execute_post_create_hooks;
+use crate::hooks::{execute_post_create_hooks, HookRun};
 
-use super::Run;
+use super::{created, Run};
 
 impl Run for Init {
     fn run(&self) -> Result<Option<WorktreeDescriptor>> {
         let path = self.path.clone().unwrap_or_else(|| PathBuf::from("."));
-        let repo = init(path.clone()).wrap_err(format!(
+        let repo = init(path.clone(), !self.no_sign).wrap_err(format!(
             "Failed to initialize repository at {}",
             path.display()
         ))?;
@@ -25,13 +25,15 @@ impl Run for Init {
             ))?;
 
         // Execute post-create hooks after successful worktree creation
+        let mut hooks = HookRun::default();
         if !self.no_hooks {
-            if let Err(e) = execute_post_create_hooks(&worktree, None, &config) {
+            hooks = execute_post_create_hooks(&repo, &worktree, None, &config, &[]);
+            if let Some(e) = &hooks.error {
                 eprintln!("Warning: Post-create hook failed: {}", e);
                 // Continue - worktree is still valid
             }
         }
 
-        Ok(Some(worktree))
+        created(worktree, hooks, self.json)
     }
 }
@@ -0,0 +1,90 @@
+//! Label command - organize worktrees with free-form labels.
+//!
+//! Labels are stored per worktree in the multi-value `workon-worktree.<worktree>.label` key
+//! of the repository's local config, where `<worktree>` is the worktree's path relative to
+//! the workon root:
+//!
+//! ```bash
+//! git workon label add feature-auth backend urgent
+//! git workon label remove feature-auth urgent
+//! ```
+//!
+//! `list` shows each worktree's labels, and `list`, `find` and `prune` accept
+//! `--label <label>` (repeatable, all must match) to narrow what they look at.
+
+use miette::Result;
+use workon::{find_worktree_fuzzy, get_repo, WorkonConfig, WorktreeDescriptor};
+
+use crate::cli::{Label, LabelAdd, LabelCmd, LabelRemove};
+use crate::output;
+
+use super::Run;
+
+impl Run for Label {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        match &self.command {
+            LabelCmd::Add(cmd) => cmd.run(),
+            LabelCmd::Remove(cmd) => cmd.run(),
+        }
+    }
+}
+
+impl Run for LabelAdd {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let wt = find_worktree_fuzzy(&repo, &self.name)?;
+        let full_name = wt.full_name().unwrap_or_else(|| self.name.clone());
+        let name = full_name.as_str();
+        let config = WorkonConfig::new(&repo)?;
+        let existing = config.labels(name)?;
+
+        let mut added: Vec<&str> = Vec::new();
+        for label in &self.labels {
+            let label = label.trim();
+            if label.is_empty() || existing.iter().any(|l| l == label) || added.contains(&label) {
+                continue;
+            }
+            config.add_label(name, label)?;
+            added.push(label);
+        }
+
+        if added.is_empty() {
+            output::notice(&format!("'{}' already has those labels", name));
+        } else {
+            output::success(&format!("Labeled '{}': {}", name, added.join(", ")));
+        }
+        Ok(None)
+    }
+}
+
+impl Run for LabelRemove {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let wt = find_worktree_fuzzy(&repo, &self.name)?;
+        let full_name = wt.full_name().unwrap_or_else(|| self.name.clone());
+        let name = full_name.as_str();
+        let config = WorkonConfig::new(&repo)?;
+        let existing = config.labels(name)?;
+
+        let mut removed: Vec<&str> = Vec::new();
+        for label in &self.labels {
+            if !existing.contains(label) {
+                output::notice(&format!("'{}' is not labeled '{}'", name, label));
+                continue;
+            }
+            if config.remove_label(name, label)? {
+                removed.push(label);
+            } else {
+                output::warn(&format!(
+                    "label '{}' on '{}' comes from global config, not this repository",
+                    label, name
+                ));
+            }
+        }
+
+        if !removed.is_empty() {
+            output::success(&format!("Removed from '{}': {}", name, removed.join(", ")));
+        }
+        Ok(None)
+    }
+}
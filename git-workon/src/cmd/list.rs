@@ -21,6 +21,63 @@
 //!
 //! Conflicting filters (--dirty and --clean together) produce an error.
 //!
+//! ## Filter Expressions
+//!
+//! `--filter` takes a boolean expression over the same statuses, labels and age, for
+//! selections the flags can't express (see git-workon-lib/src/filter.rs):
+//! ```bash
+//! git workon list --filter '(dirty || ahead) && !gone'
+//! git workon list --filter 'label:backend && age > 2w' --json
+//! ```
+//!
+//! It's combined with any other filter flags using AND.
+//!
+//! ## Hidden Worktrees
+//!
+//! Worktrees hidden with `git workon hide` are left out unless `--all` is given.
+//!
+//! ## Labels
+//!
+//! Labels added with `git workon label` are shown as `#label` tags (and as `labels` in
+//! JSON output). `--label <label>` shows only worktrees carrying it; repeat the flag to
+//! require several labels.
+//!
+//! ## Notes
+//!
+//! A note set with `git workon note` is shown dimmed at the end of the worktree's row (and
+//! as `note` in JSON output).
+//!
+//! ## Pull Requests
+//!
+//! Worktrees created with `git workon #123` are shown as `pr-123  "Fix auth bug" (alice)`
+//! instead of by their branch name, using the PR title and author recorded at creation
+//! (`pr` in JSON output).
+//!
+//! ## Streaming Output
+//!
+//! `--json-lines` emits one compact JSON object per worktree, flushed as soon as that
+//! worktree's status is computed, so consumers (fzf preview scripts, editors) can render
+//! incrementally instead of waiting for the whole workspace:
+//! ```bash
+//! git workon list --json-lines | jq -r 'select(.is_dirty) | .name'
+//! ```
+//!
+//! ## All Repositories
+//!
+//! `--all-repos` lists worktrees from every repository in the registry (see `-R`) in one
+//! table, with a leading repository column, or with a `repo` field in JSON output. Each
+//! repository is read on its own thread; one that can't be read is reported and skipped.
+//! ```bash
+//! git workon list --all-repos --dirty
+//! ```
+//!
+//! ## Status Cache
+//!
+//! With `workon.statusCache` enabled, upstream statuses (ahead, behind, gone) are reused
+//! from the previous run while a worktree's HEAD and upstream are unchanged (see
+//! git-workon-lib/src/status_cache.rs); dirtiness is always computed. `--no-cache`
+//! computes them all afresh.
+//!
 //! ## Fail-Safe Error Handling
 //!
 //! When checking status (dirty, unpushed, etc.), errors default to false
@@ -32,18 +89,33 @@
 //!
 //! TODO: Optimize status checks for performance with many worktrees
 
-use log::debug;
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+use std::sync::mpsc;
+use std::thread;
+
+use git2::Repository;
 use miette::{IntoDiagnostic, Result};
-use workon::{get_repo, get_worktrees, WorktreeDescriptor};
+use serde_json::json;
+use tracing::debug;
+use workon::{
+    get_repo, get_worktrees, RepoRegistry, WorkonSettings, WorktreeDescriptor, WorktreeFilter,
+};
 
 use crate::cli::List;
-use crate::display::{format_aligned_rows, worktree_display_row};
-use crate::json::worktree_to_json;
+use crate::display::{format_aligned_rows, worktree_display_row, WorktreeDisplayRow};
+use crate::json::{nullable, print_schema, worktree_schema, worktree_to_json};
+use crate::output;
 
-use super::Run;
+use super::{prime_statuses, save_status_cache, status_cache, Run};
 
 impl Run for List {
     fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        if self.schema {
+            print_schema("git workon list --json", json_schema())?;
+            return Ok(None);
+        }
+
         // Error if --dirty and --clean both specified
         if self.dirty && self.clean {
             return Err(miette::miette!(
@@ -51,19 +123,51 @@ impl Run for List {
             ));
         }
 
+        let filter = self
+            .filter
+            .as_deref()
+            .map(WorktreeFilter::parse)
+            .transpose()?;
+
+        if self.all_repos {
+            return self.run_all_repos(filter.as_ref());
+        }
+
         let repo = get_repo(None)?;
-        let worktrees = get_worktrees(&repo)?;
-        debug!("Found {} worktree(s)", worktrees.len());
+        let settings = WorkonSettings::load(&repo)?;
+        let worktrees = self.visible_worktrees(&repo, &settings)?;
+        let to_json = |wt: &WorktreeDescriptor| worktree_json(&settings, wt);
+        let matches =
+            |wt: &&WorktreeDescriptor| self.matches_filters(wt, &settings, filter.as_ref());
+
+        if self.json_lines {
+            let mut stdout = std::io::stdout().lock();
+            let mut cache = status_cache(&repo, self.no_cache);
+            for wt in &worktrees {
+                // Prime each worktree as it's reached, so output still streams
+                if let Some(cache) = cache.as_mut() {
+                    cache.prime(wt);
+                }
+                if !matches(&wt) {
+                    continue;
+                }
+                let line = serde_json::to_string(&to_json(wt)).into_diagnostic()?;
+                if !write_json_line(&mut stdout, &line)? {
+                    break;
+                }
+            }
+            save_status_cache(cache);
+            return Ok(None);
+        }
+
+        prime_statuses(&repo, &worktrees, self.no_cache);
 
         // Apply filters (AND logic)
-        let filtered: Vec<_> = worktrees
-            .into_iter()
-            .filter(|wt| self.matches_filters(wt))
-            .collect();
+        let filtered: Vec<_> = worktrees.iter().filter(matches).collect();
         debug!("{} worktree(s) after filtering", filtered.len());
 
         if self.json {
-            let json_array: Vec<_> = filtered.iter().map(worktree_to_json).collect();
+            let json_array: Vec<_> = filtered.iter().map(|wt| to_json(wt)).collect();
             let output = serde_json::to_string_pretty(&json_array).into_diagnostic()?;
             println!("{}", output);
             return Ok(None);
@@ -73,8 +177,8 @@ impl Run for List {
         let current_dir = std::env::current_dir().into_diagnostic()?;
 
         let rows: Vec<_> = filtered
-            .iter()
-            .filter_map(|wt| worktree_display_row(wt, root, &current_dir).ok())
+            .into_iter()
+            .filter_map(|wt| display_row(&settings, wt, root, &current_dir))
             .collect();
 
         for line in format_aligned_rows(&rows, true) {
@@ -85,9 +189,172 @@ impl Run for List {
     }
 }
 
+/// One registered repository's worktrees, rendered for the requested output.
+enum Listing {
+    Rows(Vec<WorktreeDisplayRow>),
+    Json(Vec<serde_json::Value>),
+}
+
 impl List {
-    /// Returns true if the worktree matches all active filters
-    fn matches_filters(&self, wt: &WorktreeDescriptor) -> bool {
+    /// List worktrees from every repository in the registry, one thread per repository.
+    ///
+    /// Repositories are shown in registry order; with `--json-lines`, each repository's
+    /// worktrees are emitted as soon as that repository is done. A repository that can't
+    /// be read is reported and skipped.
+    fn run_all_repos(&self, filter: Option<&WorktreeFilter>) -> Result<Option<WorktreeDescriptor>> {
+        let registry = RepoRegistry::load()?;
+        if registry.is_empty() {
+            output::notice(&format!(
+                "No repositories registered in {}",
+                registry.path().display()
+            ));
+            return Ok(None);
+        }
+        let current_dir = std::env::current_dir().into_diagnostic()?;
+
+        let repos: Vec<_> = registry.iter().collect();
+        let (tx, rx) = mpsc::channel();
+        let mut listings: Vec<Option<Listing>> = repos.iter().map(|_| None).collect();
+        let mut stdout = std::io::stdout().lock();
+        let mut streaming = true;
+
+        thread::scope(|scope| -> Result<()> {
+            for (index, &(name, root)) in repos.iter().enumerate() {
+                let tx = tx.clone();
+                let current_dir = &current_dir;
+                scope.spawn(move || {
+                    let _ = tx.send((index, self.list_repo(name, root, current_dir, filter)));
+                });
+            }
+            drop(tx);
+
+            for (index, listing) in rx {
+                let listing = match listing {
+                    Ok(listing) => listing,
+                    Err(e) => {
+                        output::warn(&format!("skipping '{}': {}", repos[index].0, e));
+                        continue;
+                    }
+                };
+                match listing {
+                    Listing::Json(values) if self.json_lines => {
+                        for value in &values {
+                            if !streaming {
+                                break;
+                            }
+                            let line = serde_json::to_string(value).into_diagnostic()?;
+                            streaming = write_json_line(&mut stdout, &line)?;
+                        }
+                    }
+                    listing => listings[index] = Some(listing),
+                }
+            }
+            Ok(())
+        })?;
+
+        if self.json_lines {
+            return Ok(None);
+        }
+
+        if self.json {
+            let json_array: Vec<_> = listings
+                .into_iter()
+                .flatten()
+                .flat_map(|listing| match listing {
+                    Listing::Json(values) => values,
+                    Listing::Rows(_) => Vec::new(),
+                })
+                .collect();
+            let output = serde_json::to_string_pretty(&json_array).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        let rows: Vec<_> = listings
+            .into_iter()
+            .flatten()
+            .flat_map(|listing| match listing {
+                Listing::Rows(rows) => rows,
+                Listing::Json(_) => Vec::new(),
+            })
+            .collect();
+        for line in format_aligned_rows(&rows, true) {
+            println!("{}", line);
+        }
+
+        Ok(None)
+    }
+
+    /// List one registered repository's worktrees, applying every filter.
+    fn list_repo(
+        &self,
+        name: &str,
+        root: &Path,
+        current_dir: &Path,
+        filter: Option<&WorktreeFilter>,
+    ) -> workon::Result<Listing> {
+        let repo = get_repo(Some(root.to_path_buf()))?;
+        let settings = WorkonSettings::load(&repo)?;
+        let mut worktrees = self.visible_worktrees(&repo, &settings)?;
+        prime_statuses(&repo, &worktrees, self.no_cache);
+        worktrees.retain(|wt| self.matches_filters(wt, &settings, filter));
+
+        if self.json || self.json_lines {
+            let values = worktrees
+                .iter()
+                .map(|wt| {
+                    let mut value = worktree_json(&settings, wt);
+                    value["repo"] = json!(name);
+                    value
+                })
+                .collect();
+            return Ok(Listing::Json(values));
+        }
+
+        let root = workon::workon_root(&repo)?;
+        let rows = worktrees
+            .iter()
+            .filter_map(|wt| display_row(&settings, wt, root, current_dir))
+            .map(|row| row.with_repo(name))
+            .collect();
+        Ok(Listing::Rows(rows))
+    }
+
+    /// All worktrees, minus hidden ones (unless `--all`) and those missing a `--label`.
+    fn visible_worktrees(
+        &self,
+        repo: &Repository,
+        settings: &WorkonSettings,
+    ) -> workon::Result<Vec<WorktreeDescriptor>> {
+        let mut worktrees = get_worktrees(repo)?;
+        debug!("Found {} worktree(s)", worktrees.len());
+
+        if !self.all {
+            worktrees.retain(|wt| !wt.full_name().is_some_and(|name| settings.is_hidden(&name)));
+        }
+        if !self.label.is_empty() {
+            worktrees.retain(|wt| {
+                wt.full_name()
+                    .is_some_and(|name| settings.has_labels(&name, &self.label))
+            });
+        }
+        Ok(worktrees)
+    }
+
+    /// Returns true if the worktree matches all active filters and the `--filter`
+    /// expression
+    fn matches_filters(
+        &self,
+        wt: &WorktreeDescriptor,
+        settings: &WorkonSettings,
+        filter: Option<&WorktreeFilter>,
+    ) -> bool {
+        if let Some(filter) = filter {
+            if !filter.matches(wt, &labels_of(settings, wt)) {
+                return false;
+            }
+        }
+
         // No filters = show all
         if !self.dirty && !self.clean && !self.ahead && !self.behind && !self.gone {
             return true;
@@ -117,3 +384,85 @@ impl List {
         true
     }
 }
+
+fn labels_of(settings: &WorkonSettings, wt: &WorktreeDescriptor) -> Vec<String> {
+    wt.full_name()
+        .map(|name| settings.labels(&name).to_vec())
+        .unwrap_or_default()
+}
+
+fn note_of(settings: &WorkonSettings, wt: &WorktreeDescriptor) -> Option<String> {
+    wt.full_name()
+        .and_then(|name| settings.note(&name).map(str::to_string))
+}
+
+/// Schema of `--json` output; each `--json-lines` line is one item.
+fn json_schema() -> serde_json::Value {
+    let mut item = worktree_schema();
+    item["properties"]["labels"] = json!({
+        "type": "array",
+        "items": { "type": "string" },
+        "description": "Labels added with 'git workon label'",
+    });
+    item["properties"]["note"] = nullable("string", "Note set with 'git workon note'");
+    item["properties"]["pr"] = json!({
+        "type": ["object", "null"],
+        "description": "Pull request the worktree was created for with 'git workon #123'",
+        "properties": {
+            "number": { "type": "integer" },
+            "title": nullable("string", "PR title when the worktree was created"),
+            "author": nullable("string", "PR author's login"),
+        },
+        "required": ["number", "title", "author"],
+    });
+    item["properties"]["repo"] = json!({
+        "type": "string",
+        "description": "Registered repository name (only with --all-repos)",
+    });
+    if let Some(required) = item["required"].as_array_mut() {
+        required.extend([json!("labels"), json!("note"), json!("pr")]);
+    }
+    json!({
+        "description": "Worktrees, one object per worktree",
+        "type": "array",
+        "items": item,
+    })
+}
+
+fn worktree_json(settings: &WorkonSettings, wt: &WorktreeDescriptor) -> serde_json::Value {
+    let mut value = worktree_to_json(wt);
+    value["labels"] = json!(labels_of(settings, wt));
+    value["note"] = json!(note_of(settings, wt));
+    value["pr"] = json!(wt
+        .full_name()
+        .and_then(|name| settings.worktree(&name))
+        .and_then(|meta| Some(json!({
+            "number": meta.pr?,
+            "title": meta.pr_title,
+            "author": meta.pr_author,
+        }))));
+    value
+}
+
+fn display_row(
+    settings: &WorkonSettings,
+    wt: &WorktreeDescriptor,
+    root: &Path,
+    current_dir: &Path,
+) -> Option<WorktreeDisplayRow> {
+    worktree_display_row(wt, root, current_dir).ok().map(|row| {
+        row.with_labels(labels_of(settings, wt))
+            .with_note(note_of(settings, wt))
+            .with_pr(settings, wt)
+    })
+}
+
+/// Write and flush one JSON line. Returns false once the consumer has stopped reading
+/// (e.g. `| head`), which isn't an error.
+fn write_json_line(out: &mut impl Write, line: &str) -> Result<bool> {
+    match writeln!(out, "{}", line).and_then(|_| out.flush()) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == ErrorKind::BrokenPipe => Ok(false),
+        Err(e) => Err(e).into_diagnostic(),
+    }
+}
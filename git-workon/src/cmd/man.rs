@@ -0,0 +1,53 @@
+//! Man command - render man pages from the clap definitions.
+//!
+//! The build script writes the same pages to `man/`; this command makes them available
+//! from an installed binary, so package managers (and `cargo install` users) can install
+//! `man git-workon`:
+//!
+//! ```bash
+//! git workon man | man -l -              # the main page
+//! git workon man new | man -l -          # git-workon-new(1)
+//! git workon man --dir /usr/share/man/man1
+//! ```
+
+use std::fs;
+use std::io::{self, Write};
+
+use clap::CommandFactory;
+use miette::{miette, IntoDiagnostic, Result};
+use workon::WorktreeDescriptor;
+
+use crate::cli::{Cli, Man};
+use crate::output;
+
+use super::Run;
+
+impl Run for Man {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let mut cmd = Cli::command().disable_help_subcommand(true);
+
+        if let Some(dir) = &self.dir {
+            fs::create_dir_all(dir).into_diagnostic()?;
+            clap_mangen::generate_to(cmd, dir).into_diagnostic()?;
+            output::success(&format!("Wrote man pages to {}", dir.display()));
+            return Ok(None);
+        }
+
+        // Building fills in subcommand display names (git-workon-new) for page titles
+        cmd.build();
+        let mut page = &cmd;
+        for name in &self.command {
+            page = page
+                .find_subcommand(name)
+                .filter(|sub| !sub.is_hide_set())
+                .ok_or_else(|| miette!("No man page for '{}'", self.command.join(" ")))?;
+        }
+
+        let mut buffer = Vec::new();
+        clap_mangen::Man::new(page.clone())
+            .render(&mut buffer)
+            .into_diagnostic()?;
+        io::stdout().write_all(&buffer).into_diagnostic()?;
+        Ok(None)
+    }
+}
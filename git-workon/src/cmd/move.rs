@@ -13,6 +13,7 @@
 //! 2. **Two-arg**: `git workon move <from> <to>` - Explicit source and target
 //!    - Can run from anywhere (doesn't need to be in a worktree)
 //!    - Explicitly specifies which worktree to rename
+//!    - `<from>` resolves like `find`: an exact name or branch, or a unique partial match
 //!
 //! ## Dry Run Mode
 //!
@@ -21,13 +22,19 @@
 //! - Shows branch and directory paths that would change
 //! - No modifications made
 //!
+//! ## Locked Worktrees
+//!
+//! Locked worktrees (`git worktree lock`) are refused with the lock reason, even
+//! with `--force`. Use `--force-locked` to move them; the lock is re-created at the
+//! new location.
+//!
 //! See git-workon-lib/src/move.rs for implementation details, safety checks,
 //! and atomic operation strategy.
 
 use miette::{bail, Context, Result};
 use workon::{
-    current_worktree, find_worktree, get_repo, move_worktree, validate_move, MoveOptions,
-    WorktreeDescriptor,
+    current_worktree, find_worktree, find_worktree_fuzzy, get_repo, move_worktree, validate_move,
+    MoveOptions, WorktreeDescriptor,
 };
 
 use crate::cli::Move;
@@ -49,8 +56,13 @@ impl Run for Move {
                 (from, self.names[0].clone())
             }
             2 => {
-                // Two arguments: explicit from and to
-                (self.names[0].clone(), self.names[1].clone())
+                // Two arguments: explicit from (which may be a partial name) and to
+                let source = find_worktree_fuzzy(&repo, &self.names[0])?;
+                let from = source
+                    .branch()?
+                    .or_else(|| source.name().map(str::to_string))
+                    .unwrap_or_else(|| self.names[0].clone());
+                (from, self.names[1].clone())
             }
             _ => {
                 bail!("Expected 1 or 2 arguments, got {}", self.names.len());
@@ -63,7 +75,10 @@ impl Run for Move {
         }
 
         // Set up options
-        let options = MoveOptions { force: self.force };
+        let options = MoveOptions {
+            force: self.force,
+            force_locked: self.force_locked,
+        };
 
         if self.dry_run {
             let root = workon::workon_root(&repo)?;
@@ -78,6 +93,12 @@ impl Run for Move {
                 source.path().display(),
                 root.join(&to).display()
             );
+            if source.is_locked()? {
+                match source.lock_reason()? {
+                    Some(reason) => println!("  Lock: kept ({})", reason),
+                    None => println!("  Lock: kept"),
+                }
+            }
 
             return Ok(None);
         }
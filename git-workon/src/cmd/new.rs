@@ -12,6 +12,12 @@
 //!
 //! Use `--no-interactive` to bypass prompts (required for testing/scripting).
 //!
+//! ## Default Base Branch
+//!
+//! Without `--base`, new branches start from `workon.defaultBranch`. When that isn't set,
+//! the remote's default branch is detected once (`origin/HEAD`, or asking the remote) and
+//! cached in `workon.remoteDefaultBranch`, rather than guessing `main` or `master`.
+//!
 //! ## PR Support Integration
 //!
 //! Detects PR references in the name and handles them specially:
@@ -23,20 +29,89 @@
 //!
 //! Combined with smart routing in main.rs, enables: `git workon #123`
 //!
+//! ## Name Validation
+//!
+//! Names are checked against git's branch name rules before anything is created; invalid
+//! ones fail with a sanitized suggestion. `workon.nameStyle = kebab` normalizes names
+//! instead (`Fix Login` → `fix-login`), see git-workon-lib/src/branch_name.rs.
+//!
 //! ## Automatic File Copying
 //!
 //! If `workon.autoCopyUntracked=true`:
-//! - Copies files from base branch's worktree (or HEAD's worktree if no base)
+//! - Copies files from base branch's worktree (or the default branch's if no base)
+//! - `--copy-from <worktree>` or `workon.copySource` picks a fixed source worktree
+//!   instead, e.g. a "golden" `main` with warm caches (`--copy-from` implies `--copy-untracked`)
+//! - Copies untracked and ignored files, never tracked ones
 //! - Uses `workon.copyPattern` patterns (or defaults to `**/*`)
 //! - Respects `workon.copyExclude` patterns
 //! - Runs after worktree creation, before post-create hooks
 //! - Can be overridden with `--(no-)copy-untracked` flags
+//! - Skips files over `workon.copyMaxFileSize`; above `workon.copyMaxTotal` in total,
+//!   asks first (or, without a terminal or with `--no-interactive`, copies nothing)
+//!
+//! ## Remote Branches
+//!
+//! `new <name> --from <remote>/<branch>` fetches the remote branch and creates the local
+//! branch `<name>` from it, tracking it, so a long or someone else's branch name doesn't
+//! have to become the local one.
+//!
+//! ## Patches
+//!
+//! `--from-patch <file>` applies a patch to the new worktree, so emailed or downloaded
+//! patches can be reviewed in isolation like PRs. The file may be a raw diff, a
+//! `git format-patch` series or an mbox (see git-workon-lib/src/patch.rs). The changes
+//! are left uncommitted unless `--commit` is given, which commits each patch with its
+//! original author and message. Without a name, the worktree is named after the file
+//! (`0001-fix-login.patch` → `fix-login`).
+//!
+//! ## Commit Signing
+//!
+//! Commits created here (the initial commit of `--orphan` branches, patches committed by
+//! `--from-patch --commit`) are signed when `commit.gpgsign` is set, using the user's
+//! gpg/ssh signing config (see git-workon-lib/src/signing.rs). `--no-sign` skips signing.
+//!
+//! ## Skipping Checkout
+//!
+//! `--no-checkout` registers the worktree and its branch but leaves the directory empty,
+//! like `git worktree add --no-checkout`. Useful when a hook or sparse-checkout selection
+//! comes first anyway; `git reset --hard` in the worktree populates it later.
+//!
+//! ## Existing Directories
+//!
+//! A directory left at the worktree's path (by a crashed prune, or a manual copy) fails
+//! with a clear error up front rather than a cryptic one from libgit2. An empty one is
+//! simply replaced. `--adopt` reuses a leftover worktree directory of the same branch in
+//! place, keeping its files; `--force` moves a leftover directory aside to `<name>.bak`
+//! (`<name>.bak.2`, ...) and creates the worktree fresh. It never moves a registered
+//! worktree, or creates a second one for a branch that's already checked out.
+//!
+//! ## Templates
+//!
+//! `--template <name>` applies a template from `workon.template.<name>.*` (see
+//! git-workon-lib/src/template.rs): its base branch (unless `--base` is given), copy
+//! patterns (turning copying on), extra post-create hooks, sparse-checkout directories
+//! and labels. `git workon templates` lists them.
+//!
+//! ## Rules
+//!
+//! `workon.rule.<glob>.*` config applies to every new branch whose name matches the glob
+//! (see git-workon-lib/src/rule.rs): its base branch (after `--base` and a template's),
+//! extra post-create hooks (before a template's), and protection from `prune`/`move`.
+//!
+//! ## Publishing
+//!
+//! `--push` pushes the new branch to the detected remote (`remote.pushDefault`, then
+//! `origin`, then the first remote) and sets it as upstream, so `--gone` and ahead/behind
+//! indicators work immediately. PR worktrees already track their remote branch.
 //!
 //! ## Execution Order
 //!
 //! 1. Create worktree
-//! 2. Copy files (if auto-copy enabled)
-//! 3. Execute post-create hooks (from hooks.rs)
+//! 2. Apply the template's sparse checkout and labels (if `--template`)
+//! 3. Apply patch (if `--from-patch`)
+//! 4. Push branch (if `--push`)
+//! 5. Copy files (if auto-copy enabled)
+//! 6. Execute post-create hooks (from hooks.rs)
 //!
 //! ## gh CLI Integration
 //!
@@ -45,17 +120,34 @@
 //! - Supports fork-based PRs by auto-adding fork remotes
 //! - Properly sets upstream tracking for PR branches
 //! - Enables format placeholders: {number}, {title}, {author}, {branch}
+//!
+//! Running a PR reference again switches to the PR's existing worktree instead of
+//! failing, so `git workon #123` doubles as "go to review #123".
+//!
+//! `--review` (or `workon.prReadOnly` for every PR) marks the worktree review-only, to
+//! avoid pushing onto someone else's PR branch: workon refuses to push from it, `new`
+//! warns when branching off it, and `prune --prs` removes it without safety checks.
+
+use std::io::IsTerminal;
+use std::path::Path;
 
-use dialoguer::{FuzzySelect, Input};
-use log::debug;
+use dialoguer::{Confirm, FuzzySelect, Input};
 use miette::{bail, IntoDiagnostic, Result, WrapErr};
+use tracing::debug;
 
 use crate::cli::New;
-use crate::hooks::execute_post_create_hooks;
+use crate::display::{format_copy_summary, format_size};
+use crate::hooks::{execute_post_create_hooks, HookRun};
 use crate::output;
-use workon::{add_worktree, copy_files, get_repo, workon_root, BranchType, WorktreeDescriptor};
+use workon::{
+    add_worktree_with_options, apply_patches, copy_entries, create_tracking_branch,
+    detect_push_remote, detect_remote_default_branch, find_worktree, get_default_branch, get_repo,
+    get_worktrees, move_aside, normalize_branch_name, parse_patches, plan_copy, push_branch,
+    set_sparse_checkout, workon_root, BranchType, NewWorktreeOptions, Patch, UntrackedFilter,
+    VisitKind, VisitStore, WorktreeDescriptor, WorktreeError, WorktreeTemplate,
+};
 
-use super::Run;
+use super::{created, Run};
 
 // Ability to easily create a worktree with namespcaing.
 // Also see: https://lists.mcs.anl.gov/pipermail/petsc-dev/2021-May/027436.html
@@ -83,9 +175,21 @@ use super::Run;
 
 impl Run for New {
     fn run(&self) -> Result<Option<WorktreeDescriptor>> {
-        let name = match &self.name {
-            Some(name) => name.clone(),
-            None => {
+        // Read the patch up front so a bad file doesn't leave an empty worktree behind
+        let patches = match &self.from_patch {
+            Some(path) => {
+                let contents = std::fs::read_to_string(path)
+                    .into_diagnostic()
+                    .wrap_err(format!("Failed to read patch '{}'", path.display()))?;
+                Some(parse_patches(&contents)?)
+            }
+            None => None,
+        };
+
+        let name = match (&self.name, &self.from_patch) {
+            (Some(name), _) => name.clone(),
+            (None, Some(path)) => patch_worktree_name(path)?,
+            (None, None) => {
                 if self.no_interactive {
                     bail!("No worktree name provided. Specify a name or remove --no-interactive.");
                 }
@@ -107,10 +211,20 @@ impl Run for New {
 
         let repo = get_repo(None).wrap_err("Failed to find git repository")?;
         let config = workon::WorkonConfig::new(&repo)?;
+        let template = match &self.template {
+            Some(name) => Some(config.template(name)?),
+            None => None,
+        };
 
         // Check if this is a PR reference
         // Only treat as PR if no conflicting flags are provided
-        let pr_info = if !self.orphan && !self.detach && self.base.is_none() {
+        let pr_info = if !self.orphan
+            && !self.detach
+            && self.base.is_none()
+            && self.from_patch.is_none()
+            && self.from.is_none()
+            && self.template.is_none()
+        {
             let info = workon::parse_pr_reference(&name)?;
             if info.is_some() {
                 debug!("Detected PR reference in '{}'", name);
@@ -121,16 +235,46 @@ impl Run for New {
             None
         };
 
-        let (worktree_name, base_branch, branch_type) = if let Some(pr) = pr_info {
+        if self.review && pr_info.is_none() {
+            bail!(
+                help = "Use it with a PR reference, e.g. git workon new #123 --review",
+                "--review only applies to pull request worktrees"
+            );
+        }
+
+        let (worktree_name, base_branch, branch_type, rules) = if let Some(pr) = pr_info {
             // This is a PR reference - use gh CLI workflow
             let pr_format = config.pr_format(None)?;
-            let (worktree_name, remote_ref, base_ref) =
+
+            // Already reviewing this PR: switch to its worktree
+            if let Some(worktree) = workon::find_pr_worktree(&repo, pr.number, &pr_format)? {
+                let name = worktree.name().unwrap_or_default();
+                output::status(&format!(
+                    "PR #{} is already checked out in '{}'",
+                    pr.number, name
+                ));
+                if self.review {
+                    let full_name = worktree.full_name().unwrap_or_default();
+                    config.set_worktree_meta(&full_name, "review", Some("true"))?;
+                }
+                record_visit(&repo, &worktree);
+                return Ok(Some(worktree));
+            }
+
+            let (worktree_name, remote_ref, metadata) =
                 workon::prepare_pr_worktree(&repo, pr.number, &pr_format)
                     .wrap_err(format!("Failed to prepare PR #{} worktree", pr.number))?;
+            let base_ref = metadata.base_ref.clone();
 
             // Create worktree
             let worktree =
-                add_worktree(&repo, &worktree_name, BranchType::Normal, Some(&remote_ref))?;
+                self.add_worktree(&repo, &worktree_name, BranchType::Normal, Some(&remote_ref))?;
+            record_creation(&repo, &worktree);
+            let full_name = worktree.full_name().unwrap_or(worktree_name.clone());
+            workon::record_pr(&repo, &full_name, &metadata)?;
+            if self.review {
+                config.set_worktree_meta(&full_name, "review", Some("true"))?;
+            }
 
             // Fix upstream tracking
             // remote_ref is in format "remote/branch" - extract both parts
@@ -142,44 +286,77 @@ impl Run for New {
                 .wrap_err("Failed to set upstream tracking for PR branch")?;
 
             // Copy files if configured
-            let copy_override = if self.copy_untracked {
-                Some(true)
-            } else if self.no_copy_untracked {
-                Some(false)
-            } else {
-                None
-            };
-
-            if config.auto_copy_untracked(copy_override)? {
-                if let Err(e) = copy_untracked_files(&repo, &worktree, Some(&base_ref), &config) {
+            if config.auto_copy_untracked(self.copy_override())? {
+                let copy_source = config.copy_source(self.copy_from.as_deref())?;
+                if let Err(e) = copy_untracked_files(
+                    &repo,
+                    &worktree,
+                    copy_source.as_deref(),
+                    Some(&base_ref),
+                    &config,
+                    &[],
+                    self.is_interactive(),
+                ) {
                     output::warn(&format!("Failed to copy untracked files: {}", e));
                 }
             }
 
             // Execute post-create hooks
+            let mut hooks = HookRun::default();
             if !self.no_hooks {
-                if let Err(e) = execute_post_create_hooks(&worktree, Some(&base_ref), &config) {
+                hooks = execute_post_create_hooks(&repo, &worktree, Some(&base_ref), &config, &[]);
+                if let Some(e) = &hooks.error {
                     output::warn(&format!("Post-create hook failed: {}", e));
                 }
             }
 
-            return Ok(Some(worktree));
+            return created(worktree, hooks, self.json);
         } else {
             // Regular worktree creation
 
+            // Catch names git can't use before creating anything
+            let normalized = normalize_branch_name(&name, config.name_style()?)?;
+            if normalized != name {
+                output::notice(&format!("Using branch name '{}'", normalized));
+            }
+
+            let rules = config.matching_rules(&normalized)?;
+            for rule in &rules {
+                output::notice(&format!("Applying rule '{}'", rule.glob));
+            }
+            let rule_base = rules.iter().find_map(|r| r.base.as_deref());
+
+            // Start from a remote branch under our own name, tracking it
+            if let Some(remote_ref) = &self.from {
+                create_tracking_branch(&repo, &normalized, remote_ref)
+                    .wrap_err(format!("Failed to create branch from '{}'", remote_ref))?;
+            }
+
             // Determine base branch
-            let base_branch = if let Some(base) = &self.base {
+            let base_branch = if self.from.is_some() {
+                None
+            } else if let Some(base) = &self.base {
                 debug!("Using explicit base branch: {}", base);
                 config.default_branch(Some(base))?
-            } else if !self.no_interactive && self.name.is_none() {
+            } else if let Some(base) = template.as_ref().and_then(|t| t.base.as_deref()) {
+                debug!("Using template base branch: {}", base);
+                config.default_branch(Some(base))?
+            } else if let Some(base) = rule_base {
+                debug!("Using rule base branch: {}", base);
+                config.default_branch(Some(base))?
+            } else if !self.no_interactive && self.name.is_none() && self.from_patch.is_none() {
                 // Interactive mode: prompt for base branch
                 debug!("Prompting for base branch (interactive mode)");
                 prompt_for_base_branch(&repo, &config)?
             } else {
-                debug!("Using default base branch from config");
-                config.default_branch(None)?
+                debug!("Using default branch as base");
+                default_base_branch(&repo, &config)?
             };
 
+            if let Some(base) = &base_branch {
+                warn_if_review_only(&repo, &config, base);
+            }
+
             let branch_type = if self.orphan {
                 BranchType::Orphan
             } else if self.detach {
@@ -188,25 +365,55 @@ impl Run for New {
                 BranchType::Normal
             };
 
-            (name, base_branch, branch_type)
+            (normalized, base_branch, branch_type, rules)
         };
 
-        let worktree = add_worktree(&repo, &worktree_name, branch_type, base_branch.as_deref())
+        let worktree = self
+            .add_worktree(&repo, &worktree_name, branch_type, base_branch.as_deref())
             .wrap_err(format!("Failed to create worktree '{}'", worktree_name))?;
+        record_creation(&repo, &worktree);
 
-        // Copy untracked files if enabled
-        let copy_override = if self.copy_untracked {
-            Some(true)
-        } else if self.no_copy_untracked {
-            Some(false)
-        } else {
-            None
-        };
+        if let Some(template) = &template {
+            apply_template(&config, &worktree, template)?;
+        }
+
+        if let (Some(patches), Some(path)) = (&patches, &self.from_patch) {
+            apply_patch_file(&worktree, patches, path, self.commit, !self.no_sign).wrap_err(
+                format!(
+                    "Worktree '{}' was created, but the patch did not apply",
+                    worktree_name
+                ),
+            )?;
+        }
 
+        // Publish the branch so it has a remote counterpart from the start
+        if self.push {
+            if let Err(e) = publish_branch(&repo, &worktree) {
+                output::warn(&format!("Failed to push branch: {}", e));
+                // Continue - worktree is still valid
+            }
+        }
+
+        // Copy untracked files if enabled; a template's copy patterns turn copying on
+        let template_patterns = template
+            .as_ref()
+            .map(|t| t.copy_patterns.as_slice())
+            .unwrap_or_default();
+        let copy_override = self
+            .copy_override()
+            .or((!template_patterns.is_empty()).then_some(true));
         if config.auto_copy_untracked(copy_override)? {
             debug!("Auto-copy enabled, copying from base worktree");
-            if let Err(e) = copy_untracked_files(&repo, &worktree, base_branch.as_deref(), &config)
-            {
+            let copy_source = config.copy_source(self.copy_from.as_deref())?;
+            if let Err(e) = copy_untracked_files(
+                &repo,
+                &worktree,
+                copy_source.as_deref(),
+                base_branch.as_deref(),
+                &config,
+                template_patterns,
+                self.is_interactive(),
+            ) {
                 output::warn(&format!("Failed to copy untracked files: {}", e));
                 // Continue - worktree is still valid
             }
@@ -215,9 +422,24 @@ impl Run for New {
         }
 
         // Execute post-create hooks after successful worktree creation
+        let mut hooks = HookRun::default();
         if !self.no_hooks {
             debug!("Executing post-create hooks");
-            if let Err(e) = execute_post_create_hooks(&worktree, base_branch.as_deref(), &config) {
+            // Rules' hooks, then the template's, after the configured ones
+            let extra_hooks: Vec<String> = rules
+                .iter()
+                .flat_map(|r| r.post_create_hooks.iter())
+                .chain(template.iter().flat_map(|t| t.post_create_hooks.iter()))
+                .cloned()
+                .collect();
+            hooks = execute_post_create_hooks(
+                &repo,
+                &worktree,
+                base_branch.as_deref(),
+                &config,
+                &extra_hooks,
+            );
+            if let Some(e) = &hooks.error {
                 output::warn(&format!("Post-create hook failed: {}", e));
                 // Continue - worktree is still valid
             }
@@ -225,8 +447,23 @@ impl Run for New {
             debug!("Hooks skipped (--no-hooks)");
         }
 
-        Ok(Some(worktree))
+        created(worktree, hooks, self.json)
+    }
+}
+
+/// The base branch when none is given: workon.defaultBranch, or the remote's default
+/// branch (detected and cached the first time), or [`get_default_branch`]'s fallbacks.
+fn default_base_branch(
+    repo: &git2::Repository,
+    config: &workon::WorkonConfig,
+) -> Result<Option<String>> {
+    if let Some(branch) = config.default_branch(None)? {
+        return Ok(Some(branch));
     }
+    if config.remote_default_branch()?.is_none() {
+        detect_remote_default_branch(repo)?;
+    }
+    Ok(get_default_branch(repo).ok())
 }
 
 /// Prompt user to select a base branch from available branches
@@ -249,9 +486,7 @@ fn prompt_for_base_branch(
         return config.default_branch(None).map_err(Into::into);
     }
 
-    let default_branch = config
-        .default_branch(None)?
-        .unwrap_or_else(|| "main".to_string());
+    let default_branch = default_base_branch(repo, config)?.unwrap_or_else(|| "main".to_string());
     let mut items = vec![format!("<default: {}>", default_branch)];
     items.extend(branch_names.iter().cloned());
 
@@ -270,15 +505,224 @@ fn prompt_for_base_branch(
     }
 }
 
-/// Copy untracked files from the base worktree to the new worktree
+/// Apply a template's sparse checkout and labels to the new worktree
+fn apply_template(
+    config: &workon::WorkonConfig,
+    worktree: &WorktreeDescriptor,
+    template: &WorktreeTemplate,
+) -> Result<()> {
+    if !template.sparse_paths.is_empty() {
+        if let Err(e) = set_sparse_checkout(worktree, &template.sparse_paths) {
+            output::warn(&format!("Failed to set up sparse checkout: {}", e));
+            // Continue - the worktree is still valid, with everything checked out
+        }
+    }
+    if let Some(full_name) = worktree.full_name() {
+        for label in &template.labels {
+            config.add_label(&full_name, label)?;
+        }
+    }
+    output::notice(&format!("Applied template '{}'", template.name));
+    Ok(())
+}
+
+/// Note the new worktree in the visit history; failures only affect future defaults
+fn record_creation(repo: &git2::Repository, worktree: &WorktreeDescriptor) {
+    if let Some(name) = worktree.name() {
+        if let Err(e) = VisitStore::new(repo).record(name, VisitKind::Create) {
+            debug!("Failed to record creation of '{}': {}", name, e);
+        }
+    }
+}
+
+/// Warn when branching off the branch of a review-only PR worktree.
+fn warn_if_review_only(repo: &git2::Repository, config: &workon::WorkonConfig, base: &str) {
+    let Ok(base_worktree) = find_worktree(repo, base) else {
+        return;
+    };
+    let review_only = base_worktree
+        .full_name()
+        .is_some_and(|full_name| config.is_review_only(&full_name));
+    if let Some(name) = base_worktree.name().filter(|_| review_only) {
+        output::warn(&format!(
+            "Branching off '{}', checked out in review-only PR worktree '{}'",
+            base, name
+        ));
+    }
+}
+
+fn record_visit(repo: &git2::Repository, worktree: &WorktreeDescriptor) {
+    if let Some(name) = worktree.name() {
+        if let Err(e) = VisitStore::new(repo).record(name, VisitKind::Visit) {
+            debug!("Failed to record visit to '{}': {}", name, e);
+        }
+    }
+}
+
+/// Name a worktree after its patch file, dropping format-patch's `0001-` numbering
+fn patch_worktree_name(path: &Path) -> Result<String> {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match stem.split_once('-') {
+        Some((number, rest)) if number.chars().all(|c| c.is_ascii_digit()) => rest,
+        _ => stem.as_str(),
+    };
+    if name.is_empty() {
+        bail!(
+            "Cannot derive a worktree name from '{}'; specify one",
+            path.display()
+        );
+    }
+    Ok(name.to_string())
+}
+
+/// Apply the parsed patches to the new worktree, committing them if requested
+fn apply_patch_file(
+    worktree: &WorktreeDescriptor,
+    patches: &[Patch],
+    path: &Path,
+    commit: bool,
+    sign: bool,
+) -> Result<()> {
+    let repo = worktree.repo()?;
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    let fallback_message = format!("Apply {}", file_name);
+    let commits = apply_patches(repo, patches, commit, sign, &fallback_message)?;
+
+    if commit {
+        output::success(&format!(
+            "Committed {} patch(es) from {}",
+            commits.len(),
+            file_name
+        ));
+    } else {
+        output::success(&format!("Applied {} (uncommitted)", file_name));
+    }
+    Ok(())
+}
+
+/// Fail if `path` is a registered worktree or `name`'s branch is checked out somewhere,
+/// so `--force` only ever moves leftover directories aside.
+fn refuse_to_displace(repo: &git2::Repository, name: &str, path: &Path) -> Result<()> {
+    let same_dir = |other: &Path| {
+        other == path
+            || matches!((other.canonicalize(), path.canonicalize()), (Ok(a), Ok(b)) if a == b)
+    };
+    for worktree in get_worktrees(repo)? {
+        if same_dir(worktree.path()) {
+            return Err(WorktreeError::TargetExists {
+                to: worktree.name().unwrap_or(name).to_string(),
+            }
+            .into());
+        }
+        if worktree.branch()?.as_deref() == Some(name) {
+            return Err(WorktreeError::BranchCheckedOut {
+                branch: name.to_string(),
+                worktree: worktree.name().unwrap_or_default().to_string(),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Push the new worktree's branch to the detected remote and track it
+fn publish_branch(repo: &git2::Repository, worktree: &WorktreeDescriptor) -> Result<()> {
+    let remote = detect_push_remote(repo)?;
+    push_branch(worktree, &remote)?;
+    output::success(&format!(
+        "Pushed {} to {} and set upstream",
+        worktree.branch()?.unwrap_or_default(),
+        remote
+    ));
+    Ok(())
+}
+
+impl New {
+    /// Create the worktree, honoring `--no-checkout`, `--no-sign`, `--adopt` and `--force`
+    fn add_worktree(
+        &self,
+        repo: &git2::Repository,
+        name: &str,
+        branch_type: BranchType,
+        base: Option<&str>,
+    ) -> Result<WorktreeDescriptor> {
+        let path = workon_root(repo)?.join(name);
+        if self.force && path.exists() {
+            refuse_to_displace(repo, name, &path)?;
+            let moved = move_aside(&path)?;
+            output::notice(&format!(
+                "Moved existing {} aside to {}",
+                path.display(),
+                moved.display()
+            ));
+        }
+
+        let options = NewWorktreeOptions {
+            checkout: !self.no_checkout,
+            sign: !self.no_sign,
+            adopt: self.adopt,
+        };
+        // An empty directory is replaced rather than adopted
+        let adopting = self.adopt && path.read_dir().is_ok_and(|mut d| d.next().is_some());
+        let worktree = add_worktree_with_options(repo, name, branch_type, base, &options)?;
+        if adopting {
+            output::notice(&format!(
+                "Adopted existing {}; its files were kept",
+                worktree.path().display()
+            ));
+            return Ok(worktree);
+        }
+        if options.checkout {
+            return Ok(worktree);
+        }
+        output::notice(&format!(
+            "Skipped checkout; run 'git reset --hard' in {} to populate it",
+            worktree.path().display()
+        ));
+        Ok(worktree)
+    }
+
+    /// Whether prompts can be shown
+    fn is_interactive(&self) -> bool {
+        !self.no_interactive && std::io::stdin().is_terminal()
+    }
+
+    /// CLI override for auto-copy: `--no-copy-untracked` wins, `--copy-from` implies copying
+    fn copy_override(&self) -> Option<bool> {
+        if self.no_copy_untracked {
+            Some(false)
+        } else if self.copy_untracked || self.copy_from.is_some() {
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
+
+/// Copy untracked files from the source (or base) worktree to the new worktree
+///
+/// `template_patterns`, when not empty, replace the configured copy patterns.
 fn copy_untracked_files(
     repo: &git2::Repository,
     worktree: &WorktreeDescriptor,
+    copy_source: Option<&str>,
     base_branch: Option<&str>,
     config: &workon::WorkonConfig,
+    template_patterns: &[String],
+    interactive: bool,
 ) -> Result<()> {
-    // Get copy patterns from config, or default to copying everything
-    let patterns = config.copy_patterns()?;
+    // Get copy patterns from the template or config, or default to copying everything
+    let patterns = if template_patterns.is_empty() {
+        config.copy_patterns()?
+    } else {
+        template_patterns.to_vec()
+    };
     let patterns = if patterns.is_empty() {
         vec!["**/*".to_string()]
     } else {
@@ -286,26 +730,30 @@ fn copy_untracked_files(
     };
 
     let excludes = config.copy_excludes()?;
+    let options = CopyOptions {
+        patterns: &patterns,
+        excludes: &excludes,
+        max_file_size: config.copy_max_file_size()?,
+        max_total: config.copy_max_total()?,
+        interactive,
+    };
+
+    // An explicit source worktree must exist; unlike the base worktree, it isn't optional
+    if let Some(source) = copy_source {
+        let source_path = find_worktree(repo, source)?.path().to_path_buf();
+        let label = format!("worktree '{}'", source);
+        return copy_from_worktree(&source_path, worktree, &options, &label);
+    }
 
     // Determine which branch to copy from
-    let source_branch_name = if let Some(base) = base_branch {
-        base.to_string()
-    } else {
-        // No base branch specified, use HEAD's branch
-        match repo.head() {
-            Ok(head) => {
-                if let Some(shorthand) = head.shorthand() {
-                    shorthand.to_string()
-                } else {
-                    // HEAD is detached or not a branch, skip copying
-                    return Ok(());
-                }
-            }
-            Err(_) => {
-                // Can't determine HEAD, skip copying
-                return Ok(());
-            }
-        }
+    let source_branch_name = match base_branch {
+        Some(base) => base.to_string(),
+        // No base branch specified, use the default branch
+        None => match get_default_branch(repo) {
+            Ok(default_branch) => default_branch,
+            // No default branch to copy from, skip copying
+            Err(_) => return Ok(()),
+        },
     };
 
     // Find the source worktree path
@@ -316,17 +764,81 @@ fn copy_untracked_files(
         return Ok(());
     }
 
-    // Get destination path
-    let dest_path = worktree.path().to_path_buf();
+    copy_from_worktree(&source_path, worktree, &options, "base worktree")
+}
+
+/// What to copy, and the size limits from `workon.copyMaxFileSize`/`workon.copyMaxTotal`
+struct CopyOptions<'a> {
+    patterns: &'a [String],
+    excludes: &'a [String],
+    max_file_size: Option<u64>,
+    max_total: Option<u64>,
+    interactive: bool,
+}
+
+/// Copy matching files into the new worktree and report the count, size and cloned share
+fn copy_from_worktree(
+    source_path: &Path,
+    worktree: &WorktreeDescriptor,
+    options: &CopyOptions,
+    source_label: &str,
+) -> Result<()> {
+    let mut entries = plan_copy(
+        source_path,
+        worktree.path(),
+        options.patterns,
+        options.excludes,
+        UntrackedFilter::default(),
+        false,
+    )?;
+
+    if let Some(max) = options.max_file_size {
+        entries.retain(|entry| {
+            let fits = entry.size <= max;
+            if !fits {
+                output::warn(&format!(
+                    "Skipping {} ({}, over workon.copyMaxFileSize of {})",
+                    entry.path.display(),
+                    format_size(entry.size),
+                    format_size(max)
+                ));
+            }
+            fits
+        });
+    }
+
+    let total: u64 = entries.iter().map(|entry| entry.size).sum();
+    if let Some(max) = options.max_total.filter(|max| total > *max) {
+        let summary = format!(
+            "{} file(s) ({}) from {}, over workon.copyMaxTotal of {}",
+            entries.len(),
+            format_size(total),
+            source_label,
+            format_size(max)
+        );
+        if !options.interactive {
+            output::warn(&format!("Not copying {}", summary));
+            return Ok(());
+        }
+        let confirmed = Confirm::new()
+            .with_prompt(format!("Copy {}?", summary))
+            .default(false)
+            .interact()
+            .into_diagnostic()?;
+        if !confirmed {
+            output::notice("Skipped copying untracked files");
+            return Ok(());
+        }
+    }
 
-    // Copy files
-    let copied = copy_files(&source_path, &dest_path, &patterns, &excludes, false)?;
+    copy_entries(source_path, worktree.path(), &mut entries)?;
 
-    // Report what was copied
-    if !copied.is_empty() {
+    // Report what was copied, and how much of it was cloned rather than copied
+    if !entries.is_empty() {
         output::success(&format!(
-            "Copied {} file(s) from base worktree",
-            copied.len()
+            "Copied {} from {}",
+            format_copy_summary(&entries),
+            source_label
         ));
     }
 
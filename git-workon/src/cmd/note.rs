@@ -0,0 +1,50 @@
+//! Note command - remember why a worktree exists.
+//!
+//! Notes are stored per worktree in `workon-worktree.<worktree>.note` in the repository's
+//! local config and shown as a dimmed column in `list` and the interactive picker:
+//!
+//! ```bash
+//! git workon note feature-auth "waiting on API review"
+//! git workon note feature-auth            # print the note
+//! git workon note feature-auth --clear
+//! ```
+
+use miette::Result;
+use workon::{find_worktree_fuzzy, get_repo, WorkonConfig, WorktreeDescriptor};
+
+use crate::cli::Note;
+use crate::output;
+
+use super::Run;
+
+impl Run for Note {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let wt = find_worktree_fuzzy(&repo, &self.name)?;
+        let full_name = wt.full_name().unwrap_or_else(|| self.name.clone());
+        let name = full_name.as_str();
+        let config = WorkonConfig::new(&repo)?;
+
+        if self.clear {
+            if config.set_worktree_meta(name, "note", None)? {
+                output::success(&format!("Cleared note for '{}'", name));
+            } else {
+                output::notice(&format!("'{}' has no note", name));
+            }
+            return Ok(None);
+        }
+
+        match &self.text {
+            Some(text) => {
+                config.set_worktree_meta(name, "note", Some(text.trim()))?;
+                output::success(&format!("Noted '{}': {}", name, text.trim()));
+            }
+            None => match config.note(name)? {
+                Some(note) => println!("{}", note),
+                None => output::notice(&format!("'{}' has no note", name)),
+            },
+        }
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,184 @@
+//! Prompt command - a compact status segment for shell prompts.
+//!
+//! Prints the current worktree's name followed by its status indicators (see display.rs),
+//! on a single line with no styling, for starship/powerlevel custom segments:
+//!
+//! ```text
+//! feature *↑
+//! ```
+//!
+//! Outside a workon worktree it prints nothing and still succeeds, so it can run
+//! unconditionally.
+//!
+//! ## Time Budget
+//!
+//! Status is computed on a background thread. If it isn't ready within `--timeout`
+//! milliseconds (default 100), the last known status for the worktree is printed instead
+//! and a detached `git workon prompt --refresh` recomputes it for the next prompt. The
+//! last known status is kept in the status cache (see git-workon-lib/src/status_cache.rs),
+//! which the prompt always records to.
+//!
+//! Only one refresh per worktree runs at a time: it holds
+//! `<git-dir>/workon/prompt-refresh.<name>.lock` until it's done, and prompts rendered
+//! meanwhile don't start another. A lock older than a minute is assumed to be left by a
+//! refresh that died, and taken over.
+//!
+//! With `workon.statusCache` enabled, the upstream status is also reused from it while
+//! the worktree's HEAD and upstream are unchanged, so the time budget is rarely hit;
+//! `--no-cache` skips that.
+//!
+//! ```toml
+//! # starship.toml
+//! [custom.workon]
+//! command = "git workon prompt"
+//! when = "git rev-parse --is-inside-work-tree"
+//! ```
+
+use std::fs::{self, OpenOptions};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use git2::Repository;
+use miette::Result;
+use tracing::debug;
+use workon::{current_worktree, get_repo, StatusCache, WorktreeDescriptor};
+
+use crate::cli::Prompt;
+use crate::display::{indicators_for, status_indicators};
+
+use super::{save_status_cache, status_cache_enabled, Run};
+
+/// How long a refresh lock holds before it's assumed to be left by a dead refresh.
+const STALE_REFRESH: Duration = Duration::from_secs(60);
+
+impl Run for Prompt {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        // A prompt must never fail; outside a worktree there's simply nothing to show
+        let Ok(repo) = get_repo(None) else {
+            return Ok(None);
+        };
+        let Ok(worktree) = current_worktree(&repo) else {
+            return Ok(None);
+        };
+        let Some(name) = worktree.name().map(str::to_string) else {
+            return Ok(None);
+        };
+
+        let lock = repo
+            .commondir()
+            .join("workon")
+            .join(format!("prompt-refresh.{}.lock", name));
+        if self.refresh {
+            record_status(&repo, &worktree, self.no_cache);
+            let _ = fs::remove_file(&lock);
+            return Ok(None);
+        }
+
+        let (tx, rx) = mpsc::channel();
+        let path = worktree.path().to_path_buf();
+        let thread_name = name.clone();
+        let no_cache = self.no_cache;
+        thread::spawn(move || {
+            let indicators = compute_indicators(path, &thread_name, no_cache);
+            let _ = tx.send(indicators);
+        });
+
+        let indicators = match rx.recv_timeout(Duration::from_millis(self.timeout)) {
+            Ok(Some(indicators)) => indicators,
+            Ok(None) => String::new(),
+            Err(_) => {
+                debug!("status for '{}' exceeded the time budget", name);
+                if claim_refresh(&lock) {
+                    spawn_refresh(&lock);
+                }
+                StatusCache::load(&repo)
+                    .ok()
+                    .and_then(|cache| cache.last_known(&name))
+                    .map(|status| indicators_for(&status).concat())
+                    .unwrap_or_default()
+            }
+        };
+
+        if indicators.is_empty() {
+            println!("{}", name);
+        } else {
+            println!("{} {}", name, indicators);
+        }
+        Ok(None)
+    }
+}
+
+/// Compute the indicators for a worktree from a fresh repository handle.
+fn compute_indicators(path: PathBuf, name: &str, no_cache: bool) -> Option<String> {
+    let repo = get_repo(Some(path)).ok()?;
+    let worktree = WorktreeDescriptor::new(&repo, name).ok()?;
+    record_status(&repo, &worktree, no_cache);
+    Some(status_indicators(&worktree).concat())
+}
+
+/// Give the worktree its status and record it in the status cache as the last known
+/// one, reusing the cached upstream status only with workon.statusCache enabled.
+fn record_status(repo: &Repository, worktree: &WorktreeDescriptor, no_cache: bool) {
+    let mut cache = StatusCache::load(repo)
+        .inspect_err(|e| debug!("Failed to read status cache: {}", e))
+        .ok();
+    match cache.as_mut() {
+        Some(cache) if status_cache_enabled(repo, no_cache) => cache.prime(worktree),
+        Some(cache) => cache.refresh(worktree),
+        None => {}
+    }
+    save_status_cache(cache);
+}
+
+/// Take the refresh lock, unless a refresh that's still running (or recently started)
+/// holds it.
+fn claim_refresh(lock: &Path) -> bool {
+    let create = || OpenOptions::new().write(true).create_new(true).open(lock);
+    if let Some(parent) = lock.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    match create() {
+        Ok(_) => true,
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+            let stale = fs::metadata(lock)
+                .and_then(|meta| meta.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age > STALE_REFRESH);
+            if !stale {
+                debug!("prompt refresh already running");
+                return false;
+            }
+            debug!("taking over stale prompt refresh lock");
+            let _ = fs::remove_file(lock);
+            create().is_ok()
+        }
+        Err(e) => {
+            debug!("failed to take prompt refresh lock: {}", e);
+            false
+        }
+    }
+}
+
+/// Start a detached `git workon prompt --refresh` so the next prompt has fresh status.
+/// It releases `lock` when done; if it can't start, the lock is released here.
+fn spawn_refresh(lock: &Path) {
+    let Ok(exe) = std::env::current_exe() else {
+        let _ = fs::remove_file(lock);
+        return;
+    };
+    let spawned = Command::new(exe)
+        .args(["prompt", "--refresh"])
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    if let Err(e) = spawned {
+        debug!("failed to start prompt refresh: {}", e);
+        let _ = fs::remove_file(lock);
+    }
+}
@@ -7,9 +7,25 @@
 //!
 //! - **Targeted pruning**: `git workon prune <name>...` - prune specific worktrees
 //! - **Bulk pruning**: `--gone` and `--merged` flags for automatic discovery
+//! - **PR worktrees**: `--prs` also discovers worktrees created with `git workon #123`.
+//!   Review-only ones (`new --review`, `workon.prReadOnly`) hold no commits of the user's,
+//!   so they skip the unmerged check, and `--delete-branch` deletes their branches merged
+//!   or not. Protection, the grace period and the dirty check still apply
 //! - **Protected branches**: Respects `workon.pruneProtectedBranches` glob patterns
 //! - **Safety checks**: `--allow-dirty` and `--allow-unmerged` to override warnings
 //! - **Dry run**: `--dry-run` to preview without deleting
+//! - **Branch cleanup**: pruning removes the directory and its worktree metadata and keeps
+//!   the local branch. `--delete-branch` deletes the branch too, if it's merged (into the
+//!   `--merged` target, or the default branch). Branches with unmerged commits are kept,
+//!   so `--allow-unmerged` never loses them
+//! - **Fork remotes**: a `pr-N-fork` remote the PR flow added for the worktree is removed
+//!   once no local branch tracks it, so it stays while the branch is kept (see
+//!   git-workon-lib/src/pr.rs)
+//! - **Orphaned branches**: `--orphaned-branches` also deletes local branches that no
+//!   worktree has checked out (e.g. left behind by worktrees removed with `git worktree
+//!   remove`), once they're merged into the default branch or fully pushed to their
+//!   upstream. `--allow-unmerged` deletes the others too; protected branches, the
+//!   default branch and the bare repository's HEAD are kept
 //!
 //! ## Protected Branch Matching
 //!
@@ -17,29 +33,57 @@
 //! - Exact match: `main` protects only "main"
 //! - Wildcard: `*` protects all branches
 //! - Prefix: `release/*` protects "release/v1", "release/v2", etc.
+//! - Other globs: `spike-*` protects "spike-cache", etc.
+//!
+//! Branches matching a `workon.rule.<glob>` with `protect = true` are protected too.
 //!
 //! ## Status Filtering
 //!
 //! When using `--gone` or `--merged`, the command uses WorktreeDescriptor's status
 //! methods to detect which worktrees can be safely pruned.
+//!
+//! `--label <label>` (repeatable) limits this discovery to worktrees carrying every given
+//! label (see `git workon label`). Explicitly named worktrees are not affected.
+//!
+//! ## Grace Period
+//!
+//! `workon.pruneGracePeriod` (e.g. `2d`) keeps discovery away from recently created
+//! worktrees: a branch cut from an already-merged base looks merged the moment it's
+//! created. Such worktrees are reported as skipped; naming them explicitly or `--force`
+//! still prunes them. Creation time comes from the visit history when `git workon new`
+//! recorded it, and from the worktree's git metadata otherwise.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 use dialoguer::Confirm;
 use git2::BranchType;
-use log::debug;
 use miette::{IntoDiagnostic, Result};
 use serde_json::json;
-use workon::{get_default_branch, get_repo, get_worktrees, WorktreeDescriptor};
+use tracing::debug;
+use workon::{
+    get_default_branch, get_repo, get_worktrees, remove_fork_remote, suggest_worktree_name,
+    unused_fork_remotes, VisitStore, WorkonConfig, WorktreeDescriptor,
+};
 
 use crate::cli::Prune;
+use crate::display::{format_duration, format_relative_time};
+use crate::json::print_schema;
 use crate::output;
 
 use super::Run;
 
 impl Run for Prune {
     fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        if self.schema {
+            print_schema("git workon prune --json", json_schema())?;
+            return Ok(None);
+        }
+
         let repo = get_repo(None)?;
-        let config = workon::WorkonConfig::new(&repo)?;
+        let config = WorkonConfig::new(&repo)?;
         let protected_patterns = config.prune_protected_branches()?;
+        let grace_period = GracePeriod::load(&repo, &config)?;
         let worktrees = get_worktrees(&repo)?;
 
         let mut candidates: Vec<(&WorktreeDescriptor, PruneCandidate)> = Vec::new();
@@ -77,10 +121,17 @@ impl Run for Prune {
                         worktree_path: wt.path().to_path_buf(),
                         branch_name,
                         reason: PruneReason::Explicit,
+                        delete_branch: false,
                     },
                 ));
             } else {
-                output::warn(&format!("worktree '{}' not found, skipping", name));
+                match suggest_worktree_name(&repo, name) {
+                    Some(suggestion) => output::warn(&format!(
+                        "worktree '{}' not found, skipping (did you mean '{}'?)",
+                        name, suggestion
+                    )),
+                    None => output::warn(&format!("worktree '{}' not found, skipping", name)),
+                }
             }
         }
 
@@ -93,74 +144,32 @@ impl Run for Prune {
                     return None;
                 }
 
+                if !config.has_labels(&wt.full_name()?, &self.label) {
+                    return None;
+                }
+
                 // Get the branch name - skip detached worktrees and worktrees with errors
                 let branch_name = match wt.branch() {
                     Ok(Some(name)) => name,
                     Ok(None) | Err(_) => return None, // Detached HEAD or error, skip
                 };
 
-                // Check if the branch still exists in the main repo
-                let branch_exists = repo
-                    .find_branch(&branch_name, git2::BranchType::Local)
-                    .is_ok();
-
-                if !branch_exists {
-                    // Branch is deleted - always prune
-                    debug!("'{}': branch deleted, candidate for pruning", branch_name);
-                    Some((
-                        wt,
-                        PruneCandidate {
-                            worktree_name: wt.name()?.to_string(),
-                            worktree_path: wt.path().to_path_buf(),
-                            branch_name,
-                            reason: PruneReason::BranchDeleted,
-                        },
-                    ))
-                } else if self.gone {
-                    // Branch exists - check if upstream is gone (only if --gone flag is set)
-                    match is_upstream_gone(&repo, &branch_name) {
-                        Ok(true) => {
-                            debug!("'{}': upstream gone, candidate for pruning", branch_name);
-                            Some((
-                                wt,
-                                PruneCandidate {
-                                    worktree_name: wt.name()?.to_string(),
-                                    worktree_path: wt.path().to_path_buf(),
-                                    branch_name,
-                                    reason: PruneReason::RemoteGone,
-                                },
-                            ))
-                        }
-                        _ => None,
-                    }
-                } else if let Some(ref merged_target) = self.merged {
-                    // Branch exists - check if merged into target (only if --merged flag is set)
-                    let target_branch = if merged_target.is_empty() {
-                        // Use default branch
-                        match get_default_branch(&repo) {
-                            Ok(b) => b,
-                            Err(_) => return None, // Can't determine default branch
-                        }
-                    } else {
-                        merged_target.clone()
-                    };
-
-                    match wt.is_merged_into(&target_branch) {
-                        Ok(true) => Some((
-                            wt,
-                            PruneCandidate {
-                                worktree_name: wt.name()?.to_string(),
-                                worktree_path: wt.path().to_path_buf(),
-                                branch_name,
-                                reason: PruneReason::Merged(target_branch),
-                            },
-                        )),
-                        _ => None,
-                    }
-                } else {
-                    debug!("'{}': no prune criteria matched, skipping", branch_name);
-                    None
-                }
+                let reason =
+                    prune_reason(&repo, wt, &branch_name, self.gone, self.merged.as_deref())
+                        .or_else(|| match config.pr_number(&wt.full_name()?) {
+                            Ok(Some(number)) if self.prs => Some(PruneReason::PullRequest(number)),
+                            _ => None,
+                        })?;
+                Some((
+                    wt,
+                    PruneCandidate {
+                        worktree_name: wt.name()?.to_string(),
+                        worktree_path: wt.path().to_path_buf(),
+                        branch_name,
+                        reason,
+                        delete_branch: false,
+                    },
+                ))
             })
             .collect();
 
@@ -169,91 +178,99 @@ impl Run for Prune {
 
         // Pre-compute default branch for safety checks
         let default_branch = get_default_branch(&repo).ok();
+        let overrides = SafetyOverrides {
+            force: self.force,
+            allow_dirty: self.allow_dirty,
+            allow_unmerged: self.allow_unmerged,
+        };
 
         // Apply safety checks to filter out unsafe worktrees
         let mut skipped: Vec<(PruneCandidate, String)> = Vec::new();
         let to_prune: Vec<PruneCandidate> = candidates
             .into_iter()
             .filter_map(|(wt, candidate)| {
-                // Check if branch is protected
-                if !self.force && is_protected(&candidate.branch_name, &protected_patterns) {
-                    debug!("'{}': skipped (protected branch)", candidate.branch_name);
-                    skipped.push((
-                        candidate,
-                        "protected by workon.pruneProtectedBranches".to_string(),
-                    ));
-                    return None;
-                }
-
-                // Never prune the default worktree
-                if !self.force {
-                    if let Some(ref branch) = default_branch {
-                        if candidate.branch_name == *branch {
-                            skipped.push((candidate, "is the default worktree".to_string()));
-                            return None;
-                        }
-                    }
-                }
-
-                // Check for uncommitted changes
-                if !self.force && !self.allow_dirty {
-                    match wt.is_dirty() {
-                        Ok(true) => {
-                            skipped.push((
-                                candidate,
-                                "has uncommitted changes, use --allow-dirty to override"
-                                    .to_string(),
-                            ));
-                            return None;
-                        }
-                        Err(_) => {
-                            skipped.push((candidate, "could not check status".to_string()));
-                            return None;
-                        }
-                        _ => {}
+                // A review-only worktree found by --prs holds no commits of the user's, but
+                // may still hold uncommitted changes
+                let review_only = matches!(candidate.reason, PruneReason::PullRequest(_))
+                    && wt
+                        .full_name()
+                        .is_some_and(|name| config.is_review_only(&name));
+                let overrides = SafetyOverrides {
+                    allow_unmerged: overrides.allow_unmerged || review_only,
+                    ..overrides
+                };
+                let within_grace_period = match (&grace_period, &candidate.reason) {
+                    (Some(grace), reason) if !overrides.force && !reason.is_explicit() => {
+                        grace.protects(wt)
                     }
-                }
-
-                // Check for unmerged commits into the default branch.
-                // Skip if branch is already deleted (deletion implies work was handled)
-                // or if --merged already verified it's merged into the specified target.
-                if !self.force
-                    && !self.allow_unmerged
-                    && !matches!(
-                        candidate.reason,
-                        PruneReason::BranchDeleted | PruneReason::Merged(_)
+                    _ => None,
+                };
+                let skip = within_grace_period.or_else(|| {
+                    skip_reason(
+                        wt,
+                        &candidate.branch_name,
+                        &candidate.reason,
+                        default_branch.as_deref(),
+                        &protected_patterns,
+                        &overrides,
                     )
-                {
-                    if let Some(ref branch) = default_branch {
-                        if let Ok(false) = wt.is_merged_into(branch) {
-                            skipped.push((
-                                candidate,
-                                "has unmerged commits, use --allow-unmerged to override"
-                                    .to_string(),
-                            ));
-                            return None;
-                        }
+                });
+                match skip {
+                    Some(reason) => {
+                        skipped.push((candidate, reason));
+                        None
                     }
+                    None if review_only => Some(PruneCandidate {
+                        delete_branch: self.delete_branch
+                            && repo
+                                .find_branch(&candidate.branch_name, BranchType::Local)
+                                .is_ok_and(|branch| !branch.is_head()),
+                        ..candidate
+                    }),
+                    None => Some(PruneCandidate {
+                        delete_branch: self.delete_branch
+                            && branch_is_deletable(
+                                &repo,
+                                wt,
+                                &candidate.branch_name,
+                                &candidate.reason,
+                                default_branch.as_deref(),
+                            ),
+                        ..candidate
+                    }),
                 }
-
-                Some(candidate)
             })
             .collect();
 
+        let (orphans, skipped_branches) = if self.orphaned_branches {
+            orphaned_branches(
+                &repo,
+                default_branch.as_deref(),
+                &protected_patterns,
+                &overrides,
+            )?
+        } else {
+            Default::default()
+        };
+
         if self.json {
             // JSON mode: skip confirmation, output structured result
             if !self.dry_run {
                 for candidate in &to_prune {
                     prune_worktree(&repo, candidate)?;
                 }
+                for orphan in &orphans {
+                    delete_orphan(&repo, orphan)?;
+                }
             }
 
-            let result = json!({
+            let mut result = json!({
                 "pruned": to_prune.iter().map(|c| json!({
                     "name": c.worktree_name,
                     "path": c.worktree_path.to_str(),
                     "branch": c.branch_name,
                     "reason": c.reason.to_string(),
+                    "branch_deleted": c.delete_branch,
                 })).collect::<Vec<_>>(),
                 "skipped": skipped.iter().map(|(c, reason)| json!({
                     "name": c.worktree_name,
@@ -263,6 +280,16 @@ impl Run for Prune {
                 })).collect::<Vec<_>>(),
                 "dry_run": self.dry_run,
             });
+            if self.orphaned_branches {
+                result["branches"] = orphans
+                    .iter()
+                    .map(|o| json!({ "branch": o.name, "reason": o.reason }))
+                    .collect();
+                result["skipped_branches"] = skipped_branches
+                    .iter()
+                    .map(|o| json!({ "branch": o.name, "reason": o.reason }))
+                    .collect();
+            }
 
             let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
             println!("{}", output);
@@ -281,21 +308,40 @@ impl Run for Prune {
             }
             eprintln!();
         }
+        if !skipped_branches.is_empty() {
+            output::notice("Skipped branches (unsafe to delete):");
+            for orphan in &skipped_branches {
+                output::detail(&format!("  {} ({})", orphan.name, orphan.reason));
+            }
+            eprintln!();
+        }
 
-        if to_prune.is_empty() {
-            output::status("No worktrees to prune");
+        if to_prune.is_empty() && orphans.is_empty() {
+            if self.orphaned_branches {
+                output::status("No worktrees or branches to prune");
+            } else {
+                output::status("No worktrees to prune");
+            }
             return Ok(None);
         }
 
         // Display what will be pruned
-        output::info("Worktrees to prune:");
-        for candidate in &to_prune {
-            output::detail(&format!(
-                "  {} (branch: {}, reason: {})",
-                candidate.worktree_path.display(),
-                candidate.branch_name,
-                candidate.reason
-            ));
+        if !to_prune.is_empty() {
+            output::info("Worktrees to prune:");
+            for candidate in &to_prune {
+                output::detail(&format!(
+                    "  {} (branch: {}, reason: {})",
+                    candidate.worktree_path.display(),
+                    candidate.branch_name,
+                    candidate.reason
+                ));
+            }
+        }
+        if !orphans.is_empty() {
+            output::info("Branches to delete:");
+            for orphan in &orphans {
+                output::detail(&format!("  {} ({})", orphan.name, orphan.reason));
+            }
         }
 
         if self.dry_run {
@@ -305,8 +351,16 @@ impl Run for Prune {
 
         // Confirm with user unless --yes flag is set
         if !self.yes {
+            let prompt = match (to_prune.len(), orphans.len()) {
+                (worktrees, 0) => format!("Prune {} worktree(s)?", worktrees),
+                (0, branches) => format!("Delete {} branch(es)?", branches),
+                (worktrees, branches) => format!(
+                    "Prune {} worktree(s) and delete {} branch(es)?",
+                    worktrees, branches
+                ),
+            };
             let confirmed = Confirm::new()
-                .with_prompt(format!("Prune {} worktree(s)?", to_prune.len()))
+                .with_prompt(prompt)
                 .default(false)
                 .interact()
                 .into_diagnostic()?;
@@ -321,17 +375,26 @@ impl Run for Prune {
         for candidate in &to_prune {
             prune_worktree(&repo, candidate)?;
         }
+        for orphan in &orphans {
+            delete_orphan(&repo, orphan)?;
+        }
 
-        output::success(&format!("Pruned {} worktree(s)", to_prune.len()));
+        if !to_prune.is_empty() {
+            output::success(&format!("Pruned {} worktree(s)", to_prune.len()));
+        }
+        if !orphans.is_empty() {
+            output::success(&format!("Deleted {} branch(es)", orphans.len()));
+        }
         Ok(None)
     }
 }
 
 #[derive(Debug)]
-enum PruneReason {
+pub(crate) enum PruneReason {
     BranchDeleted,
     RemoteGone,
     Merged(String),
+    PullRequest(u32),
     Explicit,
 }
 
@@ -341,20 +404,187 @@ impl std::fmt::Display for PruneReason {
             PruneReason::BranchDeleted => write!(f, "branch deleted"),
             PruneReason::RemoteGone => write!(f, "remote gone"),
             PruneReason::Merged(target) => write!(f, "merged into {}", target),
+            PruneReason::PullRequest(number) => write!(f, "PR #{}", number),
             PruneReason::Explicit => write!(f, "explicitly requested"),
         }
     }
 }
 
+impl PruneReason {
+    /// Whether the worktree was named on the command line rather than discovered.
+    pub(crate) fn is_explicit(&self) -> bool {
+        matches!(self, PruneReason::Explicit)
+    }
+}
+
 struct PruneCandidate {
     worktree_name: String,
     worktree_path: std::path::PathBuf,
     branch_name: String,
     reason: PruneReason,
+    /// Whether the local branch goes too; decided after the safety checks
+    delete_branch: bool,
+}
+
+/// A local branch that no worktree has checked out.
+struct OrphanBranch {
+    name: String,
+    /// Why deleting it loses nothing, or why it's skipped
+    reason: String,
+}
+
+/// Safety checks that `--force`, `--allow-dirty` and `--allow-unmerged` switch off.
+#[derive(Debug, Default)]
+pub(crate) struct SafetyOverrides {
+    pub force: bool,
+    pub allow_dirty: bool,
+    pub allow_unmerged: bool,
+}
+
+/// The workon.pruneGracePeriod window, with the creation times it's checked against.
+pub(crate) struct GracePeriod {
+    period: Duration,
+    created: HashMap<String, i64>,
+}
+
+impl GracePeriod {
+    /// Returns None if no grace period is configured.
+    pub(crate) fn load(repo: &git2::Repository, config: &WorkonConfig) -> Result<Option<Self>> {
+        let Some(period) = config.prune_grace_period()? else {
+            return Ok(None);
+        };
+        let created = VisitStore::new(repo)
+            .usage()?
+            .into_iter()
+            .filter_map(|u| Some((u.worktree, u.created?)))
+            .collect();
+        Ok(Some(Self { period, created }))
+    }
+
+    /// Returns why a worktree is too new to prune, or None if it's outside the window.
+    pub(crate) fn protects(&self, wt: &WorktreeDescriptor) -> Option<String> {
+        let created = wt
+            .name()
+            .and_then(|name| self.created.get(name).copied())
+            .or_else(|| wt.created_at().ok().flatten())?;
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs() as i64;
+        if now - created >= self.period.as_secs() as i64 {
+            return None;
+        }
+        debug!(
+            "'{}': skipped (within grace period)",
+            wt.name().unwrap_or("")
+        );
+        Some(format!(
+            "created {}, within workon.pruneGracePeriod ({})",
+            format_relative_time(created),
+            format_duration(self.period.as_secs() as i64)
+        ))
+    }
+}
+
+/// Returns why a worktree matches the prune filters, or None if it doesn't.
+///
+/// A deleted branch always matches. With `gone`, a branch whose upstream is gone matches;
+/// with `merged` (empty = default branch), a branch merged into that target matches.
+pub(crate) fn prune_reason(
+    repo: &git2::Repository,
+    wt: &WorktreeDescriptor,
+    branch_name: &str,
+    gone: bool,
+    merged: Option<&str>,
+) -> Option<PruneReason> {
+    // Check if the branch still exists in the main repo
+    let branch_exists = repo.find_branch(branch_name, BranchType::Local).is_ok();
+
+    if !branch_exists {
+        // Branch is deleted - always prune
+        debug!("'{}': branch deleted, candidate for pruning", branch_name);
+        Some(PruneReason::BranchDeleted)
+    } else if gone {
+        // Branch exists - check if upstream is gone (only if --gone flag is set)
+        match is_upstream_gone(repo, branch_name) {
+            Ok(true) => {
+                debug!("'{}': upstream gone, candidate for pruning", branch_name);
+                Some(PruneReason::RemoteGone)
+            }
+            _ => None,
+        }
+    } else if let Some(merged_target) = merged {
+        // Branch exists - check if merged into target (only if --merged flag is set)
+        let target_branch = if merged_target.is_empty() {
+            // Use default branch; can't check without one
+            get_default_branch(repo).ok()?
+        } else {
+            merged_target.to_string()
+        };
+
+        match wt.is_merged_into(&target_branch) {
+            Ok(true) => Some(PruneReason::Merged(target_branch)),
+            _ => None,
+        }
+    } else {
+        debug!("'{}': no prune criteria matched, skipping", branch_name);
+        None
+    }
+}
+
+/// Returns why a prune candidate is unsafe to remove, or None if it can be pruned.
+pub(crate) fn skip_reason(
+    wt: &WorktreeDescriptor,
+    branch_name: &str,
+    reason: &PruneReason,
+    default_branch: Option<&str>,
+    protected_patterns: &[String],
+    overrides: &SafetyOverrides,
+) -> Option<String> {
+    if overrides.force {
+        return None;
+    }
+
+    // Check if branch is protected
+    if is_protected(branch_name, protected_patterns) {
+        debug!("'{}': skipped (protected branch)", branch_name);
+        return Some("protected by workon.pruneProtectedBranches".to_string());
+    }
+
+    // Never prune the default worktree
+    if default_branch == Some(branch_name) {
+        return Some("is the default worktree".to_string());
+    }
+
+    // Check for uncommitted changes
+    if !overrides.allow_dirty {
+        match wt.is_dirty() {
+            Ok(true) => {
+                return Some("has uncommitted changes, use --allow-dirty to override".to_string())
+            }
+            Err(_) => return Some("could not check status".to_string()),
+            _ => {}
+        }
+    }
+
+    // Check for unmerged commits into the default branch.
+    // Skip if branch is already deleted (deletion implies work was handled)
+    // or if --merged already verified it's merged into the specified target.
+    if !overrides.allow_unmerged
+        && !matches!(reason, PruneReason::BranchDeleted | PruneReason::Merged(_))
+    {
+        if let Some(branch) = default_branch {
+            if let Ok(false) = wt.is_merged_into(branch) {
+                return Some("has unmerged commits, use --allow-unmerged to override".to_string());
+            }
+        }
+    }
+
+    None
 }
 
 /// Check if a branch has an upstream that no longer exists (is "gone")
-fn is_upstream_gone(repo: &git2::Repository, branch_name: &str) -> Result<bool> {
+pub(crate) fn is_upstream_gone(repo: &git2::Repository, branch_name: &str) -> Result<bool> {
     // Find the local branch
     let branch = match repo.find_branch(branch_name, BranchType::Local) {
         Ok(b) => b,
@@ -385,7 +615,181 @@ fn is_upstream_gone(repo: &git2::Repository, branch_name: &str) -> Result<bool>
     }
 }
 
+/// Local branches that no worktree has checked out: those safe to delete, and those
+/// skipped.
+///
+/// A branch is safe to delete once it's merged into the default branch, or its upstream
+/// has all of its commits. The branch the bare repository's HEAD points to is never a
+/// candidate.
+fn orphaned_branches(
+    repo: &git2::Repository,
+    default_branch: Option<&str>,
+    protected_patterns: &[String],
+    overrides: &SafetyOverrides,
+) -> Result<(Vec<OrphanBranch>, Vec<OrphanBranch>)> {
+    let checked_out = checked_out_branches(repo)?;
+    let default_tip = default_branch
+        .and_then(|name| repo.find_branch(name, BranchType::Local).ok())
+        .and_then(|branch| branch.get().target());
+    let contains = |tip: git2::Oid, oid: git2::Oid| {
+        tip == oid || repo.graph_descendant_of(tip, oid).unwrap_or(false)
+    };
+
+    let mut orphans = Vec::new();
+    let mut skipped = Vec::new();
+    for branch in repo.branches(Some(BranchType::Local)).into_diagnostic()? {
+        let (branch, _) = branch.into_diagnostic()?;
+        let Some(name) = branch.name().ok().flatten().map(str::to_string) else {
+            continue;
+        };
+        if branch.is_head() || checked_out.contains(&name) {
+            continue;
+        }
+
+        if !overrides.force {
+            if is_protected(&name, protected_patterns) {
+                debug!("'{}': orphaned, but protected", name);
+                skipped.push(OrphanBranch {
+                    name,
+                    reason: "protected by workon.pruneProtectedBranches".to_string(),
+                });
+                continue;
+            }
+            if default_branch == Some(name.as_str()) {
+                skipped.push(OrphanBranch {
+                    name,
+                    reason: "is the default branch".to_string(),
+                });
+                continue;
+            }
+        }
+
+        let Some(oid) = branch.get().target() else {
+            continue;
+        };
+        let upstream = branch.upstream().ok().and_then(|upstream| {
+            let tip = upstream.get().target()?;
+            Some((upstream.name().ok().flatten()?.to_string(), tip))
+        });
+        let reason = match (default_branch, default_tip, upstream) {
+            (Some(target), Some(tip), _) if target != name && contains(tip, oid) => {
+                format!("merged into {}", target)
+            }
+            (_, _, Some((upstream, tip))) if contains(tip, oid) => {
+                format!("pushed to {}", upstream)
+            }
+            _ if overrides.force || overrides.allow_unmerged => "unmerged".to_string(),
+            _ => {
+                skipped.push(OrphanBranch {
+                    name,
+                    reason: "has unmerged, unpushed commits, use --allow-unmerged to override"
+                        .to_string(),
+                });
+                continue;
+            }
+        };
+        debug!("'{}': orphaned, {}", name, reason);
+        orphans.push(OrphanBranch { name, reason });
+    }
+    Ok((orphans, skipped))
+}
+
+/// Branches checked out in any worktree. Read from each worktree's HEAD in the git
+/// directory, so a worktree whose directory is missing still counts.
+fn checked_out_branches(repo: &git2::Repository) -> Result<HashSet<String>> {
+    let admin = repo.commondir().join("worktrees");
+    Ok(repo
+        .worktrees()
+        .into_diagnostic()?
+        .iter()
+        .flatten()
+        .filter_map(|name| std::fs::read_to_string(admin.join(name).join("HEAD")).ok())
+        .filter_map(|head| {
+            head.trim()
+                .strip_prefix("ref: refs/heads/")
+                .map(str::to_string)
+        })
+        .collect())
+}
+
+fn delete_orphan(repo: &git2::Repository, orphan: &OrphanBranch) -> Result<()> {
+    let mut branch = repo
+        .find_branch(&orphan.name, BranchType::Local)
+        .into_diagnostic()?;
+    branch.delete().into_diagnostic()?;
+    output::detail(&format!("  Deleted branch {}", orphan.name));
+    Ok(())
+}
+
+/// Schema of `--json` output.
+fn json_schema() -> serde_json::Value {
+    let candidate = |reason: &str| {
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "description": "Worktree name" },
+                "path": { "type": ["string", "null"], "description": "Worktree path" },
+                "branch": { "type": "string", "description": "Branch checked out in the worktree" },
+                "reason": { "type": "string", "description": reason },
+            },
+            "required": ["name", "path", "branch", "reason"],
+        })
+    };
+    let branch = |reason: &str| {
+        json!({
+            "type": "object",
+            "properties": {
+                "branch": { "type": "string", "description": "Local branch name" },
+                "reason": { "type": "string", "description": reason },
+            },
+            "required": ["branch", "reason"],
+        })
+    };
+    let mut pruned = candidate("Why the worktree is a candidate");
+    pruned["properties"]["branch_deleted"] = json!({
+        "type": "boolean",
+        "description": "Whether the local branch is deleted along with the worktree",
+    });
+    pruned["required"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!("branch_deleted"));
+    json!({
+        "type": "object",
+        "properties": {
+            "pruned": {
+                "type": "array",
+                "description": "Worktrees pruned, or that would be with --dry-run",
+                "items": pruned,
+            },
+            "skipped": {
+                "type": "array",
+                "description": "Candidates left alone because pruning them could lose work",
+                "items": candidate("Why the worktree was skipped"),
+            },
+            "dry_run": { "type": "boolean" },
+            "branches": {
+                "type": "array",
+                "description": "With --orphaned-branches: branches without a worktree deleted, or that would be with --dry-run",
+                "items": branch("Why deleting the branch loses nothing"),
+            },
+            "skipped_branches": {
+                "type": "array",
+                "description": "With --orphaned-branches: branches without a worktree left alone",
+                "items": branch("Why the branch was skipped"),
+            },
+        },
+        "required": ["pruned", "skipped", "dry_run"],
+    })
+}
+
 fn prune_worktree(repo: &git2::Repository, candidate: &PruneCandidate) -> Result<()> {
+    // Read before the branch (and its config) can go
+    let tracked_remote = repo
+        .config()
+        .and_then(|config| config.get_string(&format!("branch.{}.remote", candidate.branch_name)))
+        .ok();
+
     // Remove the worktree directory first
     if candidate.worktree_path.exists() {
         std::fs::remove_dir_all(&candidate.worktree_path).into_diagnostic()?;
@@ -400,11 +804,50 @@ fn prune_worktree(repo: &git2::Repository, candidate: &PruneCandidate) -> Result
     worktree.prune(Some(&mut opts)).into_diagnostic()?;
 
     output::success(&format!("  Pruned {}", candidate.worktree_path.display()));
+
+    if candidate.delete_branch {
+        if let Ok(mut branch) = repo.find_branch(&candidate.branch_name, BranchType::Local) {
+            branch.delete().into_diagnostic()?;
+            output::detail(&format!("  Deleted branch {}", candidate.branch_name));
+        }
+    }
+
+    if let Some(remote) = tracked_remote {
+        if unused_fork_remotes(repo)?.contains(&remote) {
+            remove_fork_remote(repo, &remote)?;
+            output::detail(&format!("  Removed fork remote {}", remote));
+        }
+    }
     Ok(())
 }
 
+/// Whether a candidate's local branch exists and is merged, so deleting it loses nothing.
+///
+/// A branch found by `--merged` is checked against that target; any other against the
+/// default branch. The branch the bare repository's HEAD points to is always kept.
+fn branch_is_deletable(
+    repo: &git2::Repository,
+    wt: &WorktreeDescriptor,
+    branch_name: &str,
+    reason: &PruneReason,
+    default_branch: Option<&str>,
+) -> bool {
+    match repo.find_branch(branch_name, BranchType::Local) {
+        Ok(branch) if !branch.is_head() => {}
+        _ => return false,
+    }
+    let target = match reason {
+        PruneReason::Merged(target) => target.as_str(),
+        _ => match default_branch {
+            Some(branch) => branch,
+            None => return false,
+        },
+    };
+    wt.is_merged_into(target).unwrap_or(false)
+}
+
 /// Check if a branch name matches any of the protection patterns
-fn is_protected(branch_name: &str, patterns: &[String]) -> bool {
+pub(crate) fn is_protected(branch_name: &str, patterns: &[String]) -> bool {
     for pattern in patterns {
         if glob_match(pattern, branch_name) {
             return true;
@@ -413,6 +856,14 @@ fn is_protected(branch_name: &str, patterns: &[String]) -> bool {
     false
 }
 
+/// Returns the first protection pattern matching a branch name
+pub(crate) fn protected_by<'a>(branch_name: &str, patterns: &'a [String]) -> Option<&'a str> {
+    patterns
+        .iter()
+        .find(|pattern| glob_match(pattern, branch_name))
+        .map(String::as_str)
+}
+
 /// Simple glob pattern matching supporting * and ? wildcards
 fn glob_match(pattern: &str, text: &str) -> bool {
     // Exact match
@@ -439,5 +890,6 @@ fn glob_match(pattern: &str, text: &str) -> bool {
             && text[..text.len() - suffix.len()].ends_with('/');
     }
 
-    false
+    // Anything else, e.g. "spike-*" from a workon.rule
+    glob::Pattern::new(pattern).is_ok_and(|p| p.matches(text))
 }
@@ -0,0 +1,126 @@
+//! Relocate command - move the whole workon layout to a new directory.
+//!
+//! CLI wrapper for the relocate_root library function (see git-workon-lib/src/relocate.rs
+//! for the move and rollback strategy).
+//!
+//! ```bash
+//! git workon relocate ~/src/renamed-project
+//! git workon relocate --dry-run /mnt/fast/project
+//! ```
+//!
+//! On success the new location of the current directory is printed, so the shell
+//! integration follows the move instead of leaving the shell in a deleted directory.
+//! Entries in the repository registry (`git workon repos`) that pointed at the old root
+//! are updated to the new one.
+
+use std::env;
+use std::path::Path;
+
+use miette::{Context, Result};
+use workon::{get_repo, plan_root_relocation, relocate_root, RepoRegistry, WorktreeDescriptor};
+
+use crate::cli::Relocate;
+use crate::output;
+
+use super::Run;
+
+impl Run for Relocate {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let plan = plan_root_relocation(&repo, &self.new_root)?;
+
+        if self.dry_run {
+            println!(
+                "Would move workon root '{}' to '{}'",
+                plan.from.display(),
+                plan.to.display()
+            );
+            println!(
+                "  Bare: {} → {}",
+                plan.git_dir_from.display(),
+                plan.git_dir_to.display()
+            );
+            for wt in &plan.worktrees {
+                if wt.is_external() {
+                    println!("  {}: {} (relinked)", wt.name, wt.from.display());
+                } else {
+                    println!("  {}: {} → {}", wt.name, wt.from.display(), wt.to.display());
+                }
+            }
+            return Ok(None);
+        }
+
+        // Resolve before the move; the current directory may be inside the root
+        let cwd = env::current_dir().ok();
+        let registered = registered_names(&plan.from);
+        drop(repo);
+
+        relocate_root(&plan).wrap_err(format!(
+            "Failed to relocate workon root to '{}'",
+            plan.to.display()
+        ))?;
+
+        output::success(&format!(
+            "Moved workon root to {} ({} worktrees relinked)",
+            plan.to.display(),
+            plan.worktrees.len()
+        ));
+        update_registry(&registered, &plan.to);
+
+        let target = cwd
+            .and_then(|cwd| {
+                cwd.strip_prefix(&plan.from)
+                    .ok()
+                    .map(|rel| plan.to.join(rel))
+            })
+            .filter(|path| path.is_dir())
+            .unwrap_or_else(|| plan.to.clone());
+        println!("{}", target.display());
+        Ok(None)
+    }
+}
+
+/// Names the registry gives the workon root at `root`.
+fn registered_names(root: &Path) -> Vec<String> {
+    let (Ok(registry), Ok(root)) = (RepoRegistry::load(), root.canonicalize()) else {
+        return Vec::new();
+    };
+    registry
+        .iter()
+        .filter(|(_, path)| path.canonicalize().is_ok_and(|path| path == root))
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Point the registry entries in `names` at `new_root`, or tell the user how to.
+fn update_registry(names: &[String], new_root: &Path) {
+    if names.is_empty() {
+        return;
+    }
+    let updated = RepoRegistry::load().and_then(|mut registry| {
+        for name in names {
+            registry.insert(name, new_root);
+        }
+        registry.save()
+    });
+    match updated {
+        Ok(()) => {
+            for name in names {
+                output::detail(&format!(
+                    "  Updated registered repository '{}' → {}",
+                    name,
+                    new_root.display()
+                ));
+            }
+        }
+        Err(e) => {
+            output::warn(&format!("Could not update the repository registry: {}", e));
+            for name in names {
+                output::notice(&format!(
+                    "Run 'git workon repos remove {name} && git workon repos add {} --name {name}'",
+                    new_root.display()
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Repos command - manage the repository registry.
+//!
+//! The registry (see git-workon-lib/src/registry.rs) names workon roots so they can be
+//! targeted from anywhere with `-R <name>` and listed together with `list --all-repos`:
+//!
+//! ```bash
+//! git workon repos add                    # register the current repository
+//! git workon repos add ~/src/api --name api
+//! git workon repos list
+//! git workon repos remove api
+//! ```
+//!
+//! `add` accepts the workon root or any worktree in it and registers the root. It also runs
+//! doctor's worktree checks and reports anything that needs attention; problems don't
+//! prevent registration.
+
+use miette::{miette, IntoDiagnostic, Result};
+use serde_json::json;
+use workon::{get_repo, workon_root, RegistryError, RepoRegistry, WorktreeDescriptor};
+
+use crate::cli::{Repos, ReposAdd, ReposCmd, ReposList, ReposRemove};
+use crate::output::{self, style};
+
+use super::doctor::worktree_problems;
+use super::Run;
+
+impl Run for Repos {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        match &self.command {
+            ReposCmd::Add(cmd) => cmd.run(),
+            ReposCmd::Remove(cmd) => cmd.run(),
+            ReposCmd::List(cmd) => cmd.run(),
+        }
+    }
+}
+
+impl Run for ReposAdd {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(self.path.clone())?;
+        let root = workon_root(&repo)?.canonicalize().into_diagnostic()?;
+        let name = match &self.name {
+            Some(name) => name.clone(),
+            None => root
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .ok_or_else(|| miette!("Cannot derive a name from {}", root.display()))?,
+        };
+
+        let mut registry = RepoRegistry::load()?;
+        match registry.get(&name) {
+            Some(existing) if existing == root => {
+                output::notice(&format!("'{}' is already registered", name));
+                return Ok(None);
+            }
+            Some(existing) => {
+                return Err(RegistryError::NameTaken {
+                    name,
+                    path: existing.to_path_buf(),
+                }
+                .into());
+            }
+            None => {}
+        }
+        registry.insert(&name, root.clone());
+        registry.save()?;
+        output::success(&format!("Registered '{}' → {}", name, root.display()));
+
+        let problems = worktree_problems(&repo)?;
+        if !problems.is_empty() {
+            for (worktree, problem) in &problems {
+                output::warn(&format!("{}: {}", worktree, problem));
+            }
+            output::notice(&format!("Run 'git workon -R {} doctor' for details", name));
+        }
+        Ok(None)
+    }
+}
+
+impl Run for ReposRemove {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let mut registry = RepoRegistry::load()?;
+        registry.resolve(&self.name)?;
+        registry.remove(&self.name);
+        registry.save()?;
+        output::success(&format!("Unregistered '{}'", self.name));
+        Ok(None)
+    }
+}
+
+impl Run for ReposList {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let registry = RepoRegistry::load()?;
+
+        if self.json {
+            let repos: Vec<_> = registry
+                .iter()
+                .map(|(name, root)| {
+                    json!({
+                        "name": name,
+                        "path": root.to_str(),
+                        "exists": root.is_dir(),
+                    })
+                })
+                .collect();
+            let output = serde_json::to_string_pretty(&repos).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        if registry.is_empty() {
+            output::notice(&format!(
+                "No repositories registered in {}",
+                registry.path().display()
+            ));
+            return Ok(None);
+        }
+
+        let width = registry
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (name, root) in registry.iter() {
+            let missing = if root.is_dir() {
+                String::new()
+            } else {
+                format!("  {}", style::red("(missing)"))
+            };
+            println!(
+                "{}{}  {}{}",
+                style::bold(name),
+                " ".repeat(width - name.chars().count()),
+                style::dim(&root.display().to_string()),
+                missing,
+            );
+        }
+        Ok(None)
+    }
+}
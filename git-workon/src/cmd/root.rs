@@ -0,0 +1,43 @@
+//! Root command - print the workon layout's location.
+//!
+//! Lets scripts, shell prompts and editor plugins find the layout without reimplementing
+//! repository discovery. Works from anywhere inside the root or any of its worktrees:
+//!
+//! ```bash
+//! git workon root          # /home/me/src/project
+//! git workon root --bare   # /home/me/src/project/.bare
+//! git workon root --json   # {"root": "...", "bare": "..."}
+//! ```
+
+use std::path::PathBuf;
+
+use miette::{IntoDiagnostic, Result};
+use serde_json::json;
+use workon::{get_repo, workon_root, WorktreeDescriptor};
+
+use crate::cli::Root;
+
+use super::Run;
+
+impl Run for Root {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let root = workon_root(&repo)?;
+        // Re-collect the components to drop the trailing slash git2 leaves on repo paths
+        let bare: PathBuf = repo.path().components().collect();
+
+        if self.json {
+            let result = json!({
+                "root": root.to_str(),
+                "bare": bare.to_str(),
+            });
+            let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        let path = if self.bare { bare.as_path() } else { root };
+        println!("{}", path.display());
+        Ok(None)
+    }
+}
@@ -0,0 +1,112 @@
+//! Run command - named per-worktree tasks.
+//!
+//! Tasks are shell commands saved in config under `workon.task.<name>`, for chores that
+//! every branch needs now and then:
+//!
+//! ```bash
+//! git config workon.task.setup "npm ci && cp ../.env ."
+//! git config workon.task.reset-db "cwd=root ./scripts/reset-db.sh"
+//!
+//! git workon run setup                 # in the current worktree
+//! git workon run setup feature main    # in each named worktree, in turn
+//! git workon run                       # list the configured tasks
+//! ```
+//!
+//! A task runs exactly like a post-create hook (see src/hooks.rs): with workon.hookShell,
+//! the `WORKON_WORKTREE_PATH` and `WORKON_BRANCH_NAME` variables, `cwd=root`/`cwd=base`
+//! prefixes, and workon.hookTimeout. Its output is prefixed with the worktree's name.
+//!
+//! Worktree names resolve like everywhere else (exact, then fuzzy). When a task fails in
+//! one of several worktrees, the rest still run and the command fails at the end.
+
+use miette::{bail, Result};
+use workon::{
+    current_worktree, did_you_mean, find_worktree_fuzzy, get_repo, PostCreateHook, WorkonConfig,
+    WorktreeDescriptor,
+};
+
+use crate::cli::RunTask;
+use crate::hooks::{hook_dir, run_shell};
+use crate::output;
+
+use super::Run;
+
+impl Run for RunTask {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let config = WorkonConfig::new(&repo)?;
+        let tasks = config.tasks()?;
+
+        let Some(name) = &self.task else {
+            if tasks.is_empty() {
+                output::notice(
+                    "No tasks configured; add one with: git config workon.task.<name> <command>",
+                );
+                return Ok(None);
+            }
+            let width = tasks.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+            for (name, command) in &tasks {
+                println!("{:width$}  {}", name, command);
+            }
+            return Ok(None);
+        };
+
+        // Git lowercases config variable names, so task names are case-insensitive
+        let name = name.to_lowercase();
+        let Some((_, entry)) = tasks.iter().find(|(task, _)| *task == name) else {
+            let suggestion = did_you_mean(&name, tasks.iter().map(|(task, _)| task.as_str()));
+            match suggestion {
+                Some(suggestion) => bail!(
+                    help = format!("Did you mean '{suggestion}'?"),
+                    "No task named '{}'",
+                    name
+                ),
+                None => bail!(
+                    help = "Define it with: git config workon.task.<name> <command>",
+                    "No task named '{}'",
+                    name
+                ),
+            }
+        };
+        let task: PostCreateHook = entry.parse()?;
+
+        let worktrees = if self.worktrees.is_empty() {
+            vec![current_worktree(&repo)?]
+        } else {
+            self.worktrees
+                .iter()
+                .map(|query| find_worktree_fuzzy(&repo, query))
+                .collect::<workon::Result<Vec<_>>>()?
+        };
+
+        let what = format!("Task '{}'", name);
+        let mut failed = Vec::new();
+        for wt in &worktrees {
+            let label = wt.name().unwrap_or_default();
+            output::info(&format!("Running {} in {}: {}", name, label, entry));
+            // A task that can't even start fails for this worktree only
+            let result = hook_dir(&repo, wt, None, task.dir)
+                .and_then(|dir| run_shell(&config, wt, None, &dir, &task.command, label))
+                .and_then(|result| result.check(&what, config.hook_timeout()?));
+            match result {
+                Ok(_) => output::success(&format!("✓ {} finished in {}", name, label)),
+                Err(e) if worktrees.len() > 1 => {
+                    output::warn(&format!("{}: {}", label, e));
+                    failed.push(label);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        if !failed.is_empty() {
+            bail!(
+                "{} failed in {} of {} worktrees: {}",
+                what,
+                failed.len(),
+                worktrees.len(),
+                failed.join(", ")
+            );
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,37 @@
+//! Set-upstream command - track a remote branch after the fact.
+//!
+//! Branches created before `new --push`, or adopted from a leftover directory, have no
+//! upstream, so `list`/`find` can't show them as ahead, behind or gone and `prune --gone`
+//! never finds them:
+//!
+//! ```bash
+//! git workon set-upstream feature                  # track origin/feature
+//! git workon set-upstream feature upstream/feature # track a specific remote branch
+//! git workon set-upstream feature --push           # publish it first
+//! ```
+//!
+//! Without `--push` the remote branch has to exist already; it's fetched so the
+//! ahead/behind counts are current. See `set_upstream` in git-workon-lib/src/worktree.rs.
+
+use miette::Result;
+use workon::{find_worktree_fuzzy, get_repo, set_upstream, WorktreeDescriptor};
+
+use crate::cli::SetUpstream;
+use crate::output;
+
+use super::Run;
+
+impl Run for SetUpstream {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let wt = find_worktree_fuzzy(&repo, &self.name)?;
+        let upstream = set_upstream(&wt, self.upstream.as_deref(), self.push)?;
+        let branch = wt.branch()?.unwrap_or_default();
+
+        if self.push {
+            output::success(&format!("Pushed '{}' to {}", branch, upstream));
+        }
+        output::success(&format!("'{}' now tracks {}", branch, upstream));
+        Ok(None)
+    }
+}
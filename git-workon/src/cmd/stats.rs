@@ -0,0 +1,186 @@
+//! Stats command - summarize the workspace.
+//!
+//! Without flags, prints a one-line overview of the worktrees and their state:
+//!
+//! ```text
+//! 7 worktrees: 2 dirty, 1 ahead, 0 behind, 1 gone upstream, 1 hidden
+//! ```
+//!
+//! ## Usage Report
+//!
+//! `--usage` summarizes the visit history (see git-workon-lib/src/visits.rs): how often each
+//! worktree was switched to, the average time between visits, and which worktrees were
+//! created but never revisited, to help pick a pruning policy:
+//!
+//! ```text
+//! Worktree usage (42 events since 3 weeks ago)
+//!   feature-auth  12 visits  every 1 day    last visited 3 hours ago
+//!   main           8 visits  every 2 days   last visited 1 day ago
+//!   spike          0 visits                 created 2 weeks ago
+//!
+//! Never revisited after creation: spike
+//! ```
+//!
+//! Worktrees that have since been removed are still reported, marked `(removed)`.
+
+use miette::{IntoDiagnostic, Result};
+use serde_json::json;
+use unicode_width::UnicodeWidthStr;
+use workon::{get_repo, get_worktrees, VisitStore, WorkonSettings, WorktreeDescriptor};
+
+use crate::cli::Stats;
+use crate::display::{format_duration, format_relative_time};
+use crate::output::{self, style};
+
+use super::Run;
+
+impl Run for Stats {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        if self.usage {
+            return self.usage_report(&repo);
+        }
+
+        let worktrees = get_worktrees(&repo)?;
+        let settings = WorkonSettings::load(&repo)?;
+        let count = |check: fn(&WorktreeDescriptor) -> bool| {
+            worktrees.iter().filter(|wt| check(wt)).count()
+        };
+        let total = worktrees.len();
+        let dirty = count(|wt| wt.is_dirty().unwrap_or(false));
+        let ahead = count(|wt| wt.has_unpushed_commits().unwrap_or(false));
+        let behind = count(|wt| wt.is_behind_upstream().unwrap_or(false));
+        let gone = count(|wt| wt.has_gone_upstream().unwrap_or(false));
+        let hidden = worktrees
+            .iter()
+            .filter(|wt| wt.full_name().is_some_and(|name| settings.is_hidden(&name)))
+            .count();
+
+        if self.json {
+            let result = json!({
+                "worktrees": total,
+                "dirty": dirty,
+                "ahead": ahead,
+                "behind": behind,
+                "gone": gone,
+                "hidden": hidden,
+            });
+            let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        println!(
+            "{} worktree{}: {} dirty, {} ahead, {} behind, {} gone upstream, {} hidden",
+            total,
+            if total == 1 { "" } else { "s" },
+            dirty,
+            ahead,
+            behind,
+            gone,
+            hidden
+        );
+        Ok(None)
+    }
+}
+
+impl Stats {
+    fn usage_report(&self, repo: &git2::Repository) -> Result<Option<WorktreeDescriptor>> {
+        let store = VisitStore::new(repo);
+        let entries = store.entries()?;
+        let usage = store.usage()?;
+        let exists = |name: &str| repo.find_worktree(name).is_ok();
+        let never_revisited: Vec<&str> = usage
+            .iter()
+            .filter(|u| u.never_revisited())
+            .map(|u| u.worktree.as_str())
+            .collect();
+
+        if self.json {
+            let worktrees: Vec<_> = usage
+                .iter()
+                .map(|u| {
+                    json!({
+                        "name": u.worktree,
+                        "exists": exists(&u.worktree),
+                        "visits": u.visits,
+                        "created": u.created,
+                        "last_visit": u.last_visit,
+                        "average_interval_seconds": u.average_interval,
+                    })
+                })
+                .collect();
+            let result = json!({
+                "events": entries.len(),
+                "since": entries.first().map(|v| v.timestamp),
+                "worktrees": worktrees,
+                "never_revisited": never_revisited,
+            });
+            let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        let Some(first) = entries.first() else {
+            output::notice("No visits recorded yet; they're recorded by 'new' and 'find'");
+            return Ok(None);
+        };
+
+        println!(
+            "Worktree usage ({} events since {})",
+            entries.len(),
+            format_relative_time(first.timestamp)
+        );
+
+        let rows: Vec<[String; 4]> = usage
+            .iter()
+            .map(|u| {
+                let visits = match u.visits {
+                    1 => "1 visit".to_string(),
+                    n => format!("{} visits", n),
+                };
+                let interval = u
+                    .average_interval
+                    .filter(|_| u.visits > 0)
+                    .map(|secs| format!("every {}", format_duration(secs)))
+                    .unwrap_or_default();
+                let when = match (u.last_visit, u.created) {
+                    (Some(ts), _) => format!("last visited {}", format_relative_time(ts)),
+                    (None, Some(ts)) => format!("created {}", format_relative_time(ts)),
+                    (None, None) => String::new(),
+                };
+                [u.worktree.clone(), visits, interval, when]
+            })
+            .collect();
+        let width = |col: usize| rows.iter().map(|r| r[col].width()).max().unwrap_or(0);
+        let (name_w, visits_w, interval_w) = (width(0), width(1), width(2));
+
+        for (row, u) in rows.iter().zip(&usage) {
+            let removed = if exists(&u.worktree) {
+                String::new()
+            } else {
+                format!("  {}", style::dim("(removed)"))
+            };
+            println!(
+                "  {}{}  {}{}  {}{}  {}{}",
+                style::bold(&row[0]),
+                " ".repeat(name_w - row[0].width()),
+                " ".repeat(visits_w - row[1].width()),
+                row[1],
+                row[2],
+                " ".repeat(interval_w - row[2].width()),
+                style::dim(&row[3]),
+                removed
+            );
+        }
+
+        if !never_revisited.is_empty() {
+            println!();
+            println!(
+                "Never revisited after creation: {}",
+                never_revisited.join(", ")
+            );
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,74 @@
+//! Templates command - list the worktree templates `new --template` can apply.
+//!
+//! Templates are defined in git config (see git-workon-lib/src/template.rs):
+//!
+//! ```bash
+//! git config workon.template.hotfix.base release/current
+//! git config --add workon.template.hotfix.label hotfix
+//! git workon templates          # list templates
+//! git workon templates --json   # machine-readable list
+//! git workon new fix-login --template hotfix
+//! ```
+
+use miette::{IntoDiagnostic, Result};
+use serde_json::json;
+use workon::{get_repo, WorkonConfig, WorktreeDescriptor};
+
+use crate::cli::Templates;
+use crate::output;
+
+use super::Run;
+
+impl Run for Templates {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let config = WorkonConfig::new(&repo)?;
+        let templates = config.templates()?;
+
+        if self.json {
+            let result: Vec<_> = templates
+                .iter()
+                .map(|t| {
+                    json!({
+                        "name": t.name,
+                        "base": t.base,
+                        "copy_patterns": t.copy_patterns,
+                        "post_create_hooks": t.post_create_hooks,
+                        "sparse": t.sparse_paths,
+                        "labels": t.labels,
+                    })
+                })
+                .collect();
+            let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        if templates.is_empty() {
+            output::notice("No templates defined (set workon.template.<name>.<key> to add one)");
+            return Ok(None);
+        }
+
+        for (i, template) in templates.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            println!("{}", template.name);
+            if let Some(base) = &template.base {
+                println!("  base:           {}", base);
+            }
+            let lists = [
+                ("copyPattern", &template.copy_patterns),
+                ("postCreateHook", &template.post_create_hooks),
+                ("sparse", &template.sparse_paths),
+                ("label", &template.labels),
+            ];
+            for (key, values) in lists {
+                if !values.is_empty() {
+                    println!("  {:<15} {}", format!("{}:", key), values.join(", "));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
@@ -0,0 +1,137 @@
+//! Version command - report the version and capabilities of this build.
+//!
+//! `--version` prints just the version; `version` adds what integrations (editor plugins,
+//! shell wrappers) need to decide which features to use:
+//!
+//! ```bash
+//! git workon version          # human-readable report
+//! git workon version --json   # machine-readable report
+//! ```
+//!
+//! The report includes:
+//! - The workon version and the libgit2 version it was built against
+//! - Optional features and whether this build has them (`github-api`, `gix`, `daemon`)
+//! - External tools workon can use (`gh`, `fzf`, `tmux`), with their path and version
+//!   when found on PATH
+//! - The `workon.*` config schema version ([`workon::CONFIG_SCHEMA_VERSION`])
+//!
+//! ```json
+//! {
+//!   "version": "0.1.0",
+//!   "libgit2": "1.9.0",
+//!   "features": { "github-api": false, "gix": false, "daemon": false },
+//!   "tools": {
+//!     "gh": { "available": true, "path": "/usr/bin/gh", "version": "gh version 2.62.0 (2024-11-14)" },
+//!     "fzf": { "available": false, "path": null, "version": null },
+//!     "tmux": { "available": false, "path": null, "version": null }
+//!   },
+//!   "config_schema_version": 2
+//! }
+//! ```
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use miette::{IntoDiagnostic, Result};
+use serde_json::{json, Map, Value};
+use workon::{WorktreeDescriptor, CONFIG_SCHEMA_VERSION};
+
+use crate::cli::Version;
+
+use super::Run;
+
+/// Optional features and whether this build has them. None are implemented yet; they're
+/// listed so integrations can check for them without parsing version numbers.
+const FEATURES: &[(&str, bool)] = &[("github-api", false), ("gix", false), ("daemon", false)];
+
+/// External tools workon can use, with the argument that prints each one's version.
+const TOOLS: &[(&str, &str)] = &[("gh", "--version"), ("fzf", "--version"), ("tmux", "-V")];
+
+/// An external tool found on PATH.
+struct Tool {
+    path: PathBuf,
+    version: Option<String>,
+}
+
+impl Run for Version {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let (major, minor, patch) = git2::Version::get().libgit2_version();
+        let libgit2 = format!("{}.{}.{}", major, minor, patch);
+        let tools: Vec<(&str, Option<Tool>)> = TOOLS
+            .iter()
+            .map(|(name, version_arg)| (*name, find_tool(name, version_arg)))
+            .collect();
+
+        if self.json {
+            let features: Map<String, Value> = FEATURES
+                .iter()
+                .map(|(name, enabled)| (name.to_string(), json!(enabled)))
+                .collect();
+            let tools: Map<String, Value> = tools
+                .iter()
+                .map(|(name, tool)| {
+                    let value = json!({
+                        "available": tool.is_some(),
+                        "path": tool.as_ref().map(|t| t.path.to_string_lossy()),
+                        "version": tool.as_ref().and_then(|t| t.version.as_deref()),
+                    });
+                    (name.to_string(), value)
+                })
+                .collect();
+            let result = json!({
+                "version": env!("CARGO_PKG_VERSION"),
+                "libgit2": libgit2,
+                "features": features,
+                "tools": tools,
+                "config_schema_version": CONFIG_SCHEMA_VERSION,
+            });
+            let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        println!("git-workon {}", env!("CARGO_PKG_VERSION"));
+        println!("libgit2 {}", libgit2);
+        println!("config schema {}", CONFIG_SCHEMA_VERSION);
+        println!();
+        println!("Features:");
+        for (name, enabled) in FEATURES {
+            println!("  {:<12} {}", name, if *enabled { "yes" } else { "no" });
+        }
+        println!();
+        println!("Tools:");
+        for (name, tool) in &tools {
+            match tool {
+                Some(tool) => println!(
+                    "  {:<12} {}{}",
+                    name,
+                    tool.path.display(),
+                    tool.version
+                        .as_deref()
+                        .map(|v| format!(" ({})", v))
+                        .unwrap_or_default()
+                ),
+                None => println!("  {:<12} not found", name),
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Look `name` up on PATH and ask it for its version (the first line it prints).
+fn find_tool(name: &str, version_arg: &str) -> Option<Tool> {
+    let path = std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join(name))
+        .find(|path| path.is_file())?;
+    let version = Command::new(&path)
+        .arg(version_arg)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            stdout.lines().next().map(|line| line.trim().to_string())
+        })
+        .filter(|line| !line.is_empty());
+    Some(Tool { path, version })
+}
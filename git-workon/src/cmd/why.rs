@@ -0,0 +1,332 @@
+//! Why command - explain prune eligibility for a single worktree.
+//!
+//! Evaluates every criterion `prune` looks at for one worktree and prints the result of
+//! each, followed by what each form of `git workon prune` would do with it:
+//!
+//! ```text
+//! feature (branch: feature)
+//!   branch: exists
+//!   upstream: origin/feature (gone)
+//!   merged into main: yes
+//!   uncommitted changes: no
+//!   unpushed commits: yes
+//!   protected: no
+//!   default worktree: no
+//!   locked: no
+//!   last activity: 12 days ago
+//!
+//! Verdict:
+//!   git workon prune            keep (no prune criteria matched)
+//!   git workon prune --gone     prune (remote gone)
+//!   git workon prune --merged   prune (merged into main)
+//!   git workon prune feature    prune (explicitly requested)
+//! ```
+//!
+//! Verdicts reuse prune's own selection and safety checks, so they assume no override
+//! flags (`--force`, `--allow-dirty`, `--allow-unmerged`).
+
+use miette::{IntoDiagnostic, Result};
+use serde_json::{json, Value};
+use workon::{find_worktree_fuzzy, get_default_branch, get_repo, WorkonConfig, WorktreeDescriptor};
+
+use crate::cli::Why;
+use crate::output;
+
+use super::prune::{
+    protected_by, prune_reason, skip_reason, GracePeriod, PruneReason, SafetyOverrides,
+};
+use super::Run;
+
+impl Run for Why {
+    fn run(&self) -> Result<Option<WorktreeDescriptor>> {
+        let repo = get_repo(None)?;
+        let config = WorkonConfig::new(&repo)?;
+        let protected_patterns = config.prune_protected_branches()?;
+        let default_branch = get_default_branch(&repo).ok();
+        let wt = find_worktree_fuzzy(&repo, &self.name)?;
+        let name = wt.name().unwrap_or(&self.name).to_string();
+        let branch = wt.branch().ok().flatten();
+
+        let branch_exists = branch
+            .as_ref()
+            .map(|b| repo.find_branch(b, git2::BranchType::Local).is_ok());
+        let upstream = wt.remote_branch().ok().flatten();
+        let upstream_gone = wt.has_gone_upstream().unwrap_or(false);
+        let merged = default_branch
+            .as_ref()
+            .map(|target| (target, wt.is_merged_into(target).ok()));
+        let dirty = wt.is_dirty().ok();
+        let unpushed = wt.has_unpushed_commits().ok();
+        let protected = branch
+            .as_deref()
+            .and_then(|b| protected_by(b, &protected_patterns));
+        let is_default = branch.is_some() && branch == default_branch;
+        let locked = wt.is_locked().unwrap_or(false);
+        let lock_reason = wt.lock_reason().ok().flatten();
+        let last_activity = wt.last_activity().ok().flatten();
+        let age_days = last_activity.map(days_since);
+
+        let mut verdicts = verdicts(
+            &repo,
+            &wt,
+            &name,
+            branch.as_deref(),
+            default_branch.as_deref(),
+            &protected_patterns,
+            locked,
+        );
+        // Discovery (every verdict but the explicit one) leaves worktrees inside
+        // workon.pruneGracePeriod alone
+        if let Some(reason) = GracePeriod::load(&repo, &config)?.and_then(|g| g.protects(&wt)) {
+            for verdict in verdicts
+                .iter_mut()
+                .filter(|v| !v.explicit && v.action != "keep")
+            {
+                verdict.action = "skip";
+                verdict.reason = reason.clone();
+            }
+        }
+
+        if self.json {
+            let result = json!({
+                "name": name,
+                "path": wt.path().to_str(),
+                "branch": branch,
+                "criteria": {
+                    "branch_exists": branch_exists,
+                    "upstream": upstream,
+                    "upstream_gone": upstream_gone,
+                    "merged_into": merged.as_ref().map(|(target, _)| target),
+                    "merged": merged.and_then(|(_, merged)| merged),
+                    "dirty": dirty,
+                    "unpushed": unpushed,
+                    "protected_by": protected,
+                    "is_default": is_default,
+                    "locked": locked,
+                    "lock_reason": lock_reason,
+                    "last_activity": last_activity,
+                    "age_days": age_days,
+                },
+                "verdicts": verdicts.iter().map(Verdict::to_json).collect::<Vec<_>>(),
+            });
+            let output = serde_json::to_string_pretty(&result).into_diagnostic()?;
+            println!("{}", output);
+            return Ok(None);
+        }
+
+        output::info(&format!(
+            "{} (branch: {})",
+            name,
+            branch.as_deref().unwrap_or("detached HEAD")
+        ));
+        criterion(
+            "branch",
+            match branch_exists {
+                Some(true) => "exists".to_string(),
+                Some(false) => "deleted".to_string(),
+                None => "none (detached HEAD)".to_string(),
+            },
+        );
+        criterion(
+            "upstream",
+            match (&upstream, upstream_gone) {
+                (_, true) => "gone".to_string(),
+                (Some(upstream), false) => upstream.clone(),
+                (None, false) => "none".to_string(),
+            },
+        );
+        match &merged {
+            Some((target, merged)) => {
+                criterion(&format!("merged into {}", target), yes_no(*merged))
+            }
+            None => criterion("merged", "unknown (no default branch)".to_string()),
+        }
+        criterion("uncommitted changes", yes_no(dirty));
+        criterion("unpushed commits", yes_no(unpushed));
+        criterion(
+            "protected",
+            match &protected {
+                Some(pattern) => format!("yes (matches '{}')", pattern),
+                None => "no".to_string(),
+            },
+        );
+        criterion("default worktree", yes_no(Some(is_default)));
+        criterion(
+            "locked",
+            match (locked, &lock_reason) {
+                (true, Some(reason)) => format!("yes ({})", reason),
+                (true, None) => "yes".to_string(),
+                (false, _) => "no".to_string(),
+            },
+        );
+        criterion(
+            "last activity",
+            match age_days {
+                Some(0) => "today".to_string(),
+                Some(1) => "1 day ago".to_string(),
+                Some(days) => format!("{} days ago", days),
+                None => "unknown".to_string(),
+            },
+        );
+
+        eprintln!();
+        output::info("Verdict:");
+        let width = verdicts.iter().map(|v| v.command.len()).max().unwrap_or(0);
+        for verdict in &verdicts {
+            output::detail(&format!(
+                "  {:<width$}  {} ({})",
+                verdict.command,
+                verdict.action,
+                verdict.reason,
+                width = width
+            ));
+        }
+
+        Ok(None)
+    }
+}
+
+/// What one form of `git workon prune` would do with the worktree.
+struct Verdict {
+    command: String,
+    action: &'static str,
+    reason: String,
+    /// Whether this is `prune <name>`, which names the worktree rather than discovering it
+    explicit: bool,
+}
+
+impl Verdict {
+    fn to_json(&self) -> Value {
+        json!({
+            "command": self.command,
+            "action": self.action,
+            "reason": self.reason,
+        })
+    }
+}
+
+/// Evaluate `prune`, `prune --gone`, `prune --merged` and `prune <name>` for the worktree.
+fn verdicts(
+    repo: &git2::Repository,
+    wt: &WorktreeDescriptor,
+    name: &str,
+    branch: Option<&str>,
+    default_branch: Option<&str>,
+    protected_patterns: &[String],
+    locked: bool,
+) -> Vec<Verdict> {
+    let filters: [(&str, bool, Option<&str>); 3] = [
+        ("git workon prune", false, None),
+        ("git workon prune --gone", true, None),
+        ("git workon prune --merged", false, Some("")),
+    ];
+
+    let mut verdicts: Vec<Verdict> = filters
+        .into_iter()
+        .map(|(command, gone, merged)| {
+            // Filter-based pruning skips detached worktrees entirely
+            let reason = match branch {
+                Some(branch) => prune_reason(repo, wt, branch, gone, merged),
+                None => {
+                    return Verdict {
+                        command: command.to_string(),
+                        action: "keep",
+                        reason: "detached HEAD".to_string(),
+                        explicit: false,
+                    }
+                }
+            };
+            match reason {
+                Some(reason) => decide(
+                    command.to_string(),
+                    wt,
+                    branch.unwrap_or_default(),
+                    reason,
+                    default_branch,
+                    protected_patterns,
+                    locked,
+                ),
+                None => Verdict {
+                    command: command.to_string(),
+                    action: "keep",
+                    reason: "no prune criteria matched".to_string(),
+                    explicit: false,
+                },
+            }
+        })
+        .collect();
+
+    verdicts.push(decide(
+        format!("git workon prune {}", name),
+        wt,
+        branch.unwrap_or("(detached HEAD)"),
+        PruneReason::Explicit,
+        default_branch,
+        protected_patterns,
+        locked,
+    ));
+    verdicts
+}
+
+/// Apply prune's safety checks to a worktree that matched a prune criterion.
+fn decide(
+    command: String,
+    wt: &WorktreeDescriptor,
+    branch: &str,
+    reason: PruneReason,
+    default_branch: Option<&str>,
+    protected_patterns: &[String],
+    locked: bool,
+) -> Verdict {
+    let explicit = matches!(reason, PruneReason::Explicit);
+    let skip = skip_reason(
+        wt,
+        branch,
+        &reason,
+        default_branch,
+        protected_patterns,
+        &SafetyOverrides::default(),
+    );
+    match skip {
+        Some(skip) => Verdict {
+            command,
+            action: "skip",
+            reason: skip,
+            explicit,
+        },
+        // git refuses to prune locked worktrees
+        None if locked => Verdict {
+            command,
+            action: "fail",
+            reason: "worktree is locked, unlock it first".to_string(),
+            explicit,
+        },
+        None => Verdict {
+            command,
+            action: "prune",
+            reason: reason.to_string(),
+            explicit,
+        },
+    }
+}
+
+fn criterion(label: &str, value: String) {
+    output::detail(&format!("  {}: {}", label, value));
+}
+
+fn yes_no(value: Option<bool>) -> String {
+    match value {
+        Some(true) => "yes".to_string(),
+        Some(false) => "no".to_string(),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Whole days elapsed since a unix timestamp.
+fn days_since(timestamp: i64) -> i64 {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(timestamp);
+    (now - timestamp).max(0) / 86400
+}
@@ -67,10 +67,56 @@ pub fn complete_branch_names(current: &OsStr) -> Vec<CompletionCandidate> {
         .collect()
 }
 
+pub fn complete_repo_names(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Ok(registry) = workon::RepoRegistry::load() else {
+        return vec![];
+    };
+    let prefix = current.to_string_lossy();
+    registry
+        .iter()
+        .filter(|(name, _)| name.starts_with(prefix.as_ref()))
+        .map(|(name, path)| {
+            CompletionCandidate::new(name).help(Some(StyledStr::from(path.display().to_string())))
+        })
+        .collect()
+}
+
+pub fn complete_task_names(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Ok(repo) = workon::get_repo(None) else {
+        return vec![];
+    };
+    let Ok(tasks) = workon::WorkonConfig::new(&repo).and_then(|config| config.tasks()) else {
+        return vec![];
+    };
+    let prefix = current.to_string_lossy();
+    tasks
+        .into_iter()
+        .filter(|(name, _)| name.starts_with(prefix.as_ref()))
+        .map(|(name, command)| CompletionCandidate::new(name).help(Some(StyledStr::from(command))))
+        .collect()
+}
+
+/// Complete worktree names for a positional that other positionals follow.
+///
+/// `mut_arg` re-appends the argument it edits, which would move it behind the later
+/// positionals; editing it in place keeps the declared order.
+fn complete_leading_worktree(cmd: Command, id: &str) -> Command {
+    cmd.mut_args(|a| {
+        if a.get_id() == id {
+            a.add(ArgValueCompleter::new(complete_worktree_names))
+        } else {
+            a
+        }
+    })
+}
+
 pub fn augment(cmd: Command) -> Command {
     cmd.mut_arg("name", |a| {
         a.add(ArgValueCompleter::new(complete_worktree_names))
     })
+    .mut_arg("repo", |a| {
+        a.add(ArgValueCompleter::new(complete_repo_names))
+    })
     .mut_subcommand("find", |sub| {
         sub.mut_arg("name", |a| {
             a.add(ArgValueCompleter::new(complete_worktree_names))
@@ -86,11 +132,54 @@ pub fn augment(cmd: Command) -> Command {
             a.add(ArgValueCompleter::new(complete_worktree_names))
         })
     })
-    .mut_subcommand("copy-untracked", |sub| {
-        sub.mut_arg("from", |a| {
+    .mut_subcommand("default", |sub| {
+        sub.mut_arg("name", |a| {
+            a.add(ArgValueCompleter::new(complete_worktree_names))
+        })
+    })
+    .mut_subcommand("hide", |sub| {
+        sub.mut_arg("name", |a| {
+            a.add(ArgValueCompleter::new(complete_worktree_names))
+        })
+    })
+    .mut_subcommand("unhide", |sub| {
+        sub.mut_arg("name", |a| {
             a.add(ArgValueCompleter::new(complete_worktree_names))
         })
-        .mut_arg("to", |a| {
+    })
+    .mut_subcommand("label", |sub| {
+        sub.mut_subcommand("add", |sub| complete_leading_worktree(sub, "name"))
+            .mut_subcommand("remove", |sub| complete_leading_worktree(sub, "name"))
+    })
+    .mut_subcommand("note", |sub| complete_leading_worktree(sub, "name"))
+    .mut_subcommand("repos", |sub| {
+        sub.mut_subcommand("remove", |sub| {
+            sub.mut_arg("name", |a| {
+                a.add(ArgValueCompleter::new(complete_repo_names))
+            })
+        })
+    })
+    .mut_subcommand("run", |sub| {
+        sub.mut_args(|a| match a.get_id().as_str() {
+            "task" => a.add(ArgValueCompleter::new(complete_task_names)),
+            "worktrees" => a.add(ArgValueCompleter::new(complete_worktree_names)),
+            _ => a,
+        })
+    })
+    .mut_subcommand("set-upstream", |sub| {
+        sub.mut_args(|a| match a.get_id().as_str() {
+            "name" => a.add(ArgValueCompleter::new(complete_worktree_names)),
+            "upstream" => a.add(ArgValueCompleter::new(complete_branch_names)),
+            _ => a,
+        })
+    })
+    .mut_subcommand("why", |sub| {
+        sub.mut_arg("name", |a| {
+            a.add(ArgValueCompleter::new(complete_worktree_names))
+        })
+    })
+    .mut_subcommand("copy-untracked", |sub| {
+        sub.mut_arg("names", |a| {
             a.add(ArgValueCompleter::new(complete_worktree_names))
         })
     })
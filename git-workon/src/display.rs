@@ -16,10 +16,18 @@
 //!
 //! ## Display Format
 //!
-//! Column-aligned output with active marker, indicators, path, and last activity:
+//! Column-aligned output with active marker, repository (for `list --all-repos`),
+//! indicators, path, labels (if any worktree has them), last activity, and the worktree's
+//! note (if any):
 //! ```text
-//!   main              ./main           2 hours ago
-//! → feature-auth   *  ./feature-auth   3 days ago
+//!   main              ./main                            2 hours ago
+//! → feature-auth   *  ./feature-auth   #backend #urgent  3 days ago   waiting on API review
+//! ```
+//!
+//! Worktrees created from a pull request (`git workon #123`) show their worktree name and
+//! the PR's title and author in place of the branch name:
+//! ```text
+//!   pr-123  "Fix auth bug" (alice)     ./pr-123                          5 hours ago
 //! ```
 //!
 //! Used by `list` for output and `find` for interactive selection.
@@ -28,7 +36,7 @@ use std::path::Path;
 
 use miette::Result;
 use unicode_width::UnicodeWidthStr;
-use workon::WorktreeDescriptor;
+use workon::{WorkonSettings, WorktreeDescriptor, WorktreeStatus};
 
 use crate::output::style;
 
@@ -38,7 +46,58 @@ pub struct WorktreeDisplayRow {
     pub branch_name: String,
     pub indicators: Vec<String>,
     pub path: String,
+    pub labels: Vec<String>,
     pub last_activity: String,
+    pub note: Option<String>,
+    pub repo: Option<String>,
+    /// Title and author of the PR the worktree was created for, shown after its name
+    pub pr: Option<String>,
+}
+
+impl WorktreeDisplayRow {
+    /// Attach the worktree's labels (see `git workon label`) to the row.
+    pub fn with_labels(mut self, labels: Vec<String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Attach the worktree's note (see `git workon note`) to the row.
+    pub fn with_note(mut self, note: Option<String>) -> Self {
+        self.note = note;
+        self
+    }
+
+    /// Show a PR worktree by its name and the PR's title and author, if they were recorded
+    /// when it was created.
+    pub fn with_pr(mut self, settings: &WorkonSettings, wt: &WorktreeDescriptor) -> Self {
+        let (Some(name), Some(full_name)) = (wt.name(), wt.full_name()) else {
+            return self;
+        };
+        let Some(meta) = settings.worktree(&full_name) else {
+            return self;
+        };
+        let Some(title) = &meta.pr_title else {
+            return self;
+        };
+        self.branch_name = name.to_string();
+        self.pr = Some(match &meta.pr_author {
+            Some(author) => format!("\"{}\" ({})", title, author),
+            None => format!("\"{}\"", title),
+        });
+        self
+    }
+
+    /// The first column: the branch name, or a PR worktree's name, title and author.
+    fn label_width(&self) -> usize {
+        self.branch_name.width() + self.pr.as_ref().map_or(0, |pr| 2 + pr.width())
+    }
+
+    /// Attach the name of the registered repository the worktree belongs to (see
+    /// `list --all-repos`).
+    pub fn with_repo(mut self, repo: &str) -> Self {
+        self.repo = Some(repo.to_string());
+        self
+    }
 }
 
 /// Build a display row from a worktree descriptor.
@@ -54,19 +113,7 @@ pub fn worktree_display_row(
         None => "(detached HEAD)".to_string(),
     };
 
-    let mut indicators: Vec<String> = Vec::new();
-    if wt.is_dirty().unwrap_or(false) {
-        indicators.push("*".to_string());
-    }
-    if wt.has_unpushed_commits().unwrap_or(false) {
-        indicators.push("↑".to_string());
-    }
-    if wt.is_behind_upstream().unwrap_or(false) {
-        indicators.push("↓".to_string());
-    }
-    if wt.has_gone_upstream().unwrap_or(false) {
-        indicators.push("✗".to_string());
-    }
+    let indicators = status_indicators(wt);
 
     let rel_path = pathdiff::diff_paths(wt.path(), root)
         .map(|p| format!("./{}", p.display()))
@@ -84,10 +131,38 @@ pub fn worktree_display_row(
         branch_name,
         indicators,
         path: rel_path,
+        labels: Vec::new(),
         last_activity,
+        note: None,
+        repo: None,
+        pr: None,
+    })
+}
+
+/// Status indicators for a worktree, in display order (`*`, `↑`, `↓`, `✗`).
+pub fn status_indicators(wt: &WorktreeDescriptor) -> Vec<String> {
+    indicators_for(&WorktreeStatus {
+        dirty: wt.is_dirty().unwrap_or(false),
+        ahead: wt.has_unpushed_commits().unwrap_or(false),
+        behind: wt.is_behind_upstream().unwrap_or(false),
+        gone: wt.has_gone_upstream().unwrap_or(false),
     })
 }
 
+/// Status indicators for an already known status (see [`status_indicators`]).
+pub fn indicators_for(status: &WorktreeStatus) -> Vec<String> {
+    [
+        (status.dirty, "*"),
+        (status.ahead, "↑"),
+        (status.behind, "↓"),
+        (status.gone, "✗"),
+    ]
+    .into_iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, indicator)| indicator.to_string())
+    .collect()
+}
+
 /// Format display rows into column-aligned strings.
 ///
 /// When `show_active_marker` is true, rows are prefixed with `→` for the active
@@ -100,7 +175,7 @@ pub fn format_aligned_rows(rows: &[WorktreeDisplayRow], show_active_marker: bool
 
     let max_branch = rows
         .iter()
-        .map(|r| r.branch_name.width())
+        .map(WorktreeDisplayRow::label_width)
         .max()
         .unwrap_or(0);
     let max_indicators = rows
@@ -109,11 +184,41 @@ pub fn format_aligned_rows(rows: &[WorktreeDisplayRow], show_active_marker: bool
         .max()
         .unwrap_or(0);
     let max_path = rows.iter().map(|r| r.path.width()).max().unwrap_or(0);
+    let max_activity = rows
+        .iter()
+        .map(|r| r.last_activity.width())
+        .max()
+        .unwrap_or(0);
+    let max_labels = rows
+        .iter()
+        .map(|r| format_labels(&r.labels).width())
+        .max()
+        .unwrap_or(0);
+    let max_repo = rows
+        .iter()
+        .map(|r| r.repo.as_deref().unwrap_or_default().width())
+        .max()
+        .unwrap_or(0);
 
     rows.iter()
         .map(|row| {
-            let branch = style::bold(&row.branch_name);
-            let branch_pad = max_branch - row.branch_name.width();
+            // The repo column only appears when listing several repositories
+            let repo_plain = row.repo.as_deref().unwrap_or_default();
+            let repo = if max_repo == 0 {
+                String::new()
+            } else {
+                format!(
+                    "{}{}  ",
+                    style::cyan(repo_plain),
+                    " ".repeat(max_repo - repo_plain.width())
+                )
+            };
+
+            let branch = match &row.pr {
+                Some(pr) => format!("{}  {}", style::bold(&row.branch_name), pr),
+                None => style::bold(&row.branch_name),
+            };
+            let branch_pad = max_branch - row.label_width();
 
             let indicators_plain = row.indicators.join(" ");
             let indicators_display = if row.indicators.is_empty() {
@@ -136,7 +241,28 @@ pub fn format_aligned_rows(rows: &[WorktreeDisplayRow], show_active_marker: bool
             let path = style::dim(&row.path);
             let path_pad = max_path - row.path.width();
 
-            let activity = style::dim(&row.last_activity);
+            // The labels column only appears when some worktree has labels
+            let labels_plain = format_labels(&row.labels);
+            let labels = if max_labels == 0 {
+                String::new()
+            } else {
+                format!(
+                    "{}{}  ",
+                    labels_plain,
+                    " ".repeat(max_labels - labels_plain.width())
+                )
+            };
+
+            // Notes trail the row, so activity only needs padding when some row has one
+            let activity = match &row.note {
+                Some(note) => format!(
+                    "{}{}  {}",
+                    style::dim(&row.last_activity),
+                    " ".repeat(max_activity - row.last_activity.width()),
+                    style::dim(note)
+                ),
+                None => style::dim(&row.last_activity),
+            };
 
             if show_active_marker {
                 let marker = if row.is_active {
@@ -145,25 +271,29 @@ pub fn format_aligned_rows(rows: &[WorktreeDisplayRow], show_active_marker: bool
                     " ".to_string()
                 };
                 format!(
-                    "{} {}{} {}{} {}{}  {}",
+                    "{} {}{}{} {}{} {}{}  {}{}",
                     marker,
+                    repo,
                     branch,
                     " ".repeat(branch_pad),
                     indicators_display,
                     " ".repeat(indicators_pad),
                     path,
                     " ".repeat(path_pad),
+                    labels,
                     activity,
                 )
             } else {
                 format!(
-                    "{}{} {}{} {}{}  {}",
+                    "{}{}{} {}{} {}{}  {}{}",
+                    repo,
                     branch,
                     " ".repeat(branch_pad),
                     indicators_display,
                     " ".repeat(indicators_pad),
                     path,
                     " ".repeat(path_pad),
+                    labels,
                     activity,
                 )
             }
@@ -171,6 +301,15 @@ pub fn format_aligned_rows(rows: &[WorktreeDisplayRow], show_active_marker: bool
         .collect()
 }
 
+/// Render labels as space-separated `#label` tags.
+fn format_labels(labels: &[String]) -> String {
+    labels
+        .iter()
+        .map(|label| format!("#{}", label))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Convert a Unix timestamp to a human-readable relative time string.
 pub fn format_relative_time(epoch_seconds: i64) -> String {
     let now = std::time::SystemTime::now()
@@ -179,11 +318,14 @@ pub fn format_relative_time(epoch_seconds: i64) -> String {
         .as_secs() as i64;
     let diff = now - epoch_seconds;
 
-    if diff < 0 {
+    if diff < 60 {
         return "just now".to_string();
     }
+    format!("{} ago", format_duration(diff))
+}
 
-    let seconds = diff;
+/// Format a span of seconds in its largest whole unit, e.g. `3 days` or `1 hour`.
+pub fn format_duration(seconds: i64) -> String {
     let minutes = seconds / 60;
     let hours = minutes / 60;
     let days = hours / 24;
@@ -192,30 +334,68 @@ pub fn format_relative_time(epoch_seconds: i64) -> String {
     let years = days / 365;
 
     if seconds < 60 {
-        "just now".to_string()
+        "less than a minute".to_string()
     } else if minutes == 1 {
-        "1 minute ago".to_string()
+        "1 minute".to_string()
     } else if minutes < 60 {
-        format!("{minutes} minutes ago")
+        format!("{minutes} minutes")
     } else if hours == 1 {
-        "1 hour ago".to_string()
+        "1 hour".to_string()
     } else if hours < 24 {
-        format!("{hours} hours ago")
+        format!("{hours} hours")
     } else if days == 1 {
-        "1 day ago".to_string()
+        "1 day".to_string()
     } else if days < 7 {
-        format!("{days} days ago")
+        format!("{days} days")
     } else if weeks == 1 {
-        "1 week ago".to_string()
+        "1 week".to_string()
     } else if weeks < 5 {
-        format!("{weeks} weeks ago")
+        format!("{weeks} weeks")
     } else if months == 1 {
-        "1 month ago".to_string()
+        "1 month".to_string()
     } else if months < 12 {
-        format!("{months} months ago")
+        format!("{months} months")
     } else if years == 1 {
-        "1 year ago".to_string()
+        "1 year".to_string()
     } else {
-        format!("{years} years ago")
+        format!("{years} years")
+    }
+}
+
+/// Format a byte count in the largest fitting binary unit, e.g. `512 B` or `1.5 MiB`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
     }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+/// Summarize copied files, e.g. `412 file(s), 1.8 GiB, 96% cloned`.
+///
+/// The cloned share is by size, as that's what makes byte copies slow.
+pub fn format_copy_summary(entries: &[workon::CopyEntry]) -> String {
+    let total: u64 = entries.iter().map(|entry| entry.size).sum();
+    let summary = format!("{} file(s), {}", entries.len(), format_size(total));
+    if entries.is_empty() {
+        return summary;
+    }
+    let cloned: u64 = entries
+        .iter()
+        .filter(|entry| entry.cloned)
+        .map(|entry| entry.size)
+        .sum();
+    let percent = if total == 0 {
+        let cloned_files = entries.iter().filter(|entry| entry.cloned).count();
+        cloned_files * 100 / entries.len()
+    } else {
+        (cloned as u128 * 100 / total as u128) as usize
+    };
+    format!("{}, {}% cloned", summary, percent)
 }
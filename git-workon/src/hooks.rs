@@ -1,7 +1,9 @@
 //! Post-creation hook execution.
 //!
 //! This module executes user-configured commands automatically after worktree creation,
-//! providing a simpler alternative to git's native `post-checkout` hook.
+//! providing a simpler alternative to git's native `post-checkout` hook. Tasks run with
+//! `git workon run` (see src/cmd/run.rs) go through the same [`run_shell`], so they get
+//! the same environment, shell, working directory prefixes and timeout.
 //!
 //! ## Design: Hybrid Approach
 //!
@@ -39,6 +41,44 @@
 //! git config --add workon.postCreateHook "cp ../.env .env"
 //! ```
 //!
+//! ## Shell and Working Directory
+//!
+//! Hooks run with `sh -c` (`cmd /C` on Windows) in the new worktree. `workon.hookShell`
+//! picks another shell: a lone program runs hooks as `<program> -c <hook>`, anything
+//! longer is used as the whole prefix:
+//!
+//! ```bash
+//! git config workon.hookShell bash
+//! git config workon.hookShell "pwsh -NoProfile -Command"
+//! ```
+//!
+//! A `cwd=root` or `cwd=base` prefix runs a hook from the workon root or the base
+//! branch's worktree instead (the default branch's, when there's no base):
+//!
+//! ```bash
+//! git config --add workon.postCreateHook "cwd=base make dist"
+//! ```
+//!
+//! `git workon doctor` reports unknown `cwd=` values and a hook shell missing from PATH.
+//!
+//! ## Output
+//!
+//! Hook output is piped rather than written straight to the terminal: each line goes to
+//! stderr behind the hook's program name, so output from several hooks stays readable
+//! and never mixes into a command's stdout:
+//!
+//! ```text
+//! Running hook 1/2: npm install
+//! [npm] added 312 packages in 4s
+//! ```
+//!
+//! Hooks keep the terminal's stdin, so one that prompts (or a tool that checks for a
+//! TTY) still works.
+//!
+//! The lines are also kept: a failed hook's error ends with its last lines, and
+//! `--json` output of `new`, `clone` and `init` includes every hook's command, exit code
+//! and output under `hooks` (see [`HookRun`]).
+//!
 //! ## Security Considerations
 //!
 //! Hooks execute arbitrary commands from config. Users should:
@@ -66,25 +106,131 @@
 //! If a hook exceeds the timeout, it is killed and an error is returned.
 //! Set `workon.hookTimeout` to `0` to disable the timeout.
 
-use std::env;
-use std::process::Command;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
 use std::thread;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use log::debug;
-use miette::{IntoDiagnostic, Result};
-use workon::{WorkonConfig, WorktreeDescriptor};
+use git2::Repository;
+use miette::{IntoDiagnostic, Report, Result, WrapErr};
+use serde_json::{json, Value};
+use tracing::{debug, debug_span};
+use workon::{
+    find_worktree, get_default_branch, workon_root, HookDir, PostCreateHook, WorkonConfig,
+    WorktreeDescriptor,
+};
+
+use crate::output;
+
+/// How often a running hook is checked on while waiting for its output.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Lines of output shown with a failed hook.
+const EXCERPT_LINES: usize = 10;
+
+/// The outcome of a hook (or task) run with [`run_shell`].
+#[derive(Debug, Clone)]
+pub struct HookResult {
+    pub command: String,
+    /// Exit code, None when killed by a signal or the timeout
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+    /// Lines printed to stdout and stderr, interleaved as they arrived
+    pub output: Vec<String>,
+}
 
-/// Execute post-creation hooks configured in workon.postCreateHook
+impl HookResult {
+    pub fn success(&self) -> bool {
+        !self.timed_out && self.exit_code == Some(0)
+    }
+
+    /// Turn a failed run into an error, with the end of its output as help. `what` names
+    /// the command, e.g. "Hook".
+    pub fn check(self, what: &str, timeout: Duration) -> Result<Self> {
+        if self.success() {
+            return Ok(self);
+        }
+        let message = if self.timed_out {
+            format!(
+                "{} timed out after {}s: {}",
+                what,
+                timeout.as_secs(),
+                self.command
+            )
+        } else {
+            format!("{} failed with exit code: {:?}", what, self.exit_code)
+        };
+        let excerpt = &self.output[self.output.len().saturating_sub(EXCERPT_LINES)..];
+        if excerpt.is_empty() {
+            return Err(miette::miette!("{}", message));
+        }
+        Err(miette::miette!(
+            help = format!("Last output:\n{}", excerpt.join("\n")),
+            "{}",
+            message
+        ))
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "command": self.command,
+            "success": self.success(),
+            "exit_code": self.exit_code,
+            "timed_out": self.timed_out,
+            "output": self.output,
+        })
+    }
+}
+
+/// The post-create hooks a command ran, for its `--json` output, and the error that
+/// stopped them, if any.
+#[derive(Debug, Default)]
+pub struct HookRun {
+    pub results: Vec<HookResult>,
+    pub error: Option<Report>,
+}
+
+impl HookRun {
+    /// The results as the `hooks` array of `--json` output.
+    pub fn to_json(&self) -> Value {
+        self.results.iter().map(HookResult::to_json).collect()
+    }
+}
+
+/// Execute post-creation hooks configured in workon.postCreateHook, then `extra_hooks`
+/// (a template's)
 ///
 /// Hooks are executed sequentially in the worktree directory with environment variables set.
-/// If a hook fails, an error is returned but the worktree remains valid.
+/// Their output is prefixed with the hook's program name and returned for `--json`. If a
+/// hook fails, the rest are skipped and the error is returned alongside the results; the
+/// worktree remains valid. A malformed entry (e.g. an unknown `cwd=`) is only warned
+/// about and skipped.
 pub fn execute_post_create_hooks(
+    repo: &Repository,
     worktree: &WorktreeDescriptor,
     base_branch: Option<&str>,
     config: &WorkonConfig,
+    extra_hooks: &[String],
+) -> HookRun {
+    let mut run = HookRun::default();
+    if let Err(e) = run_hooks(repo, worktree, base_branch, config, extra_hooks, &mut run) {
+        run.error = Some(e);
+    }
+    run
+}
+
+fn run_hooks(
+    repo: &Repository,
+    worktree: &WorktreeDescriptor,
+    base_branch: Option<&str>,
+    config: &WorkonConfig,
+    extra_hooks: &[String],
+    run: &mut HookRun,
 ) -> Result<()> {
-    let hooks = config.post_create_hooks()?;
+    let mut hooks = config.post_create_hooks()?;
+    hooks.extend_from_slice(extra_hooks);
 
     if hooks.is_empty() {
         debug!("No post-create hooks configured");
@@ -93,80 +239,189 @@ pub fn execute_post_create_hooks(
 
     debug!("Found {} post-create hook(s)", hooks.len());
 
-    for (i, hook_cmd) in hooks.iter().enumerate() {
-        eprintln!("Running hook {}/{}: {}", i + 1, hooks.len(), hook_cmd);
+    for (i, entry) in hooks.iter().enumerate() {
+        output::status(&format!(
+            "Running hook {}/{}: {}",
+            i + 1,
+            hooks.len(),
+            entry
+        ));
+        let _span = debug_span!("hook", command = %entry).entered();
+        // A malformed entry is reported and skipped; the hooks after it still run
+        let hook: PostCreateHook = match entry.parse() {
+            Ok(hook) => hook,
+            Err(e) => {
+                output::warn(&format!("Skipping hook '{}': {}", entry, e));
+                continue;
+            }
+        };
+        let dir = hook_dir(repo, worktree, base_branch, hook.dir)?;
+        let prefix = hook_prefix(&hook.command);
+        let result = run_shell(config, worktree, base_branch, &dir, &hook.command, &prefix)?;
+        run.results.push(result.clone());
+        result.check("Hook", config.hook_timeout()?)?;
+        output::status("✓ Hook completed successfully");
+    }
 
-        // Set up environment variables for the hook
-        debug!("Setting WORKON_WORKTREE_PATH={}", worktree.path().display());
-        env::set_var("WORKON_WORKTREE_PATH", worktree.path());
+    Ok(())
+}
 
-        if let Ok(Some(branch)) = worktree.branch() {
-            debug!("Setting WORKON_BRANCH_NAME={}", branch);
-            env::set_var("WORKON_BRANCH_NAME", branch);
-        }
+/// The name a hook's output is prefixed with: its program, e.g. `npm` or `setup.sh`.
+fn hook_prefix(command: &str) -> String {
+    let program = command.split_whitespace().next().unwrap_or(command);
+    Path::new(program)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or(program)
+        .to_string()
+}
 
-        if let Some(base) = base_branch {
-            debug!("Setting WORKON_BASE_BRANCH={}", base);
-            env::set_var("WORKON_BASE_BRANCH", base);
-        }
+/// Run `command` in `dir` with workon.hookShell, the `WORKON_*` variables for `worktree`
+/// set, and workon.hookTimeout enforced.
+///
+/// Output is streamed to stderr as it arrives, each line after `[prefix]`, and returned
+/// in the result; use [`HookResult::check`] to treat a failed run as an error.
+pub fn run_shell(
+    config: &WorkonConfig,
+    worktree: &WorktreeDescriptor,
+    base_branch: Option<&str>,
+    dir: &Path,
+    command: &str,
+    prefix: &str,
+) -> Result<HookResult> {
+    let shell = config.hook_shell()?;
+    let mut process = shell_command(shell.as_deref(), command);
 
-        debug!(
-            "Executing in working directory: {}",
-            worktree.path().display()
-        );
-
-        // Execute using shell (platform-dependent)
-        let mut child = if cfg!(target_os = "windows") {
-            Command::new("cmd")
-                .args(["/C", hook_cmd])
-                .current_dir(worktree.path())
-                .spawn()
-        } else {
-            Command::new("sh")
-                .args(["-c", hook_cmd])
-                .current_dir(worktree.path())
-                .spawn()
+    // Set up environment variables for the command
+    debug!("Setting WORKON_WORKTREE_PATH={}", worktree.path().display());
+    process.env("WORKON_WORKTREE_PATH", worktree.path());
+
+    if let Ok(Some(branch)) = worktree.branch() {
+        debug!("Setting WORKON_BRANCH_NAME={}", branch);
+        process.env("WORKON_BRANCH_NAME", branch);
+    }
+
+    if let Some(base) = base_branch {
+        debug!("Setting WORKON_BASE_BRANCH={}", base);
+        process.env("WORKON_BASE_BRANCH", base);
+    }
+
+    debug!("Executing in working directory: {}", dir.display());
+
+    let mut child = process
+        .current_dir(dir)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .into_diagnostic()
+        .wrap_err(format!("Failed to start {}: {}", prefix, command))?;
+
+    let (tx, rx) = mpsc::channel();
+    if let Some(stdout) = child.stdout.take() {
+        forward_lines(stdout, tx.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        forward_lines(stderr, tx);
+    }
+
+    let timeout = config.hook_timeout()?;
+    let mut result = HookResult {
+        command: command.to_string(),
+        exit_code: None,
+        timed_out: false,
+        output: Vec::new(),
+    };
+    let mut emit = |line: String| {
+        output::prefixed(prefix, &line);
+        result.output.push(line);
+    };
+
+    let start = Instant::now();
+    let status = loop {
+        match rx.recv_timeout(POLL_INTERVAL) {
+            Ok(line) => emit(line),
+            Err(RecvTimeoutError::Timeout) => {}
+            // Both streams closed; just wait for the exit
+            Err(RecvTimeoutError::Disconnected) => thread::sleep(POLL_INTERVAL),
+        }
+        if let Some(status) = child.try_wait().into_diagnostic()? {
+            break Some(status);
         }
-        .into_diagnostic()?;
-
-        let timeout = config.hook_timeout()?;
-
-        if timeout.is_zero() {
-            // No timeout - blocking wait
-            let status = child.wait().into_diagnostic()?;
-            if !status.success() {
-                return Err(miette::miette!(
-                    "Hook failed with exit code: {:?}",
-                    status.code()
-                ));
+        if !timeout.is_zero() && start.elapsed() >= timeout {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+    };
+
+    // Collect what's still buffered. A background process the hook started may hold the
+    // streams open, so stop once they go quiet rather than waiting for them to close.
+    while let Ok(line) = rx.recv_timeout(POLL_INTERVAL) {
+        emit(line);
+    }
+
+    match status {
+        Some(status) => result.exit_code = status.code(),
+        None => result.timed_out = true,
+    }
+    Ok(result)
+}
+
+/// Send each line read from `stream` to `tx`, from a background thread.
+fn forward_lines(stream: impl Read + Send + 'static, tx: Sender<String>) {
+    thread::spawn(move || {
+        let mut reader = BufReader::new(stream);
+        let mut line = Vec::new();
+        while let Ok(n) = reader.read_until(b'\n', &mut line) {
+            if n == 0 {
+                break;
             }
-        } else {
-            let start = Instant::now();
-            loop {
-                match child.try_wait().into_diagnostic()? {
-                    Some(status) if status.success() => break,
-                    Some(status) => {
-                        return Err(miette::miette!(
-                            "Hook failed with exit code: {:?}",
-                            status.code()
-                        ));
-                    }
-                    None if start.elapsed() >= timeout => {
-                        let _ = child.kill();
-                        let _ = child.wait();
-                        return Err(miette::miette!(
-                            "Hook timed out after {}s: {}",
-                            timeout.as_secs(),
-                            hook_cmd
-                        ));
-                    }
-                    None => thread::sleep(std::time::Duration::from_millis(100)),
-                }
+            let text = String::from_utf8_lossy(&line);
+            if tx
+                .send(text.trim_end_matches(['\n', '\r']).to_string())
+                .is_err()
+            {
+                break;
             }
+            line.clear();
         }
+    });
+}
 
-        eprintln!("✓ Hook completed successfully");
+/// The command that runs `hook` with `shell` (workon.hookShell), or the platform shell.
+fn shell_command(shell: Option<&str>, hook: &str) -> Command {
+    let mut words: Vec<&str> = shell.map_or_else(Vec::new, |s| s.split_whitespace().collect());
+    match words.as_slice() {
+        [] if cfg!(target_os = "windows") => words = vec!["cmd", "/C"],
+        [] => words = vec!["sh", "-c"],
+        ["cmd"] => words.push("/C"),
+        [_] => words.push("-c"),
+        _ => {}
     }
+    debug!("Running hook with: {}", words.join(" "));
+    let mut command = Command::new(words[0]);
+    command.args(&words[1..]).arg(hook);
+    command
+}
 
-    Ok(())
+/// The directory a hook (or task) runs in.
+pub fn hook_dir(
+    repo: &Repository,
+    worktree: &WorktreeDescriptor,
+    base_branch: Option<&str>,
+    dir: HookDir,
+) -> Result<PathBuf> {
+    match dir {
+        HookDir::Worktree => Ok(worktree.path().to_path_buf()),
+        HookDir::Root => Ok(workon_root(repo)?.to_path_buf()),
+        HookDir::Base => {
+            let base = match base_branch {
+                Some(base) => base.to_string(),
+                None => get_default_branch(repo)?,
+            };
+            let base_worktree = find_worktree(repo, &base)
+                .wrap_err(format!("cwd=base: no worktree for base branch '{}'", base))?;
+            Ok(base_worktree.path().to_path_buf())
+        }
+    }
 }
@@ -1,6 +1,9 @@
+use miette::{IntoDiagnostic, Report, Result};
 use serde_json::{json, Value};
 use workon::WorktreeDescriptor;
 
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
 /// Convert a WorktreeDescriptor to a JSON value.
 ///
 /// Fields that error during access are represented as `null`.
@@ -20,3 +23,58 @@ pub fn worktree_to_json(wt: &WorktreeDescriptor) -> Value {
         "last_activity": wt.last_activity().ok().flatten(),
     })
 }
+
+/// Convert an error to `{"error": {"code", "message", "help", "causes"}}`, as printed
+/// to stderr in JSON mode. `code` is the diagnostic code (e.g.
+/// `workon::worktree::target_exists`); it and `help` are `null` when the error has none.
+/// `causes` lists the underlying errors' messages, outermost first.
+pub fn error_to_json(report: &Report) -> Value {
+    json!({
+        "error": {
+            "code": report.code().map(|code| code.to_string()),
+            "message": report.to_string(),
+            "help": report.help().map(|help| help.to_string()),
+            "causes": report.chain().skip(1).map(|cause| cause.to_string()).collect::<Vec<_>>(),
+        }
+    })
+}
+
+/// JSON Schema for the object produced by [`worktree_to_json`].
+pub fn worktree_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "name": nullable("string", "Worktree name"),
+            "path": nullable("string", "Absolute path of the worktree"),
+            "branch": nullable("string", "Checked out branch, null when detached"),
+            "head_commit": nullable("string", "Full SHA of HEAD"),
+            "is_dirty": nullable("boolean", "Has uncommitted changes"),
+            "has_unpushed_commits": nullable("boolean", "Has commits not on the upstream"),
+            "is_behind_upstream": nullable("boolean", "Upstream has commits not in HEAD"),
+            "has_gone_upstream": nullable("boolean", "Upstream branch was deleted"),
+            "remote": nullable("string", "Remote of the upstream branch"),
+            "remote_branch": nullable("string", "Upstream branch, e.g. origin/main"),
+            "remote_url": nullable("string", "URL of the upstream remote"),
+            "last_activity": nullable("integer", "Unix time of the last commit on HEAD"),
+        },
+        "required": [
+            "name", "path", "branch", "head_commit", "is_dirty", "has_unpushed_commits",
+            "is_behind_upstream", "has_gone_upstream", "remote", "remote_branch", "remote_url",
+            "last_activity",
+        ],
+    })
+}
+
+/// A nullable property of the given JSON type.
+pub fn nullable(ty: &str, description: &str) -> Value {
+    json!({ "type": [ty, "null"], "description": description })
+}
+
+/// Print a command's output schema as a standalone JSON Schema document.
+pub fn print_schema(title: &str, mut schema: Value) -> Result<()> {
+    schema["$schema"] = json!(SCHEMA_DIALECT);
+    schema["title"] = json!(title);
+    let output = serde_json::to_string_pretty(&schema).into_diagnostic()?;
+    println!("{}", output);
+    Ok(())
+}
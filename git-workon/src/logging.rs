@@ -0,0 +1,129 @@
+//! Log output and span timing.
+//!
+//! Diagnostics go through `tracing`. Events (`debug!` and friends) are printed to stderr as
+//! bare messages, filtered by `-v`/`-q`. Spans wrap the expensive operations — repo
+//! discovery, per-worktree status, fetches, hooks and copies.
+//!
+//! With `--trace` (or `WORKON_TRACE=1`), every span also reports how long it took when it
+//! closes, indented by nesting depth:
+//!
+//! ```text
+//! [trace]   is_dirty{worktree=feature} 41.2ms
+//! [trace]   has_unpushed_commits{worktree=feature} 0.8ms
+//! [trace] get_repo 1.3ms
+//! ```
+//!
+//! Spans are reported as they close, so children are listed before their parent.
+
+use std::fmt::{self, Write as _};
+use std::time::Instant;
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::fmt::format::{self as format, FormatEvent, FormatFields};
+use tracing_subscriber::fmt::FmtContext;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+/// Install the global subscriber.
+pub fn init(level: LevelFilter, trace: bool) {
+    let messages = tracing_subscriber::fmt::layer()
+        .with_writer(std::io::stderr)
+        .event_format(MessageOnly)
+        .with_filter(level);
+    let timings = trace.then(|| SpanTimings.with_filter(LevelFilter::TRACE));
+
+    tracing_subscriber::registry()
+        .with(messages)
+        .with(timings)
+        .init();
+}
+
+/// Formats an event as just its message, without timestamps, levels or span context.
+struct MessageOnly;
+
+impl<S, N> FormatEvent<S, N> for MessageOnly
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: format::Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        ctx.field_format().format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+}
+
+/// Prints each span's name, fields and wall-clock duration when it closes.
+struct SpanTimings;
+
+/// Per-span state stored in the registry's extensions.
+struct Timing {
+    start: Instant,
+    fields: String,
+}
+
+impl<S> Layer<S> for SpanTimings
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = FieldList::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(Timing {
+            start: Instant::now(),
+            fields: fields.0,
+        });
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let depth = span.scope().skip(1).count();
+        let extensions = span.extensions();
+        let Some(timing) = extensions.get::<Timing>() else {
+            return;
+        };
+        let fields = if timing.fields.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", timing.fields)
+        };
+        eprintln!(
+            "[trace] {}{}{} {:.1}ms",
+            "  ".repeat(depth),
+            span.name(),
+            fields,
+            timing.start.elapsed().as_secs_f64() * 1000.0
+        );
+    }
+}
+
+/// Collects span fields as `key=value` pairs separated by spaces.
+#[derive(Default)]
+struct FieldList(String);
+
+impl Visit for FieldList {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &format_args!("{value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        let _ = write!(self.0, "{}={:?}", field.name(), value);
+    }
+}
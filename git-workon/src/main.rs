@@ -1,32 +1,50 @@
+mod aliases;
 mod cli;
 mod cmd;
 mod completers;
 mod display;
 mod hooks;
 mod json;
+mod logging;
 mod output;
+mod plugins;
 
 use clap::{CommandFactory, Parser};
 use clap_complete::env::CompleteEnv;
 use cli::Cmd;
-use miette::{IntoDiagnostic, Result};
+use miette::{IntoDiagnostic, Result, WrapErr};
 
 use crate::cli::Cli;
 use crate::cmd::Run;
-use crate::json::worktree_to_json;
+use crate::json::{error_to_json, worktree_to_json};
 
 fn main() -> Result<()> {
+    match run() {
+        // Wrappers branch on the diagnostic code rather than scraping the report
+        Err(report) if output::is_json_mode() => {
+            eprintln!("{}", error_to_json(&report));
+            std::process::exit(1);
+        }
+        result => result,
+    }
+}
+
+fn run() -> Result<()> {
     CompleteEnv::with_factory(|| completers::augment(Cli::command())).complete();
 
-    let mut cli = Cli::parse();
+    let args: Vec<_> = std::env::args_os().collect();
+    if let Some(plugin) = plugins::find(&args) {
+        std::process::exit(plugin.run()?);
+    }
+    let args = match aliases::expand(args)? {
+        aliases::Expansion::Args(args) => args,
+        aliases::Expansion::Shell { command, args } => {
+            std::process::exit(aliases::run_shell(&command, &args)?);
+        }
+    };
+    let mut cli = Cli::parse_from(args);
 
-    env_logger::Builder::new()
-        .filter_level(cli.verbose.log_level_filter())
-        .format(|buf, record| {
-            use std::io::Write;
-            writeln!(buf, "{}", record.args())
-        })
-        .init();
+    logging::init(cli.verbose.tracing_level_filter(), cli.trace);
 
     let json_mode = cli.json;
 
@@ -38,6 +56,16 @@ fn main() -> Result<()> {
         output::set_no_color(true);
     }
 
+    // Commands discover the repository from the current directory, so targeting a
+    // registered repository is a matter of starting there
+    if let Some(name) = &cli.repo {
+        let registry = workon::RepoRegistry::load()?;
+        let root = registry.resolve(name)?;
+        std::env::set_current_dir(root)
+            .into_diagnostic()
+            .wrap_err(format!("Failed to enter repository '{}'", name))?;
+    }
+
     if cli.command.is_none() {
         match cli.find.name {
             Some(ref name) if workon::is_pr_reference(name) => {
@@ -54,14 +82,45 @@ fn main() -> Result<()> {
     // Propagate --json to commands that handle it internally
     if json_mode {
         match &mut cmd {
+            Cmd::Clone(clone) => clone.json = true,
+            Cmd::Compare(compare) => compare.json = true,
+            Cmd::Init(init) => init.json = true,
+            Cmd::New(new) => new.json = true,
             Cmd::List(list) => list.json = true,
             Cmd::Prune(prune) => prune.json = true,
             Cmd::Doctor(doctor) => doctor.json = true,
-            Cmd::Find(find) => find.no_interactive = true,
+            Cmd::Env(env) => env.json = true,
+            Cmd::Why(why) => why.json = true,
+            Cmd::Root(root) => root.json = true,
+            Cmd::Stats(stats) => stats.json = true,
+            Cmd::Templates(templates) => templates.json = true,
+            Cmd::Version(version) => version.json = true,
+            Cmd::Repos(cli::Repos {
+                command: cli::ReposCmd::List(list),
+            }) => list.json = true,
+            Cmd::Find(find) => {
+                find.no_interactive = true;
+                find.json = true;
+            }
             _ => {}
         }
     }
 
+    // Non-interactive invocations fail rather than wait at a credential prompt
+    let no_interactive = matches!(
+        &cmd,
+        Cmd::New(cli::New {
+            no_interactive: true,
+            ..
+        }) | Cmd::Find(cli::Find {
+            no_interactive: true,
+            ..
+        })
+    );
+    if json_mode || no_interactive {
+        workon::set_auth_prompt(false);
+    }
+
     let worktree = cmd.run()?;
 
     if json_mode {
@@ -69,7 +128,8 @@ fn main() -> Result<()> {
             let json = serde_json::to_string_pretty(&worktree_to_json(&wt)).into_diagnostic()?;
             println!("{}", json);
         }
-        // list/prune already printed their JSON in run()
+        // list/prune, and new/clone/init (with their hooks), already printed their JSON
+        // in run()
         // other None cases: output nothing (valid for commands that don't return a worktree)
     } else if let Some(worktree) = worktree {
         if let Some(path_str) = worktree.path().to_str() {
@@ -80,25 +140,12 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-/// Returns `Some(Cmd::New)` if PR worktree doesn't exist yet; `None` if it exists or parsing fails.
+/// Routes a PR reference to `new`, which creates the PR worktree or switches to the
+/// existing one. Returns `None` if parsing fails.
 fn route_pr_ref_to_command(pr_ref: &str) -> Option<Cmd> {
-    let repo = workon::get_repo(None).ok()?;
-    let config = workon::WorkonConfig::new(&repo).ok()?;
-    let pr_format = config.pr_format(None).ok()?;
-    let pr_info = workon::parse_pr_reference(pr_ref).ok()??;
-    let pr_name = workon::format_pr_name(&pr_format, pr_info.number);
-
-    match repo.find_worktree(&pr_name) {
-        Ok(_) => None, // worktree already exists
-        _ => Some(Cmd::New(cli::New {
-            name: Some(pr_name),
-            base: None,
-            orphan: false,
-            detach: false,
-            no_hooks: false,
-            copy_untracked: false,
-            no_copy_untracked: false,
-            no_interactive: false,
-        })),
-    }
+    workon::parse_pr_reference(pr_ref).ok()??;
+    Some(Cmd::New(cli::New {
+        name: Some(pr_ref.to_string()),
+        ..Default::default()
+    }))
 }
@@ -12,6 +12,7 @@
 //! - [`detail`] — dim text, for secondary information
 //! - [`notice`] — yellow text, for dry-run/cancelled/skipped status
 //! - [`status`] — plain text, for neutral status messages
+//! - [`prefixed`] — a line of a hook's or task's output, after a dim `[name]` prefix
 //!
 //! **stdout** (primary data — pipeable to fzf, grep, etc.):
 //! - Use `println!()` directly for primary output
@@ -35,7 +36,7 @@ pub fn set_json_mode(enabled: bool) {
     JSON_MODE.store(enabled, Ordering::Relaxed);
 }
 
-fn is_json_mode() -> bool {
+pub fn is_json_mode() -> bool {
     JSON_MODE.load(Ordering::Relaxed)
 }
 
@@ -122,6 +123,18 @@ pub fn status(msg: &str) {
     eprintln!("{}", msg);
 }
 
+/// Print a line of a hook's or task's output to stderr as `[prefix] line` (dim prefix).
+pub fn prefixed(prefix: &str, line: &str) {
+    if is_json_mode() {
+        return;
+    }
+    if use_color() {
+        eprintln!("{} {}", format!("[{}]", prefix).dimmed(), line);
+    } else {
+        eprintln!("[{}] {}", prefix, line);
+    }
+}
+
 /// Print a passing check item: `  ✓ label` (green checkmark, dim label).
 pub fn check_pass(label: &str) {
     if is_json_mode() {
@@ -158,6 +171,18 @@ pub fn check_warn(label: &str, detail: &str) {
     }
 }
 
+/// Print an informational check item: `  ℹ label — detail` (blue info sign).
+pub fn check_info(label: &str, detail: &str) {
+    if is_json_mode() {
+        return;
+    }
+    if use_color() {
+        eprintln!("  {} {} — {}", "ℹ".blue(), label, detail);
+    } else {
+        eprintln!("  ℹ {} — {}", label, detail);
+    }
+}
+
 /// Style module for inline string formatting (checks stdout color support).
 pub mod style {
     use super::*;
@@ -186,6 +211,14 @@ pub mod style {
         }
     }
 
+    pub fn cyan(s: &str) -> String {
+        if use_color() {
+            s.cyan().to_string()
+        } else {
+            s.to_string()
+        }
+    }
+
     pub fn red(s: &str) -> String {
         if use_color() {
             s.red().to_string()
@@ -0,0 +1,125 @@
+//! External subcommands.
+//!
+//! Like git with `git-<cmd>`, an unknown subcommand runs `git-workon-<cmd>` from PATH, so
+//! third parties can add commands without forking workon:
+//!
+//! ```bash
+//! git workon sync --all       # runs git-workon-sync --all
+//! ```
+//!
+//! Built-in commands always win, and plugins win over aliases (as external commands do
+//! in git). A word that matches neither is still a worktree name for `find`.
+//!
+//! The plugin runs with the remaining arguments, in the current directory (or the root of
+//! the repository selected with `-R`), with the workon context in its environment:
+//!
+//! - `WORKON_ROOT` - The workon root (only inside a workon repository)
+//! - `WORKON_GIT_DIR` - The bare repository (only inside a workon repository)
+//! - `WORKON_WORKTREE_PATH` - The worktree containing the current directory, if any
+//! - `WORKON_BRANCH_NAME` - That worktree's branch, unless HEAD is detached
+//!
+//! Other global options (`--json`, `-v`, ...) are not forwarded.
+
+use std::env;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use clap::CommandFactory;
+use miette::{IntoDiagnostic, Result, WrapErr};
+use workon::{current_worktree, get_repo, workon_root, RepoRegistry};
+
+use crate::aliases::command_position;
+use crate::cli::Cli;
+
+/// An external subcommand found on PATH.
+pub struct Plugin {
+    path: PathBuf,
+    args: Vec<OsString>,
+    repo: Option<String>,
+}
+
+/// The plugin `args` (the full command line) invokes, if its subcommand isn't built in.
+pub fn find(args: &[OsString]) -> Option<Plugin> {
+    let mut cli = Cli::command();
+    cli.build();
+    let (position, repo) = command_position(&cli, args)?;
+    let name = args[position].to_str()?;
+    if cli.find_subcommand(name).is_some() || name.contains(['/', '\\']) {
+        return None;
+    }
+
+    let program = format!("{}-{}", env!("CARGO_PKG_NAME"), name);
+    let path = env::split_paths(&env::var_os("PATH")?)
+        .flat_map(|dir| executable_names(&program).map(move |file| dir.join(file)))
+        .find(|path| path.is_file() && is_executable(path))?;
+    Some(Plugin {
+        path,
+        args: args[position + 1..].to_vec(),
+        repo,
+    })
+}
+
+impl Plugin {
+    /// Run the plugin and return its exit code.
+    pub fn run(&self) -> Result<i32> {
+        let mut command = Command::new(&self.path);
+        command.args(&self.args);
+
+        let dir = match &self.repo {
+            Some(name) => {
+                let root = RepoRegistry::load()?.resolve(name)?.to_path_buf();
+                command.current_dir(&root);
+                Some(root)
+            }
+            None => None,
+        };
+        if let Ok(repo) = get_repo(dir) {
+            command
+                .env("WORKON_ROOT", workon_root(&repo)?)
+                .env("WORKON_GIT_DIR", repo.path());
+            // With -R, the plugin doesn't run inside a worktree
+            let worktree = match self.repo {
+                Some(_) => None,
+                None => current_worktree(&repo).ok(),
+            };
+            if let Some(wt) = worktree {
+                command.env("WORKON_WORKTREE_PATH", wt.path());
+                if let Ok(Some(branch)) = wt.branch() {
+                    command.env("WORKON_BRANCH_NAME", branch);
+                }
+            }
+        }
+
+        let status = command
+            .status()
+            .into_diagnostic()
+            .wrap_err(format!("Failed to run {}", self.path.display()))?;
+        Ok(status.code().unwrap_or(1))
+    }
+}
+
+#[cfg(windows)]
+fn executable_names(program: &str) -> impl Iterator<Item = String> + '_ {
+    ["exe", "cmd", "bat"]
+        .into_iter()
+        .map(move |ext| format!("{}.{}", program, ext))
+}
+
+#[cfg(not(windows))]
+fn executable_names(program: &str) -> impl Iterator<Item = String> + '_ {
+    std::iter::once(program.to_string())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata()
+        .map(|m| m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> bool {
+    true
+}
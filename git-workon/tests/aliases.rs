@@ -0,0 +1,92 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn alias_expands_to_command_with_extra_args() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.alias.r", "root")
+        .build()?;
+
+    let expected = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["root", "--bare"])
+        .output()?;
+    assert!(expected.status.success());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["r", "--bare"])
+        .assert()
+        .success()
+        .stdout(String::from_utf8(expected.stdout)?);
+
+    Ok(())
+}
+
+#[test]
+fn alias_cannot_shadow_builtin_command() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.alias.root", "stats")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("root")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("worktree").not());
+
+    Ok(())
+}
+
+#[test]
+fn shell_alias_receives_arguments() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.alias.greet", "!echo hello")
+        .config("workon.alias.fail", "!exit 3")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["greet", "big world"])
+        .assert()
+        .success()
+        .stdout("hello big world\n");
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("fail")
+        .assert()
+        .code(3);
+
+    Ok(())
+}
+
+#[test]
+fn alias_loop_is_an_error() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.alias.a", "b --dirty")
+        .config("workon.alias.b", "a")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("a")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Alias loop: a -> b -> a"));
+
+    Ok(())
+}
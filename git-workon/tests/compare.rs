@@ -0,0 +1,93 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+fn diverged_fixture() -> Result<Fixture, Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+    fixture.commit("feature").create("Add login form")?;
+    fixture.commit("feature").create("Handle empty input")?;
+    fixture.commit("main").create("Bump dependencies")?;
+    Ok(fixture)
+}
+
+#[test]
+fn compare_shows_counts_merge_base_and_unique_commits() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = diverged_fixture()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("compare")
+        .arg("feature")
+        .arg("main")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "feature is 2 ahead, 1 behind main",
+        ))
+        .stdout(predicate::str::contains("merge base: "))
+        .stdout(predicate::str::contains("Only on feature (2):"))
+        .stdout(predicate::str::contains("Handle empty input"))
+        .stdout(predicate::str::contains("Only on main (1):"))
+        .stdout(predicate::str::contains("Bump dependencies"));
+
+    Ok(())
+}
+
+#[test]
+fn compare_json_lists_commits_newest_first() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = diverged_fixture()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("compare")
+        .arg("feature")
+        .arg("main")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["ahead"], 2);
+    assert_eq!(json["behind"], 1);
+    assert_eq!(json["a"]["name"], "feature");
+    assert_eq!(json["a"]["branch"], "feature");
+    assert_eq!(json["a"]["commits"][0]["summary"], "Handle empty input");
+    assert_eq!(json["a"]["commits"][1]["summary"], "Add login form");
+    assert_eq!(json["b"]["commits"][0]["summary"], "Bump dependencies");
+
+    let repo = fixture.repo()?;
+    let feature = repo.revparse_single("feature")?.id();
+    let main = repo.revparse_single("main")?.id();
+    let merge_base = repo.merge_base(feature, main)?;
+    assert_eq!(json["merge_base"], merge_base.to_string());
+
+    Ok(())
+}
+
+#[test]
+fn compare_same_commit() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("compare")
+        .arg("feature")
+        .arg("main")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "feature and main are at the same commit",
+        ))
+        .stdout(predicate::str::contains("Only on").not());
+
+    Ok(())
+}
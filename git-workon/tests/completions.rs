@@ -1,63 +1,54 @@
-// use assert_cmd::Command;
-//
-// #[test]
-// fn completions_bash() {
-//     let source = include_str!("../contrib/completions/git-workon.bash");
-//     Command::new("bash")
-//         .args(&["--noprofile", "--norc", "-c", source])
-//         .assert()
-//         .success()
-//         .stdout("")
-//         .stderr("");
-// }
-//
-// #[test]
-// fn completions_fish() {
-//     let source = include_str!("../contrib/completions/git-workon.fish");
-//     let tempdir = tempfile::tempdir().unwrap();
-//     let tempdir = tempdir.path().to_str().unwrap();
-//
-//     Command::new("fish")
-//         .env("HOME", tempdir)
-//         .args(&["--command", source, "--private"])
-//         .assert()
-//         .success()
-//         .stdout("")
-//         .stderr("");
-// }
-//
-// #[test]
-// fn completions_powershell() {
-//     let source = include_str!("../contrib/completions/_git-workon.ps1");
-//     Command::new("pwsh")
-//         .args(&[
-//             "-NoLogo",
-//             "-NonInteractive",
-//             "-NoProfile",
-//             "-Command",
-//             source,
-//         ])
-//         .assert()
-//         .success()
-//         .stdout("")
-//         .stderr("");
-// }
-//
-// #[test]
-// fn completions_zsh() {
-//     let source = r#"
-//     set -eu
-//     completions='./contrib/completions'
-//     test -d "$completions"
-//     fpath=("$completions" $fpath)
-//     autoload -Uz compinit
-//     compinit -u
-//     "#;
-//
-//     Command::new("zsh")
-//         .args(&["-c", source, "--no-rcs"])
-//         .assert()
-//         .success()
-//         .stdout("")
-//         .stderr("");
-// }
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn completions_bash() -> Result<(), Box<dyn std::error::Error>> {
+    let output = Command::cargo_bin("git-workon")?
+        .args(["completions", "bash"])
+        .output()?;
+    assert!(output.status.success());
+    let source = String::from_utf8(output.stdout)?;
+    // The function name varies between clap versions; the command it completes doesn't
+    assert!(
+        source
+            .lines()
+            .any(|line| line.trim_start().starts_with("complete -F ")
+                && line.trim_end().ends_with(" git-workon")),
+        "no completion registered for git-workon:\n{}",
+        source
+    );
+
+    Command::new("bash")
+        .args(["--noprofile", "--norc", "-c", &source])
+        .assert()
+        .success()
+        .stdout("")
+        .stderr("");
+
+    Ok(())
+}
+
+#[test]
+fn completions_cover_subcommands_and_flags() -> Result<(), Box<dyn std::error::Error>> {
+    for shell in ["zsh", "fish", "powershell"] {
+        Command::cargo_bin("git-workon")?
+            .args(["completions", shell])
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("prune"))
+            .stdout(predicate::str::contains("allow-dirty"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn completions_rejects_unknown_shell() -> Result<(), Box<dyn std::error::Error>> {
+    Command::cargo_bin("git-workon")?
+        .args(["completions", "tcsh"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value 'tcsh'"));
+
+    Ok(())
+}
@@ -7,6 +7,7 @@ fn copy_basic() -> Result<(), Box<dyn std::error::Error>> {
     let fixture = FixtureBuilder::new()
         .bare(true)
         .default_branch("main")
+        .worktree("main")
         .worktree("feature")
         .config("workon.copyPattern", "**/*")
         .build()?;
@@ -30,7 +31,10 @@ fn copy_basic() -> Result<(), Box<dyn std::error::Error>> {
         .arg("main")
         .arg("feature")
         .assert()
-        .success();
+        .success()
+        // Whether the files could be cloned depends on the filesystem
+        .stdout(predicate::str::contains("Copied 2 file(s), 22 B, "))
+        .stdout(predicate::str::contains("% cloned"));
 
     // Verify files were copied
     assert!(
@@ -240,6 +244,7 @@ fn copy_creates_directories() -> Result<(), Box<dyn std::error::Error>> {
     let fixture = FixtureBuilder::new()
         .bare(true)
         .default_branch("main")
+        .worktree("main")
         .worktree("feature")
         .config("workon.copyPattern", "**/*")
         .build()?;
@@ -362,3 +367,212 @@ fn copy_without_config_defaults_to_all() -> Result<(), Box<dyn std::error::Error
 
     Ok(())
 }
+
+#[test]
+fn copy_skips_tracked_files() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let main_worktree = fixture.root()?.join("main");
+    let feature_worktree = fixture.root()?.join("feature");
+
+    // Commit a file in main so it is tracked there but absent in feature
+    fixture
+        .commit("main")
+        .file("tracked.txt", "tracked")
+        .create("Add tracked file")?;
+    fs::write(main_worktree.join("untracked.txt"), "untracked")?;
+
+    let mut cmd = Command::cargo_bin("git-workon")?;
+    cmd.current_dir(&fixture)
+        .arg("copy-untracked")
+        .arg("main")
+        .arg("feature")
+        .assert()
+        .success();
+
+    assert!(
+        feature_worktree.join("untracked.txt").exists(),
+        "Should copy untracked.txt"
+    );
+    assert!(
+        !feature_worktree.join("tracked.txt").exists(),
+        "Should not copy tracked.txt"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn copy_only_ignored_files() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let main_worktree = fixture.root()?.join("main");
+    let feature_worktree = fixture.root()?.join("feature");
+
+    fixture
+        .commit("main")
+        .file(".gitignore", "*.log\nnode_modules/\n")
+        .create("Add gitignore")?;
+    fs::write(main_worktree.join("debug.log"), "debug info")?;
+    fs::create_dir_all(main_worktree.join("node_modules/pkg"))?;
+    fs::write(main_worktree.join("node_modules/pkg/index.js"), "module")?;
+    fs::write(main_worktree.join("notes.txt"), "notes")?;
+
+    let mut cmd = Command::cargo_bin("git-workon")?;
+    cmd.current_dir(&fixture)
+        .arg("copy-untracked")
+        .arg("--only")
+        .arg("ignored")
+        .arg("main")
+        .arg("feature")
+        .assert()
+        .success();
+
+    assert!(
+        feature_worktree.join("debug.log").exists(),
+        "Should copy debug.log (ignored)"
+    );
+    assert!(
+        feature_worktree.join("node_modules/pkg/index.js").exists(),
+        "Should copy files inside ignored directories"
+    );
+    assert!(
+        !feature_worktree.join("notes.txt").exists(),
+        "Should not copy notes.txt (untracked, not ignored)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn copy_directory_pattern_includes_contents() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let main_worktree = fixture.root()?.join("main");
+    let feature_worktree = fixture.root()?.join("feature");
+
+    fs::create_dir_all(main_worktree.join(".vscode/extra"))?;
+    fs::write(main_worktree.join(".vscode/settings.json"), "{}")?;
+    fs::write(main_worktree.join(".vscode/extra/tasks.json"), "{}")?;
+    fs::write(main_worktree.join("other.txt"), "other")?;
+
+    let mut cmd = Command::cargo_bin("git-workon")?;
+    cmd.current_dir(&fixture)
+        .arg("copy-untracked")
+        .arg("--pattern")
+        .arg(".vscode/")
+        .arg("main")
+        .arg("feature")
+        .assert()
+        .success();
+
+    assert!(feature_worktree.join(".vscode/settings.json").exists());
+    assert!(feature_worktree.join(".vscode/extra/tasks.json").exists());
+    assert!(!feature_worktree.join("other.txt").exists());
+
+    Ok(())
+}
+
+#[test]
+fn copy_defaults_source_to_default_branch_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let main_worktree = fixture.root()?.join("main");
+    let feature_worktree = fixture.root()?.join("feature");
+    fs::write(main_worktree.join(".env.local"), "SECRET=1")?;
+
+    // Only the destination is given
+    let mut cmd = Command::cargo_bin("git-workon")?;
+    cmd.current_dir(&main_worktree)
+        .arg("copy-untracked")
+        .arg("feature")
+        .assert()
+        .success();
+
+    assert!(
+        feature_worktree.join(".env.local").exists(),
+        "Should copy from the default branch's worktree"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn copy_without_args_copies_into_current_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let main_worktree = fixture.root()?.join("main");
+    let feature_worktree = fixture.root()?.join("feature");
+    fs::write(main_worktree.join(".env.local"), "SECRET=1")?;
+
+    let mut cmd = Command::cargo_bin("git-workon")?;
+    cmd.current_dir(&feature_worktree)
+        .arg("copy-untracked")
+        .assert()
+        .success();
+
+    assert!(
+        feature_worktree.join(".env.local").exists(),
+        "Should copy from the default branch's worktree into the current one"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn copy_without_args_into_namespaced_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["new", "user/feature", "--no-interactive"])
+        .assert()
+        .success();
+
+    let main_worktree = fixture.root()?.join("main");
+    let feature_worktree = fixture.root()?.join("user/feature");
+    fs::write(main_worktree.join(".env.local"), "SECRET=1")?;
+
+    let mut cmd = Command::cargo_bin("git-workon")?;
+    cmd.current_dir(&feature_worktree)
+        .arg("copy-untracked")
+        .assert()
+        .success();
+
+    assert!(
+        feature_worktree.join(".env.local").exists(),
+        "Should copy into the worktree's actual path, not <root>/<basename>"
+    );
+    assert!(!fixture.root()?.join("feature").exists());
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn default_prints_default_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("default")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("main\n"))
+        .stderr(predicate::str::contains("Default branch: main"));
+
+    Ok(())
+}
+
+#[test]
+fn default_sets_workon_default_branch() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("default")
+        .arg("develop")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Default branch set to 'develop'"));
+
+    let default_branch = fixture
+        .repo()?
+        .config()?
+        .get_string("workon.defaultBranch")?;
+    assert_eq!(default_branch, "develop");
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("default")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("develop\n"));
+
+    Ok(())
+}
+
+#[test]
+fn prune_merged_targets_configured_default_branch() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .worktree("feature")
+        .config("workon.defaultBranch", "develop")
+        .build()?;
+
+    let feature_commit_oid = fixture
+        .commit("feature")
+        .file("feature.txt", "feature")
+        .create("Feature commit")?;
+
+    // Merge feature into develop only
+    let repo = fixture.repo()?;
+    repo.find_branch("develop", git2::BranchType::Local)?
+        .get_mut()
+        .set_target(feature_commit_oid, "Fast-forward to feature")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--merged")
+        .arg("--yes")
+        .assert()
+        .success();
+
+    assert!(!fixture.root()?.join("feature").exists());
+    assert!(fixture.root()?.join("develop").is_dir());
+
+    Ok(())
+}
+
+#[test]
+fn default_unknown_name_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("default")
+        .arg("nope")
+        .assert()
+        .failure();
+
+    assert!(fixture
+        .repo()?
+        .config()?
+        .get_string("workon.defaultBranch")
+        .is_err());
+
+    Ok(())
+}
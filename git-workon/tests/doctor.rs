@@ -48,7 +48,7 @@ fn doctor_detects_missing_directory() -> Result<(), Box<dyn std::error::Error>>
         .current_dir(&main_path)
         .arg("doctor")
         .assert()
-        .success()
+        .code(1)
         .stderr(predicate::str::contains("missing directory"));
 
     Ok(())
@@ -78,7 +78,7 @@ fn doctor_fix_missing_directory() -> Result<(), Box<dyn std::error::Error>> {
         .stderr(predicate::str::contains("Pruned: feature"));
 
     // Verify the worktree entry is removed from git
-    let bare_path = fixture.root()?.join(".bare");
+    let bare_path = fixture.bare_path()?;
     let bare_repo = git2::Repository::open_bare(&bare_path)?;
     assert!(
         bare_repo.find_worktree("feature").is_err(),
@@ -108,11 +108,11 @@ fn doctor_dry_run_does_not_fix() -> Result<(), Box<dyn std::error::Error>> {
         .arg("doctor")
         .arg("--dry-run")
         .assert()
-        .success()
+        .code(1)
         .stderr(predicate::str::contains("Would fix 1 issue(s)"));
 
     // Verify the worktree entry is still registered (not pruned)
-    let bare_path = fixture.root()?.join(".bare");
+    let bare_path = fixture.bare_path()?;
     let bare_repo = git2::Repository::open_bare(&bare_path)?;
     assert!(
         bare_repo.find_worktree("feature").is_ok(),
@@ -142,8 +142,9 @@ fn doctor_json_output() -> Result<(), Box<dyn std::error::Error>> {
         .arg("--json")
         .output()?;
 
-    assert!(
-        output.status.success(),
+    assert_eq!(
+        output.status.code(),
+        Some(1),
         "stderr: {}",
         std::str::from_utf8(&output.stderr).unwrap_or("(invalid utf8)")
     );
@@ -164,3 +165,817 @@ fn doctor_json_output() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn doctor_detects_branch_renamed_outside_workon() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    // Rename the branch behind workon's back
+    fixture
+        .repo()?
+        .find_branch("feature", git2::BranchType::Local)?
+        .rename("renamed", false)?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "branch was renamed to 'renamed' outside workon",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn doctor_fix_completes_external_branch_rename() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    fixture
+        .repo()?
+        .find_branch("feature", git2::BranchType::Local)?
+        .rename("renamed", false)?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .arg("--fix")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Moved: feature → renamed"));
+
+    // Directory and metadata now follow the branch name
+    fixture
+        .root()?
+        .child("renamed")
+        .assert(predicate::path::is_dir());
+    fixture
+        .root()?
+        .child("feature")
+        .assert(predicate::path::missing());
+
+    let bare_repo = git2::Repository::open_bare(fixture.bare_path()?)?;
+    bare_repo.assert(predicate::repo::has_worktree("renamed"));
+    bare_repo.find_worktree("renamed")?.validate()?;
+
+    Ok(())
+}
+
+#[test]
+fn doctor_fix_keeps_going_after_a_failed_fix() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .config("workon-worktree.old.note", "waiting on review")
+        .build()?;
+
+    // A file where the renamed branch's directory would go makes the move fail
+    fixture
+        .repo()?
+        .find_branch("feature", git2::BranchType::Local)?
+        .rename("blocked/feature", false)?;
+    std::fs::write(fixture.root()?.join("blocked"), "")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("doctor")
+        .arg("--fix")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("Could not fix 'feature'"))
+        .stderr(predicate::str::contains("Removed labels/notes: old"));
+
+    fixture
+        .root()?
+        .child("feature")
+        .assert(predicate::path::is_dir());
+
+    Ok(())
+}
+
+/// Create a worktree for a new `elsewhere` branch outside the fixture root, the way
+/// `git worktree add -b elsewhere ../elsewhere` would.
+fn add_outside_worktree(
+    fixture: &Fixture,
+    outside: &assert_fs::TempDir,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let repo = fixture.repo()?;
+    let head = repo.head()?.peel_to_commit()?;
+    let branch = repo.branch("elsewhere", &head, false)?;
+    let mut opts = git2::WorktreeAddOptions::new();
+    opts.reference(Some(branch.get()));
+    repo.worktree("elsewhere", &outside.path().join("elsewhere"), Some(&opts))?;
+    Ok(())
+}
+
+#[test]
+fn doctor_detects_worktree_outside_root() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let outside = assert_fs::TempDir::new()?;
+    add_outside_worktree(&fixture, &outside)?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("outside workon root"));
+
+    Ok(())
+}
+
+#[test]
+fn doctor_fix_moves_worktree_under_root() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let outside = assert_fs::TempDir::new()?;
+    add_outside_worktree(&fixture, &outside)?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .arg("--fix")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Moved: elsewhere"));
+
+    fixture
+        .root()?
+        .child("elsewhere")
+        .assert(predicate::path::is_dir());
+    outside
+        .child("elsewhere")
+        .assert(predicate::path::missing());
+
+    let bare_repo = git2::Repository::open_bare(fixture.bare_path()?)?;
+    bare_repo.find_worktree("elsewhere")?.validate()?;
+
+    Ok(())
+}
+
+#[test]
+fn doctor_fix_registers_external_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let outside = assert_fs::TempDir::new()?;
+    add_outside_worktree(&fixture, &outside)?;
+    // Registering doesn't need the destination a move would
+    std::fs::create_dir(fixture.root()?.join("elsewhere"))?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .arg("--fix")
+        .arg("--register-external")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Registered as external: elsewhere",
+        ));
+
+    // The worktree stays put and is no longer reported
+    outside.child("elsewhere").assert(predicate::path::is_dir());
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("outside workon root").not());
+
+    Ok(())
+}
+
+#[test]
+fn doctor_detects_broken_git_link() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    fixture
+        .root()?
+        .child("feature/.git")
+        .write_str("gitdir: /nonexistent/worktrees/feature\n")?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("broken git link"))
+        .stderr(predicate::str::contains(
+            "1 issue(s) can be automatically fixed",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn doctor_fix_repairs_broken_git_link() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    fixture
+        .root()?
+        .child("feature/.git")
+        .write_str("gitdir: /nonexistent/worktrees/feature\n")?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .arg("--fix")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Repaired: feature"));
+
+    // The worktree opens as a repository again
+    let feature = git2::Repository::open(fixture.root()?.join("feature"))?;
+    assert!(feature.is_worktree());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("broken git link").not());
+
+    Ok(())
+}
+
+#[test]
+fn doctor_network_checks_remotes() -> Result<(), Box<dyn std::error::Error>> {
+    let upstream = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let missing = assert_fs::TempDir::new()?;
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote(
+            "origin",
+            upstream.bare_path()?.to_string_lossy().to_string(),
+        )
+        .remote(
+            "dead",
+            missing.path().join("gone").to_string_lossy().to_string(),
+        )
+        .worktree("main")
+        .build()?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .args(["doctor", "--network"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Checking remotes..."))
+        .stderr(predicate::str::contains("✓ origin"))
+        .stderr(predicate::str::contains("✗ dead"));
+
+    Ok(())
+}
+
+#[test]
+fn doctor_skips_remotes_without_network_flag() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote("origin", "/dev/null")
+        .worktree("main")
+        .build()?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Checking remotes...").not());
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn mode(path: &std::path::Path) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).unwrap().permissions().mode()
+}
+
+#[test]
+fn doctor_detects_missing_hook_script() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .config("workon.postCreateHook", "./scripts/setup.sh --quiet")
+        .worktree("main")
+        .build()?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .args(["doctor", "--json"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("\"hook_script_missing\""));
+
+    Ok(())
+}
+
+#[test]
+fn doctor_validates_hook_cwd_and_shell() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .config("workon.hookShell", "no-such-shell-xyz -c")
+        .config("workon.postCreateHook", "cwd=nowhere make")
+        .worktree("main")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .args(["doctor", "--json"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(2));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let issues = json["issues"].as_array().unwrap();
+    assert!(issues
+        .iter()
+        .any(|i| i["kind"] == "hook_shell_not_found" && i["command"] == "no-such-shell-xyz"));
+    assert!(issues
+        .iter()
+        .any(|i| i["kind"] == "invalid_hook" && i["hook"] == "cwd=nowhere make"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn doctor_fix_makes_hook_scripts_executable() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .config("workon.postCreateHook", "./scripts/setup.sh")
+        .worktree("main")
+        .build()?;
+
+    let main = fixture.root()?.child("main");
+    main.child("scripts/setup.sh").write_str("#!/bin/sh\n")?;
+    main.child(".workon/hooks/post-create")
+        .write_str("#!/bin/sh\n")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(main.path())
+        .arg("doctor")
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("chmod +x"))
+        .stderr(predicate::str::contains(".workon/hooks/post-create"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(main.path())
+        .args(["doctor", "--fix"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Made executable"));
+
+    assert_ne!(mode(&main.path().join("scripts/setup.sh")) & 0o111, 0);
+    assert_ne!(
+        mode(&main.path().join(".workon/hooks/post-create")) & 0o111,
+        0
+    );
+
+    Ok(())
+}
+
+#[test]
+fn doctor_exits_2_for_unfixable_issues() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .config("workon.postCreateHook", "definitely-not-a-real-command")
+        .build()?;
+
+    // One fixable and one unfixable issue: the unfixable one wins
+    std::fs::remove_dir_all(fixture.cwd()?.path())?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .assert()
+        .code(2);
+
+    // Fixing the missing directory leaves only the unfixable hook issue
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .args(["doctor", "--fix"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Pruned: feature"));
+
+    Ok(())
+}
+
+#[test]
+fn doctor_fail_on_warn_counts_warnings() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote("origin", "/dev/null")
+        .worktree("main")
+        .upstream("main", "origin/main")
+        .build()?;
+
+    // A gone upstream is only a warning
+    fixture
+        .repo()?
+        .find_reference("refs/remotes/origin/main")?
+        .delete()?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("upstream branch is gone"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .args(["doctor", "--fail-on", "warn"])
+        .assert()
+        .code(2);
+
+    Ok(())
+}
+
+#[test]
+fn doctor_detects_stale_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon-worktree.main.label", "keep")
+        .config("workon-worktree.old.label", "backend")
+        .config("workon-worktree.old.note", "waiting on review")
+        .config("workon.hiddenWorktree", "old")
+        // Aliases share the three-part key shape but aren't worktree metadata
+        .config("workon.alias.p", "prune --merged")
+        .build()?;
+
+    let workon_dir = fixture.repo()?.commondir().join("workon");
+    std::fs::create_dir_all(&workon_dir)?;
+    std::fs::write(
+        workon_dir.join("visits"),
+        "1700000000\tvisit\tmain\n1700000001\tvisit\told\n",
+    )?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("doctor")
+        .arg("--json")
+        .output()?;
+    // Stale metadata is a warning, not a failure
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let stale: Vec<(String, String)> = json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|issue| issue["kind"] == "stale_metadata")
+        .map(|issue| {
+            (
+                issue["name"].as_str().unwrap().to_string(),
+                issue["store"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        stale,
+        vec![
+            ("old".to_string(), "worktree_config".to_string()),
+            ("old".to_string(), "workon.hiddenWorktree".to_string()),
+            ("old".to_string(), "visits".to_string()),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn doctor_fix_removes_stale_metadata() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon-worktree.main.label", "keep")
+        .config("workon-worktree.old.label", "backend")
+        .config("workon-worktree.old.note", "waiting on review")
+        .config("workon.hiddenWorktree", "old")
+        .build()?;
+
+    let visits = fixture.repo()?.commondir().join("workon").join("visits");
+    std::fs::create_dir_all(visits.parent().unwrap())?;
+    std::fs::write(&visits, "1700000000\tvisit\tmain\n1700000001\tvisit\told\n")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("doctor")
+        .arg("--fix")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed labels/notes: old"))
+        .stderr(predicate::str::contains(
+            "Removed from workon.hiddenWorktree: old",
+        ))
+        .stderr(predicate::str::contains("Removed visit history: old"));
+
+    let config = fixture.repo()?.config()?;
+    assert!(config.get_string("workon-worktree.old.label").is_err());
+    assert!(config.get_string("workon-worktree.old.note").is_err());
+    assert!(config.get_string("workon.hiddenWorktree").is_err());
+    assert_eq!(config.get_string("workon-worktree.main.label")?, "keep");
+    assert_eq!(
+        std::fs::read_to_string(&visits)?,
+        "1700000000\tvisit\tmain\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn doctor_interactive_fix_requires_a_terminal() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let feature_path = fixture.cwd()?.to_path_buf();
+    std::fs::remove_dir_all(&feature_path)?;
+
+    let main_path = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .arg("--fix")
+        .arg("--interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("needs a terminal"));
+
+    // Nothing was fixed
+    let bare_repo = git2::Repository::open_bare(fixture.bare_path()?)?;
+    assert!(bare_repo.find_worktree("feature").is_ok());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .arg("--interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--fix"));
+
+    Ok(())
+}
+
+#[test]
+fn doctor_schema_describes_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+    std::fs::remove_dir_all(fixture.cwd()?)?;
+
+    let main_path = fixture.root()?.join("main");
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .arg("--schema")
+        .output()?;
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&main_path)
+        .arg("doctor")
+        .arg("--json")
+        .output()?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    for key in json.as_object().unwrap().keys() {
+        assert!(schema["properties"].get(key).is_some(), "'{key}' missing");
+    }
+    let issue_schema = &schema["properties"]["issues"]["items"];
+    let kinds = issue_schema["properties"]["kind"]["enum"]
+        .as_array()
+        .unwrap();
+    for issue in json["issues"].as_array().unwrap() {
+        assert!(
+            kinds.contains(&issue["kind"]),
+            "{} not in schema",
+            issue["kind"]
+        );
+        for key in issue.as_object().unwrap().keys() {
+            assert!(
+                issue_schema["properties"].get(key).is_some(),
+                "'{key}' missing"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn doctor_fix_removes_unused_fork_remotes() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote("pr-7-fork", "/dev/null")
+        .remote("pr-8-fork", "/dev/null")
+        .remote("mine", "/dev/null")
+        .worktree("main")
+        .worktree("pr-8")
+        .upstream("pr-8", "pr-8-fork/pr-8")
+        .config("workon.forkRemote", "pr-7-fork")
+        .config("workon.forkRemote", "pr-8-fork")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("doctor")
+        .arg("--json")
+        .output()?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let remotes: Vec<_> = json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|issue| issue["kind"] == "unused_fork_remote")
+        .map(|issue| issue["remote"].as_str().unwrap())
+        .collect();
+    assert_eq!(remotes, ["pr-7-fork"]);
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("doctor")
+        .arg("--fix")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed fork remote: pr-7-fork"));
+
+    let repo = fixture.repo()?;
+    assert!(repo.find_remote("pr-7-fork").is_err());
+    assert!(repo.find_remote("pr-8-fork").is_ok());
+    assert!(repo.find_remote("mine").is_ok());
+    assert_eq!(repo.config()?.get_string("workon.forkRemote")?, "pr-8-fork");
+
+    Ok(())
+}
+
+#[test]
+fn doctor_reports_config_problems_by_severity() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.prFormat", "pr")
+        .config("workon.prFromat", "pr-{number}")
+        .config("workon.main.colour", "blue")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("doctor")
+        .arg("--json")
+        .output()?;
+    // An invalid value is an error nothing can fix
+    assert_eq!(output.status.code(), Some(2));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let issue = |kind: &str| {
+        json["issues"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|i| i["kind"] == kind)
+            .cloned()
+            .unwrap_or_else(|| panic!("no {kind} issue in {json}"))
+    };
+
+    let invalid = issue("invalid_config");
+    assert_eq!(invalid["severity"], "error");
+    assert_eq!(invalid["key"], "workon.prformat");
+    assert_eq!(invalid["value"], "pr");
+
+    let unknown = issue("unknown_config_key");
+    assert_eq!(unknown["severity"], "warning");
+    assert_eq!(unknown["key"], "workon.prfromat");
+    assert_eq!(unknown["suggestion"], "workon.prFormat");
+
+    let worktree_key = issue("unknown_worktree_config_key");
+    assert_eq!(worktree_key["severity"], "info");
+    assert_eq!(worktree_key["key"], "workon.main.colour");
+
+    Ok(())
+}
+
+#[test]
+fn doctor_info_findings_do_not_fail() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.main.colour", "blue")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("doctor")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "workon.main.colour — not a worktree setting workon uses",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn doctor_reports_network_filesystem() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.networkFilesystem", "true")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("doctor")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let issue = json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|issue| issue["kind"] == "network_filesystem")
+        .expect("network_filesystem issue");
+    assert_eq!(issue["severity"], "info");
+    assert_eq!(issue["filesystem"], serde_json::Value::Null);
+    assert!(issue["message"]
+        .as_str()
+        .unwrap()
+        .contains("move copies worktrees"));
+    assert_eq!(
+        json["configuration"]["workon.networkFilesystem"]["value"],
+        "true"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn env_exports_current_worktree_variables() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(fixture.cwd()?.path())
+        .arg("env")
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+
+    let path = fixture.cwd()?.path().to_str().unwrap().to_string();
+    assert!(stdout.contains("export WORKON_ROOT='"), "{stdout}");
+    assert!(
+        stdout.contains(&format!("export WORKON_WORKTREE_PATH='{}'", path)),
+        "{stdout}"
+    );
+    assert!(stdout.contains("export WORKON_BRANCH_NAME='feature'"));
+    assert!(stdout.contains("export WORKON_BASE_BRANCH='main'"));
+
+    Ok(())
+}
+
+#[test]
+fn env_unsets_base_branch_for_default_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("env")
+        .arg("main")
+        .arg("--shell")
+        .arg("fish")
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+
+    assert!(
+        stdout.contains("set -gx WORKON_BRANCH_NAME 'main'"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("set -e WORKON_BASE_BRANCH"), "{stdout}");
+
+    Ok(())
+}
+
+#[test]
+fn env_json_reports_variables() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("env")
+        .arg("feat")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["WORKON_BRANCH_NAME"], "feature");
+    assert_eq!(json["WORKON_BASE_BRANCH"], "main");
+    assert!(json["WORKON_WORKTREE_PATH"]
+        .as_str()
+        .is_some_and(|p| p.ends_with("feature")));
+
+    Ok(())
+}
@@ -42,6 +42,127 @@ fn find_no_match_errors() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn find_no_match_suggests_similar_name() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature-auth")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("feature-atuh")
+        .arg("--no-interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Did you mean 'feature-auth'?"));
+
+    Ok(())
+}
+
+#[test]
+fn find_no_match_suggests_create() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("login-form")
+        .arg("--no-interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--create"));
+
+    fixture
+        .root()?
+        .child("login-form")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+#[test]
+fn find_create_makes_missing_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    // The default invocation, as `git workon <name> --create`
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("login-form")
+        .arg("--create")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("login-form\n"));
+
+    let repo = fixture.repo()?;
+    repo.assert(predicate::repo::has_branch("login-form"));
+    repo.assert(predicate::repo::has_worktree("login-form"));
+
+    // Once it exists, the same invocation just finds it
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("login-form")
+        .arg("--create")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("login-form\n"));
+
+    Ok(())
+}
+
+#[test]
+fn find_auto_create_config() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.autoCreate", "true")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("login-form")
+        .arg("--no-interactive")
+        .assert()
+        .success();
+
+    fixture
+        .root()?
+        .child("login-form")
+        .assert(predicate::path::is_dir());
+
+    // Filters may be hiding the worktree, so they never create one
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("signup-form")
+        .arg("--dirty")
+        .arg("--no-interactive")
+        .assert()
+        .failure();
+
+    fixture
+        .root()?
+        .child("signup-form")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
 #[test]
 fn find_multiple_fuzzy_matches_errors_with_no_interactive() -> Result<(), Box<dyn std::error::Error>>
 {
@@ -119,6 +240,54 @@ fn find_with_dirty_filter() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn find_any_combines_filters_with_or() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("idle")
+        .worktree("edited")
+        .worktree("committed")
+        .remote("origin", "https://github.com/test/test.git")
+        .upstream("committed", "origin/committed")
+        .build()?;
+
+    std::fs::write(
+        fixture.root()?.child("edited").join("test.txt"),
+        "uncommitted",
+    )?;
+    fixture
+        .commit("committed")
+        .file("ahead.txt", "content")
+        .create("Ahead commit")?;
+
+    // Neither worktree is both dirty and ahead
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "--dirty", "--ahead", "--all", "--no-interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No worktrees match"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args([
+            "find",
+            "--dirty",
+            "--ahead",
+            "--any",
+            "--all",
+            "--no-interactive",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("edited"))
+        .stdout(predicate::str::contains("committed"))
+        .stdout(predicate::str::contains("idle").not());
+
+    Ok(())
+}
+
 #[test]
 fn find_all_filtered_out_errors() -> Result<(), Box<dyn std::error::Error>> {
     let fixture = FixtureBuilder::new()
@@ -179,6 +348,158 @@ fn find_case_insensitive_fuzzy_match() -> Result<(), Box<dyn std::error::Error>>
     Ok(())
 }
 
+#[test]
+fn find_matches_branch_name_prefix() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    // Worktree names are basenames, so only the branch carries the "user/" prefix
+    for name in ["user/feature", "other"] {
+        Command::cargo_bin("git-workon")?
+            .current_dir(&fixture)
+            .args(["new", name, "--no-interactive"])
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "user/", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("user/feature"));
+
+    Ok(())
+}
+
+#[test]
+fn find_exact_branch_name_match() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    for name in ["user/fix", "user/fix-2"] {
+        Command::cargo_bin("git-workon")?
+            .current_dir(&fixture)
+            .args(["new", name, "--no-interactive"])
+            .assert()
+            .success();
+    }
+
+    // "user/fix" is a substring of both branches, but matches one exactly
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "user/fix", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("user/fix\n"));
+
+    Ok(())
+}
+
+#[test]
+fn find_all_prints_every_match() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature-a")
+        .worktree("feature-b")
+        .worktree("other")
+        .build()?;
+
+    let root = fixture.root()?;
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "feature", "--all"])
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            root.join("feature-a").to_str().unwrap(),
+            root.join("feature-b").to_str().unwrap(),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn find_all_without_name_prints_all_worktrees() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "--all", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main"))
+        .stdout(predicate::str::contains("feature"));
+
+    Ok(())
+}
+
+#[test]
+fn find_all_json_prints_array() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature-a")
+        .worktree("feature-b")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "feature", "--all", "--json"])
+        .output()?;
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let names: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|wt| wt["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["feature-a", "feature-b"]);
+
+    Ok(())
+}
+
+#[test]
+fn find_records_visit() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "feature"])
+        .assert()
+        .success();
+
+    let store = workon::VisitStore::new(fixture.repo()?);
+    assert_eq!(store.last_visited()?, Some("feature".to_string()));
+
+    Ok(())
+}
+
 // --- Interactive PTY tests ---
 
 const ARROW_DOWN: &[u8] = b"\x1b[B";
@@ -0,0 +1,172 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn hide_excludes_worktree_from_list() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("gh-pages")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("hide")
+        .arg("gh-pages")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Hid 'gh-pages'"));
+
+    let hidden = fixture
+        .repo()?
+        .config()?
+        .get_string("workon.hiddenWorktree")?;
+    assert_eq!(hidden, "gh-pages");
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main"))
+        .stdout(predicate::str::contains("gh-pages").not());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--all")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("gh-pages"));
+
+    Ok(())
+}
+
+#[test]
+fn unhide_restores_worktree_in_list() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("gh-pages")
+        .config("workon.hiddenWorktree", "gh-pages")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("unhide")
+        .arg("gh-pages")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Unhid 'gh-pages'"));
+
+    assert!(fixture
+        .repo()?
+        .config()?
+        .get_string("workon.hiddenWorktree")
+        .is_err());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("gh-pages"));
+
+    Ok(())
+}
+
+#[test]
+fn find_skips_hidden_worktrees_unless_named_exactly() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("docs")
+        .config("workon.hiddenWorktree", "docs")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("doc")
+        .arg("--no-interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No matching worktree found for 'doc'",
+        ));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("docs")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("docs\n"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("--all")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("docs").not());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("doc")
+        .arg("--include-hidden")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("docs\n"));
+
+    Ok(())
+}
+
+#[test]
+fn hide_keys_worktrees_by_full_name() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    for name in ["a/feature", "b/fix"] {
+        Command::cargo_bin("git-workon")?
+            .current_dir(&fixture)
+            .arg("new")
+            .arg(name)
+            .arg("--no-interactive")
+            .assert()
+            .success();
+    }
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("hide")
+        .arg("a/feature")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Hid 'a/feature'"));
+
+    assert_eq!(
+        fixture
+            .repo()?
+            .config()?
+            .get_string("workon.hiddenWorktree")?,
+        "a/feature"
+    );
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("b/fix"))
+        .stdout(predicate::str::contains("a/feature").not());
+
+    Ok(())
+}
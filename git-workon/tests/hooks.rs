@@ -331,3 +331,201 @@ fn no_hooks_configured_succeeds() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn hook_runs_with_configured_shell() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.hookShell", "bash")
+        .config(
+            "workon.postCreateHook",
+            "[[ -n $BASH_VERSION ]] && echo bash > shell.txt",
+        )
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .assert()
+        .success();
+
+    let shell = fs::read_to_string(fixture.root()?.join("feature/shell.txt"))?;
+    assert_eq!(shell.trim(), "bash");
+
+    Ok(())
+}
+
+#[test]
+fn hook_cwd_prefix_picks_directory() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .config("workon.postCreateHook", "cwd=root touch from-root")
+        .config("workon.postCreateHook", "cwd=base touch from-base")
+        .config("workon.postCreateHook", "touch from-worktree")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("--base")
+        .arg("develop")
+        .arg("feature")
+        .assert()
+        .success();
+
+    let root = fixture.root()?;
+    assert!(root.join("from-root").exists());
+    assert!(root.join("develop/from-base").exists());
+    assert!(root.join("feature/from-worktree").exists());
+    assert!(!root.join("feature/from-root").exists());
+
+    Ok(())
+}
+
+#[test]
+fn hook_with_unknown_cwd_warns() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.postCreateHook", "cwd=elsewhere touch marker")
+        .config("workon.postCreateHook", "touch after")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("unknown cwd 'elsewhere'"))
+        .stderr(predicate::str::contains(
+            "Skipping hook 'cwd=elsewhere touch marker'",
+        ));
+
+    // Only the malformed hook is skipped
+    let feature = fixture.root()?.child("feature");
+    feature.child("marker").assert(predicate::path::missing());
+    feature.child("after").assert(predicate::path::is_file());
+
+    Ok(())
+}
+
+#[test]
+fn hook_output_is_prefixed_on_stderr() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config(
+            "workon.postCreateHook",
+            "echo from-stdout; echo from-stderr >&2",
+        )
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let stderr = String::from_utf8(output.stderr)?;
+    assert!(stderr.contains("[echo] from-stdout"), "stderr: {}", stderr);
+    assert!(stderr.contains("[echo] from-stderr"), "stderr: {}", stderr);
+    // stdout is left to the worktree path, for shell integration
+    assert!(!stdout.contains("from-stdout"), "stdout: {}", stdout);
+
+    Ok(())
+}
+
+#[test]
+fn hook_output_is_included_in_json() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.postCreateHook", "echo first; echo second")
+        .config("workon.postCreateHook", "echo failing; exit 3")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["name"], "feature");
+    let hooks = json["hooks"].as_array().unwrap();
+    assert_eq!(hooks.len(), 2);
+    assert_eq!(hooks[0]["command"], "echo first; echo second");
+    assert_eq!(hooks[0]["success"], true);
+    assert_eq!(hooks[0]["output"], serde_json::json!(["first", "second"]));
+    assert_eq!(hooks[1]["success"], false);
+    assert_eq!(hooks[1]["exit_code"], 3);
+    assert_eq!(hooks[1]["output"], serde_json::json!(["failing"]));
+
+    Ok(())
+}
+
+#[test]
+fn failed_task_shows_its_last_output() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config(
+            "workon.task.build",
+            "echo compiling; echo 'error: boom' >&2; exit 1",
+        )
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .args(["run", "build"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("[main] error: boom"))
+        .stderr(predicate::str::contains(
+            "Task 'build' failed with exit code",
+        ))
+        .stderr(predicate::str::contains("Last output"));
+
+    Ok(())
+}
+
+#[test]
+fn hook_reads_the_terminal_stdin() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .config(
+            "workon.postCreateHook",
+            "read answer && echo \"$answer\" > answer.txt",
+        )
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["new", "feature", "--no-interactive"])
+        .write_stdin("yes\n")
+        .assert()
+        .success();
+
+    assert_eq!(
+        fs::read_to_string(fixture.root()?.join("feature/answer.txt"))?,
+        "yes\n"
+    );
+
+    Ok(())
+}
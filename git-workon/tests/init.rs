@@ -56,3 +56,36 @@ fn init_with_name() -> Result<(), Box<dyn std::error::Error>> {
     temp.close()?;
     Ok(())
 }
+
+#[cfg(unix)]
+#[test]
+fn init_no_sign_skips_a_failing_signer() -> Result<(), Box<dyn std::error::Error>> {
+    let home = TempDir::new()?;
+    home.child(".gitconfig").write_str(
+        "[user]\n\tname = Test User\n\temail = test@example.com\n\
+         [commit]\n\tgpgsign = true\n[gpg]\n\tprogram = /nonexistent/gpg\n",
+    )?;
+    let temp = TempDir::new()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&temp)
+        .env("HOME", home.path())
+        .env_remove("XDG_CONFIG_HOME")
+        .args(["init", "signed"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--no-sign"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&temp)
+        .env("HOME", home.path())
+        .env_remove("XDG_CONFIG_HOME")
+        .args(["init", "unsigned", "--no-sign"])
+        .assert()
+        .success();
+    let repo = Repository::open(temp.path().join("unsigned/.bare"))?;
+    let head = repo.head()?.peel_to_commit()?;
+    assert!(repo.extract_signature(&head.id(), None).is_err());
+
+    Ok(())
+}
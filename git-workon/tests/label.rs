@@ -0,0 +1,214 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn label_add_stores_labels_and_list_shows_them() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("label")
+        .arg("add")
+        .arg("feature")
+        .arg("backend")
+        .arg("urgent")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Labeled 'feature': backend, urgent",
+        ));
+
+    let config = fixture.repo()?.config()?;
+    let mut labels = Vec::new();
+    config
+        .multivar("workon-worktree.feature.label", None)?
+        .for_each(|entry| labels.push(entry.value().unwrap().to_string()))?;
+    assert_eq!(labels, vec!["backend", "urgent"]);
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("#backend #urgent"));
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let feature = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|wt| wt["name"] == "feature")
+        .unwrap();
+    assert_eq!(feature["labels"], serde_json::json!(["backend", "urgent"]));
+
+    Ok(())
+}
+
+#[test]
+fn label_remove_drops_only_named_labels() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .config("workon-worktree.feature.label", "backend")
+        .config("workon-worktree.feature.label", "urgent")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("label")
+        .arg("remove")
+        .arg("feature")
+        .arg("urgent")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed from 'feature': urgent"));
+
+    let config = fixture.repo()?.config()?;
+    let mut labels = Vec::new();
+    config
+        .multivar("workon-worktree.feature.label", None)?
+        .for_each(|entry| labels.push(entry.value().unwrap().to_string()))?;
+    assert_eq!(labels, vec!["backend"]);
+
+    Ok(())
+}
+
+#[test]
+fn list_and_find_filter_by_label() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("api")
+        .worktree("ui")
+        .config("workon-worktree.api.label", "backend")
+        .config("workon-worktree.api.label", "urgent")
+        .config("workon-worktree.ui.label", "urgent")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--label")
+        .arg("urgent")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("api"))
+        .stdout(predicate::str::contains("ui"))
+        .stdout(predicate::str::contains("main").not());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--label")
+        .arg("urgent")
+        .arg("--label")
+        .arg("backend")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("api"))
+        .stdout(predicate::str::contains("ui").not());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("find")
+        .arg("--label")
+        .arg("backend")
+        .arg("--all")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("api\n"));
+
+    Ok(())
+}
+
+#[test]
+fn label_keys_worktrees_by_full_name_in_own_section() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("task")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["new", "alice/fix", "--no-interactive"])
+        .assert()
+        .success();
+
+    for (name, label) in [("task", "backend"), ("alice/fix", "urgent")] {
+        Command::cargo_bin("git-workon")?
+            .current_dir(&fixture)
+            .args(["label", "add", name, label])
+            .assert()
+            .success();
+    }
+
+    let config = fixture.repo()?.config()?;
+    assert_eq!(config.get_string("workon-worktree.task.label")?, "backend");
+    assert_eq!(
+        config.get_string("workon-worktree.alice/fix.label")?,
+        "urgent"
+    );
+    // A worktree named like a workon subsection doesn't write into it
+    assert!(config.get_string("workon.task.label").is_err());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "--label", "backend", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("task\n"));
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["find", "--label", "urgent", "--all"])
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("alice/fix\n"));
+
+    Ok(())
+}
+
+#[test]
+fn prune_label_limits_discovered_worktrees() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("spike")
+        .worktree("feature")
+        .config("workon-worktree.spike.label", "experiment")
+        .build()?;
+
+    // Both branches are deleted, so both are prune candidates without --label
+    let repo = fixture.repo()?;
+    repo.find_reference("refs/heads/spike")?.delete()?;
+    repo.find_reference("refs/heads/feature")?.delete()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--label")
+        .arg("experiment")
+        .arg("--yes")
+        .assert()
+        .success();
+
+    assert!(!fixture.root()?.join("spike").exists());
+    assert!(fixture.root()?.join("feature").is_dir());
+
+    Ok(())
+}
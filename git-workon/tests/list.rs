@@ -247,6 +247,77 @@ fn list_dirty_and_ahead_combines_filters() -> Result<(), Box<dyn std::error::Err
     Ok(())
 }
 
+#[test]
+fn list_filter_expression_combines_with_or_and_not() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("clean-uptodate")
+        .worktree("dirty-uptodate")
+        .worktree("clean-ahead")
+        .worktree("dirty-ahead")
+        .remote("origin", "https://github.com/test/test.git")
+        .upstream("clean-uptodate", "origin/clean-uptodate")
+        .upstream("dirty-uptodate", "origin/dirty-uptodate")
+        .upstream("clean-ahead", "origin/clean-ahead")
+        .upstream("dirty-ahead", "origin/dirty-ahead")
+        .config("workon-worktree.dirty-ahead.label", "parked")
+        .build()?;
+
+    std::fs::write(
+        fixture.root()?.child("dirty-uptodate").join("test.txt"),
+        "uncommitted",
+    )?;
+    std::fs::write(
+        fixture.root()?.child("dirty-ahead").join("test.txt"),
+        "uncommitted",
+    )?;
+    fixture
+        .commit("clean-ahead")
+        .file("ahead1.txt", "content")
+        .create("Ahead commit")?;
+    fixture
+        .commit("dirty-ahead")
+        .file("ahead2.txt", "content")
+        .create("Ahead commit")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--filter")
+        .arg("(dirty || ahead) && !label:parked && age < 1d")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("dirty-uptodate"))
+        .stdout(predicate::str::contains("clean-ahead"))
+        .stdout(predicate::str::contains("dirty-ahead").not())
+        .stdout(predicate::str::contains("clean-uptodate").not());
+
+    // Flags still apply, with AND
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--filter")
+        .arg("dirty || ahead")
+        .arg("--clean")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("clean-ahead"))
+        .stdout(predicate::str::contains("dirty-").not())
+        .stdout(predicate::str::contains("clean-uptodate").not());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--filter")
+        .arg("dirty && (ahead")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid filter expression"));
+
+    Ok(())
+}
+
 #[test]
 fn list_ahead_and_behind_shows_diverged_worktrees() -> Result<(), Box<dyn std::error::Error>> {
     let fixture = FixtureBuilder::new()
@@ -497,3 +568,246 @@ fn list_empty_result_when_no_worktrees_match_filters() -> Result<(), Box<dyn std
 
     Ok(())
 }
+
+// ============================================================================
+// Output Format Tests
+// ============================================================================
+
+#[test]
+fn list_json_lines_emits_one_object_per_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature-1")
+        .worktree("feature-2")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--json-lines")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let output_str = String::from_utf8(output)?;
+    let names: Vec<String> = output_str
+        .lines()
+        .map(|line| {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            value["name"].as_str().unwrap().to_string()
+        })
+        .collect();
+    assert_eq!(
+        names.len(),
+        3,
+        "Expected one line per worktree: {output_str}"
+    );
+    assert!(names.contains(&"feature-1".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn list_json_lines_respects_filters() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("clean-wt")
+        .worktree("dirty-wt")
+        .build()?;
+
+    std::fs::write(
+        fixture.root()?.child("dirty-wt").join("test.txt"),
+        "uncommitted",
+    )?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--json-lines")
+        .arg("--dirty")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\":\"dirty-wt\""))
+        .stdout(predicate::str::contains("clean-wt").not());
+
+    Ok(())
+}
+
+#[test]
+fn list_trace_reports_span_timings() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--dirty")
+        .arg("--trace")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("[trace] get_repo"))
+        .stderr(predicate::str::contains("is_dirty{worktree=main}"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .env("WORKON_TRACE", "1")
+        .arg("list")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("[trace] get_worktrees"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .env("WORKON_TRACE", "0")
+        .arg("list")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("[trace]").not());
+
+    Ok(())
+}
+
+#[test]
+fn list_schema_describes_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--schema")
+        .output()?;
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        schema["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    assert_eq!(schema["type"], "array");
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--json")
+        .output()?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let properties = &schema["items"]["properties"];
+    for key in json[0].as_object().unwrap().keys() {
+        assert!(properties.get(key).is_some(), "'{key}' missing from schema");
+    }
+    for key in schema["items"]["required"].as_array().unwrap() {
+        assert!(
+            json[0].get(key.as_str().unwrap()).is_some(),
+            "{key} not in output"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn list_shows_pr_title_and_author() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("pr-123")
+        .config("workon-worktree.pr-123.pr", "123")
+        .config("workon-worktree.pr-123.prTitle", "Fix auth bug")
+        .config("workon-worktree.pr-123.prAuthor", "alice")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pr-123  \"Fix auth bug\" (alice)"));
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--json")
+        .output()?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let worktrees = json.as_array().unwrap();
+    let pr = worktrees.iter().find(|wt| wt["name"] == "pr-123").unwrap();
+    assert_eq!(pr["pr"]["number"], 123);
+    assert_eq!(pr["pr"]["title"], "Fix auth bug");
+    assert_eq!(pr["pr"]["author"], "alice");
+    let main = worktrees.iter().find(|wt| wt["name"] == "main").unwrap();
+    assert!(main["pr"].is_null());
+
+    Ok(())
+}
+
+#[test]
+fn list_reuses_cached_status_unless_no_cache() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.statusCache", "true")
+        .build()?;
+
+    let list = |extra: &[&str]| -> Result<String, Box<dyn std::error::Error>> {
+        let output = Command::cargo_bin("git-workon")?
+            .current_dir(&fixture)
+            .args(["list", "--json-lines"])
+            .args(extra)
+            .output()?;
+        assert!(output.status.success());
+        Ok(String::from_utf8(output.stdout)?)
+    };
+
+    assert!(list(&[])?.contains("\"has_unpushed_commits\":false"));
+
+    // Rewrite the cached entry as ahead, keeping its keys: it's reused while HEAD and
+    // the upstream are unchanged
+    let cache_path = fixture
+        .repo()?
+        .commondir()
+        .join("workon")
+        .join("status-cache");
+    let contents = std::fs::read_to_string(&cache_path)?;
+    std::fs::write(&cache_path, contents.replace("\t-\n", "\tahead\n"))?;
+    assert!(list(&[])?.contains("\"has_unpushed_commits\":true"));
+    assert!(list(&["--no-cache"])?.contains("\"has_unpushed_commits\":false"));
+
+    // Dirtiness is never served from the cache
+    std::fs::write(fixture.root()?.child("main").join("scratch.txt"), "x")?;
+    assert!(list(&[])?.contains("\"is_dirty\":true"));
+
+    Ok(())
+}
+
+#[test]
+fn list_warns_about_an_invalid_unrelated_value() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.pruneGracePeriod", "soon")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("main"))
+        .stderr(predicate::str::contains("workon.prunegraceperiod"));
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use assert_cmd::Command;
+use predicates::prelude::*;
+
+#[test]
+fn man_prints_main_page() -> Result<(), Box<dyn std::error::Error>> {
+    Command::cargo_bin("git-workon")?
+        .arg("man")
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with(".ie"))
+        .stdout(predicate::str::contains(".TH git-workon 1"))
+        .stdout(predicate::str::contains("git\\-workon\\-new(1)"));
+
+    Ok(())
+}
+
+#[test]
+fn man_prints_subcommand_page() -> Result<(), Box<dyn std::error::Error>> {
+    Command::cargo_bin("git-workon")?
+        .args(["man", "repos", "add"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(".TH git-workon-repos-add 1"));
+
+    Command::cargo_bin("git-workon")?
+        .args(["man", "_complete"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No man page for '_complete'"));
+
+    Ok(())
+}
+
+#[test]
+fn man_dir_writes_page_per_command() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = assert_fs::TempDir::new()?;
+
+    Command::cargo_bin("git-workon")?
+        .arg("man")
+        .arg("--dir")
+        .arg(dir.path())
+        .assert()
+        .success();
+
+    for page in [
+        "git-workon.1",
+        "git-workon-new.1",
+        "git-workon-repos-list.1",
+    ] {
+        assert!(dir.path().join(page).is_file(), "{page} not written");
+    }
+    assert!(!dir.path().join("git-workon-_complete.1").exists());
+
+    Ok(())
+}
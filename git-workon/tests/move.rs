@@ -104,6 +104,38 @@ fn move_fails_if_target_exists() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn move_json_reports_error_code() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .worktree("bugfix")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("move")
+        .arg("feature")
+        .arg("bugfix")
+        .arg("--json")
+        .output()?;
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stderr)?;
+    assert_eq!(json["error"]["code"], "workon::worktree::target_exists");
+    assert_eq!(
+        json["error"]["causes"],
+        serde_json::json!(["Worktree 'bugfix' already exists"])
+    );
+    assert!(json["error"]["help"]
+        .as_str()
+        .is_some_and(|help| help.contains("remove the existing worktree")));
+
+    Ok(())
+}
+
 #[test]
 fn move_fails_if_source_not_found() -> Result<(), Box<dyn std::error::Error>> {
     let fixture = FixtureBuilder::new()
@@ -401,7 +433,7 @@ fn move_current_worktree_fails_outside_worktree() -> Result<(), Box<dyn std::err
         .build()?;
 
     // Try to move with single arg from the bare repo (not in a worktree)
-    let bare_dir = fixture.root()?.join(".bare");
+    let bare_dir = fixture.bare_path()?;
     Command::cargo_bin("git-workon")?
         .current_dir(&bare_dir)
         .arg("move")
@@ -449,3 +481,164 @@ fn move_current_worktree_dry_run() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn move_fails_on_locked_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    repo.find_worktree("feature")?
+        .lock(Some("on removable drive"))?;
+
+    // Even --force does not override a lock
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("move")
+        .arg("--force")
+        .arg("feature")
+        .arg("bugfix")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("locked"))
+        .stderr(predicate::str::contains("on removable drive"));
+
+    // Nothing changed
+    repo.assert(predicate::repo::has_branch("feature"));
+    fixture
+        .root()?
+        .child("feature")
+        .assert(predicate::path::is_dir());
+
+    Ok(())
+}
+
+#[test]
+fn move_force_locked_keeps_lock() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    repo.find_worktree("feature")?
+        .lock(Some("on removable drive"))?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("move")
+        .arg("--force-locked")
+        .arg("feature")
+        .arg("bugfix")
+        .assert()
+        .success();
+
+    repo.assert(predicate::repo::has_branch("bugfix"));
+    fixture
+        .root()?
+        .child("bugfix")
+        .assert(predicate::path::is_dir());
+
+    // The lock was re-created at the new location with the same reason
+    let bare_repo = git2::Repository::open_bare(fixture.bare_path()?)?;
+    assert_eq!(
+        bare_repo.find_worktree("bugfix")?.is_locked()?,
+        git2::WorktreeLockStatus::Locked(Some("on removable drive".to_string()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn move_force_locked_restores_lock_when_move_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    repo.find_worktree("feature")?
+        .lock(Some("on removable drive"))?;
+    // Leftovers where the metadata directory would be renamed to
+    let leftover = fixture.bare_path()?.join("worktrees").join("bugfix");
+    std::fs::create_dir_all(&leftover)?;
+    std::fs::write(leftover.join("junk"), "")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("move")
+        .arg("--force-locked")
+        .arg("feature")
+        .arg("bugfix")
+        .assert()
+        .failure();
+
+    let bare_repo = git2::Repository::open_bare(fixture.bare_path()?)?;
+    assert_eq!(
+        bare_repo.find_worktree("feature")?.is_locked()?,
+        git2::WorktreeLockStatus::Locked(Some("on removable drive".to_string()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn move_suggests_similar_worktree_name() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature-auth")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("move")
+        .arg("feature-atuh")
+        .arg("new-name")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Did you mean 'feature-auth'?"));
+
+    Ok(())
+}
+
+#[test]
+fn move_copies_worktree_on_network_filesystem() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .config("workon.networkFilesystem", "true")
+        .build()?;
+    let feature = fixture.root()?.join("feature");
+    std::fs::create_dir_all(feature.join("notes"))?;
+    std::fs::write(feature.join("notes/todo.txt"), "ship it")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("move")
+        .arg("--force")
+        .arg("feature")
+        .arg("user/feature")
+        .assert()
+        .success();
+
+    let moved = fixture.root()?.join("user/feature");
+    assert!(!feature.exists());
+    assert_eq!(
+        std::fs::read_to_string(moved.join("notes/todo.txt"))?,
+        "ship it"
+    );
+    let repo = fixture.repo()?;
+    repo.assert(predicate::repo::has_worktree("feature"));
+    let moved_repo = git2::Repository::open(&moved)?;
+    assert_eq!(moved_repo.head()?.shorthand(), Some("user/feature"));
+
+    Ok(())
+}
@@ -359,6 +359,73 @@ fn new_with_auto_copy_enabled() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn new_auto_copy_skips_files_over_size_limit() -> Result<(), Box<dyn std::error::Error>> {
+    use std::fs;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.autoCopyUntracked", "true")
+        .config("workon.copyMaxFileSize", "1k")
+        .build()?;
+
+    let main_worktree = fixture.root()?.join("main");
+    fs::write(main_worktree.join(".env"), "SECRET=value")?;
+    fs::write(main_worktree.join("huge.bin"), vec![0u8; 4096])?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Skipping huge.bin (4.0 KiB"))
+        .stderr(predicate::str::contains("Copied 1 file(s), 12 B"));
+
+    let feature_worktree = fixture.root()?.join("feature");
+    assert!(feature_worktree.join(".env").exists());
+    assert!(!feature_worktree.join("huge.bin").exists());
+
+    Ok(())
+}
+
+#[test]
+fn new_auto_copy_refuses_total_over_limit_non_interactively(
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::fs;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.autoCopyUntracked", "true")
+        .config("workon.copyMaxTotal", "1k")
+        .build()?;
+
+    let main_worktree = fixture.root()?.join("main");
+    fs::write(main_worktree.join("a.bin"), vec![0u8; 1000])?;
+    fs::write(main_worktree.join("b.bin"), vec![0u8; 1000])?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Not copying 2 file(s) (2.0 KiB) from base worktree",
+        ));
+
+    let feature_worktree = fixture.root()?.join("feature");
+    assert!(!feature_worktree.join("a.bin").exists());
+    assert!(!feature_worktree.join("b.bin").exists());
+
+    Ok(())
+}
+
 #[test]
 fn new_with_auto_copy_respects_excludes() -> Result<(), Box<dyn std::error::Error>> {
     use std::fs;
@@ -719,3 +786,830 @@ fn new_interactive_prompts_for_base_branch() -> Result<(), Box<dyn std::error::E
 
     Ok(())
 }
+
+#[test]
+fn new_copy_from_uses_given_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    use std::fs;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .config("workon.copyPattern", "*.txt")
+        .build()?;
+
+    fs::write(fixture.root()?.join("main/golden.txt"), "warm cache")?;
+    fs::write(fixture.root()?.join("develop/base.txt"), "base")?;
+
+    // Branch off develop, but copy from main (--copy-from implies --copy-untracked)
+    let mut new_cmd = Command::cargo_bin("git-workon")?;
+    new_cmd
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("--base")
+        .arg("develop")
+        .arg("--copy-from")
+        .arg("main")
+        .arg("feature")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("from worktree 'main'"));
+
+    let feature_worktree = fixture.root()?.join("feature");
+    assert!(
+        feature_worktree.join("golden.txt").exists(),
+        "Should copy from the --copy-from worktree"
+    );
+    assert!(
+        !feature_worktree.join("base.txt").exists(),
+        "Should not copy from the base worktree"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn new_copy_source_config_picks_source() -> Result<(), Box<dyn std::error::Error>> {
+    use std::fs;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .config("workon.autoCopyUntracked", "true")
+        .config("workon.copySource", "main")
+        .config("workon.copyPattern", "*.txt")
+        .build()?;
+
+    fs::write(fixture.root()?.join("main/golden.txt"), "warm cache")?;
+
+    let mut new_cmd = Command::cargo_bin("git-workon")?;
+    new_cmd
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("--base")
+        .arg("develop")
+        .arg("feature")
+        .assert()
+        .success();
+
+    assert!(
+        fixture.root()?.join("feature/golden.txt").exists(),
+        "Should copy from workon.copySource"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn new_push_publishes_branch_and_sets_upstream() -> Result<(), Box<dyn std::error::Error>> {
+    let upstream = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote(
+            "origin",
+            upstream.bare_path()?.to_string_lossy().to_string(),
+        )
+        .worktree("main")
+        .build()?;
+
+    let mut new_cmd = Command::cargo_bin("git-workon")?;
+    new_cmd
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("--push")
+        .arg("feature")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Pushed feature to origin"));
+
+    // The remote now has the branch
+    let upstream_repo = upstream.repo()?;
+    assert!(upstream_repo.find_reference("refs/heads/feature").is_ok());
+
+    // And the local branch tracks it
+    let repo = fixture.repo()?;
+    let branch = repo.find_branch("feature", git2::BranchType::Local)?;
+    assert_eq!(
+        branch.upstream()?.name()?,
+        Some("origin/feature"),
+        "feature should track origin/feature"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn new_push_without_remote_warns() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    let mut new_cmd = Command::cargo_bin("git-workon")?;
+    new_cmd
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("--push")
+        .arg("feature")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("No git remote to push to"));
+
+    assert!(
+        fixture.root()?.join("feature").is_dir(),
+        "Worktree should still be created"
+    );
+
+    Ok(())
+}
+
+const GREETING_DIFF: &str = "\
+diff --git a/greeting.txt b/greeting.txt
+--- a/greeting.txt
++++ b/greeting.txt
+@@ -1 +1 @@
+-hello
++hello, world
+";
+
+const SERIES: &str = "\
+From 1111111111111111111111111111111111111111 Mon Sep 17 00:00:00 2001
+From: Ada Lovelace <ada@example.com>
+Date: Tue, 13 Feb 2024 10:21:43 +0100
+Subject: [PATCH 1/2] Add notes
+
+Explain the engine.
+---
+ notes.txt | 1 +
+ 1 file changed, 1 insertion(+)
+ create mode 100644 notes.txt
+
+diff --git a/notes.txt b/notes.txt
+new file mode 100644
+--- /dev/null
++++ b/notes.txt
+@@ -0,0 +1 @@
++analytical engine
+-- 
+2.43.0
+
+From 2222222222222222222222222222222222222222 Mon Sep 17 00:00:00 2001
+From: Ada Lovelace <ada@example.com>
+Date: Tue, 13 Feb 2024 10:25:00 +0100
+Subject: [PATCH 2/2] Greet the world
+
+---
+diff --git a/greeting.txt b/greeting.txt
+--- a/greeting.txt
++++ b/greeting.txt
+@@ -1 +1 @@
+-hello
++hello, world
+-- 
+2.43.0
+";
+
+fn patch_fixture() -> Result<Fixture, Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    fixture
+        .commit("main")
+        .file("greeting.txt", "hello\n")
+        .create("Add greeting")?;
+    Ok(fixture)
+}
+
+#[test]
+fn new_from_patch_applies_diff_uncommitted() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = patch_fixture()?;
+    let patches = assert_fs::TempDir::new()?;
+    let patch = patches.child("0001-greet-the-world.patch");
+    patch.write_str(GREETING_DIFF)?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("--from-patch")
+        .arg(patch.path())
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Applied 0001-greet-the-world.patch (uncommitted)",
+        ));
+
+    // Named after the patch file, without the format-patch numbering
+    let worktree = fixture.root()?.join("greet-the-world");
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("greeting.txt"))?,
+        "hello, world\n"
+    );
+    let repo = git2::Repository::open(&worktree)?;
+    assert_eq!(
+        repo.head()?.peel_to_commit()?.summary(),
+        Some("Add greeting")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn new_from_patch_commits_series() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = patch_fixture()?;
+    let patches = assert_fs::TempDir::new()?;
+    let patch = patches.child("series.mbox");
+    patch.write_str(SERIES)?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("review")
+        .arg("--from-patch")
+        .arg(patch.path())
+        .arg("--commit")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Committed 2 patch(es) from series.mbox",
+        ));
+
+    let worktree = fixture.root()?.join("review");
+    let repo = git2::Repository::open(&worktree)?;
+    let head = repo.head()?.peel_to_commit()?;
+    assert_eq!(head.summary(), Some("Greet the world"));
+    let first = head.parent(0)?;
+    assert_eq!(first.summary(), Some("Add notes"));
+    assert_eq!(first.body(), Some("Explain the engine."));
+    assert_eq!(first.author().name(), Some("Ada Lovelace"));
+    assert_eq!(first.author().email(), Some("ada@example.com"));
+    assert_eq!(first.parent(0)?.summary(), Some("Add greeting"));
+
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("notes.txt"))?,
+        "analytical engine\n"
+    );
+    let statuses = repo.statuses(None)?;
+    assert!(statuses.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn new_from_patch_reports_patch_that_does_not_apply() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = patch_fixture()?;
+    let patches = assert_fs::TempDir::new()?;
+    let patch = patches.child("stale.diff");
+    patch.write_str(&GREETING_DIFF.replace("-hello\n", "-goodbye\n"))?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("--from-patch")
+        .arg(patch.path())
+        .arg("--no-interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("the patch did not apply"))
+        .stderr(predicate::str::contains("0 of 1 patches applied"));
+
+    let empty = patches.child("empty.diff");
+    empty.write_str("nothing to see here\n")?;
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("--from-patch")
+        .arg(empty.path())
+        .arg("--no-interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("contains no changes"));
+    fixture
+        .root()?
+        .child("empty")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+#[test]
+fn new_no_checkout_registers_empty_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = patch_fixture()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("sparse")
+        .arg("--no-checkout")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Skipped checkout"));
+
+    let worktree = fixture.root()?.join("sparse");
+    let entries: Vec<_> = std::fs::read_dir(&worktree)?
+        .map(|e| e.map(|e| e.file_name()))
+        .collect::<Result<_, _>>()?;
+    assert_eq!(entries, vec![std::ffi::OsString::from(".git")]);
+
+    fixture.assert(predicate::repo::has_branch("sparse"));
+    let repo = git2::Repository::open(&worktree)?;
+    assert!(repo.is_worktree());
+    assert_eq!(repo.head()?.shorthand(), Some("sparse"));
+    assert_eq!(
+        repo.head()?.peel_to_commit()?.summary(),
+        Some("Add greeting")
+    );
+
+    // Populating it later yields the branch's files
+    repo.reset(
+        repo.head()?.peel_to_commit()?.as_object(),
+        git2::ResetType::Hard,
+        None,
+    )?;
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("greeting.txt"))?,
+        "hello\n"
+    );
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("sparse"));
+
+    Ok(())
+}
+
+#[test]
+fn new_rejects_invalid_name_with_suggestion() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("Fix Login")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not a valid branch name"))
+        .stderr(predicate::str::contains("Try 'fix-login'"));
+    fixture
+        .root()?
+        .child("Fix Login")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+#[test]
+fn new_normalizes_name_with_kebab_style() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .config("workon.nameStyle", "kebab")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("Lettertwo/Fix Login")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Using branch name 'lettertwo/fix-login'",
+        ));
+    fixture
+        .root()?
+        .child("lettertwo/fix-login")
+        .assert(predicate::path::is_dir());
+    fixture.assert(predicate::repo::has_branch("lettertwo/fix-login"));
+
+    Ok(())
+}
+
+/// Write an executable stand-in for gpg that emits a fixed signature (or fails).
+#[cfg(unix)]
+fn fake_gpg(
+    dir: &assert_fs::TempDir,
+    succeed: bool,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let script = if succeed {
+        "#!/bin/sh\ncat >/dev/null\nprintf -- '-----BEGIN PGP SIGNATURE-----\\n\\nfake\\n-----END PGP SIGNATURE-----\\n'\n"
+    } else {
+        "#!/bin/sh\ncat >/dev/null\necho 'no secret key' >&2\nexit 2\n"
+    };
+    let path = dir.path().join("fake-gpg");
+    std::fs::write(&path, script)?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))?;
+    Ok(path)
+}
+
+#[cfg(unix)]
+#[test]
+fn new_orphan_signs_initial_commit_when_configured() -> Result<(), Box<dyn std::error::Error>> {
+    let bin = assert_fs::TempDir::new()?;
+    let gpg = fake_gpg(&bin, true)?;
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .build()?;
+    let mut config = fixture.repo()?.config()?;
+    config.set_bool("commit.gpgsign", true)?;
+    config.set_str("gpg.program", gpg.to_str().unwrap())?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("docs")
+        .arg("--orphan")
+        .assert()
+        .success();
+    let repo = git2::Repository::open(fixture.root()?.join("docs"))?;
+    let head = repo.head()?.peel_to_commit()?;
+    let (signature, _) = repo.extract_signature(&head.id(), None)?;
+    assert!(signature.as_str().unwrap().contains("fake"));
+    assert_eq!(head.parent_count(), 0);
+
+    // --no-sign skips signing
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("site")
+        .arg("--orphan")
+        .arg("--no-sign")
+        .assert()
+        .success();
+    let repo = git2::Repository::open(fixture.root()?.join("site"))?;
+    let head = repo.head()?.peel_to_commit()?;
+    assert!(repo.extract_signature(&head.id(), None).is_err());
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn new_reports_signing_failure() -> Result<(), Box<dyn std::error::Error>> {
+    let bin = assert_fs::TempDir::new()?;
+    let gpg = fake_gpg(&bin, false)?;
+    let fixture = patch_fixture()?;
+    let mut config = fixture.repo()?.config()?;
+    config.set_bool("commit.gpgsign", true)?;
+    config.set_str("gpg.program", gpg.to_str().unwrap())?;
+    let patches = assert_fs::TempDir::new()?;
+    let patch = patches.child("fix.diff");
+    patch.write_str(GREETING_DIFF)?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("review")
+        .arg("--from-patch")
+        .arg(patch.path())
+        .arg("--commit")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no secret key"))
+        .stderr(predicate::str::contains("--no-sign"));
+
+    Ok(())
+}
+
+#[test]
+fn new_from_remote_branch_uses_local_name() -> Result<(), Box<dyn std::error::Error>> {
+    let upstream = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let remote_branch = "someones/long-branch-name";
+    let tip = upstream
+        .commit("main")
+        .file("work.txt", "in progress")
+        .create("Work in progress")?;
+    let upstream_repo = upstream.repo()?;
+    upstream_repo.branch(remote_branch, &upstream_repo.find_commit(tip)?, false)?;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote(
+            "origin",
+            RemoteSource::Path(upstream.repo()?.commondir().to_path_buf()),
+        )
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("mine")
+        .arg("--from")
+        .arg(format!("origin/{}", remote_branch))
+        .assert()
+        .success();
+
+    fixture
+        .root()?
+        .child("mine/work.txt")
+        .assert(predicate::path::is_file());
+    fixture.assert(predicate::repo::has_branch("mine"));
+    let repo = fixture.repo()?;
+    let config = repo.config()?;
+    assert_eq!(config.get_string("branch.mine.remote")?, "origin");
+    assert_eq!(
+        config.get_string("branch.mine.merge")?,
+        format!("refs/heads/{}", remote_branch)
+    );
+    let mine = repo.find_branch("mine", git2::BranchType::Local)?;
+    assert_eq!(mine.get().peel_to_commit()?.id(), tip);
+
+    // The local name is taken now
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("mine")
+        .arg("--from")
+        .arg(format!("origin/{}", remote_branch))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("other")
+        .arg("--from")
+        .arg("nowhere/branch")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("is not a remote branch"));
+
+    Ok(())
+}
+
+#[test]
+fn new_adopts_leftover_worktree_directory() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = patch_fixture()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .assert()
+        .success();
+
+    // A crashed prune: the registration is gone, the directory and branch are left
+    let worktree = fixture.root()?.join("feature");
+    std::fs::remove_dir_all(fixture.repo()?.commondir().join("worktrees/feature"))?;
+    std::fs::write(worktree.join("greeting.txt"), "hello, world\n")?;
+    std::fs::write(worktree.join("notes.txt"), "keep me\n")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"))
+        .stderr(predicate::str::contains("--adopt"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--adopt")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Adopted"));
+
+    assert_eq!(
+        std::fs::read_to_string(worktree.join("notes.txt"))?,
+        "keep me\n"
+    );
+    let repo = git2::Repository::open(&worktree)?;
+    assert!(repo.is_worktree());
+    assert_eq!(repo.head()?.shorthand(), Some("feature"));
+    assert_eq!(
+        repo.status_file(Path::new("greeting.txt"))?,
+        git2::Status::WT_MODIFIED
+    );
+
+    Ok(())
+}
+
+#[test]
+fn new_force_moves_existing_directory_aside() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = patch_fixture()?;
+    let root = fixture.root()?;
+    root.child("feature/stray.txt").write_str("stray\n")?;
+
+    // Not a worktree of this repository, so it can't be adopted
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--adopt")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Cannot adopt"));
+    fixture.assert(predicate::repo::has_branch("feature").not());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--force")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("feature.bak"));
+
+    root.child("feature.bak/stray.txt")
+        .assert(predicate::path::is_file());
+    root.child("feature/greeting.txt")
+        .assert(predicate::path::is_file());
+    root.child("feature/stray.txt")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+#[test]
+fn new_force_refuses_to_move_a_registered_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+    let root = fixture.root()?;
+    root.child("feature/wip.txt").write_str("wip\n")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["new", "feature", "--force"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+
+    // The branch checked out elsewhere is refused too, whatever is at the path
+    root.child("elsewhere").create_dir_all()?;
+    let status = std::process::Command::new("git")
+        .current_dir(root.child("main").path())
+        .args(["worktree", "move", "../feature", "../elsewhere/feature"])
+        .status()?;
+    assert!(status.success());
+    root.child("feature/stray.txt").write_str("stray\n")?;
+    Command::cargo_bin("git-workon")?
+        .current_dir(root.child("main").path())
+        .args(["new", "feature", "--force"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already checked out"));
+
+    root.child("elsewhere/feature/wip.txt")
+        .assert(predicate::path::is_file());
+    root.child("feature/stray.txt")
+        .assert(predicate::path::is_file());
+    root.child("feature.bak").assert(predicate::path::missing());
+
+    Ok(())
+}
+
+#[test]
+fn new_bases_on_remote_head_when_default_branch_unset() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .remote("origin", "/dev/null")
+        .build()?;
+    let tip = fixture
+        .commit("develop")
+        .file("develop.txt", "develop")
+        .create("Develop work")?;
+    fixture.create_remote_ref("origin/develop", tip)?;
+    let repo = fixture.repo()?;
+    repo.reference_symbolic(
+        "refs/remotes/origin/HEAD",
+        "refs/remotes/origin/develop",
+        false,
+        "set origin/HEAD",
+    )?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--no-interactive")
+        .assert()
+        .success();
+
+    let feature = repo.find_branch("feature", git2::BranchType::Local)?;
+    assert_eq!(feature.get().target(), Some(tip));
+    assert_eq!(
+        repo.config()?.get_string("workon.remoteDefaultBranch")?,
+        "develop"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn new_asks_remote_for_default_branch_without_remote_head() -> Result<(), Box<dyn std::error::Error>>
+{
+    let upstream = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("develop")
+        .worktree("develop")
+        .build()?;
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .remote(
+            "origin",
+            RemoteSource::Path(upstream.repo()?.commondir().to_path_buf()),
+        )
+        .build()?;
+    let tip = fixture
+        .commit("develop")
+        .file("develop.txt", "develop")
+        .create("Develop work")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--no-interactive")
+        .assert()
+        .success();
+
+    let repo = fixture.repo()?;
+    let feature = repo.find_branch("feature", git2::BranchType::Local)?;
+    assert_eq!(feature.get().target(), Some(tip));
+    assert_eq!(
+        repo.config()?.get_string("workon.remoteDefaultBranch")?,
+        "develop"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn new_prefers_configured_default_branch_over_remote_head() -> Result<(), Box<dyn std::error::Error>>
+{
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .remote("origin", "/dev/null")
+        .config("workon.defaultBranch", "main")
+        .build()?;
+    let tip = fixture
+        .commit("develop")
+        .file("develop.txt", "develop")
+        .create("Develop work")?;
+    fixture.create_remote_ref("origin/develop", tip)?;
+    let repo = fixture.repo()?;
+    repo.reference_symbolic(
+        "refs/remotes/origin/HEAD",
+        "refs/remotes/origin/develop",
+        false,
+        "set origin/HEAD",
+    )?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--no-interactive")
+        .assert()
+        .success();
+
+    let feature = repo.find_branch("feature", git2::BranchType::Local)?;
+    assert_ne!(feature.get().target(), Some(tip));
+    assert!(repo
+        .config()?
+        .get_string("workon.remoteDefaultBranch")
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn note_is_stored_and_shown_in_list() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("note")
+        .arg("feature")
+        .arg("waiting on API review")
+        .assert()
+        .success();
+
+    let note = fixture
+        .repo()?
+        .config()?
+        .get_string("workon-worktree.feature.note")?;
+    assert_eq!(note, "waiting on API review");
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("note")
+        .arg("feature")
+        .assert()
+        .success()
+        .stdout("waiting on API review\n");
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_match(r"feature.*waiting on API review")?);
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("list")
+        .arg("--json")
+        .output()?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let feature = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|wt| wt["name"] == "feature")
+        .unwrap();
+    assert_eq!(feature["note"], "waiting on API review");
+
+    Ok(())
+}
+
+#[test]
+fn note_clear_removes_note() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .config("workon-worktree.feature.note", "waiting on API review")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("note")
+        .arg("feature")
+        .arg("--clear")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Cleared note for 'feature'"));
+
+    assert!(fixture
+        .repo()?
+        .config()?
+        .get_string("workon-worktree.feature.note")
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn note_resolves_a_partial_name() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature-auth")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("note")
+        .arg("auth")
+        .arg("needs tests")
+        .assert()
+        .success();
+
+    let note = fixture
+        .repo()?
+        .config()?
+        .get_string("workon-worktree.feature-auth.note")?;
+    assert_eq!(note, "needs tests");
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("note")
+        .arg("a")
+        .arg("ambiguous")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("matches more than one worktree"));
+
+    Ok(())
+}
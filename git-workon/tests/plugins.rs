@@ -0,0 +1,90 @@
+#![cfg(unix)]
+
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+/// Write an executable `git-workon-<name>` script into `dir`.
+fn write_plugin(dir: &std::path::Path, name: &str, script: &str) -> std::io::Result<()> {
+    let path = dir.join(format!("git-workon-{}", name));
+    std::fs::write(&path, format!("#!/bin/sh\n{}\n", script))?;
+    std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+}
+
+fn path_with(dir: &std::path::Path) -> std::ffi::OsString {
+    let mut paths = vec![dir.to_path_buf()];
+    paths.extend(std::env::split_paths(
+        &std::env::var_os("PATH").unwrap_or_default(),
+    ));
+    std::env::join_paths(paths).unwrap()
+}
+
+#[test]
+fn unknown_subcommand_runs_plugin_with_context() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let bin = assert_fs::TempDir::new()?;
+    write_plugin(
+        bin.path(),
+        "hello",
+        r#"echo "args: $*"; echo "branch: $WORKON_BRANCH_NAME"; echo "root: $WORKON_ROOT"; exit 4"#,
+    )?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(fixture.cwd()?.path())
+        .env("PATH", path_with(bin.path()))
+        .args(["hello", "--flag", "value"])
+        .output()?;
+    assert_eq!(output.status.code(), Some(4));
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(stdout.contains("args: --flag value"), "{stdout}");
+    assert!(stdout.contains("branch: main"), "{stdout}");
+    let root = fixture.root()?.path().to_str().unwrap().to_string();
+    assert!(stdout.contains(&format!("root: {}", root)), "{stdout}");
+
+    Ok(())
+}
+
+#[test]
+fn builtin_commands_win_over_plugins() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let bin = assert_fs::TempDir::new()?;
+    write_plugin(bin.path(), "root", "echo plugin")?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(fixture.cwd()?.path())
+        .env("PATH", path_with(bin.path()))
+        .arg("root")
+        .output()?;
+    assert!(output.status.success());
+    assert!(!String::from_utf8(output.stdout)?.contains("plugin"));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_name_without_plugin_is_a_find_query() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["feature", "--no-interactive"])
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("feature\n"));
+
+    Ok(())
+}
@@ -142,7 +142,7 @@ fn pr_reference_with_base_flag_creates_literal_branch() -> Result {
         .success();
 
     // Should have created worktree with literal name
-    let bare_repo = git2::Repository::open(fixture.root()?.join(".bare"))?;
+    let bare_repo = git2::Repository::open(fixture.bare_path()?)?;
     bare_repo.assert(predicate::repo::has_worktree("#123"));
     bare_repo.assert(predicate::repo::has_branch("#123"));
 
@@ -170,9 +170,126 @@ fn pr_reference_with_orphan_flag_creates_literal_branch() -> Result {
         .success();
 
     // Should have created worktree with literal name
-    let bare_repo = git2::Repository::open(fixture.root()?.join(".bare"))?;
+    let bare_repo = git2::Repository::open(fixture.bare_path()?)?;
     bare_repo.assert(predicate::repo::has_worktree("pr#456"));
     bare_repo.assert(predicate::repo::has_branch("pr#456"));
 
     Ok(())
 }
+
+#[test]
+fn pr_reference_switches_to_existing_worktree() -> Result {
+    use assert_cmd::Command;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .worktree("main")
+        .worktree("pr-123")
+        .build()?;
+
+    // No gh needed: the worktree named by workon.prFormat already exists
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("new")
+        .arg("#123")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("pr-123\n"))
+        .stderr(predicate::str::contains("already checked out in 'pr-123'"));
+
+    Ok(())
+}
+
+#[test]
+fn pr_reference_switches_to_worktree_with_recorded_pr() -> Result {
+    use assert_cmd::Command;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .worktree("main")
+        .worktree("fix-auth")
+        .config("workon.prFormat", "{number}-{title}")
+        .config("workon-worktree.fix-auth.pr", "123")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("new")
+        .arg("pr#123")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("fix-auth\n"));
+
+    Ok(())
+}
+
+#[test]
+fn bare_pr_reference_switches_to_existing_worktree() -> Result {
+    use assert_cmd::Command;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .worktree("main")
+        .worktree("fix-auth")
+        .config("workon.prFormat", "{number}-{title}")
+        .config("workon-worktree.fix-auth.pr", "123")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("#123")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("fix-auth\n"));
+
+    Ok(())
+}
+
+#[test]
+fn new_warns_when_branching_off_review_only_worktree() -> Result {
+    use assert_cmd::Command;
+
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .worktree("main")
+        .worktree("pr-5")
+        .config("workon-worktree.pr-5.pr", "5")
+        .config("workon.prReadOnly", "true")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("new")
+        .arg("fixup")
+        .arg("--base")
+        .arg("pr-5")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "checked out in review-only PR worktree 'pr-5'",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn review_flag_requires_pr_reference() -> Result {
+    use assert_cmd::Command;
+
+    let fixture = FixtureBuilder::new().bare(true).worktree("main").build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .arg("new")
+        .arg("feature")
+        .arg("--review")
+        .arg("--no-interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "--review only applies to pull request worktrees",
+        ));
+
+    Ok(())
+}
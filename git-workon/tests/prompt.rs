@@ -0,0 +1,116 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn prompt_prints_worktree_name_and_indicators() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let feature = fixture.root()?.join("feature");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&feature)
+        .arg("prompt")
+        .arg("--timeout")
+        .arg("10000")
+        .assert()
+        .success()
+        .stdout("feature\n");
+
+    std::fs::write(feature.join("test.txt"), "uncommitted")?;
+    Command::cargo_bin("git-workon")?
+        .current_dir(&feature)
+        .arg("prompt")
+        .arg("--timeout")
+        .arg("10000")
+        .assert()
+        .success()
+        .stdout("feature *\n");
+
+    Ok(())
+}
+
+#[test]
+fn prompt_falls_back_to_last_known_status() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .build()?;
+
+    let feature = fixture.root()?.join("feature");
+    std::fs::write(feature.join("test.txt"), "uncommitted")?;
+
+    // Populate the cache with a full computation
+    Command::cargo_bin("git-workon")?
+        .current_dir(&feature)
+        .arg("prompt")
+        .arg("--refresh")
+        .assert()
+        .success();
+    let workon_dir = fixture.repo()?.commondir().join("workon");
+    let cache = std::fs::read_to_string(workon_dir.join("status-cache"))?;
+    assert!(cache.starts_with("feature\t") && cache.ends_with("\tdirty\n"));
+    assert!(!workon_dir.join("prompt").exists());
+
+    // With no time budget, the cached status is shown
+    Command::cargo_bin("git-workon")?
+        .current_dir(&feature)
+        .arg("prompt")
+        .arg("--timeout")
+        .arg("0")
+        .assert()
+        .success()
+        .stdout("feature *\n");
+
+    Ok(())
+}
+
+#[test]
+fn prompt_outside_workon_repo_prints_nothing() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = assert_fs::TempDir::new()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(dir.path())
+        .arg("prompt")
+        .assert()
+        .success()
+        .stdout("");
+
+    Ok(())
+}
+
+#[test]
+fn prompt_starts_no_refresh_while_one_is_running() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .build()?;
+    let feature = fixture.root()?.join("feature");
+    let workon_dir = fixture.repo()?.commondir().join("workon");
+    let lock = workon_dir.join("prompt-refresh.feature.lock");
+    std::fs::create_dir_all(&workon_dir)?;
+    std::fs::write(&lock, "")?;
+
+    // A refresh it started would release the lock when done
+    Command::cargo_bin("git-workon")?
+        .current_dir(&feature)
+        .args(["prompt", "--timeout", "0"])
+        .assert()
+        .success();
+    std::thread::sleep(std::time::Duration::from_secs(2));
+    assert!(lock.exists());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&feature)
+        .args(["prompt", "--refresh"])
+        .assert()
+        .success();
+    assert!(!lock.exists());
+
+    Ok(())
+}
@@ -473,6 +473,78 @@ fn prune_merged_removes_merged_branch() -> Result<(), Box<dyn std::error::Error>
     Ok(())
 }
 
+#[test]
+fn prune_merged_skips_worktrees_within_grace_period() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .config("workon.pruneGracePeriod", "2d")
+        .build()?;
+
+    // A branch cut from main without new commits is "merged" the moment it exists
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--merged")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "within workon.pruneGracePeriod (2 days)",
+        ))
+        .stderr(predicate::str::contains("No worktrees to prune"));
+    fixture.cwd()?.assert(predicate::path::is_dir());
+
+    // Naming it explicitly still prunes it
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("feature")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("explicitly requested"));
+
+    Ok(())
+}
+
+#[test]
+fn prune_merged_removes_worktrees_past_grace_period() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .config("workon.pruneGracePeriod", "2d")
+        .build()?;
+
+    // Recorded as created three days ago
+    let created = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs()
+        - 3 * 86400;
+    let visits = fixture.repo()?.commondir().join("workon");
+    std::fs::create_dir_all(&visits)?;
+    std::fs::write(
+        visits.join("visits"),
+        format!("{}\tcreate\tfeature\n", created),
+    )?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--merged")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Pruned 1 worktree"));
+    fixture.cwd()?.assert(predicate::path::missing());
+
+    Ok(())
+}
+
 #[test]
 fn prune_merged_skips_unmerged_branch() -> Result<(), Box<dyn std::error::Error>> {
     let fixture = FixtureBuilder::new()
@@ -1220,3 +1292,375 @@ fn prune_interactive_confirm_no() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn prune_schema_describes_json_output() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+    fixture
+        .repo()?
+        .find_reference("refs/heads/feature")?
+        .delete()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--schema")
+        .output()?;
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--json")
+        .arg("--dry-run")
+        .output()?;
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    for key in schema["required"].as_array().unwrap() {
+        assert!(json.get(key.as_str().unwrap()).is_some(), "{key} missing");
+    }
+    let candidate = &schema["properties"]["pruned"]["items"]["properties"];
+    let pruned = json["pruned"].as_array().unwrap();
+    assert_eq!(pruned.len(), 1);
+    for key in pruned[0].as_object().unwrap().keys() {
+        assert!(candidate.get(key).is_some(), "'{key}' missing from schema");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn prune_deletes_merged_branch() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("feature")
+        .arg("--delete-branch")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Deleted branch feature"));
+
+    fixture.cwd()?.assert(predicate::path::missing());
+    assert!(fixture
+        .repo()?
+        .find_branch("feature", git2::BranchType::Local)
+        .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn prune_keeps_branch_by_default() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("feature")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Pruned 1 worktree"))
+        .stderr(predicate::str::contains("Deleted branch").not());
+
+    let repo = fixture.repo()?;
+    fixture
+        .root()?
+        .child("feature")
+        .assert(predicate::path::missing());
+    assert!(repo.find_worktree("feature").is_err());
+    assert!(repo.find_branch("feature", git2::BranchType::Local).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn prune_keeps_unmerged_branch() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+    fixture
+        .commit("feature")
+        .file("test.txt", "test")
+        .create("Unmerged commit")?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("feature")
+        .arg("--allow-unmerged")
+        .arg("--delete-branch")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["pruned"][0]["branch_deleted"], false);
+
+    fixture.cwd()?.assert(predicate::path::missing());
+    assert!(fixture
+        .repo()?
+        .find_branch("feature", git2::BranchType::Local)
+        .is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn prune_removes_fork_remote_of_pruned_pr_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote("pr-7-fork", "/dev/null")
+        .worktree("main")
+        .worktree("pr-7")
+        .upstream("pr-7", "pr-7-fork/pr-7")
+        .config("workon.forkRemote", "pr-7-fork")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("pr-7")
+        .arg("--delete-branch")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed fork remote pr-7-fork"));
+
+    let repo = fixture.repo()?;
+    assert!(repo.find_remote("pr-7-fork").is_err());
+    assert!(repo.find_branch("pr-7", git2::BranchType::Local).is_err());
+    assert!(repo.config()?.get_string("workon.forkRemote").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn prune_keeps_fork_remote_tracked_by_a_kept_branch() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote("pr-7-fork", "/dev/null")
+        .worktree("main")
+        .worktree("pr-7")
+        .upstream("pr-7", "pr-7-fork/pr-7")
+        .config("workon.forkRemote", "pr-7-fork")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("pr-7")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Removed fork remote").not());
+
+    let repo = fixture.repo()?;
+    assert!(repo.find_remote("pr-7-fork").is_ok());
+    let branch = repo.find_branch("pr-7", git2::BranchType::Local)?;
+    assert_eq!(branch.upstream()?.name()?, Some("pr-7-fork/pr-7"));
+    assert_eq!(repo.config()?.get_string("workon.forkRemote")?, "pr-7-fork");
+
+    Ok(())
+}
+
+#[test]
+fn prune_prs_removes_review_only_worktrees_with_unmerged_commits(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .worktree("pr-6")
+        .worktree("pr-5")
+        .config("workon-worktree.pr-5.pr", "5")
+        .config("workon-worktree.pr-5.review", "true")
+        .config("workon-worktree.pr-6.pr", "6")
+        .build()?;
+    for branch in ["pr-5", "pr-6"] {
+        fixture
+            .commit(branch)
+            .file("review.txt", "review")
+            .create("Someone else's commit")?;
+    }
+    let pr_5 = fixture.root()?.join("pr-5");
+    std::fs::write(pr_5.join("scratch.txt"), "notes")?;
+
+    // Uncommitted changes are still the user's
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--prs")
+        .arg("--delete-branch")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("has uncommitted changes"));
+    assert!(pr_5.join("scratch.txt").is_file());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--prs")
+        .arg("--delete-branch")
+        .arg("--allow-dirty")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("reason: PR #5"))
+        .stderr(predicate::str::contains("has unmerged commits"));
+
+    let repo = fixture.repo()?;
+    assert!(!pr_5.exists());
+    assert!(repo.find_branch("pr-5", git2::BranchType::Local).is_err());
+    assert!(fixture.root()?.join("pr-6").is_dir());
+    assert!(fixture.root()?.join("feature").is_dir());
+
+    Ok(())
+}
+
+#[test]
+fn prune_prs_keeps_protected_review_only_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("pr-5")
+        .config("workon-worktree.pr-5.pr", "5")
+        .config("workon-worktree.pr-5.review", "true")
+        .config("workon.rule.pr-*.protect", "true")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--prs")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("protected"));
+
+    assert!(fixture.root()?.join("pr-5").is_dir());
+
+    Ok(())
+}
+
+#[test]
+fn prune_orphaned_branches_deletes_merged_branches_without_worktree(
+) -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("wip")
+        .config("workon.pruneProtectedBranches", "release/*")
+        .build()?;
+    let unmerged = fixture
+        .commit("wip")
+        .file("wip.txt", "wip")
+        .create("Unmerged commit")?;
+    let repo = fixture.repo()?;
+    let main = repo.find_branch("main", git2::BranchType::Local)?;
+    let main = main.get().peel_to_commit()?;
+    repo.branch("merged", &main, false)?;
+    repo.branch("release/1", &main, false)?;
+    repo.branch("unmerged", &repo.find_commit(unmerged)?, false)?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--orphaned-branches")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["pruned"], serde_json::json!([]));
+    assert_eq!(
+        json["branches"],
+        serde_json::json!([{ "branch": "merged", "reason": "merged into main" }])
+    );
+    let skipped: Vec<&str> = json["skipped_branches"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|b| b["branch"].as_str().unwrap())
+        .collect();
+    assert_eq!(skipped, ["release/1", "unmerged"]);
+
+    let branch = |name| repo.find_branch(name, git2::BranchType::Local);
+    assert!(branch("merged").is_err());
+    assert!(branch("release/1").is_ok());
+    assert!(branch("unmerged").is_ok());
+    assert!(branch("wip").is_ok());
+    assert!(branch("main").is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn prune_orphaned_branches_with_allow_unmerged() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("wip")
+        .build()?;
+    let unmerged = fixture
+        .commit("wip")
+        .file("wip.txt", "wip")
+        .create("Unmerged commit")?;
+    let repo = fixture.repo()?;
+    repo.branch("unmerged", &repo.find_commit(unmerged)?, false)?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--orphaned-branches")
+        .arg("--dry-run")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("has unmerged, unpushed commits"));
+    assert!(repo
+        .find_branch("unmerged", git2::BranchType::Local)
+        .is_ok());
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--orphaned-branches")
+        .arg("--allow-unmerged")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Deleted branch unmerged"))
+        .stderr(predicate::str::contains("Deleted 1 branch(es)"));
+    assert!(repo
+        .find_branch("unmerged", git2::BranchType::Local)
+        .is_err());
+    assert!(repo.find_branch("wip", git2::BranchType::Local).is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn repo_flag_targets_registered_repository() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+    // Same layout `git workon clone` creates, so the root itself is discoverable
+    std::fs::write(fixture.root()?.join(".git"), "gitdir: ./.bare\n")?;
+
+    let config = assert_fs::TempDir::new()?;
+    let registry = config.path().join("workon").join("repos.toml");
+    std::fs::create_dir_all(registry.parent().unwrap())?;
+    std::fs::write(
+        &registry,
+        format!("[repos]\napi = \"{}\"\n", fixture.root()?.display()),
+    )?;
+
+    let elsewhere = assert_fs::TempDir::new()?;
+    Command::cargo_bin("git-workon")?
+        .current_dir(elsewhere.path())
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("-R")
+        .arg("api")
+        .arg("feature")
+        .assert()
+        .success()
+        .stdout(predicate::str::ends_with("feature\n"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(elsewhere.path())
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("list")
+        .arg("--repo")
+        .arg("api")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("feature"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(elsewhere.path())
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("-R")
+        .arg("apx")
+        .arg("list")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No registered repository named 'apx'",
+        ))
+        .stderr(predicate::str::contains("Did you mean 'api'?"));
+
+    Ok(())
+}
+
+#[test]
+fn list_all_repos_shows_every_registered_repository() -> Result<(), Box<dyn std::error::Error>> {
+    let api = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("api-feature")
+        .build()?;
+    let web = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("web-feature")
+        .build()?;
+    for fixture in [&api, &web] {
+        std::fs::write(fixture.root()?.join(".git"), "gitdir: ./.bare\n")?;
+    }
+    std::fs::write(web.root()?.join("web-feature").join("dirty.txt"), "wip")?;
+
+    let config = assert_fs::TempDir::new()?;
+    let registry = config.path().join("workon").join("repos.toml");
+    std::fs::create_dir_all(registry.parent().unwrap())?;
+    std::fs::write(
+        &registry,
+        format!(
+            "[repos]\napi = \"{}\"\nweb = \"{}\"\ngone = \"/nonexistent/workon\"\n",
+            api.root()?.display(),
+            web.root()?.display()
+        ),
+    )?;
+
+    let elsewhere = assert_fs::TempDir::new()?;
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(elsewhere.path())
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("list")
+        .arg("--all-repos")
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    let mut rows: Vec<(&str, &str)> = stdout
+        .lines()
+        .map(|line| {
+            let mut columns = line.split_whitespace();
+            (columns.next().unwrap(), columns.next().unwrap())
+        })
+        .collect();
+    // Grouped by repository, in registry order
+    let repos: Vec<_> = rows.iter().map(|(repo, _)| *repo).collect();
+    assert_eq!(repos, vec!["api", "api", "web", "web"]);
+    rows.sort();
+    assert_eq!(
+        rows,
+        vec![
+            ("api", "api-feature"),
+            ("api", "main"),
+            ("web", "main"),
+            ("web", "web-feature"),
+        ]
+    );
+    assert!(String::from_utf8(output.stderr)?.contains("skipping 'gone'"));
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(elsewhere.path())
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("list")
+        .arg("--all-repos")
+        .arg("--dirty")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let dirty: Vec<_> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|wt| (wt["repo"].as_str().unwrap(), wt["name"].as_str().unwrap()))
+        .collect();
+    assert_eq!(dirty, vec![("web", "web-feature")]);
+
+    Ok(())
+}
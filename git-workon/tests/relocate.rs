@@ -0,0 +1,168 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn relocate_moves_root_and_relinks_worktrees() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+
+    let root = fixture.root()?.to_path_buf();
+    let dest = assert_fs::TempDir::new()?;
+    let new_root = dest.path().canonicalize()?.join("renamed");
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(root.join("feature"))
+        .arg("relocate")
+        .arg(&new_root)
+        .assert()
+        .success()
+        .stdout(format!("{}\n", new_root.join("feature").display()))
+        .stderr(predicate::str::contains("Moved workon root"));
+
+    assert!(!root.exists());
+    for name in ["main", "feature"] {
+        let wt = git2::Repository::open(new_root.join(name))?;
+        assert!(wt.is_worktree());
+        let gitdir = std::fs::read_to_string(wt.path().join("gitdir"))?;
+        assert!(gitdir.starts_with(&new_root.display().to_string()));
+    }
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(new_root.join("main"))
+        .arg("doctor")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("broken git link").not())
+        .stderr(predicate::str::contains("missing directory").not());
+
+    // Moving back restores the original layout (and lets the fixture clean up)
+    Command::cargo_bin("git-workon")?
+        .current_dir(new_root.join("main"))
+        .arg("relocate")
+        .arg(&root)
+        .assert()
+        .success();
+    assert!(root.join("feature").join(".git").is_file());
+    assert!(!new_root.exists());
+
+    Ok(())
+}
+
+#[test]
+fn relocate_updates_registered_repository() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    let root = fixture.root()?.to_path_buf();
+    let dest = assert_fs::TempDir::new()?;
+    let new_root = dest.path().canonicalize()?.join("renamed");
+    let config = assert_fs::TempDir::new()?;
+    let registry = config.path().join("workon").join("repos.toml");
+    std::fs::create_dir_all(registry.parent().unwrap())?;
+    std::fs::write(
+        &registry,
+        format!(
+            "[repos]\napi = \"{}\"\nweb = \"/src/web\"\n",
+            root.canonicalize()?.display()
+        ),
+    )?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(root.join("main"))
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("relocate")
+        .arg(&new_root)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Updated registered repository 'api'",
+        ));
+
+    assert_eq!(
+        std::fs::read_to_string(&registry)?,
+        format!(
+            "[repos]\napi = \"{}\"\nweb = \"/src/web\"\n",
+            new_root.display()
+        )
+    );
+
+    // Moving back lets the fixture clean up
+    Command::cargo_bin("git-workon")?
+        .current_dir(new_root.join("main"))
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("relocate")
+        .arg(&root)
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[test]
+fn relocate_dry_run_changes_nothing() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    let root = fixture.root()?.to_path_buf();
+    let dest = assert_fs::TempDir::new()?;
+    let new_root = dest.path().canonicalize()?.join("renamed");
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("relocate")
+        .arg("--dry-run")
+        .arg(&new_root)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would move workon root"))
+        .stdout(predicate::str::contains(
+            new_root.join("main").display().to_string(),
+        ));
+
+    assert!(root.join("main").is_dir());
+    assert!(!new_root.exists());
+
+    Ok(())
+}
+
+#[test]
+fn relocate_refuses_existing_target() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    let dest = assert_fs::TempDir::new()?;
+    std::fs::write(dest.path().join("occupied"), "")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("relocate")
+        .arg(dest.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("relocate")
+        .arg(fixture.root()?.join("nested"))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("into itself"));
+
+    assert!(fixture.root()?.join("main").is_dir());
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn repos_add_list_remove_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+    let root = fixture.root()?.canonicalize()?;
+
+    let config = assert_fs::TempDir::new()?;
+    let registry = config.path().join("workon").join("repos.toml");
+    std::fs::create_dir_all(registry.parent().unwrap())?;
+    std::fs::write(&registry, "# my repos\n\n[settings]\nkeep = \"me\"\n")?;
+
+    // From a worktree, the workon root is registered
+    Command::cargo_bin("git-workon")?
+        .current_dir(root.join("main"))
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("repos")
+        .arg("add")
+        .arg("--name")
+        .arg("api")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Registered 'api'"));
+
+    let contents = std::fs::read_to_string(&registry)?;
+    assert_eq!(
+        contents,
+        format!(
+            "# my repos\n\n[settings]\nkeep = \"me\"\n\n[repos]\napi = \"{}\"\n",
+            root.display()
+        )
+    );
+
+    let output = Command::cargo_bin("git-workon")?
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("repos")
+        .arg("list")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(
+        json,
+        serde_json::json!([{ "name": "api", "path": root.to_str(), "exists": true }])
+    );
+
+    // The name is taken by this repository; another one can't reuse it
+    let other = assert_fs::TempDir::new()?;
+    std::fs::write(
+        &registry,
+        format!(
+            "[repos]\napi = \"{}\"\n\n[settings]\nkeep = \"me\"\n",
+            other.path().display()
+        ),
+    )?;
+    Command::cargo_bin("git-workon")?
+        .current_dir(root.join("main"))
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("repos")
+        .arg("add")
+        .arg("--name")
+        .arg("api")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already registered as 'api'"));
+
+    Command::cargo_bin("git-workon")?
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("repos")
+        .arg("remove")
+        .arg("api")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Unregistered 'api'"));
+    assert_eq!(
+        std::fs::read_to_string(&registry)?,
+        "[repos]\n\n[settings]\nkeep = \"me\"\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn repos_add_reports_doctor_problems() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .build()?;
+    std::fs::remove_dir_all(fixture.root()?.join("feature"))?;
+
+    let config = assert_fs::TempDir::new()?;
+    Command::cargo_bin("git-workon")?
+        .current_dir(fixture.root()?.join("main"))
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("repos")
+        .arg("add")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Registered"))
+        .stderr(predicate::str::contains("feature: missing directory"));
+
+    Command::cargo_bin("git-workon")?
+        .env("XDG_CONFIG_HOME", config.path())
+        .arg("repos")
+        .arg("remove")
+        .arg("nope")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No registered repository named 'nope'",
+        ));
+
+    Ok(())
+}
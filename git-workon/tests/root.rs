@@ -0,0 +1,47 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn root_prints_workon_root_from_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    let root = fixture.root()?.path().canonicalize()?;
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(fixture.cwd()?.path())
+        .arg("root")
+        .output()?;
+    assert!(output.status.success());
+    let printed = std::path::PathBuf::from(String::from_utf8(output.stdout)?.trim_end());
+    assert_eq!(printed.canonicalize()?, root);
+
+    Ok(())
+}
+
+#[test]
+fn root_bare_prints_repository_path() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()?;
+
+    let bare = fixture.repo()?.commondir().canonicalize()?;
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("root")
+        .arg("--bare")
+        .output()?;
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(!stdout.trim_end().ends_with('/'));
+    assert_eq!(
+        std::path::PathBuf::from(stdout.trim_end()).canonicalize()?,
+        bare
+    );
+
+    Ok(())
+}
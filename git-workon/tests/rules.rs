@@ -0,0 +1,117 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+use std::fs;
+
+#[test]
+fn new_applies_matching_rule_base_and_hooks() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .config("workon.rule.hotfix/*.base", "develop")
+        .config(
+            "workon.rule.hotfix/*.postCreateHook",
+            "echo ruled > hook_output.log",
+        )
+        .build()?;
+
+    let repo = fixture.repo()?;
+    fixture.commit("develop").create("Develop only")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("hotfix/login")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Applying rule 'hotfix/*'"));
+
+    let develop = repo.revparse_single("develop")?.peel_to_commit()?.id();
+    let created = repo.revparse_single("hotfix/login")?.peel_to_commit()?.id();
+    assert_eq!(created, develop, "Should branch from the rule's base");
+    assert_eq!(
+        fs::read_to_string(fixture.root()?.join("hotfix/login/hook_output.log"))?.trim(),
+        "ruled"
+    );
+
+    // Names that don't match are left alone
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("feature")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Applying rule").not());
+    assert!(!fixture.root()?.join("feature/hook_output.log").exists());
+
+    Ok(())
+}
+
+#[test]
+fn new_explicit_base_overrides_rule() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .config("workon.rule.hotfix/*.base", "develop")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    fixture.commit("develop").create("Develop only")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("hotfix/login")
+        .arg("--base")
+        .arg("main")
+        .arg("--no-interactive")
+        .assert()
+        .success();
+
+    let main = repo.revparse_single("main")?.peel_to_commit()?.id();
+    let created = repo.revparse_single("hotfix/login")?.peel_to_commit()?.id();
+    assert_eq!(created, main, "--base should win over the rule's base");
+
+    Ok(())
+}
+
+#[test]
+fn prune_skips_branches_protected_by_rule() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("spike-cache")
+        .worktree("feature")
+        .config("workon.rule.spike-*.protect", "true")
+        .build()?;
+
+    let repo = fixture.repo()?;
+    repo.find_reference("refs/heads/spike-cache")?.delete()?;
+    repo.find_reference("refs/heads/feature")?.delete()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("prune")
+        .arg("--yes")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("spike-cache"))
+        .stderr(predicate::str::contains("Pruned 1 worktree"));
+
+    fixture
+        .root()?
+        .child("spike-cache")
+        .assert(predicate::path::is_dir());
+    fixture
+        .root()?
+        .child("feature")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn run_without_a_task_lists_tasks() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.task.setup", "npm ci")
+        .config("workon.task.lint", "cargo clippy")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("run")
+        .assert()
+        .success()
+        .stdout("lint   cargo clippy\nsetup  npm ci\n");
+
+    Ok(())
+}
+
+#[test]
+fn run_ignores_metadata_of_a_worktree_named_task() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("task")
+        .config("workon.task.setup", "npm ci")
+        .config("workon.task.x.label", "stray")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["label", "add", "task", "backend"])
+        .assert()
+        .success();
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["note", "task", "wip"])
+        .assert()
+        .success();
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("run")
+        .assert()
+        .success()
+        .stdout("setup  npm ci\n");
+
+    Ok(())
+}
+
+#[test]
+fn run_task_in_current_worktree_with_hook_environment() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config(
+            "workon.task.whoami",
+            "echo \"$WORKON_BRANCH_NAME\" > task.out",
+        )
+        .build()?;
+
+    let main = fixture.root()?.join("main");
+    Command::cargo_bin("git-workon")?
+        .current_dir(&main)
+        .args(["run", "WhoAmI"])
+        .assert()
+        .success();
+
+    assert_eq!(std::fs::read_to_string(main.join("task.out"))?, "main\n");
+
+    Ok(())
+}
+
+#[test]
+fn run_task_in_several_worktrees_reports_failures() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .worktree("other")
+        .config("workon.task.check", "test -f marker && touch checked")
+        .build()?;
+
+    let root = fixture.root()?;
+    std::fs::write(root.join("main").join("marker"), "")?;
+    std::fs::write(root.join("other").join("marker"), "")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["run", "check", "main", "feature", "other"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Task 'check' failed in 1 of 3 worktrees: feature",
+        ));
+
+    // The worktrees after the failing one still ran
+    assert!(root.join("main").join("checked").exists());
+    assert!(root.join("other").join("checked").exists());
+
+    Ok(())
+}
+
+#[test]
+fn run_unknown_task_suggests_a_close_one() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.task.setup", "npm ci")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["run", "setp", "main"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No task named 'setp'"))
+        .stderr(predicate::str::contains("Did you mean 'setup'?"));
+
+    Ok(())
+}
+
+#[test]
+fn run_task_that_cannot_start_fails_per_worktree() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .config("workon.task.check", "true")
+        .config("workon.hookShell", "no-such-shell-for-workon")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .args(["run", "check", "main", "feature"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Failed to start").count(2))
+        .stderr(predicate::str::contains("failed in 2 of 2 worktrees"));
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+fn upstream_fixture() -> Result<Fixture, Box<dyn std::error::Error>> {
+    FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .build()
+}
+
+fn fixture_with_origin(upstream: &Fixture) -> Result<Fixture, Box<dyn std::error::Error>> {
+    FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .remote(
+            "origin",
+            upstream.bare_path()?.to_string_lossy().to_string(),
+        )
+        .worktree("main")
+        .worktree("feature")
+        .build()
+}
+
+#[test]
+fn set_upstream_push_publishes_and_tracks() -> Result<(), Box<dyn std::error::Error>> {
+    let upstream = upstream_fixture()?;
+    let fixture = fixture_with_origin(&upstream)?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("set-upstream")
+        .arg("feature")
+        .arg("--push")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "'feature' now tracks origin/feature",
+        ));
+
+    assert!(upstream
+        .repo()?
+        .find_reference("refs/heads/feature")
+        .is_ok());
+    let repo = fixture.repo()?;
+    let branch = repo.find_branch("feature", git2::BranchType::Local)?;
+    assert_eq!(branch.upstream()?.name()?, Some("origin/feature"));
+
+    Ok(())
+}
+
+#[test]
+fn set_upstream_tracks_existing_remote_branch() -> Result<(), Box<dyn std::error::Error>> {
+    let upstream = upstream_fixture()?;
+    let upstream_repo = upstream.repo()?;
+    let head = upstream_repo.head()?.peel_to_commit()?;
+    upstream_repo.branch("shared", &head, false)?;
+    let fixture = fixture_with_origin(&upstream)?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("set-upstream")
+        .arg("feature")
+        .arg("origin/shared")
+        .assert()
+        .success();
+
+    let repo = fixture.repo()?;
+    let branch = repo.find_branch("feature", git2::BranchType::Local)?;
+    assert_eq!(branch.upstream()?.name()?, Some("origin/shared"));
+    assert!(upstream_repo.find_reference("refs/heads/feature").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn set_upstream_without_remote_branch_fails() -> Result<(), Box<dyn std::error::Error>> {
+    let upstream = upstream_fixture()?;
+    let fixture = fixture_with_origin(&upstream)?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("set-upstream")
+        .arg("feature")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Remote branch 'origin/feature' doesn't exist",
+        ));
+
+    let repo = fixture.repo()?;
+    let branch = repo.find_branch("feature", git2::BranchType::Local)?;
+    assert!(branch.upstream().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn stats_summarizes_worktree_states() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .config("workon.hiddenWorktree", "feature")
+        .build()?;
+    std::fs::write(fixture.root()?.join("feature").join("wip.txt"), "wip")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("stats")
+        .assert()
+        .success()
+        .stdout("2 worktrees: 1 dirty, 0 ahead, 0 behind, 0 gone upstream, 1 hidden\n");
+
+    Ok(())
+}
+
+#[test]
+fn stats_usage_reports_visits_and_never_revisited() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("feature")
+        .worktree("spike")
+        .build()?;
+
+    let log = fixture.repo()?.commondir().join("workon").join("visits");
+    std::fs::create_dir_all(log.parent().unwrap())?;
+    std::fs::write(
+        &log,
+        "1000\tcreate\tfeature\n\
+         1000\tcreate\tspike\n\
+         1000\tcreate\told\n\
+         87400\tvisit\tfeature\n\
+         173800\tvisit\tfeature\n\
+         173800\tvisit\told\n",
+    )?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("stats")
+        .arg("--usage")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Worktree usage (6 events since"))
+        .stdout(predicate::str::is_match(
+            r"feature\s+2 visits\s+every 1 day\s+last visited",
+        )?)
+        .stdout(predicate::str::is_match(r"old\s+1 visit .*\(removed\)")?)
+        .stdout(predicate::str::is_match(r"spike\s+0 visits\s+created")?)
+        .stdout(predicate::str::contains(
+            "Never revisited after creation: spike\n",
+        ));
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("stats")
+        .arg("--usage")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["events"], 6);
+    assert_eq!(json["never_revisited"], serde_json::json!(["spike"]));
+    let feature = &json["worktrees"][0];
+    assert_eq!(feature["name"], "feature");
+    assert_eq!(feature["visits"], 2);
+    assert_eq!(feature["average_interval_seconds"], 86400);
+    assert_eq!(feature["exists"], true);
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+use std::fs;
+
+#[test]
+fn new_template_applies_base_copy_labels_and_hooks() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .worktree("develop")
+        .config("workon.template.hotfix.base", "develop")
+        .config("workon.template.hotfix.copyPattern", "*.txt")
+        .config("workon.template.hotfix.label", "hotfix")
+        .config(
+            "workon.template.hotfix.postCreateHook",
+            "echo templated > hook_output.log",
+        )
+        .build()?;
+
+    fs::write(fixture.root()?.join("develop/base.txt"), "base")?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("fix-login")
+        .arg("--template")
+        .arg("hotfix")
+        .arg("--no-interactive")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Applied template 'hotfix'"));
+
+    let worktree = fixture.root()?.join("fix-login");
+    assert!(
+        worktree.join("base.txt").exists(),
+        "Template copy patterns should turn copying on, from the template's base"
+    );
+    assert_eq!(
+        fs::read_to_string(worktree.join("hook_output.log"))?.trim(),
+        "templated"
+    );
+
+    let repo = fixture.repo()?;
+    let config = repo.config()?;
+    assert_eq!(
+        config.get_string("workon-worktree.fix-login.label")?,
+        "hotfix"
+    );
+    assert_eq!(
+        config.get_string("branch.fix-login.merge").ok(),
+        None,
+        "A local base shouldn't set an upstream"
+    );
+    let develop = repo.revparse_single("develop")?.peel_to_commit()?.id();
+    let created = repo.revparse_single("fix-login")?.peel_to_commit()?.id();
+    assert_eq!(created, develop, "Should branch from the template's base");
+
+    Ok(())
+}
+
+#[test]
+fn new_unknown_template_suggests_closest() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.template.hotfix.label", "hotfix")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("new")
+        .arg("fix-login")
+        .arg("--template")
+        .arg("hotfx")
+        .arg("--no-interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("hotfx"))
+        .stderr(predicate::str::contains("hotfix"));
+
+    fixture
+        .root()?
+        .child("fix-login")
+        .assert(predicate::path::missing());
+
+    Ok(())
+}
+
+#[test]
+fn templates_lists_defined_templates() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("main")
+        .config("workon.template.hotfix.base", "release/current")
+        .config("workon.template.hotfix.sparse", "services/api")
+        .config("workon.template.spike.label", "experiment")
+        .build()?;
+
+    Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("templates")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("hotfix"))
+        .stdout(predicate::str::contains("release/current"))
+        .stdout(predicate::str::contains("spike"));
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("templates")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json[0]["name"], "hotfix");
+    assert_eq!(json[0]["base"], "release/current");
+    assert_eq!(json[0]["sparse"], serde_json::json!(["services/api"]));
+    assert_eq!(json[1]["name"], "spike");
+    assert_eq!(json[1]["labels"], serde_json::json!(["experiment"]));
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+#![cfg(unix)]
+
+use std::os::unix::fs::PermissionsExt;
+
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn version_json_reports_capabilities() -> Result<(), Box<dyn std::error::Error>> {
+    let output = Command::cargo_bin("git-workon")?
+        .arg("version")
+        .arg("--json")
+        .env("PATH", "")
+        .output()?;
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+    assert!(json["libgit2"].is_string());
+    assert_eq!(json["config_schema_version"], 2);
+    for feature in ["github-api", "gix", "daemon"] {
+        assert!(json["features"][feature].is_boolean(), "{}", feature);
+    }
+    for tool in ["gh", "fzf", "tmux"] {
+        assert_eq!(json["tools"][tool]["available"], false, "{}", tool);
+        assert!(json["tools"][tool]["path"].is_null());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn version_finds_tools_on_path() -> Result<(), Box<dyn std::error::Error>> {
+    let bin = assert_fs::TempDir::new()?;
+    let gh = bin.path().join("gh");
+    std::fs::write(&gh, "#!/bin/sh\necho 'gh version 2.0.0'\n")?;
+    std::fs::set_permissions(&gh, std::fs::Permissions::from_mode(0o755))?;
+
+    Command::cargo_bin("git-workon")?
+        .arg("version")
+        .env("PATH", bin.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "git-workon {}",
+            env!("CARGO_PKG_VERSION")
+        )))
+        .stdout(predicate::str::contains("gh version 2.0.0"))
+        .stdout(predicate::str::is_match(r"fzf\s+not found")?);
+
+    Ok(())
+}
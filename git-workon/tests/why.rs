@@ -0,0 +1,132 @@
+use assert_cmd::Command;
+use git_workon_fixture::prelude::*;
+
+#[test]
+fn why_reports_merged_worktree_as_prunable() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .build()?;
+
+    let feature_commit_oid = fixture
+        .commit("feature")
+        .file("feature.txt", "feature")
+        .create("Feature commit")?;
+
+    // Fast-forward main to include the feature commit (simulating merge)
+    let repo = fixture.repo()?;
+    repo.find_branch("main", git2::BranchType::Local)?
+        .get_mut()
+        .set_target(feature_commit_oid, "Fast-forward to feature")?;
+
+    let mut why_cmd = Command::cargo_bin("git-workon")?;
+    why_cmd
+        .current_dir(&fixture)
+        .arg("why")
+        .arg("feature")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("branch: exists"))
+        .stderr(predicate::str::contains("merged into main: yes"))
+        .stderr(predicate::str::contains("uncommitted changes: no"))
+        .stderr(predicate::str::is_match(
+            r"git workon prune --merged\s+prune \(merged into main\)",
+        )?)
+        .stderr(predicate::str::is_match(
+            r"git workon prune\s+keep \(no prune criteria matched\)",
+        )?);
+
+    // Nothing was pruned
+    fixture.cwd()?.assert(predicate::path::is_dir());
+
+    Ok(())
+}
+
+#[test]
+fn why_explains_dirty_worktree_is_skipped() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .build()?;
+
+    std::fs::write(fixture.cwd()?.join("test.txt"), "test content")?;
+    fixture
+        .repo()?
+        .find_reference("refs/heads/feature")?
+        .delete()?;
+
+    let mut why_cmd = Command::cargo_bin("git-workon")?;
+    why_cmd
+        .current_dir(&fixture)
+        .arg("why")
+        .arg("feature")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("branch: deleted"))
+        .stderr(predicate::str::contains("uncommitted changes: yes"))
+        .stderr(predicate::str::is_match(
+            r"git workon prune\s+skip \(has uncommitted changes",
+        )?);
+
+    Ok(())
+}
+
+#[test]
+fn why_json_reports_protected_pattern() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("develop")
+        .config("workon.pruneProtectedBranches", "staging")
+        .config("workon.pruneProtectedBranches", "develop")
+        .build()?;
+
+    let output = Command::cargo_bin("git-workon")?
+        .current_dir(&fixture)
+        .arg("why")
+        .arg("develop")
+        .arg("--json")
+        .output()?;
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(json["branch"], "develop");
+    assert_eq!(json["criteria"]["protected_by"], "develop");
+    assert_eq!(json["criteria"]["locked"], false);
+
+    let explicit = json["verdicts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|v| v["command"] == "git workon prune develop")
+        .unwrap();
+    assert_eq!(explicit["action"], "skip");
+    assert_eq!(
+        explicit["reason"],
+        "protected by workon.pruneProtectedBranches"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn why_unknown_worktree_suggests_similar_name() -> Result<(), Box<dyn std::error::Error>> {
+    let fixture = FixtureBuilder::new()
+        .bare(true)
+        .default_branch("main")
+        .worktree("feature")
+        .build()?;
+
+    let mut why_cmd = Command::cargo_bin("git-workon")?;
+    why_cmd
+        .current_dir(&fixture)
+        .arg("why")
+        .arg("featrue")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Did you mean 'feature'?"));
+
+    Ok(())
+}